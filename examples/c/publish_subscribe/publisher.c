@@ -0,0 +1,85 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/iceoryx2.h"
+#include "transmission_data.h"
+
+#include <stdio.h>
+#include <unistd.h>
+
+int main(void) {
+    int ret_val = -1;
+    iox2_node_h node = NULL;
+    iox2_service_name_h service_name = NULL;
+    iox2_port_factory_pub_sub_h service = NULL;
+    iox2_publisher_h publisher = NULL;
+
+    if (iox2_node_new(iox2_service_type_e_IPC, &node) != IOX2_OK) {
+        printf("Could not create node!\n");
+        goto end;
+    }
+
+    if (iox2_service_name_new("My/Funk/ServiceName", &service_name) != IOX2_OK) {
+        printf("Unable to create service name!\n");
+        goto drop_node;
+    }
+
+    if (iox2_node_pub_sub_open_or_create(node, service_name, &TRANSMISSION_DATA_TYPE_DETAILS, &service)
+        != IOX2_OK) {
+        printf("Unable to create service!\n");
+        goto drop_service_name;
+    }
+
+    if (iox2_port_factory_pub_sub_publisher_create(service, 1, &publisher) != IOX2_OK) {
+        printf("Unable to create publisher!\n");
+        goto drop_service;
+    }
+
+    for (int32_t counter = 1; counter <= 10; ++counter) {
+        iox2_sample_mut_h sample = NULL;
+        if (iox2_publisher_loan(publisher, 1, &sample) != IOX2_OK) {
+            printf("Failed to loan sample\n");
+            goto drop_publisher;
+        }
+
+        TransmissionData* payload = NULL;
+        iox2_sample_mut_payload_mut(sample, (void**) &payload, NULL);
+        payload->x = counter;
+        payload->y = counter * 3;
+        payload->funky = counter * 812.12;
+
+        if (iox2_sample_mut_send(sample, NULL) != IOX2_OK) {
+            printf("Failed to send sample\n");
+            goto drop_publisher;
+        }
+
+        printf("Send sample %d ...\n", counter);
+        sleep(1);
+    }
+
+    ret_val = 0;
+
+drop_publisher:
+    iox2_publisher_drop(publisher);
+
+drop_service:
+    iox2_port_factory_pub_sub_drop(service);
+
+drop_service_name:
+    iox2_service_name_drop(service_name);
+
+drop_node:
+    iox2_node_drop(node);
+
+end:
+    return ret_val;
+}
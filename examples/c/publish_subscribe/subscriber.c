@@ -0,0 +1,85 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/iceoryx2.h"
+#include "transmission_data.h"
+
+#include <stdio.h>
+#include <unistd.h>
+
+int main(void) {
+    int ret_val = -1;
+    iox2_node_h node = NULL;
+    iox2_service_name_h service_name = NULL;
+    iox2_port_factory_pub_sub_h service = NULL;
+    iox2_subscriber_h subscriber = NULL;
+
+    if (iox2_node_new(iox2_service_type_e_IPC, &node) != IOX2_OK) {
+        printf("Could not create node!\n");
+        goto end;
+    }
+
+    if (iox2_service_name_new("My/Funk/ServiceName", &service_name) != IOX2_OK) {
+        printf("Unable to create service name!\n");
+        goto drop_node;
+    }
+
+    if (iox2_node_pub_sub_open_or_create(node, service_name, &TRANSMISSION_DATA_TYPE_DETAILS, &service)
+        != IOX2_OK) {
+        printf("Unable to create service!\n");
+        goto drop_service_name;
+    }
+
+    if (iox2_port_factory_pub_sub_subscriber_create(service, &subscriber) != IOX2_OK) {
+        printf("Unable to create subscriber!\n");
+        goto drop_service;
+    }
+
+    for (int cycle = 0; cycle < 10; ++cycle) {
+        iox2_sample_h sample = NULL;
+        do {
+            if (iox2_subscriber_receive(subscriber, &sample) != IOX2_OK) {
+                printf("Failed to receive sample\n");
+                goto drop_subscriber;
+            }
+
+            if (sample != NULL) {
+                const TransmissionData* payload = NULL;
+                iox2_sample_payload(sample, (const void**) &payload, NULL);
+                printf("received: TransmissionData { x: %d, y: %d, funky: %.2f }\n",
+                       payload->x,
+                       payload->y,
+                       payload->funky);
+                iox2_sample_drop(sample);
+            }
+        } while (sample != NULL);
+
+        sleep(1);
+    }
+
+    ret_val = 0;
+
+drop_subscriber:
+    iox2_subscriber_drop(subscriber);
+
+drop_service:
+    iox2_port_factory_pub_sub_drop(service);
+
+drop_service_name:
+    iox2_service_name_drop(service_name);
+
+drop_node:
+    iox2_node_drop(node);
+
+end:
+    return ret_val;
+}
@@ -0,0 +1,37 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_EXAMPLES_TRANSMISSION_DATA_H
+#define IOX2_EXAMPLES_TRANSMISSION_DATA_H
+
+#include "iox2/iceoryx2.h"
+
+#include <stdalign.h>
+#include <stdint.h>
+
+// C counterpart of the Rust type `transmission_data::TransmissionData` of the
+// Rust examples. The type details must match so that C and Rust processes can
+// communicate via the same service.
+typedef struct {
+    int32_t x;
+    int32_t y;
+    double funky;
+} TransmissionData;
+
+static const iox2_type_details_t TRANSMISSION_DATA_TYPE_DETAILS = {
+    .variant = iox2_type_variant_e_FIXED_SIZE,
+    .type_name = "transmission_data::TransmissionData",
+    .size = sizeof(TransmissionData),
+    .alignment = alignof(TransmissionData),
+};
+
+#endif
@@ -0,0 +1,42 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::async_subscriber::AsyncSubscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::zero_copy_socket;
+use transmission_data::TransmissionData;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/ServiceName")?;
+
+    let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe()
+        .typed::<TransmissionData>()
+        .open_or_create()?;
+
+    let event = node
+        .service_builder(&service_name)
+        .event()
+        .open_or_create()?;
+
+    let subscriber =
+        AsyncSubscriber::new(service.subscriber().create()?, event.listener().create()?)?;
+
+    loop {
+        let sample = subscriber.receive().await?;
+        println!("received: {:?}", *sample);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/ServiceName")?;
+
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .blackboard::<u64>()
+        .add::<u64>(0, 0)
+        .add::<f32>(5, 0.0)
+        .create()?;
+
+    println!("Blackboard created.\n");
+
+    let writer = service.writer().create()?;
+
+    let entry_handle_mut_key_0 = writer.entry::<u64>(&0)?;
+    let entry_handle_mut_key_5 = writer.entry::<f32>(&5)?;
+
+    let mut counter = 0;
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+
+        entry_handle_mut_key_0.update_with_copy(counter);
+        println!("Write new value for key 0: {}", counter);
+
+        let value = counter as f32 * 1.5;
+        entry_handle_mut_key_5.update_with_copy(value);
+        println!("Write new value for key 5: {}\n", value);
+    }
+
+    println!("exit");
+
+    Ok(())
+}
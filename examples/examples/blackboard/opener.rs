@@ -0,0 +1,42 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/ServiceName")?;
+
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .blackboard::<u64>()
+        .open()?;
+
+    let reader = service.reader().create()?;
+
+    let entry_handle_key_0 = reader.entry::<u64>(&0)?;
+    let entry_handle_key_5 = reader.entry::<f32>(&5)?;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        println!("read values:");
+        println!("key: 0, value: {}", entry_handle_key_0.get());
+        println!("key: 5, value: {}\n", entry_handle_key_5.get());
+    }
+
+    println!("exit");
+
+    Ok(())
+}
@@ -17,14 +17,14 @@ use iceoryx2_bb_container::{
     byte_string::FixedSizeByteString, queue::FixedSizeQueue, vec::FixedSizeVec,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexData {
     name: FixedSizeByteString<4>,
     data: FixedSizeVec<u64, 4>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexDataType {
     plain_old_data: u64,
@@ -39,7 +39,10 @@ const CYCLE_TIME: Duration = Duration::from_secs(1);
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let service_name = ServiceName::new("Complex Data Type Example")?;
 
-    let service = zero_copy::Service::new(&service_name)
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
         .publish_subscribe()
         .max_publishers(16)
         .max_subscribers(16)
@@ -18,9 +18,9 @@ const CYCLE_TIME: Duration = Duration::from_secs(1);
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let event_name = ServiceName::new("MyEventName")?;
 
-    let event = zero_copy::Service::new(&event_name)
-        .event()
-        .open_or_create()?;
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let event = node.service_builder(&event_name).event().open_or_create()?;
 
     let listener = event.listener().create()?;
 
@@ -0,0 +1,49 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use iceoryx2::service::zero_copy_socket;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+
+    let fast_event = node
+        .service_builder(&ServiceName::new("FastEvent")?)
+        .event()
+        .open_or_create()?;
+    let slow_event = node
+        .service_builder(&ServiceName::new("SlowEvent")?)
+        .event()
+        .open_or_create()?;
+
+    let fast_notifier = fast_event.notifier().create()?;
+    let slow_notifier = slow_event.notifier().create()?;
+
+    let mut counter: usize = 0;
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+        fast_notifier.notify_with_custom_event_id(EventId::new(counter))?;
+        println!("Trigger fast event with id {} ...", counter);
+
+        if counter % 3 == 0 {
+            slow_notifier.notify_with_custom_event_id(EventId::new(counter))?;
+            println!("Trigger slow event with id {} ...", counter);
+        }
+    }
+
+    println!("exit ... ");
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::port::waitset::{WaitSetAttachmentId, WaitSetBuilder, WaitSetWaitError};
+use iceoryx2::prelude::*;
+use iceoryx2::service::zero_copy_socket;
+
+const SLOW_EVENT_DEADLINE: Duration = Duration::from_secs(2);
+const STATUS_INTERVAL: Duration = Duration::from_secs(5);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+
+    let fast_event = node
+        .service_builder(&ServiceName::new("FastEvent")?)
+        .event()
+        .open_or_create()?;
+    let slow_event = node
+        .service_builder(&ServiceName::new("SlowEvent")?)
+        .event()
+        .open_or_create()?;
+
+    let fast_listener = fast_event.listener().create()?;
+    let slow_listener = slow_event.listener().create()?;
+
+    let waitset = WaitSetBuilder::new().create::<zero_copy_socket::Service>()?;
+    let fast_guard = waitset.attach_notification(&fast_listener)?;
+    let slow_guard = waitset.attach_deadline(&slow_listener, SLOW_EVENT_DEADLINE)?;
+    let status_guard = waitset.attach_interval(STATUS_INTERVAL)?;
+
+    let mut number_of_events: usize = 0;
+    let mut on_event = |attachment_id: WaitSetAttachmentId<zero_copy_socket::Service>| {
+        if attachment_id.has_event_from(&fast_guard) {
+            fast_listener
+                .try_wait_all(|event_id| {
+                    number_of_events += 1;
+                    println!("fast event was triggered with id: {:?}", event_id);
+                })
+                .ok();
+        } else if attachment_id.has_event_from(&slow_guard) {
+            slow_listener
+                .try_wait_all(|event_id| {
+                    number_of_events += 1;
+                    println!("slow event was triggered with id: {:?}", event_id);
+                })
+                .ok();
+        } else if attachment_id.has_missed_deadline(&slow_guard) {
+            println!(
+                "slow event missed its deadline of {:?}",
+                SLOW_EVENT_DEADLINE
+            );
+        } else if attachment_id.has_event_from(&status_guard) {
+            println!("received {} events so far", number_of_events);
+        }
+    };
+
+    while let Iox2Event::Tick = Iox2::wait(Duration::ZERO) {
+        match waitset.blocking_wait(&mut on_event) {
+            Ok(()) | Err(WaitSetWaitError::Interrupt) => (),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -19,7 +19,10 @@ const CYCLE_TIME: Duration = Duration::from_secs(1);
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let service_name = ServiceName::new("My/Funk/ServiceName")?;
 
-    let service = zero_copy::Service::new(&service_name)
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
         .publish_subscribe()
         .typed::<TransmissionData>()
         .open_or_create()?;
@@ -0,0 +1,50 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use iceoryx2_cal::serialize::toml::Toml;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Diagnostics {
+    component: String,
+    counter: u32,
+    settings: HashMap<String, String>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("Service With Serialized Data")?;
+
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe()
+        .serialized::<Diagnostics, Toml>()
+        .open_or_create()?;
+
+    let subscriber = service.subscriber().create()?;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        while let Some(diagnostics) = subscriber.receive()? {
+            println!("received: {:?}", diagnostics);
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
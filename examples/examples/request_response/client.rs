@@ -0,0 +1,54 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/ServiceName")?;
+
+    let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .request_response()
+        .typed::<u64, TransmissionData>()
+        .open_or_create()?;
+
+    let client = service.client().create()?;
+
+    let mut counter: u64 = 0;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        while let Some(response) = client.receive()? {
+            println!(
+                "received response for request {}: {:?}",
+                response.header().request_id(),
+                *response
+            );
+        }
+
+        counter += 1;
+        let request = client.loan_uninit()?;
+        let request = request.write_payload(counter);
+        request.send()?;
+
+        println!("Send request {} ...", counter);
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
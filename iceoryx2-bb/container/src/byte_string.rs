@@ -37,6 +37,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
 
 /// Returns the length of a string
@@ -80,6 +81,7 @@ pub struct FixedSizeByteString<const CAPACITY: usize> {
 }
 
 unsafe impl<const CAPACITY: usize> Send for FixedSizeByteString<CAPACITY> {}
+unsafe impl<const CAPACITY: usize> ZeroCopySend for FixedSizeByteString<CAPACITY> {}
 
 impl<const CAPACITY: usize, const CAPACITY_OTHER: usize>
     PartialOrd<FixedSizeByteString<CAPACITY_OTHER>> for FixedSizeByteString<CAPACITY>
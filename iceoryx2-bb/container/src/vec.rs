@@ -88,6 +88,7 @@ use iceoryx2_bb_elementary::{
     pointer_trait::PointerTrait,
     relocatable_container::RelocatableContainer,
     relocatable_ptr::RelocatablePointer,
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
 
@@ -380,6 +381,7 @@ impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeVec<T, CAPACITY> {
 }
 
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeVec<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {}
 
 impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
     /// Creates a new vector.
@@ -0,0 +1,197 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contains derive macros for the traits of the building blocks.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields,
+    Type,
+};
+
+/// Members that refer to the address space of the process that created them.
+const PROCESS_LOCAL_TYPES: &[&str] = &[
+    "Arc", "Box", "Cell", "HashMap", "Rc", "RefCell", "String", "Vec", "VecDeque",
+];
+
+/// Implements `iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend` for a struct or enum.
+/// The crate `iceoryx2-bb-elementary` must be a dependency of the crate that uses the macro.
+///
+/// The macro verifies at compile time that
+///
+///  * the type has a `#[repr(C)]` or `#[repr(transparent)]` layout, enums may additionally use
+///    a primitive representation like `#[repr(u8)]`,
+///  * no member is a pointer, a reference or a heap allocated type like `Box`, `String` or `Vec`,
+///  * every member implements `ZeroCopySend`.
+///
+/// Generic type parameters are required to implement `ZeroCopySend`.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[repr(C)]
+/// struct TransmissionData {
+///     x: i32,
+///     y: i32,
+///     funky: [f64; 4],
+/// }
+/// ```
+///
+/// A heap allocated member fails to compile:
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct Message {
+///     text: String,
+/// }
+/// ```
+///
+/// A type without a defined layout fails to compile:
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// struct Message {
+///     value: u64,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend)]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match zero_copy_send_impl(input) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn zero_copy_send_impl(mut input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let is_enum = matches!(input.data, Data::Enum(_));
+    verify_repr(&input.attrs, is_enum, input.ident.span())?;
+
+    let fields: Vec<&Fields> = match &input.data {
+        Data::Struct(data) => vec![&data.fields],
+        Data::Enum(data) => data.variants.iter().map(|v| &v.fields).collect(),
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "ZeroCopySend cannot be derived for unions",
+            ))
+        }
+    };
+
+    let field_types: Vec<&Type> = fields
+        .iter()
+        .flat_map(|f| f.iter())
+        .map(|f| &f.ty)
+        .collect();
+
+    for ty in &field_types {
+        verify_field_type(ty)?;
+    }
+
+    let trait_path: syn::Path =
+        parse_quote!(::iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend);
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#trait_path));
+    }
+
+    let where_clause = input.generics.make_where_clause();
+    for ty in field_types {
+        where_clause.predicates.push(parse_quote!(#ty: #trait_path));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics #trait_path for #name #ty_generics #where_clause {}
+    })
+}
+
+fn verify_repr(attrs: &[Attribute], is_enum: bool, span: Span) -> Result<(), Error> {
+    const PRIMITIVE_REPRS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+    ];
+
+    let mut has_defined_layout = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C")
+                || meta.path.is_ident("transparent")
+                || (is_enum && PRIMITIVE_REPRS.iter().any(|r| meta.path.is_ident(r)))
+            {
+                has_defined_layout = true;
+            }
+
+            // consume arguments like in `#[repr(align(8))]`
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    if !has_defined_layout {
+        return Err(Error::new(
+            span,
+            "ZeroCopySend requires a defined memory layout, add #[repr(C)] or #[repr(transparent)]",
+        ));
+    }
+
+    Ok(())
+}
+
+fn verify_field_type(ty: &Type) -> Result<(), Error> {
+    match ty {
+        Type::Ptr(_) => Err(Error::new(
+            ty.span(),
+            "ZeroCopySend types cannot contain pointers since they refer to the address space of the creating process",
+        )),
+        Type::Reference(_) => Err(Error::new(
+            ty.span(),
+            "ZeroCopySend types cannot contain references since they refer to the address space of the creating process",
+        )),
+        Type::Array(array) => verify_field_type(&array.elem),
+        Type::Paren(paren) => verify_field_type(&paren.elem),
+        Type::Group(group) => verify_field_type(&group.elem),
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment)
+                if PROCESS_LOCAL_TYPES
+                    .iter()
+                    .any(|local_type| segment.ident == local_type) =>
+            {
+                Err(Error::new(
+                    ty.span(),
+                    format!(
+                        "ZeroCopySend types cannot contain a {} since its data is stored in the heap of the creating process",
+                        segment.ident
+                    ),
+                ))
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
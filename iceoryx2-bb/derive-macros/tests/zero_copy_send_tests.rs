@@ -0,0 +1,127 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::marker::PhantomData;
+
+use iceoryx2_bb_container::{
+    byte_string::FixedSizeByteString, queue::FixedSizeQueue, vec::FixedSizeVec,
+};
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+fn verify_zero_copy_send<T: ZeroCopySend + ?Sized>(_: PhantomData<T>) {}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct NamedFields {
+    _a: u8,
+    _b: [f64; 3],
+    _c: bool,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct UnnamedFields(u64, [i32; 4]);
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct Unit;
+
+#[derive(ZeroCopySend)]
+#[repr(transparent)]
+struct Transparent(u32);
+
+#[derive(ZeroCopySend)]
+#[repr(C, align(64))]
+struct Aligned {
+    _value: u32,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct Generic<T, const N: usize> {
+    _data: [T; N],
+    _marker: PhantomData<T>,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct Nested {
+    _named: NamedFields,
+    _generic: Generic<u16, 8>,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+struct WithContainers {
+    _vec: FixedSizeVec<u64, 8>,
+    _queue: FixedSizeQueue<NamedFields, 4>,
+    _string: FixedSizeByteString<32>,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Primitive {
+    A,
+    B(u32),
+    C { value: i64 },
+}
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+#[allow(dead_code)]
+enum CEnum {
+    A,
+    B,
+}
+
+#[test]
+fn zero_copy_send_is_implemented_for_primitives_and_arrays() {
+    verify_zero_copy_send(PhantomData::<u8>);
+    verify_zero_copy_send(PhantomData::<i128>);
+    verify_zero_copy_send(PhantomData::<f64>);
+    verify_zero_copy_send(PhantomData::<bool>);
+    verify_zero_copy_send(PhantomData::<[u32; 12]>);
+    verify_zero_copy_send(PhantomData::<[u32]>);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_structs() {
+    verify_zero_copy_send(PhantomData::<NamedFields>);
+    verify_zero_copy_send(PhantomData::<UnnamedFields>);
+    verify_zero_copy_send(PhantomData::<Unit>);
+    verify_zero_copy_send(PhantomData::<Transparent>);
+    verify_zero_copy_send(PhantomData::<Aligned>);
+    verify_zero_copy_send(PhantomData::<Nested>);
+    verify_zero_copy_send(PhantomData::<[Nested]>);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_generic_structs() {
+    verify_zero_copy_send(PhantomData::<Generic<u64, 4>>);
+    verify_zero_copy_send(PhantomData::<Generic<NamedFields, 2>>);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_enums() {
+    verify_zero_copy_send(PhantomData::<Primitive>);
+    verify_zero_copy_send(PhantomData::<CEnum>);
+}
+
+#[test]
+fn zero_copy_send_is_implemented_for_fixed_size_containers() {
+    verify_zero_copy_send(PhantomData::<FixedSizeVec<u64, 8>>);
+    verify_zero_copy_send(PhantomData::<FixedSizeQueue<u64, 8>>);
+    verify_zero_copy_send(PhantomData::<FixedSizeByteString<8>>);
+    verify_zero_copy_send(PhantomData::<WithContainers>);
+}
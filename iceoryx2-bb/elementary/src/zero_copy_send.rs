@@ -0,0 +1,90 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Marker trait for types that can be placed into shared memory and be consumed by another
+//! process that maps the memory at a different address.
+//!
+//! Implement it with the derive macro `#[derive(ZeroCopySend)]` from the
+//! `iceoryx2-bb-derive-macros` crate, it verifies the layout and all members at compile time.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+//!
+//! fn is_zero_copy_send<T: ZeroCopySend + ?Sized>() {}
+//!
+//! is_zero_copy_send::<u64>();
+//! is_zero_copy_send::<[f32; 16]>();
+//! is_zero_copy_send::<[u8]>();
+//! ```
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize,
+};
+
+/// Types that can be sent via zero-copy between processes.
+///
+/// # Safety
+///
+///  * The type must have a defined layout, `#[repr(C)]` or `#[repr(transparent)]`, so that
+///    processes compiled independently interpret the memory in the same way.
+///  * The type must be self-contained. It shall not contain pointers, references or heap
+///    allocated members like `Box`, `String` or `Vec` since they refer to the address space of
+///    the process that created them.
+///  * All members must implement [`ZeroCopySend`] as well.
+pub unsafe trait ZeroCopySend {}
+
+macro_rules! impl_zero_copy_send {
+    ($($t:ty),*) => {
+        $(unsafe impl ZeroCopySend for $t {})*
+    };
+}
+
+impl_zero_copy_send!(
+    (),
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    AtomicBool,
+    AtomicI8,
+    AtomicI16,
+    AtomicI32,
+    AtomicI64,
+    AtomicIsize,
+    AtomicU8,
+    AtomicU16,
+    AtomicU32,
+    AtomicU64,
+    AtomicUsize
+);
+
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for MaybeUninit<T> {}
+unsafe impl<T: ?Sized> ZeroCopySend for PhantomData<T> {}
@@ -258,6 +258,26 @@ impl<T: Copy + Debug> Container<T> {
         self.len() == 0
     }
 
+    /// Returns the [`ContainerHandle`] of the element at the provided index. The index can be
+    /// acquired with [`ContainerState::for_each()`]. Useful in IPC context when an application
+    /// that owned the [`ContainerHandle`] died and the element must be removed by someone else.
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that the either [`Container::new()`] was used or [`Container::init()`] was used
+    ///    before calling this method
+    ///  * Ensure that the index is smaller than [`Container::capacity()`]
+    ///
+    pub unsafe fn handle_from_index(&self, index: u32) -> ContainerHandle {
+        self.verify_memory_initialization("handle_from_index");
+        debug_assert!((index as usize) < self.capacity);
+
+        ContainerHandle {
+            index,
+            container_id: self.container_id.value(),
+        }
+    }
+
     /// Adds a new element to the [`Container`]. If there is no more space available it returns
     /// [`None`], otherwise [`Some`] containing the the index value to the underlying element.
     ///
@@ -501,6 +521,16 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeContainer<T, CAPACITY> {
         self.container.remove(handle)
     }
 
+    /// Returns the [`ContainerHandle`] of the element at the provided index. The index can be
+    /// acquired with [`ContainerState::for_each()`].
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that the index is smaller than [`FixedSizeContainer::capacity()`]
+    pub unsafe fn handle_from_index(&self, index: u32) -> ContainerHandle {
+        self.container.handle_from_index(index)
+    }
+
     /// Returns [`ContainerState`] which contains all elements of this container. Be aware that
     /// this state can be out of date as soon as it is returned from this function.
     pub fn get_state(&self) -> ContainerState<T> {
@@ -166,6 +166,37 @@ mod mpmc_container {
         }
     }
 
+    #[test]
+    fn mpmc_container_handle_from_index_corresponds_to_added_handle<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let mut stored_handles: Vec<ContainerHandle> = vec![];
+
+        for i in 0..CAPACITY {
+            let handle = unsafe { sut.add(i.into()) };
+            assert_that!(handle, is_some);
+            stored_handles.push(handle.unwrap());
+        }
+
+        let state = sut.get_state();
+        let mut handles_from_index = vec![];
+        state.for_each(|index: u32, _: &T| {
+            handles_from_index.push(unsafe { sut.handle_from_index(index) })
+        });
+
+        assert_that!(handles_from_index, len CAPACITY);
+        for handle in handles_from_index {
+            assert_that!(stored_handles, contains handle);
+            unsafe { sut.remove(handle) };
+        }
+
+        let mut number_of_elements = 0;
+        sut.get_state()
+            .for_each(|_: u32, _: &T| number_of_elements += 1);
+        assert_that!(number_of_elements, eq 0);
+    }
+
     #[test]
     fn mpmc_container_state_of_empty_container_is_empty<
         T: Debug + Copy + From<usize> + Into<usize>,
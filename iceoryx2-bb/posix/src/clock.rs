@@ -169,6 +169,7 @@ impl TimeBuilder {
 
 /// Represents time under a specified [`ClockType`]
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[repr(C)]
 pub struct Time {
     pub(crate) clock_type: ClockType,
     pub(crate) seconds: u64,
@@ -0,0 +1,329 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux specific [`Epoll`] which can be used, like the
+//! [`crate::file_descriptor_set::FileDescriptorSet`], to wait on multiple objects which
+//! implement the [`SynchronousMultiplexing`] trait. In contrast to the
+//! [`crate::file_descriptor_set::FileDescriptorSet`] it is not limited by `FD_SETSIZE` and the
+//! cost of a wait call does not grow with the number of attached [`FileDescriptor`]s.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::epoll::*;
+//! use iceoryx2_bb_posix::event_fd::*;
+//! use iceoryx2_bb_posix::file_descriptor_set::FileEvent;
+//! use std::time::Duration;
+//!
+//! let epoll = EpollBuilder::new().create().unwrap();
+//! let event_fd = EventFdBuilder::new().create().unwrap();
+//!
+//! let _guard = epoll.add(&event_fd, FileEvent::Read).unwrap();
+//! event_fd.notify().unwrap();
+//!
+//! epoll.timed_wait(Duration::from_secs(1),
+//!     |fd| println!("Fd was triggered {}", unsafe { fd.native_handle() })).unwrap();
+//! ```
+
+use std::{cell::UnsafeCell, fmt::Debug, time::Duration};
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::{FileEvent, SynchronousMultiplexing};
+use iceoryx2_bb_log::warn;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::posix::Struct;
+use iceoryx2_pal_posix::*;
+
+const INFINITE_TIMEOUT: posix::int = -1;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollCreationError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollAddError {
+    AlreadyAttached,
+    CapacityExceeded,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// Creates an [`Epoll`].
+#[derive(Debug, Default)]
+pub struct EpollBuilder {}
+
+impl EpollBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn create(self) -> Result<Epoll, EpollCreationError> {
+        let fd = unsafe { posix::epoll_create1(posix::EPOLL_CLOEXEC) };
+        if let Some(file_descriptor) = FileDescriptor::new(fd) {
+            return Ok(Epoll {
+                file_descriptor,
+                internals: UnsafeCell::new(Internals {
+                    events: vec![],
+                    file_descriptors: vec![],
+                }),
+            });
+        }
+
+        let msg = "Unable to create epoll file descriptor";
+        handle_errno!(EpollCreationError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+pub struct EpollGuard<'epoll, 'fd> {
+    epoll: &'epoll Epoll,
+    fd: &'fd FileDescriptor,
+}
+
+impl<'epoll, 'fd> EpollGuard<'epoll, 'fd> {
+    pub fn file_descriptor(&self) -> &'fd FileDescriptor {
+        self.fd
+    }
+}
+
+impl Drop for EpollGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.epoll.remove(unsafe { self.fd.native_handle() })
+    }
+}
+
+/// The Linux epoll abstraction to wait on multiple objects which implement the
+/// [`SynchronousMultiplexing`] trait. It is created with the [`EpollBuilder`].
+pub struct Epoll {
+    file_descriptor: FileDescriptor,
+    internals: UnsafeCell<Internals>,
+}
+
+struct Internals {
+    events: Vec<posix::epoll_event>,
+    file_descriptors: Vec<i32>,
+}
+
+impl Debug for Epoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Epoll {{ file_descriptor: {:?}, file_descriptors: {:?} }}",
+            self.file_descriptor,
+            self.internals().file_descriptors
+        )
+    }
+}
+
+impl FileDescriptorBased for Epoll {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for Epoll {}
+
+impl Epoll {
+    fn internals(&self) -> &Internals {
+        unsafe { &*self.internals.get() }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn internals_mut(&self) -> &mut Internals {
+        unsafe { &mut *self.internals.get() }
+    }
+
+    /// Adds a file descriptor. The [`Epoll`] waits for the provided [`FileEvent`] until the
+    /// returned [`EpollGuard`] goes out of scope.
+    pub fn add<'epoll, 'fd, F: SynchronousMultiplexing>(
+        &'epoll self,
+        fd: &'fd F,
+        event: FileEvent,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        self.add_impl(fd.file_descriptor(), event)
+    }
+
+    fn add_impl<'epoll, 'fd>(
+        &'epoll self,
+        fd: &'fd FileDescriptor,
+        event: FileEvent,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        let raw_fd = unsafe { fd.native_handle() };
+        let mut epoll_event = posix::epoll_event::new();
+        epoll_event.events = Self::to_epoll_events(event);
+        epoll_event.data = raw_fd as u64;
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.file_descriptor.native_handle(),
+                posix::EPOLL_CTL_ADD,
+                raw_fd,
+                &mut epoll_event,
+            )
+        } == 0
+        {
+            let internals = self.internals_mut();
+            internals.file_descriptors.push(raw_fd);
+            internals
+                .events
+                .resize(internals.file_descriptors.len(), posix::epoll_event::new());
+            return Ok(EpollGuard { epoll: self, fd });
+        }
+
+        let msg = "Unable to add file descriptor";
+        handle_errno!(EpollAddError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} {:?} since it is invalid.", msg, fd),
+            Errno::EEXIST => (AlreadyAttached, "{} {:?} since it is already attached.", msg, fd),
+            Errno::ENOSPC => (CapacityExceeded, "{} {:?} since the maximum number of epoll watches of the user is exceeded.", msg, fd),
+            Errno::ENOMEM => (InsufficientMemory, "{} {:?} due to insufficient memory.", msg, fd),
+            v => (UnknownError(v as i32), "{} {:?} since an unknown error occurred ({}).", msg, fd, v)
+        );
+    }
+
+    fn remove(&self, value: i32) {
+        let mut epoll_event = posix::epoll_event::new();
+        if unsafe {
+            posix::epoll_ctl(
+                self.file_descriptor.native_handle(),
+                posix::EPOLL_CTL_DEL,
+                value,
+                &mut epoll_event,
+            )
+        } != 0
+        {
+            warn!(from self, "Unable to remove file descriptor {} ({}).", value, Errno::get());
+        }
+
+        let internals = self.internals_mut();
+        internals.file_descriptors.retain(|&v| value != v);
+        internals.events.truncate(internals.file_descriptors.len());
+    }
+
+    fn to_epoll_events(event: FileEvent) -> u32 {
+        match event {
+            FileEvent::Read => posix::EPOLLIN,
+            FileEvent::Write => posix::EPOLLOUT,
+            FileEvent::Exceptional => posix::EPOLLPRI,
+            FileEvent::ReadWrite => posix::EPOLLIN | posix::EPOLLOUT,
+            FileEvent::ReadExceptional => posix::EPOLLIN | posix::EPOLLPRI,
+            FileEvent::WriteExceptional => posix::EPOLLOUT | posix::EPOLLPRI,
+            FileEvent::ReadWriteExceptional => posix::EPOLLIN | posix::EPOLLOUT | posix::EPOLLPRI,
+        }
+    }
+
+    /// Returns the number of attached [`FileDescriptor`]s
+    pub fn len(&self) -> usize {
+        self.internals().file_descriptors.len()
+    }
+
+    /// Returns true if the [`Epoll`] is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.internals().file_descriptors.is_empty()
+    }
+
+    /// Returns true if the object is attached to the [`Epoll`], otherwise false.
+    pub fn contains<T: SynchronousMultiplexing>(&self, fd: &T) -> bool {
+        let raw_fd = unsafe { fd.file_descriptor().native_handle() };
+        self.internals().file_descriptors.contains(&raw_fd)
+    }
+
+    /// Waits until the attached events of at least one [`FileDescriptor`] have occurred
+    /// without blocking and calls the callback for every triggered [`FileDescriptor`].
+    pub fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(0, fd_callback)
+    }
+
+    /// Waits until either the timeout has passed or the attached events of at least one
+    /// [`FileDescriptor`] have occurred and calls the callback for every triggered
+    /// [`FileDescriptor`].
+    pub fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Duration,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        // epoll has a millisecond resolution, round up to never wake up too early
+        let timeout = timeout
+            .as_nanos()
+            .div_ceil(1_000_000)
+            .min(posix::int::MAX as u128) as posix::int;
+        self.wait(timeout, fd_callback)
+    }
+
+    /// Blocks until the attached events of at least one [`FileDescriptor`] have occurred and
+    /// calls the callback for every triggered [`FileDescriptor`].
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(INFINITE_TIMEOUT, fd_callback)
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: posix::int,
+        mut fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        // epoll_wait requires a buffer with at least one element, even when nothing is attached
+        let mut empty_event = posix::epoll_event::new();
+        let (events, max_events) = match self.internals_mut().events.len() {
+            0 => (&mut empty_event as *mut posix::epoll_event, 1),
+            n => (self.internals_mut().events.as_mut_ptr(), n),
+        };
+
+        let number_of_triggered_fds = unsafe {
+            posix::epoll_wait(
+                self.file_descriptor.native_handle(),
+                events,
+                max_events.min(posix::int::MAX as usize) as posix::int,
+                timeout,
+            )
+        };
+
+        if number_of_triggered_fds == -1 {
+            let msg = "Failure while waiting for epoll events";
+            handle_errno!(EpollWaitError, from self,
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        // the callback may attach or detach file descriptors, therefore the raw file
+        // descriptors are copied out of the event buffer before the callback is called
+        let triggered_fds: Vec<i32> = (0..number_of_triggered_fds as usize)
+            .map(|n| unsafe { (*events.add(n)).data as i32 })
+            .collect();
+
+        for raw_fd in triggered_fds {
+            let fd = FileDescriptor::non_owning_new(raw_fd).unwrap();
+            fd_callback(&fd);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides the Linux specific [`EventFd`], a counter that is maintained by the kernel and
+//! that can be used to signal events. Since it is based on a [`FileDescriptor`] it can be
+//! attached to a [`crate::file_descriptor_set::FileDescriptorSet`] and it can be shared with
+//! other processes via
+//! [`crate::unix_datagram_socket::UnixDatagramSender::try_send_msg()`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::event_fd::*;
+//! use std::time::Duration;
+//!
+//! let event_fd = EventFdBuilder::new().create().unwrap();
+//!
+//! event_fd.notify().unwrap();
+//! event_fd.notify().unwrap();
+//!
+//! // in counter mode all notifications are collected at once
+//! assert_eq!(event_fd.try_wait().unwrap(), 2);
+//! assert_eq!(event_fd.timed_wait(Duration::from_millis(10)).unwrap(), 0);
+//! ```
+
+use std::time::Duration;
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+};
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+const BLOCKING_TIMEOUT: Duration = Duration::from_secs(i16::MAX as _);
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdCreationError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdNotifyError {
+    Overflow,
+    Interrupt,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdWaitError {
+    Interrupt,
+    InsufficientPermissions,
+    UnknownError(i32),
+}
+
+/// Creates an [`EventFd`].
+#[derive(Debug, Default)]
+pub struct EventFdBuilder {
+    initial_value: u32,
+    is_semaphore: bool,
+}
+
+impl EventFdBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the initial value of the counter.
+    pub fn initial_value(mut self, value: u32) -> Self {
+        self.initial_value = value;
+        self
+    }
+
+    /// Defines if the [`EventFd`] operates in semaphore mode. In semaphore mode every wait
+    /// call decrements the counter by one, otherwise it acquires the whole counter and resets
+    /// it to zero.
+    pub fn semaphore_mode(mut self, value: bool) -> Self {
+        self.is_semaphore = value;
+        self
+    }
+
+    pub fn create(self) -> Result<EventFd, EventFdCreationError> {
+        let mut flags = posix::EFD_CLOEXEC | posix::EFD_NONBLOCK;
+        if self.is_semaphore {
+            flags |= posix::EFD_SEMAPHORE;
+        }
+
+        let fd = unsafe { posix::eventfd(self.initial_value, flags) };
+        if let Some(file_descriptor) = FileDescriptor::new(fd) {
+            return Ok(EventFd { file_descriptor });
+        }
+
+        let msg = "Unable to create event file descriptor";
+        handle_errno!(EventFdCreationError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// A kernel maintained 64-bit counter, see `man eventfd`. Every notification increments the
+/// counter and every wait call acquires the counter. It is created with the
+/// [`EventFdBuilder`].
+#[derive(Debug)]
+pub struct EventFd {
+    file_descriptor: FileDescriptor,
+}
+
+impl FileDescriptorBased for EventFd {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for EventFd {}
+
+impl EventFd {
+    /// Creates an [`EventFd`] from a [`FileDescriptor`] that was created by another
+    /// [`EventFd`], for instance one that was received from another process via
+    /// [`crate::unix_datagram_socket::UnixDatagramReceiver::try_receive_msg()`].
+    ///
+    /// # Safety
+    ///
+    ///  * the [`FileDescriptor`] must refer to an event file descriptor that was created
+    ///    with the [`EventFdBuilder`]
+    ///
+    pub unsafe fn from_file_descriptor(file_descriptor: FileDescriptor) -> Self {
+        Self { file_descriptor }
+    }
+
+    /// Increments the counter by one and wakes up a waiting process.
+    pub fn notify(&self) -> Result<(), EventFdNotifyError> {
+        self.add(1)
+    }
+
+    /// Increments the counter by `value` and wakes up a waiting process. If the counter would
+    /// exceed its maximum value it fails with [`EventFdNotifyError::Overflow`].
+    pub fn add(&self, value: u64) -> Result<(), EventFdNotifyError> {
+        let bytes_written = unsafe {
+            posix::write(
+                self.file_descriptor.native_handle(),
+                (&value as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+
+        if bytes_written == core::mem::size_of::<u64>() as _ {
+            return Ok(());
+        }
+
+        let msg = "Unable to notify event file descriptor";
+        handle_errno!(EventFdNotifyError, from self,
+            Errno::EAGAIN => (Overflow, "{} with {} since the counter would overflow.", msg, value),
+            Errno::EINTR => (Interrupt, "{} with {} since an interrupt signal was received.", msg, value),
+            v => (UnknownError(v as i32), "{} with {} since an unknown error occurred ({}).", msg, value, v)
+        );
+    }
+
+    /// Acquires the counter without blocking. In semaphore mode it returns 1 and decrements
+    /// the counter, otherwise it returns the counter and resets it. If the counter is zero
+    /// it returns 0.
+    pub fn try_wait(&self) -> Result<u64, EventFdWaitError> {
+        let mut value = 0u64;
+        let bytes_read = unsafe {
+            posix::read(
+                self.file_descriptor.native_handle(),
+                (&mut value as *mut u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+
+        if bytes_read == core::mem::size_of::<u64>() as _ {
+            return Ok(value);
+        }
+
+        let msg = "Unable to acquire the counter of the event file descriptor";
+        handle_errno!(EventFdWaitError, from self,
+            success Errno::EAGAIN => 0,
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Blocks until the counter is non-zero or the timeout has passed and acquires it like
+    /// [`EventFd::try_wait()`]. If the timeout has passed it returns 0.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<u64, EventFdWaitError> {
+        let value = self.try_wait()?;
+        if value != 0 {
+            return Ok(value);
+        }
+
+        let fd_set = FileDescriptorSet::new();
+        // a single file descriptor never exceeds the capacity of the set
+        let _guard = fd_set.add(self).unwrap();
+
+        let msg = "Unable to wait on event file descriptor";
+        match fd_set.timed_wait(timeout, FileEvent::Read, |_| {}) {
+            Ok(()) => self.try_wait(),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with EventFdWaitError::Interrupt,
+                    "{} with a timeout of {:?} since an interrupt signal was received.", msg, timeout);
+            }
+            Err(FileDescriptorSetWaitError::InsufficientPermissions) => {
+                fail!(from self, with EventFdWaitError::InsufficientPermissions,
+                    "{} with a timeout of {:?} due to insufficient permissions.", msg, timeout);
+            }
+            Err(FileDescriptorSetWaitError::UnknownError(v)) => {
+                fail!(from self, with EventFdWaitError::UnknownError(v),
+                    "{} with a timeout of {:?} since an unknown error occurred ({}).", msg, timeout, v);
+            }
+            Err(e) => {
+                fail!(from self, with EventFdWaitError::UnknownError(-1),
+                    "{} with a timeout of {:?} since an unknown error occurred ({:?}).", msg, timeout, e);
+            }
+        }
+    }
+
+    /// Blocks until the counter is non-zero and acquires it like [`EventFd::try_wait()`].
+    pub fn blocking_wait(&self) -> Result<u64, EventFdWaitError> {
+        loop {
+            let value = self.timed_wait(BLOCKING_TIMEOUT)?;
+            if value != 0 {
+                return Ok(value);
+            }
+        }
+    }
+}
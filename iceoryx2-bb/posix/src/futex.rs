@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides the Linux specific [`Futex`], an [`AtomicU32`] on which threads can wait until
+//! its value changes. Since it does not contain any pointers or operating system resources
+//! it can be placed into shared memory and be used by multiple processes. A process that
+//! dies while waiting does not leave an inconsistent state behind.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::futex::*;
+//! use std::sync::atomic::Ordering;
+//! use std::time::Duration;
+//!
+//! let futex = Futex::new(0);
+//!
+//! // blocks for at most 10ms since the value is 0
+//! futex.timed_wait(0, Duration::from_millis(10)).unwrap();
+//!
+//! // in some other thread or process
+//! futex.value().store(1, Ordering::Release);
+//! futex.wake_one();
+//! ```
+
+use std::{sync::atomic::AtomicU32, time::Duration};
+
+use crate::clock::AsTimespec;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum FutexWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// A Linux futex, see `man futex`. The [`Futex`] is not bound to a process and can be
+/// placed into shared memory.
+#[derive(Debug, Default)]
+#[repr(C)]
+pub struct Futex {
+    value: AtomicU32,
+}
+
+impl Futex {
+    /// Creates a new [`Futex`] with the provided initial value.
+    pub const fn new(value: u32) -> Self {
+        Self {
+            value: AtomicU32::new(value),
+        }
+    }
+
+    /// Returns the underlying [`AtomicU32`] on which the [`Futex`] operates.
+    pub fn value(&self) -> &AtomicU32 {
+        &self.value
+    }
+
+    /// Wakes up at most one thread that waits on the [`Futex`]. Returns the number of
+    /// threads that were woken up.
+    pub fn wake_one(&self) -> usize {
+        self.wake(1)
+    }
+
+    /// Wakes up all threads that wait on the [`Futex`]. Returns the number of threads that
+    /// were woken up.
+    pub fn wake_all(&self) -> usize {
+        self.wake(i32::MAX as u32)
+    }
+
+    fn wake(&self, number_of_waiters: u32) -> usize {
+        let result = unsafe { posix::futex_wake(&self.value, number_of_waiters) };
+        if result < 0 {
+            fatal_panic!(from self,
+                "This should never happen! Unable to wake up {} waiters due to an unknown error ({}).",
+                number_of_waiters, Errno::get());
+        }
+
+        result as usize
+    }
+
+    /// Blocks as long as the [`Futex`] contains `expected_value`, no wake up was called and
+    /// the timeout has not passed. Returns false when the timeout has passed, otherwise true.
+    /// Like every futex it may wake up spuriously, therefore the caller has to reevaluate
+    /// the value.
+    pub fn timed_wait(
+        &self,
+        expected_value: u32,
+        timeout: Duration,
+    ) -> Result<bool, FutexWaitError> {
+        let timeout = timeout.as_timespec();
+        self.wait(expected_value, &timeout)
+    }
+
+    /// Blocks as long as the [`Futex`] contains `expected_value` and no wake up was called.
+    /// Like every futex it may wake up spuriously, therefore the caller has to reevaluate
+    /// the value.
+    pub fn blocking_wait(&self, expected_value: u32) -> Result<(), FutexWaitError> {
+        self.wait(expected_value, core::ptr::null())?;
+        Ok(())
+    }
+
+    fn wait(
+        &self,
+        expected_value: u32,
+        timeout: *const posix::timespec,
+    ) -> Result<bool, FutexWaitError> {
+        if unsafe { posix::futex_wait(&self.value, expected_value, timeout) } == 0 {
+            return Ok(true);
+        }
+
+        let msg = "Unable to wait on futex";
+        match Errno::get() {
+            // the value was already changed before the wait call, no need to wait
+            Errno::EAGAIN => Ok(true),
+            Errno::ETIMEDOUT => Ok(false),
+            Errno::EINTR => {
+                fail!(from self, with FutexWaitError::Interrupt,
+                    "{} with value {} since an interrupt signal was received.", msg, expected_value);
+            }
+            v => {
+                fail!(from self, with FutexWaitError::UnknownError(v as i32),
+                    "{} with value {} since an unknown error occurred ({}).", msg, expected_value, v);
+            }
+        }
+    }
+}
@@ -45,15 +45,24 @@ pub mod udp_socket;
 #[macro_use]
 pub mod handle_errno;
 pub mod directory;
+#[cfg(target_os = "linux")]
+pub mod epoll;
+#[cfg(target_os = "linux")]
+pub mod event_fd;
 pub mod file;
 pub mod file_descriptor;
 pub mod file_descriptor_set;
 pub mod file_lock;
 pub mod file_type;
+#[cfg(target_os = "linux")]
+pub mod futex;
 pub mod group;
 pub mod ipc_capable;
+#[cfg(target_os = "linux")]
+pub mod mem_fd;
 pub mod memory;
 pub mod memory_lock;
+pub mod memory_placement;
 pub mod message_queue;
 pub mod metadata;
 pub mod mutex;
@@ -0,0 +1,297 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides the Linux specific [`MemFd`], an anonymous memory file that is mapped into the
+//! process. In contrast to the [`crate::shared_memory::SharedMemory`] it has no name in the
+//! file system and cannot be opened by other processes. It can only be shared by sending
+//! its [`FileDescriptor`] via
+//! [`crate::unix_datagram_socket::UnixDatagramSender::try_send_msg()`]. The kernel releases
+//! the memory as soon as the last process that holds the [`FileDescriptor`] or a mapping
+//! closes it.
+//!
+//! The size of a [`MemFd`] is sealed on creation, so no process that receives it can shrink
+//! it below the mapping of another process.
+//!
+//! With a [`MemoryPlacement`] the [`MemFd`] can be backed by huge pages, populated on creation
+//! and bound to a NUMA node.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::mem_fd::*;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someMemFdName").unwrap();
+//! let mut mem_fd = MemFdBuilder::new(&name)
+//!                     .size(1024)
+//!                     .create()
+//!                     .expect("failed to create memfd");
+//!
+//! mem_fd.as_mut_slice()[0] = 0xFF;
+//!
+//! // usually the file descriptor is received from another process
+//! let view = MemFd::from_file_descriptor(mem_fd.file_descriptor().clone())
+//!                     .expect("failed to map memfd");
+//! assert_eq!(view.as_slice()[0], 0xFF);
+//! ```
+
+use std::ptr::NonNull;
+
+use crate::file::{FileStatError, FileTruncateError};
+use crate::file_descriptor::*;
+use crate::memory_placement::{HugePageSize, MemoryPlacement, MemoryPlacementError};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::file_name::*;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+const REQUIRED_SEALS: i32 = posix::F_SEAL_SHRINK | posix::F_SEAL_GROW | posix::F_SEAL_SEAL;
+
+enum_gen! { MemFdCreationError
+  entry:
+    UnsupportedSizeOfZero,
+    InsufficientMemory,
+    InsufficientMemoryToBeMemoryLocked,
+    MappedRegionLimitReached,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InvalidName,
+    NotSealed,
+    UnknownError(i32)
+  mapping:
+    FileTruncateError,
+    FileStatError,
+    MemoryPlacementError
+}
+
+/// The builder for the [`MemFd`].
+#[derive(Debug)]
+pub struct MemFdBuilder {
+    name: FileName,
+    size: usize,
+    memory_placement: MemoryPlacement,
+}
+
+impl MemFdBuilder {
+    /// The name is only used for debugging purposes, it appears as target of the symlink
+    /// in `/proc/self/fd/`. Multiple [`MemFd`]s can have the same name.
+    pub fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            size: 0,
+            memory_placement: MemoryPlacement::default(),
+        }
+    }
+
+    /// Defines the size of the [`MemFd`]. It cannot be changed after creation.
+    pub fn size(mut self, value: usize) -> Self {
+        self.size = value;
+        self
+    }
+
+    /// Defines the [`MemoryPlacement`] of the [`MemFd`]. When huge pages are requested the size
+    /// is rounded up to a multiple of the [`HugePageSize`].
+    pub fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+        self.memory_placement = value;
+        self
+    }
+
+    /// Creates and maps a new [`MemFd`]. The memory is zeroed.
+    pub fn create(self) -> Result<MemFd, MemFdCreationError> {
+        let msg = "Unable to create memfd";
+        if self.size == 0 {
+            fail!(from self, with MemFdCreationError::UnsupportedSizeOfZero,
+                "{} since a size of zero is not supported.", msg);
+        }
+
+        let size = self.memory_placement.required_size(self.size);
+        let mut file_descriptor = self.memfd_create()?;
+        fail!(from self, when file_descriptor.truncate(size),
+            "{} since the memfd could not be truncated to {}.", msg, size);
+
+        if unsafe {
+            posix::fcntl_int(
+                file_descriptor.native_handle(),
+                posix::F_ADD_SEALS,
+                REQUIRED_SEALS,
+            )
+        } != 0
+        {
+            fail!(from self, with MemFdCreationError::UnknownError(Errno::get() as i32),
+                "{} since the size could not be sealed ({}).", msg, Errno::get());
+        }
+
+        let mem_fd = MemFd::map(file_descriptor, size, self.memory_placement.map_flags())?;
+        fail!(from self, when unsafe { self.memory_placement.apply(mem_fd.base_address, mem_fd.size) },
+            "{} since the memory placement could not be applied.", msg);
+
+        trace!(from mem_fd, "create");
+        Ok(mem_fd)
+    }
+
+    fn memfd_create(&self) -> Result<FileDescriptor, MemFdCreationError> {
+        let huge_page_flags = match self.memory_placement.get_huge_pages() {
+            None => 0,
+            Some(HugePageSize::Size2MB) => posix::MFD_HUGETLB | posix::MFD_HUGE_2MB,
+            Some(HugePageSize::Size1GB) => posix::MFD_HUGETLB | posix::MFD_HUGE_1GB,
+        };
+
+        let fd = FileDescriptor::new(unsafe {
+            posix::memfd_create(
+                self.name.as_c_str(),
+                posix::MFD_CLOEXEC | posix::MFD_ALLOW_SEALING | huge_page_flags,
+            )
+        });
+
+        if let Some(v) = fd {
+            return Ok(v);
+        }
+
+        let msg = "Unable to create memfd";
+        if huge_page_flags != 0 && Errno::get() == Errno::EINVAL {
+            fail!(from self, with MemFdCreationError::MemoryPlacementError(MemoryPlacementError::HugePagesNotSupported),
+                "{} since huge pages of size {:?} are not supported.", msg, self.memory_placement.get_huge_pages());
+        }
+
+        handle_errno!(MemFdCreationError, from self,
+            Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, self.name),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// An anonymous memory file that is mapped into the process, see `man memfd_create`. It is
+/// created with the [`MemFdBuilder`] or from a received [`FileDescriptor`] with
+/// [`MemFd::from_file_descriptor()`].
+#[derive(Debug)]
+pub struct MemFd {
+    base_address: *mut u8,
+    size: usize,
+    file_descriptor: FileDescriptor,
+}
+
+impl Drop for MemFd {
+    fn drop(&mut self) {
+        if unsafe { posix::munmap(self.base_address as *mut posix::void, self.size) } != 0 {
+            fatal_panic!(from self, "This should never happen! Unable to unmap since the base address or range is invalid.");
+        }
+        trace!(from self, "close");
+    }
+}
+
+impl FileDescriptorBased for MemFd {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl FileDescriptorManagement for MemFd {}
+
+impl MemFd {
+    /// Maps a [`FileDescriptor`] of a [`MemFd`] that was created by another process and
+    /// received, for instance, via
+    /// [`crate::unix_datagram_socket::UnixDatagramReceiver::try_receive_msg()`]. It fails
+    /// with [`MemFdCreationError::NotSealed`] when the size of the underlying file is not
+    /// sealed since then any process could shrink it below the mapping.
+    pub fn from_file_descriptor(
+        file_descriptor: FileDescriptor,
+    ) -> Result<Self, MemFdCreationError> {
+        let msg = "Unable to map memfd";
+        let origin = format!("MemFd::from_file_descriptor({:?})", file_descriptor);
+
+        let seals = unsafe { posix::fcntl2(file_descriptor.native_handle(), posix::F_GET_SEALS) };
+        if seals < 0 {
+            fail!(from origin, with MemFdCreationError::NotSealed,
+                "{} since the file descriptor does not support sealing ({}).", msg, Errno::get());
+        }
+
+        if seals & REQUIRED_SEALS != REQUIRED_SEALS {
+            fail!(from origin, with MemFdCreationError::NotSealed,
+                "{} since the size of the file descriptor is not sealed.", msg);
+        }
+
+        let size = fail!(from origin, when file_descriptor.metadata(),
+            "{} since a failure occurred while acquiring the file attributes.", msg)
+        .size() as usize;
+
+        let mem_fd = Self::map(file_descriptor, size, 0)?;
+        trace!(from mem_fd, "open");
+        Ok(mem_fd)
+    }
+
+    /// Returns the base address of the [`MemFd`]. It is always aligned to the page size.
+    pub fn base_address(&self) -> NonNull<u8> {
+        match NonNull::new(self.base_address) {
+            Some(v) => v,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! A valid memfd should never contain a base address with null value.");
+            }
+        }
+    }
+
+    /// Returns the size of the [`MemFd`]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a slice to the memory
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.base_address, self.size) }
+    }
+
+    /// Returns a mutable slice to the memory
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.base_address, self.size) }
+    }
+
+    fn map(
+        file_descriptor: FileDescriptor,
+        size: usize,
+        flags: posix::int,
+    ) -> Result<Self, MemFdCreationError> {
+        let base_address = unsafe {
+            posix::mmap(
+                std::ptr::null_mut::<posix::void>(),
+                size,
+                posix::PROT_READ | posix::PROT_WRITE,
+                posix::MAP_SHARED | flags,
+                file_descriptor.native_handle(),
+                0,
+            )
+        };
+
+        if base_address != posix::MAP_FAILED {
+            return Ok(Self {
+                base_address: base_address as *mut u8,
+                size,
+                file_descriptor,
+            });
+        }
+
+        let msg = "Unable to map memfd";
+        let origin = format!("MemFd::map({:?})", file_descriptor);
+        handle_errno!(MemFdCreationError, from origin,
+            Errno::EAGAIN => (InsufficientMemoryToBeMemoryLocked, "{} since a previous mlockall() enforces all mappings to be memory locked but this mapping cannot be locked due to insufficient memory.", msg),
+            Errno::EINVAL => (UnsupportedSizeOfZero, "{} since a size of zero is not supported.", msg),
+            Errno::EMFILE => (MappedRegionLimitReached, "{} since the number of mapped regions would exceed the process or system limit.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
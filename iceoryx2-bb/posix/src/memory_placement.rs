@@ -0,0 +1,243 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`MemoryPlacement`] describes where and when the pages of a newly created memory mapping
+//! are allocated. It can request [`HugePageSize`]d pages to reduce TLB misses, pre-fault all
+//! pages on creation so that the first write does not cause a page fault and bind the pages
+//! to a NUMA node with a [`NumaPolicy`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::memory_placement::*;
+//!
+//! let placement = MemoryPlacement::new()
+//!                     .populate(true)
+//!                     .numa_policy(NumaPolicy::Preferred(0));
+//!
+//! assert_eq!(placement.get_numa_policy(), Some(NumaPolicy::Preferred(0)));
+//! ```
+
+use crate::system_configuration::SystemInfo;
+use iceoryx2_bb_log::fail;
+#[cfg(target_os = "linux")]
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+/// Failures that can occur when a [`MemoryPlacement`] is applied to a memory mapping.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MemoryPlacementError {
+    HugePagesNotSupported,
+    NumaNotSupported,
+    InvalidNumaNode,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+/// The size of a huge page. The system must provide a pool of huge pages of that size, see
+/// `/sys/kernel/mm/hugepages/`.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HugePageSize {
+    Size2MB,
+    Size1GB,
+}
+
+impl HugePageSize {
+    /// Returns the size of the huge page in bytes.
+    pub fn value(&self) -> usize {
+        match self {
+            HugePageSize::Size2MB => 2 * 1024 * 1024,
+            HugePageSize::Size1GB => 1024 * 1024 * 1024,
+        }
+    }
+}
+
+/// Defines on which NUMA node the pages of a memory mapping are allocated, see `man mbind`.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NumaPolicy {
+    /// The pages are allocated only on the given node. If the node has no memory left the
+    /// allocation fails.
+    Bind(u32),
+    /// The pages are allocated on the given node as long as it has memory left, otherwise
+    /// on another node.
+    Preferred(u32),
+}
+
+impl NumaPolicy {
+    /// Returns the NUMA node of the policy
+    pub fn node(&self) -> u32 {
+        match self {
+            NumaPolicy::Bind(node) => *node,
+            NumaPolicy::Preferred(node) => *node,
+        }
+    }
+}
+
+/// Describes how the pages of a newly created memory mapping shall be placed. The default
+/// uses regular pages that are allocated on first access according to the NUMA policy of
+/// the process.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Default)]
+pub struct MemoryPlacement {
+    huge_pages: Option<HugePageSize>,
+    populate: bool,
+    numa_policy: Option<NumaPolicy>,
+}
+
+impl MemoryPlacement {
+    /// Creates a [`MemoryPlacement`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Backs the mapping with huge pages of the given [`HugePageSize`]. The size of the
+    /// mapping is rounded up to a multiple of the [`HugePageSize`].
+    pub fn huge_pages(mut self, value: HugePageSize) -> Self {
+        self.huge_pages = Some(value);
+        self
+    }
+
+    /// If enabled, all pages are allocated and mapped when the memory is created so that the
+    /// first access does not cause a page fault.
+    pub fn populate(mut self, value: bool) -> Self {
+        self.populate = value;
+        self
+    }
+
+    /// Sets the [`NumaPolicy`] for the pages of the mapping.
+    pub fn numa_policy(mut self, value: NumaPolicy) -> Self {
+        self.numa_policy = Some(value);
+        self
+    }
+
+    /// Returns the [`HugePageSize`] when huge pages are requested.
+    pub fn get_huge_pages(&self) -> Option<HugePageSize> {
+        self.huge_pages
+    }
+
+    /// Returns true when the pages are allocated on creation.
+    pub fn get_populate(&self) -> bool {
+        self.populate
+    }
+
+    /// Returns the [`NumaPolicy`] when one is set.
+    pub fn get_numa_policy(&self) -> Option<NumaPolicy> {
+        self.numa_policy
+    }
+
+    /// Returns the size that is required to back `size` bytes with the [`MemoryPlacement`].
+    pub fn required_size(&self, size: usize) -> usize {
+        match self.huge_pages {
+            Some(page_size) => size.div_ceil(page_size.value()) * page_size.value(),
+            None => size,
+        }
+    }
+
+    /// Returns the additional flags for `mmap`. When a [`NumaPolicy`] is set the pages are
+    /// populated in [`MemoryPlacement::apply()`] instead since they must be allocated after
+    /// the policy was set.
+    pub(crate) fn map_flags(&self) -> posix::int {
+        #[cfg(target_os = "linux")]
+        if self.populate && self.numa_policy.is_none() {
+            return posix::MAP_POPULATE;
+        }
+
+        0
+    }
+
+    /// Applies the [`NumaPolicy`] to the mapping and populates it when required.
+    ///
+    /// # Safety
+    ///
+    ///  * `base_address` and `size` must describe a valid, writable and page aligned mapping
+    ///  * the mapping must not be accessed concurrently
+    pub(crate) unsafe fn apply(
+        &self,
+        base_address: *mut u8,
+        size: usize,
+    ) -> Result<(), MemoryPlacementError> {
+        if let Some(numa_policy) = self.numa_policy {
+            Self::mbind(base_address, size, numa_policy)?;
+        }
+
+        // populate the pages that were not already populated by mmap
+        if self.populate && self.map_flags() == 0 {
+            Self::touch_pages(base_address, size);
+        }
+
+        Ok(())
+    }
+
+    unsafe fn touch_pages(base_address: *mut u8, size: usize) {
+        for offset in (0..size).step_by(SystemInfo::PageSize.value()) {
+            let page = base_address.add(offset);
+            page.write_volatile(page.read_volatile());
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn mbind(
+        base_address: *mut u8,
+        size: usize,
+        numa_policy: NumaPolicy,
+    ) -> Result<(), MemoryPlacementError> {
+        const NODE_MASK_LEN: usize = 16;
+        const BITS_PER_ENTRY: usize = posix::ulong::BITS as usize;
+
+        let msg = "Unable to set the NUMA policy";
+        let origin = format!("MemoryPlacement::mbind({:?})", numa_policy);
+        let node = numa_policy.node() as usize;
+        if node >= NODE_MASK_LEN * BITS_PER_ENTRY {
+            fail!(from origin, with MemoryPlacementError::InvalidNumaNode,
+                "{} since the node {} exceeds the maximum supported node {}.",
+                msg, node, NODE_MASK_LEN * BITS_PER_ENTRY - 1);
+        }
+
+        let mut node_mask: [posix::ulong; NODE_MASK_LEN] = [0; NODE_MASK_LEN];
+        node_mask[node / BITS_PER_ENTRY] = 1 << (node % BITS_PER_ENTRY);
+
+        let mode = match numa_policy {
+            NumaPolicy::Bind(_) => posix::MPOL_BIND,
+            NumaPolicy::Preferred(_) => posix::MPOL_PREFERRED,
+        };
+
+        // the kernel expects the number of bits plus one
+        if posix::mbind(
+            base_address as *mut posix::void,
+            size,
+            mode,
+            node_mask.as_ptr(),
+            (NODE_MASK_LEN * BITS_PER_ENTRY + 1) as _,
+            0,
+        ) == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(MemoryPlacementError, from origin,
+            Errno::EINVAL => (InvalidNumaNode, "{} since the node {} does not exist.", msg, node),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::ENOSYS => (NumaNotSupported, "{} since the system does not support NUMA.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    unsafe fn mbind(
+        _base_address: *mut u8,
+        _size: usize,
+        numa_policy: NumaPolicy,
+    ) -> Result<(), MemoryPlacementError> {
+        fail!(from format!("MemoryPlacement::mbind({:?})", numa_policy),
+            with MemoryPlacementError::NumaNotSupported,
+            "Unable to set the NUMA policy since it is not supported on this platform.");
+    }
+}
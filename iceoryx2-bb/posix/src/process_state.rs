@@ -564,7 +564,9 @@ impl ProcessMonitor {
             owner_lock_path,
         };
 
-        new_self.file.set(Self::open_file(&new_self.path)?);
+        new_self
+            .file
+            .set(Self::open_file(&new_self.path, AccessMode::Read)?);
         Ok(new_self)
     }
 
@@ -611,7 +613,8 @@ impl ProcessMonitor {
             Some(_) => self.read_state_from_file(),
             None => match File::does_exist(&self.path) {
                 Ok(true) => {
-                    self.file.set(Self::open_file(&self.path)?);
+                    self.file
+                        .set(Self::open_file(&self.path, AccessMode::Read)?);
                     self.read_state_from_file()
                 }
                 Ok(false) => Ok(ProcessState::DoesNotExist),
@@ -662,7 +665,7 @@ impl ProcessMonitor {
                     Ok(INIT_PERMISSION) => Ok(ProcessState::Starting),
                     Err(_) | Ok(_) => {
                         self.file.set(None);
-                        match Self::open_file(&self.owner_lock_path)? {
+                        match Self::open_file(&self.owner_lock_path, AccessMode::Read)? {
                             Some(f) => {
                                 let lock_state = fail!(from self, when Self::get_lock_state(&f),
                                                 "{} since the lock state of the owner_lock file could not be acquired.", msg);
@@ -692,11 +695,14 @@ impl ProcessMonitor {
         }
     }
 
-    fn open_file(path: &FilePath) -> Result<Option<File>, ProcessMonitorCreateError> {
+    fn open_file(
+        path: &FilePath,
+        access_mode: AccessMode,
+    ) -> Result<Option<File>, ProcessMonitorCreateError> {
         let origin = "ProcessMonitor::new()";
         let msg = format!("Unable to open ProcessMonitor state file \"{}\"", path);
 
-        match FileBuilder::new(path).open_existing(AccessMode::Read) {
+        match FileBuilder::new(path).open_existing(access_mode) {
             Ok(f) => Ok(Some(f)),
             Err(FileOpenError::FileDoesNotExist) => Ok(None),
             Err(FileOpenError::IsDirectory) => {
@@ -762,7 +768,7 @@ impl ProcessCleaner {
             }
         };
 
-        let mut owner_lock_file = match fail!(from origin, when ProcessMonitor::open_file(&owner_lock_path),
+        let mut owner_lock_file = match fail!(from origin, when ProcessMonitor::open_file(&owner_lock_path, AccessMode::ReadWrite),
             with ProcessCleanerCreateError::UnableToOpenCleanerFile,
             "{} since the owner_lock file could not be opened.", msg)
         {
@@ -773,7 +779,7 @@ impl ProcessCleaner {
             }
         };
 
-        let mut file = match fail!(from origin, when ProcessMonitor::open_file(path),
+        let mut file = match fail!(from origin, when ProcessMonitor::open_file(path, AccessMode::Read),
             with ProcessCleanerCreateError::UnableToOpenStateFile,
             "{} since the state file could not be opened.", msg)
         {
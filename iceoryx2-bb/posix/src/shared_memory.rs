@@ -67,6 +67,7 @@ use crate::file::{FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
 use crate::handle_errno;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
+use crate::memory_placement::{MemoryPlacement, MemoryPlacementError};
 use crate::signal::SignalHandler;
 use crate::system_configuration::Limit;
 use iceoryx2_bb_container::semantic_string::*;
@@ -108,6 +109,7 @@ enum_gen! { SharedMemoryCreationError
     FileTruncateError,
     FileStatError,
     MemoryLockCreationError,
+    MemoryPlacementError,
     SharedMemoryRemoveError
 }
 
@@ -129,6 +131,7 @@ pub struct SharedMemoryBuilder {
     zero_memory: bool,
     access_mode: AccessMode,
     enforce_base_address: Option<u64>,
+    memory_placement: MemoryPlacement,
 }
 
 impl SharedMemoryBuilder {
@@ -143,6 +146,7 @@ impl SharedMemoryBuilder {
             creation_mode: None,
             zero_memory: true,
             enforce_base_address: None,
+            memory_placement: MemoryPlacement::default(),
         }
     }
 
@@ -237,6 +241,14 @@ impl SharedMemoryCreationBuilder {
         self
     }
 
+    /// Defines the [`MemoryPlacement`] of the newly created shared memory. Huge pages are not
+    /// supported since the POSIX shared memory is backed by a file system that cannot provide
+    /// them, use the [`crate::mem_fd::MemFd`] instead.
+    pub fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+        self.config.memory_placement = value;
+        self
+    }
+
     /// Defines if a newly created [`SharedMemory`] owns the underlying resources. If they are not
     /// owned they will not be cleaned up and can be opened later but they need to be explicitly
     /// removed.
@@ -249,6 +261,11 @@ impl SharedMemoryCreationBuilder {
     pub fn create(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to create shared memory";
 
+        if self.config.memory_placement.get_huge_pages().is_some() {
+            fail!(from self.config, with SharedMemoryCreationError::MemoryPlacementError(MemoryPlacementError::HugePagesNotSupported),
+                "{} since POSIX shared memory does not support huge pages.", msg);
+        }
+
         let shm_created;
         let fd = match self
             .config
@@ -335,6 +352,9 @@ impl SharedMemoryCreationBuilder {
         }
         shm.size = actual_shm_size as _;
 
+        fail!(from self.config, when unsafe { self.config.memory_placement.apply(shm.base_address, shm.size) },
+                "{} since the memory placement could not be applied.", msg);
+
         if self.config.is_memory_locked {
             shm.memory_lock = Some(
                 fail!(from self.config, when unsafe { MemoryLock::new(shm.base_address.cast(), shm.size) },
@@ -570,7 +590,7 @@ impl SharedMemory {
                 std::ptr::null_mut::<posix::void>(),
                 config.size,
                 config.access_mode.as_protflag(),
-                posix::MAP_SHARED,
+                posix::MAP_SHARED | config.memory_placement.map_flags(),
                 file_descriptor.native_handle(),
                 0,
             )
@@ -82,9 +82,12 @@ use std::{fmt::Display, marker::PhantomPinned};
 /// Defines the maximum amount of [`FileDescriptor`]s which can be sent with a single message.
 pub const MAX_FILE_DESCRIPTORS_PER_MESSAGE: usize = posix::SCM_MAX_FD as usize;
 
+/// Defines the maximum amount of bytes which can be sent as data with a single message.
+pub const MAX_DATA_SIZE_PER_MESSAGE: usize = 4096;
+
 const SIZE_OF_CRED: usize = std::mem::size_of::<posix::ucred>();
 const SIZE_OF_FD: usize = std::mem::size_of::<i32>();
-const IOVEC_BUFFER_CAPACITY: usize = 1;
+const NUMBER_OF_IOVECS: usize = 1;
 const BUFFER_CAPACITY: usize = 3072;
 pub(crate) const CMSG_SOCKET_LEVEL: posix::int = posix::SOL_SOCKET;
 
@@ -170,9 +173,10 @@ impl Display for SocketCred {
 /// [`crate::unix_datagram_socket::UnixDatagramReceiver::try_receive_msg()`].
 pub struct SocketAncillary {
     message_buffer: [u8; BUFFER_CAPACITY],
-    iovec_buffer: [u8; IOVEC_BUFFER_CAPACITY],
+    iovec_buffer: [u8; MAX_DATA_SIZE_PER_MESSAGE],
     iovec: posix::iovec,
     message: posix::msghdr,
+    data_len: usize,
     file_descriptors: Vec<FileDescriptor>,
     credentials: Option<SocketCred>,
     is_prepared_for_send: bool,
@@ -191,8 +195,8 @@ impl Display for SocketAncillary {
 
         write!(
             f,
-            "SocketAncillary {{ credentials: {}, file descriptors: {:?} }}",
-            cred, self.file_descriptors
+            "SocketAncillary {{ credentials: {}, file descriptors: {:?}, data size: {} }}",
+            cred, self.file_descriptors, self.data_len
         )
     }
 }
@@ -243,20 +247,21 @@ impl Default for SocketAncillary {
     fn default() -> Self {
         let mut new_self = Self {
             message_buffer: [0u8; BUFFER_CAPACITY],
-            iovec_buffer: [0u8; IOVEC_BUFFER_CAPACITY],
+            iovec_buffer: [0u8; MAX_DATA_SIZE_PER_MESSAGE],
             iovec: posix::iovec {
                 iov_base: std::ptr::null_mut::<posix::void>(),
-                iov_len: IOVEC_BUFFER_CAPACITY,
+                iov_len: MAX_DATA_SIZE_PER_MESSAGE,
             },
             message: posix::msghdr {
                 msg_name: std::ptr::null_mut::<posix::void>(),
                 msg_namelen: 0,
                 msg_iov: std::ptr::null_mut::<posix::iovec>(),
-                msg_iovlen: IOVEC_BUFFER_CAPACITY as _,
+                msg_iovlen: NUMBER_OF_IOVECS as _,
                 msg_control: std::ptr::null_mut::<posix::void>(),
                 msg_controllen: buffer_capacity() as _,
                 msg_flags: 0,
             },
+            data_len: 0,
             file_descriptors: vec![],
             credentials: None,
             is_prepared_for_send: false,
@@ -265,10 +270,7 @@ impl Default for SocketAncillary {
             _pin: PhantomPinned,
         };
 
-        new_self.iovec.iov_base = new_self.iovec_buffer.as_mut_ptr() as *mut posix::void;
-        new_self.message.msg_iov = &mut new_self.iovec;
-        new_self.message.msg_control = new_self.message_buffer.as_mut_ptr() as *mut posix::void;
-
+        new_self.update_pointers();
         new_self
     }
 }
@@ -305,6 +307,25 @@ impl SocketAncillary {
         self.is_prepared_for_send = false;
     }
 
+    /// Sets the data of the message. Returns false when the data exceeds
+    /// [`MAX_DATA_SIZE_PER_MESSAGE`], in this case the message stays unchanged.
+    pub fn set_data(&mut self, data: &[u8]) -> bool {
+        if data.len() > MAX_DATA_SIZE_PER_MESSAGE {
+            return false;
+        }
+
+        self.iovec_buffer[..data.len()].copy_from_slice(data);
+        self.data_len = data.len();
+        self.is_prepared_for_send = false;
+        true
+    }
+
+    /// Returns the contained data. A message that was sent without data is received with
+    /// a single zero byte since at least one byte is transferred with every message.
+    pub fn get_data(&self) -> &[u8] {
+        &self.iovec_buffer[..self.data_len]
+    }
+
     /// Returns the contained [`FileDescriptor`] vector
     pub fn get_fds(&self) -> &Vec<FileDescriptor> {
         &self.file_descriptors
@@ -320,10 +341,11 @@ impl SocketAncillary {
         self.file_descriptors
     }
 
-    /// Clears all contained [`FileDescriptor`] and [`SocketCred`]
+    /// Clears all contained [`FileDescriptor`], [`SocketCred`] and data
     pub fn clear(&mut self) {
         self.file_descriptors.clear();
         self.credentials = None;
+        self.data_len = 0;
         self.message.msg_controllen = buffer_capacity() as _;
         self.is_prepared_for_send = false;
         self.set_memory_to_zero_first = true;
@@ -333,7 +355,12 @@ impl SocketAncillary {
         self.message.msg_controllen as _
     }
 
-    pub(crate) fn extract_received_data(&mut self, receiver: &UnixDatagramReceiver) {
+    pub(crate) fn extract_received_data(
+        &mut self,
+        receiver: &UnixDatagramReceiver,
+        received_bytes: usize,
+    ) {
+        self.data_len = received_bytes.min(MAX_DATA_SIZE_PER_MESSAGE);
         let mut cmsghdr = unsafe { posix::CMSG_FIRSTHDR(&self.message) };
 
         loop {
@@ -402,13 +429,18 @@ impl SocketAncillary {
     }
 
     pub(crate) fn prepare_for_send(&mut self) {
+        // the message can be moved after the last preparation
+        self.update_pointers();
+        // at least one byte must be sent to transfer the ancillary data
+        self.iovec.iov_len = self.data_len.max(1);
+
         if self.is_prepared_for_send {
             return;
         }
 
         if self.set_memory_to_zero_first {
             self.message_buffer = [0u8; BUFFER_CAPACITY];
-            self.iovec_buffer = [0u8; IOVEC_BUFFER_CAPACITY];
+            self.iovec_buffer[self.data_len..].fill(0);
         }
 
         let mut controllen: usize = 0;
@@ -453,10 +485,25 @@ impl SocketAncillary {
         &self.message
     }
 
+    pub(crate) fn data_len_to_send(&self) -> usize {
+        self.iovec.iov_len
+    }
+
+    pub(crate) fn prepare_for_receive(&mut self) {
+        self.update_pointers();
+        self.iovec.iov_len = MAX_DATA_SIZE_PER_MESSAGE;
+    }
+
     pub(crate) fn get_mut(&mut self) -> *mut posix::msghdr {
         &mut self.message
     }
 
+    fn update_pointers(&mut self) {
+        self.iovec.iov_base = self.iovec_buffer.as_mut_ptr() as *mut posix::void;
+        self.message.msg_iov = &mut self.iovec;
+        self.message.msg_control = self.message_buffer.as_mut_ptr() as *mut posix::void;
+    }
+
     fn header_from(&mut self, header: *mut posix::cmsghdr) -> Option<UdsMsgHeader> {
         match !header.is_null() {
             true => {
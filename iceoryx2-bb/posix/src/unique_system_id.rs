@@ -45,6 +45,7 @@ use std::{
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_pal_posix::posix;
+use serde::{de::Visitor, Deserialize, Serialize};
 
 use crate::{
     clock::Time,
@@ -72,6 +73,14 @@ impl Display for UniqueSystemId {
     }
 }
 
+impl From<u128> for UniqueSystemId {
+    /// Restores a [`UniqueSystemId`] from a value that was acquired with
+    /// [`UniqueSystemId::value()`].
+    fn from(value: u128) -> Self {
+        Self { value }
+    }
+}
+
 impl UniqueSystemId {
     /// Creates a new system wide unique id
     pub fn new() -> Result<Self, UniqueSystemIdCreationError> {
@@ -113,3 +122,46 @@ impl UniqueSystemId {
         }
     }
 }
+
+struct UniqueSystemIdVisitor;
+
+impl<'de> Visitor<'de> for UniqueSystemIdVisitor {
+    type Value = UniqueSystemId;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string containing the value of a unique system id")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v.parse::<u128>() {
+            Ok(v) => Ok(UniqueSystemId::from(v)),
+            Err(v) => Err(E::custom(format!(
+                "invalid unique system id provided {:?}.",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UniqueSystemId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(UniqueSystemIdVisitor)
+    }
+}
+
+impl Serialize for UniqueSystemId {
+    /// The value is serialized as string since not every serialization format supports
+    /// 128-bit integers.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.value.to_string())
+    }
+}
@@ -614,9 +614,9 @@ impl UnixDatagramSender {
         };
 
         if bytes_sent > 0 {
-            if bytes_sent as usize == uds_msg.len() {
+            if (bytes_sent as usize) < uds_msg.data_len_to_send() {
                 fail!(from self, with UnixDatagramSendMsgError::MessagePartiallySend(bytes_sent as u64),
-                    "{} since only {} bytes were sent. {} bytes remain unsent.", msg, bytes_sent, uds_msg.len() - bytes_sent as usize );
+                    "{} since only {} bytes were sent. {} bytes remain unsent.", msg, bytes_sent, uds_msg.data_len_to_send() - bytes_sent as usize );
             }
 
             return Ok(true);
@@ -950,6 +950,7 @@ impl UnixDatagramReceiver {
         socket_msg: &mut SocketAncillary,
     ) -> Result<bool, UnixDatagramReceiveFdError> {
         socket_msg.clear();
+        socket_msg.prepare_for_receive();
 
         let msg = "Unable to receive file descriptor";
         match unsafe {
@@ -959,8 +960,8 @@ impl UnixDatagramReceiver {
                 0,
             )
         } {
-            1..=isize::MAX => {
-                socket_msg.extract_received_data(self);
+            received_bytes @ 1..=isize::MAX => {
+                socket_msg.extract_received_data(self, received_bytes as usize);
                 Ok(true)
             }
             _ => {
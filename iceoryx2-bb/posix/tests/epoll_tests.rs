@@ -0,0 +1,171 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_posix::epoll::*;
+use iceoryx2_bb_posix::event_fd::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor_set::FileEvent;
+use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::test_requires;
+use iceoryx2_bb_testing::watchdog::Watchdog;
+use iceoryx2_pal_posix::posix;
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+#[test]
+fn epoll_timed_wait_blocks_at_least_timeout() {
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fd = EventFdBuilder::new().create().unwrap();
+    let _guard = sut.add(&event_fd, FileEvent::Read).unwrap();
+
+    let start = Instant::now();
+
+    let mut result = vec![];
+    sut.timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(result, len 0);
+}
+
+#[test]
+fn epoll_add_and_remove_works() {
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fd_1 = EventFdBuilder::new().create().unwrap();
+    let event_fd_2 = EventFdBuilder::new().create().unwrap();
+
+    assert_that!(sut, is_empty);
+
+    let guard_1 = sut.add(&event_fd_1, FileEvent::Read).unwrap();
+    assert_that!(sut, len 1);
+    assert_that!(sut.contains(&event_fd_1), eq true);
+    assert_that!(sut.contains(&event_fd_2), eq false);
+
+    let guard_2 = sut.add(&event_fd_2, FileEvent::Read).unwrap();
+    assert_that!(sut, len 2);
+    assert_that!(sut.contains(&event_fd_2), eq true);
+
+    drop(guard_1);
+    assert_that!(sut, len 1);
+    assert_that!(sut.contains(&event_fd_1), eq false);
+
+    drop(guard_2);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+fn epoll_adding_the_same_file_descriptor_twice_fails() {
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fd = EventFdBuilder::new().create().unwrap();
+
+    let _guard = sut.add(&event_fd, FileEvent::Read).unwrap();
+    let result = sut.add(&event_fd, FileEvent::Read);
+
+    assert_that!(result.err(), eq Some(EpollAddError::AlreadyAttached));
+    assert_that!(sut, len 1);
+}
+
+#[test]
+fn epoll_try_wait_reports_only_triggered_file_descriptors() {
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fd_1 = EventFdBuilder::new().create().unwrap();
+    let event_fd_2 = EventFdBuilder::new().create().unwrap();
+
+    let _guard_1 = sut.add(&event_fd_1, FileEvent::Read).unwrap();
+    let _guard_2 = sut.add(&event_fd_2, FileEvent::Read).unwrap();
+
+    event_fd_2.notify().unwrap();
+
+    let mut result = vec![];
+    sut.try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe { event_fd_2.file_descriptor().native_handle() });
+}
+
+#[test]
+fn epoll_detached_file_descriptors_are_no_longer_reported() {
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fd = EventFdBuilder::new().create().unwrap();
+
+    let guard = sut.add(&event_fd, FileEvent::Read).unwrap();
+    drop(guard);
+
+    event_fd.notify().unwrap();
+
+    let mut counter = 0;
+    sut.try_wait(|_| counter += 1).unwrap();
+
+    assert_that!(counter, eq 0);
+}
+
+#[test]
+fn epoll_blocking_wait_blocks_until_triggered() {
+    let _watchdog = Watchdog::new();
+    let event_fd = EventFdBuilder::new().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        let t = s.spawn(|| {
+            let sut = EpollBuilder::new().create().unwrap();
+            let _guard = sut.add(&event_fd, FileEvent::Read).unwrap();
+            barrier.wait();
+            let mut counter = 0;
+            sut.blocking_wait(|_| counter += 1).unwrap();
+            counter
+        });
+
+        barrier.wait();
+        thread::sleep(TIMEOUT);
+        event_fd.notify().unwrap();
+
+        assert_that!(t.join().unwrap(), eq 1);
+    });
+}
+
+#[test]
+fn epoll_can_attach_more_file_descriptors_than_fd_setsize() {
+    const NUMBER_OF_FDS: usize = posix::FD_SETSIZE + 128;
+    test_requires!(
+        ProcessResourceLimit::MaxNumberOfOpenFileDescriptors.soft_limit()
+            > (NUMBER_OF_FDS + 128) as u64
+    );
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let event_fds: Vec<EventFd> = (0..NUMBER_OF_FDS)
+        .map(|_| EventFdBuilder::new().create().unwrap())
+        .collect();
+
+    let mut guards = vec![];
+    for event_fd in &event_fds {
+        guards.push(sut.add(event_fd, FileEvent::Read).unwrap());
+    }
+    assert_that!(sut, len NUMBER_OF_FDS);
+
+    let last_fd = event_fds.last().unwrap();
+    last_fd.notify().unwrap();
+
+    let mut result = vec![];
+    sut.try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe { last_fd.file_descriptor().native_handle() });
+    assert_that!(result[0] as usize, ge posix::FD_SETSIZE);
+}
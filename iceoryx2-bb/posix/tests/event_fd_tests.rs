@@ -0,0 +1,93 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_posix::event_fd::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::watchdog::Watchdog;
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+#[test]
+fn event_fd_try_wait_does_not_block() {
+    let sut = EventFdBuilder::new().create().unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq 0);
+}
+
+#[test]
+fn event_fd_counter_mode_acquires_all_notifications_at_once() {
+    let sut = EventFdBuilder::new().initial_value(3).create().unwrap();
+
+    sut.notify().unwrap();
+    sut.add(5).unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq 9);
+    assert_that!(sut.try_wait().unwrap(), eq 0);
+}
+
+#[test]
+fn event_fd_semaphore_mode_acquires_one_notification_at_a_time() {
+    let sut = EventFdBuilder::new().semaphore_mode(true).create().unwrap();
+
+    sut.notify().unwrap();
+    sut.notify().unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq 1);
+    assert_that!(sut.try_wait().unwrap(), eq 1);
+    assert_that!(sut.try_wait().unwrap(), eq 0);
+}
+
+#[test]
+fn event_fd_timed_wait_blocks_for_at_least_timeout() {
+    let sut = EventFdBuilder::new().create().unwrap();
+
+    let start = Instant::now();
+    assert_that!(sut.timed_wait(TIMEOUT).unwrap(), eq 0);
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+}
+
+#[test]
+fn event_fd_blocking_wait_blocks_until_notified() {
+    let _watchdog = Watchdog::new();
+    let sut = EventFdBuilder::new().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        let t = s.spawn(|| {
+            barrier.wait();
+            sut.blocking_wait().unwrap()
+        });
+
+        barrier.wait();
+        thread::sleep(TIMEOUT);
+        sut.notify().unwrap();
+
+        assert_that!(t.join().unwrap(), eq 1);
+    });
+}
+
+#[test]
+fn event_fd_from_file_descriptor_shares_the_counter() {
+    let sut = EventFdBuilder::new().create().unwrap();
+    let sut_clone = unsafe { EventFd::from_file_descriptor(sut.file_descriptor().clone()) };
+
+    sut_clone.notify().unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq 1);
+    assert_that!(sut_clone.try_wait().unwrap(), eq 0);
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_posix::futex::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::watchdog::Watchdog;
+use std::sync::atomic::Ordering;
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+#[test]
+fn futex_timed_wait_blocks_for_at_least_timeout() {
+    let sut = Futex::new(0);
+
+    let start = Instant::now();
+    assert_that!(sut.timed_wait(0, TIMEOUT).unwrap(), eq false);
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+}
+
+#[test]
+fn futex_timed_wait_does_not_block_when_value_differs() {
+    let sut = Futex::new(5);
+
+    let start = Instant::now();
+    assert_that!(sut.timed_wait(0, TIMEOUT * 100).unwrap(), eq true);
+    assert_that!(start.elapsed(), lt TIMEOUT * 100);
+}
+
+#[test]
+fn futex_wake_without_waiters_wakes_nobody() {
+    let sut = Futex::new(0);
+
+    assert_that!(sut.wake_one(), eq 0);
+    assert_that!(sut.wake_all(), eq 0);
+}
+
+#[test]
+fn futex_blocking_wait_blocks_until_woken_up() {
+    let _watchdog = Watchdog::new();
+    let sut = Futex::new(0);
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        let t = s.spawn(|| {
+            barrier.wait();
+            while sut.value().load(Ordering::Acquire) == 0 {
+                sut.blocking_wait(0).unwrap();
+            }
+        });
+
+        barrier.wait();
+        thread::sleep(TIMEOUT);
+        assert_that!(t.is_finished(), eq false);
+
+        sut.value().store(1, Ordering::Release);
+        sut.wake_one();
+
+        t.join().unwrap();
+    });
+}
+
+#[test]
+fn futex_wake_all_wakes_up_all_waiters() {
+    const NUMBER_OF_WAITERS: usize = 4;
+    let _watchdog = Watchdog::new();
+    let sut = Futex::new(0);
+    let barrier = Barrier::new(NUMBER_OF_WAITERS + 1);
+
+    thread::scope(|s| {
+        let mut threads = vec![];
+        for _ in 0..NUMBER_OF_WAITERS {
+            threads.push(s.spawn(|| {
+                barrier.wait();
+                while sut.value().load(Ordering::Acquire) == 0 {
+                    sut.blocking_wait(0).unwrap();
+                }
+            }));
+        }
+
+        barrier.wait();
+        thread::sleep(TIMEOUT);
+
+        sut.value().store(1, Ordering::Release);
+        sut.wake_all();
+
+        for t in threads {
+            t.join().unwrap();
+        }
+    });
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::event_fd::*;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptorBased, FileDescriptorManagement};
+use iceoryx2_bb_posix::mem_fd::*;
+use iceoryx2_bb_posix::memory_placement::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_testing::system::{free_huge_pages, is_numa_supported};
+use iceoryx2_bb_testing::{assert_that, test_requires};
+
+const SIZE: usize = 8192;
+
+fn name() -> FileName {
+    FileName::new(b"mem_fd_tests").unwrap()
+}
+
+#[test]
+fn mem_fd_with_size_of_zero_fails() {
+    let sut = MemFdBuilder::new(&name()).size(0).create();
+
+    assert_that!(sut.err(), eq Some(MemFdCreationError::UnsupportedSizeOfZero));
+}
+
+#[test]
+fn mem_fd_create_provides_zeroed_memory_of_requested_size() {
+    let sut = MemFdBuilder::new(&name()).size(SIZE).create().unwrap();
+
+    assert_that!(sut.size(), eq SIZE);
+    assert_that!(sut.as_slice().iter().all(|v| *v == 0), eq true);
+}
+
+#[test]
+fn mem_fd_mapped_from_file_descriptor_shares_memory() {
+    let mut sut = MemFdBuilder::new(&name()).size(SIZE).create().unwrap();
+    let view = MemFd::from_file_descriptor(sut.file_descriptor().clone()).unwrap();
+
+    assert_that!(view.size(), eq SIZE);
+    assert_that!(view.base_address(), ne sut.base_address());
+
+    sut.as_mut_slice()[SIZE - 1] = 0xAB;
+    assert_that!(view.as_slice()[SIZE - 1], eq 0xAB);
+}
+
+#[test]
+fn mem_fd_cannot_be_resized_after_creation() {
+    let sut = MemFdBuilder::new(&name()).size(SIZE).create().unwrap();
+    let mut view = MemFd::from_file_descriptor(sut.file_descriptor().clone()).unwrap();
+
+    assert_that!(view.truncate(SIZE / 2), is_err);
+    assert_that!(view.truncate(SIZE * 2), is_err);
+    assert_that!(view.metadata().unwrap().size(), eq SIZE as u64);
+}
+
+#[test]
+fn mem_fd_from_file_descriptor_without_seals_fails() {
+    let event_fd = EventFdBuilder::new().create().unwrap();
+
+    let sut = MemFd::from_file_descriptor(event_fd.file_descriptor().clone());
+
+    assert_that!(sut.err(), eq Some(MemFdCreationError::NotSealed));
+}
+
+#[test]
+fn mem_fd_with_huge_pages_rounds_size_up() {
+    test_requires!(free_huge_pages(HugePageSize::Size2MB.value()) > 0);
+
+    let sut = MemFdBuilder::new(&name())
+        .size(SIZE)
+        .memory_placement(MemoryPlacement::new().huge_pages(HugePageSize::Size2MB))
+        .create()
+        .unwrap();
+    let view = MemFd::from_file_descriptor(sut.file_descriptor().clone()).unwrap();
+
+    assert_that!(sut.size(), eq HugePageSize::Size2MB.value());
+    assert_that!(view.size(), eq HugePageSize::Size2MB.value());
+}
+
+#[test]
+fn mem_fd_with_populated_memory_and_numa_policy_works() {
+    test_requires!(is_numa_supported());
+
+    let mut sut = MemFdBuilder::new(&name())
+        .size(SIZE)
+        .memory_placement(
+            MemoryPlacement::new()
+                .populate(true)
+                .numa_policy(NumaPolicy::Bind(0)),
+        )
+        .create()
+        .unwrap();
+    let view = MemFd::from_file_descriptor(sut.file_descriptor().clone()).unwrap();
+
+    assert_that!(sut.size(), eq SIZE);
+    sut.as_mut_slice()[SIZE - 1] = 0xAB;
+    assert_that!(view.as_slice()[SIZE - 1], eq 0xAB);
+}
+
+#[test]
+fn mem_fd_with_invalid_numa_node_fails() {
+    test_requires!(is_numa_supported());
+
+    let sut = MemFdBuilder::new(&name())
+        .size(SIZE)
+        .memory_placement(MemoryPlacement::new().numa_policy(NumaPolicy::Bind(u32::MAX)))
+        .create();
+
+    assert_that!(sut.err(), eq Some(MemFdCreationError::MemoryPlacementError(MemoryPlacementError::InvalidNumaNode)));
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::memory_placement::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn memory_placement_default_uses_regular_pages() {
+    let sut = MemoryPlacement::new();
+
+    assert_that!(sut.get_huge_pages(), eq None);
+    assert_that!(sut.get_populate(), eq false);
+    assert_that!(sut.get_numa_policy(), eq None);
+    assert_that!(sut.required_size(1234), eq 1234);
+}
+
+#[test]
+fn memory_placement_settings_are_applied() {
+    let sut = MemoryPlacement::new()
+        .huge_pages(HugePageSize::Size1GB)
+        .populate(true)
+        .numa_policy(NumaPolicy::Bind(3));
+
+    assert_that!(sut.get_huge_pages(), eq Some(HugePageSize::Size1GB));
+    assert_that!(sut.get_populate(), eq true);
+    assert_that!(sut.get_numa_policy(), eq Some(NumaPolicy::Bind(3)));
+    assert_that!(sut.get_numa_policy().unwrap().node(), eq 3);
+}
+
+#[test]
+fn memory_placement_with_huge_pages_rounds_required_size_up() {
+    let page_size = HugePageSize::Size2MB.value();
+    let sut = MemoryPlacement::new().huge_pages(HugePageSize::Size2MB);
+
+    assert_that!(sut.required_size(1), eq page_size);
+    assert_that!(sut.required_size(page_size), eq page_size);
+    assert_that!(sut.required_size(page_size + 1), eq 2 * page_size);
+}
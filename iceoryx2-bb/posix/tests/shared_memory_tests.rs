@@ -12,8 +12,11 @@
 
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::memory_placement::*;
 use iceoryx2_bb_posix::{shared_memory::*, unique_system_id::UniqueSystemId};
 use iceoryx2_bb_system_types::file_name::FileName;
+#[cfg(target_os = "linux")]
+use iceoryx2_bb_testing::system::is_numa_supported;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 
@@ -260,3 +263,68 @@ fn shared_memory_existing_shm_can_be_listed() {
         assert_that!(shm_list, contains * shm.name());
     }
 }
+
+#[test]
+fn shared_memory_with_huge_pages_fails() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .memory_placement(MemoryPlacement::new().huge_pages(HugePageSize::Size2MB))
+        .create();
+
+    assert_that!(sut.err(), eq Some(SharedMemoryCreationError::MemoryPlacementError(MemoryPlacementError::HugePagesNotSupported)));
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+}
+
+#[test]
+fn shared_memory_with_populated_memory_works() {
+    let shm_name = generate_shm_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(8192)
+        .memory_placement(MemoryPlacement::new().populate(true))
+        .create()
+        .unwrap();
+
+    assert_that!(sut.size(), ge 8192);
+    sut.as_mut_slice()[8191] = 0xAB;
+    assert_that!(sut.as_slice()[8191], eq 0xAB);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn shared_memory_with_numa_policy_works() {
+    test_requires!(is_numa_supported());
+
+    let shm_name = generate_shm_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(8192)
+        .memory_placement(
+            MemoryPlacement::new()
+                .populate(true)
+                .numa_policy(NumaPolicy::Preferred(0)),
+        )
+        .create()
+        .unwrap();
+
+    assert_that!(sut.size(), ge 8192);
+    sut.as_mut_slice()[8191] = 0xAB;
+    assert_that!(sut.as_slice()[8191], eq 0xAB);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn shared_memory_with_invalid_numa_node_fails() {
+    test_requires!(is_numa_supported());
+
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .memory_placement(MemoryPlacement::new().numa_policy(NumaPolicy::Bind(u32::MAX)))
+        .create();
+
+    assert_that!(sut.err(), eq Some(SharedMemoryCreationError::MemoryPlacementError(MemoryPlacementError::InvalidNumaNode)));
+}
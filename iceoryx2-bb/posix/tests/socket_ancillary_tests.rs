@@ -98,6 +98,25 @@ fn socket_ancillary_credentials_work() {
     assert_that!(sut.get_creds(), eq None);
 }
 
+#[test]
+fn socket_ancillary_data_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS);
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    let mut sut = SocketAncillary::new();
+    assert_that!(sut.get_data(), is_empty);
+
+    assert_that!(sut.set_data(b"fuu"), eq true);
+    assert_that!(sut.get_data(), eq b"fuu");
+
+    let too_large_data = vec![0u8; MAX_DATA_SIZE_PER_MESSAGE + 1];
+    assert_that!(sut.set_data(&too_large_data), eq false);
+    assert_that!(sut.get_data(), eq b"fuu");
+
+    sut.clear();
+    assert_that!(sut.get_data(), is_empty);
+}
+
 #[test]
 fn socket_ancillary_add_file_descriptors_work() {
     test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS);
@@ -69,3 +69,12 @@ fn unique_system_id_concurrently_created_ids_are_unique() {
         }
     });
 }
+
+#[test]
+fn unique_system_id_can_be_restored_from_value() {
+    let sut = UniqueSystemId::new().unwrap();
+    let restored_sut = UniqueSystemId::from(sut.value());
+
+    assert_that!(restored_sut, eq sut);
+    assert_that!(restored_sut.pid(), eq sut.pid());
+}
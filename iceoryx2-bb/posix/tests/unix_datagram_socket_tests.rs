@@ -315,6 +315,37 @@ fn unix_datagram_socket_sending_receiving_credentials_works() {
     assert_that!(recv_credentials, eq Some(send_credentials));
 }
 
+#[test]
+fn unix_datagram_socket_sending_receiving_data_with_credentials_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS);
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .permission(Permission::OWNER_ALL)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let send_credentials = SocketCred::new();
+    let send_data = b"the hypnotoad is watching you";
+
+    let mut msg = SocketAncillary::new();
+    assert_that!(msg.set_data(send_data), eq true);
+    msg.set_creds(&send_credentials);
+
+    sut_sender.blocking_send_msg(&mut msg).unwrap();
+
+    let mut received_msg = SocketAncillary::new();
+    assert_that!(sut_receiver.try_receive_msg(&mut received_msg).unwrap(), eq true);
+    assert_that!(received_msg.get_data(), eq send_data);
+    assert_that!(received_msg.get_creds(), eq Some(send_credentials));
+}
+
 #[ignore]
 #[test]
 fn unix_datagram_socket_sending_receiving_with_max_supported_fd_and_credentials_works() {
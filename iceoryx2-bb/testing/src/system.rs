@@ -0,0 +1,32 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Queries system resources that tests can require with
+//! [`test_requires!`](crate::test_requires). On systems that do not provide the information
+//! the resources are reported as unavailable.
+
+/// Returns the number of free huge pages with the provided page size in bytes.
+pub fn free_huge_pages(page_size: usize) -> usize {
+    let path = format!(
+        "/sys/kernel/mm/hugepages/hugepages-{}kB/free_hugepages",
+        page_size / 1024
+    );
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Returns true when the kernel supports NUMA memory policies.
+pub fn is_numa_supported() -> bool {
+    std::path::Path::new("/sys/devices/system/node/node0").exists()
+}
@@ -0,0 +1,632 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Linux specific [`DynamicStorage`] based on an anonymous [`MemFd`]. In contrast to the
+//! [`crate::dynamic_storage::posix_shared_memory::Storage`] the memory has no name in
+//! `/dev/shm` and cannot be discovered or opened by arbitrary processes.
+//!
+//! The creator binds a rendezvous socket, that is only accessible by its owner, and sends
+//! the [`FileDescriptor`](iceoryx2_bb_posix::file_descriptor::FileDescriptor) of the
+//! [`MemFd`] in a background thread to the processes that open the [`Storage`]. Every
+//! request is authenticated with the credentials the operating system attaches to it,
+//! processes of other users are rejected and the
+//! [`DynamicStorageBuilder::peer_authorization()`] decides about the processes of the same
+//! user. Therefore, the [`Storage`] exists only as long as its creator is alive. The kernel
+//! releases the memory as soon as the last process that has mapped it exits, so a crashed
+//! process never leaves the memory behind.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::memfd::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use std::sync::atomic::{AtomicI64, Ordering};
+//!
+//! let storage_name = FileName::new(b"myMemFdStorageName").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 // we always have to use a thread-safe object since multiple processes can
+//!                 // access this concurrently
+//!                 .create(AtomicI64::new(0)).unwrap();
+//! owner.get().store(123, Ordering::Relaxed);
+//!
+//! // usually a different process, the owner must be alive while opening
+//! let storage = Builder::<AtomicI64>::new(&storage_name)
+//!                 .open().unwrap();
+//!
+//! println!("Initial value: {}", storage.get().load(Ordering::Relaxed));
+//! storage.get().store(456, Ordering::Relaxed);
+//! ```
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::directory::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::mem_fd::*;
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+pub use crate::dynamic_storage::*;
+use crate::fd_rendezvous::{request_file_descriptor, FdRendezvousError, FdRendezvousServer};
+use crate::hash::{sha1::Sha1, Hash};
+use crate::static_storage::file::{
+    NamedConceptConfiguration, NamedConceptListError, NamedConceptRemoveError,
+};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_system_types::path::Path;
+pub use std::ops::Deref;
+
+const RENDEZVOUS_TIMEOUT: Duration = Duration::from_secs(1);
+const REPLY_SUFFIX: &[u8] = b".reply";
+const TYPE_TAG_LEN: usize = 11;
+
+/// The builder of [`Storage`].
+#[derive(Debug)]
+pub struct Builder<'builder, T: Send + Sync + Debug> {
+    storage_name: FileName,
+    supplementary_size: usize,
+    has_ownership: bool,
+    config: Configuration<T>,
+    timeout: Duration,
+    memory_placement: MemoryPlacement,
+    peer_authorization: Option<PeerAuthorization>,
+    initializer: Initializer<'builder, T>,
+    _phantom_data: PhantomData<T>,
+}
+
+#[derive(Debug)]
+pub struct Configuration<T: Send + Sync + Debug> {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    _data: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Debug> Clone for Configuration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            suffix: self.suffix,
+            prefix: self.prefix,
+            path: self.path,
+            _data: PhantomData,
+        }
+    }
+}
+
+#[repr(C)]
+struct Data<T: Send + Sync + Debug> {
+    version: AtomicU64,
+    data: T,
+}
+
+impl<T: Send + Sync + Debug> Default for Configuration<T> {
+    fn default() -> Self {
+        Self {
+            path: Storage::<()>::default_path_hint(),
+            suffix: Storage::<()>::default_suffix(),
+            prefix: Storage::<()>::default_prefix(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+
+    fn path_for(&self, value: &FileName) -> FilePath {
+        let mut file = self.typed_prefix();
+        fatal_panic!(from self, when file.push_bytes(value.as_bytes()),
+            "The typed prefix \"{}\" in combination with the file name \"{}\" exceeds the maximum supported file name length of {}.",
+            file, value, FileName::max_len());
+        fatal_panic!(from self, when file.push_bytes(self.suffix.as_bytes()),
+            "The file \"{}\" in combination with the suffix \"{}\" exceeds the maximum supported file name length of {}.",
+            file, self.suffix, FileName::max_len());
+
+        fatal_panic!(from self, when FilePath::from_path_and_file(&self.path, &file),
+            "The path \"{}\" in combination with the file \"{}\" exceeds the maximum supported path length of {}.",
+            self.path, file, FilePath::max_len())
+    }
+
+    fn extract_name_from_file(&self, value: &FileName) -> Option<FileName> {
+        let mut file = *value;
+        let typed_prefix = self.typed_prefix();
+
+        if !fatal_panic!(from self, when file.strip_prefix(typed_prefix.as_bytes()),
+                "Stripping the typed prefix \"{}\" from the file name \"{}\" leads to invalid content.",
+                typed_prefix, file)
+        {
+            return None;
+        }
+
+        if !fatal_panic!(from self, when file.strip_suffix(self.suffix.as_bytes()),
+                "Stripping the suffix \"{}\" from the file name \"{}\" leads to invalid content.",
+                self.suffix, file)
+        {
+            return None;
+        }
+
+        Some(file)
+    }
+}
+
+impl<T: Send + Sync + Debug> Configuration<T> {
+    // Storages of different types are separated by a tag derived from the type name. In
+    // contrast to the posix shared memory only a part of the type hash is used since the
+    // path of the rendezvous socket is limited to 108 bytes.
+    fn typed_prefix(&self) -> FileName {
+        let type_hash = Sha1::new(std::any::type_name::<T>().as_bytes()).value();
+        let mut file = self.prefix;
+        fatal_panic!(from self, when file.push_bytes(&type_hash.as_base64url().as_bytes()[..TYPE_TAG_LEN]),
+            "The prefix \"{}\" in combination with the type tag exceeds the maximum supported file name length of {}.",
+            self.prefix, FileName::max_len());
+        fatal_panic!(from self, when file.push(b'_'),
+            "The prefix \"{}\" in combination with \"_\" exceeds the maximum supported file name length of {}.",
+            file, FileName::max_len());
+        file
+    }
+
+    // The reply socket is not derived from the storage name since the path of a unix
+    // domain socket is limited to 108 bytes.
+    fn reply_path_for(&self, id: UniqueSystemId) -> FilePath {
+        let mut file = self.prefix;
+        fatal_panic!(from self, when file.push_bytes(id.value().to_b64().as_bytes()),
+            "The prefix \"{}\" in combination with the reply id exceeds the maximum supported file name length of {}.",
+            self.prefix, FileName::max_len());
+        fatal_panic!(from self, when file.push_bytes(REPLY_SUFFIX),
+            "The file \"{}\" in combination with the reply suffix exceeds the maximum supported file name length of {}.",
+            file, FileName::max_len());
+
+        fatal_panic!(from self, when FilePath::from_path_and_file(&self.path, &file),
+            "The path \"{}\" in combination with the reply file \"{}\" exceeds the maximum supported path length of {}.",
+            self.path, file, FilePath::max_len())
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'builder, T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            has_ownership: true,
+            storage_name: *storage_name,
+            supplementary_size: 0,
+            config: Configuration::default(),
+            timeout: Duration::ZERO,
+            memory_placement: MemoryPlacement::default(),
+            peer_authorization: None,
+            initializer: Initializer::new(|_, _| true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<T>) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
+    fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open dynamic_storage::memfd";
+
+        let rendezvous_path = self.config.path_for(&self.storage_name);
+        let reply_id = fail!(from self, when UniqueSystemId::new(),
+            with DynamicStorageOpenError::InternalError,
+            "{} since no unique id for the reply socket could be generated.", msg);
+
+        let file_descriptor = match request_file_descriptor(
+            &rendezvous_path,
+            &self.config.reply_path_for(reply_id),
+            self.timeout.max(RENDEZVOUS_TIMEOUT),
+        ) {
+            Ok(fd) => fd,
+            Err(FdRendezvousError::DoesNotExist) => {
+                fail!(from self, with DynamicStorageOpenError::DoesNotExist,
+                    "{} since no creator serves the storage.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageOpenError::InternalError,
+                    "{} since the memfd could not be acquired from the creator ({:?}).", msg, e);
+            }
+        };
+
+        let mem_fd = fail!(from self, when MemFd::from_file_descriptor(file_descriptor),
+            with DynamicStorageOpenError::InternalError,
+            "{} since the received memfd could not be mapped.", msg);
+
+        let required_size = std::mem::size_of::<Data<T>>() + self.supplementary_size;
+        if mem_fd.size() < required_size {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the actual size {} does not match the required size of {}.", msg, mem_fd.size(), required_size);
+        }
+
+        let init_state = mem_fd.base_address().as_ptr() as *const Data<T>;
+        let mut wait_for_initialization = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+            with DynamicStorageOpenError::InternalError,
+            "{} since the AdaptiveWait could not be initialized.", msg);
+
+        // the creator serves the memfd already during the initialization, therefore we have
+        // to wait until the version is written
+        let package_version = loop {
+            //////////////////////////////////////////
+            // SYNC POINT: read Data<T>::data
+            //////////////////////////////////////////
+            let package_version = unsafe { &(*init_state) }.version.load(Ordering::SeqCst);
+            if package_version != 0 {
+                break PackageVersion::from_u64(package_version);
+            }
+
+            let elapsed_time = fail!(from self, when wait_for_initialization.wait(),
+                with DynamicStorageOpenError::InternalError,
+                "{} since the adaptive wait call failed.", msg);
+
+            if elapsed_time >= self.timeout {
+                fail!(from self, with DynamicStorageOpenError::InitializationNotYetFinalized,
+                    "{} since it is not yet initialized after {:?}.", msg, self.timeout);
+            }
+        };
+
+        if package_version != PackageVersion::get() {
+            fail!(from self, with DynamicStorageOpenError::VersionMismatch,
+                "{} since the dynamic storage was created with version {} but this process requires version {}.",
+                msg, package_version, PackageVersion::get());
+        }
+
+        Ok(Storage {
+            mem_fd,
+            name: self.storage_name,
+            rendezvous_path,
+            has_ownership: AtomicBool::new(false),
+            _rendezvous: None,
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn create_impl(&mut self) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic_storage::memfd";
+
+        let mem_fd = match MemFdBuilder::new(&self.storage_name)
+            // a memfd is always aligned to the page size therefore we do not have to add
+            // additional alignment space for T
+            .size(std::mem::size_of::<Data<T>>() + self.supplementary_size)
+            .memory_placement(self.memory_placement)
+            .create()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the underlying memfd could not be created ({:?}).", msg, e);
+            }
+        };
+
+        let rendezvous_path = self.config.path_for(&self.storage_name);
+        let rendezvous = match UnixDatagramReceiverBuilder::new(&rendezvous_path)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(Permission::OWNER_ALL)
+            .create()
+        {
+            Ok(v) => v,
+            Err(UnixDatagramReceiverCreationError::SocketFileAlreadyExists) => {
+                fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                    "{} since a storage with the name already exists.", msg);
+            }
+            Err(UnixDatagramReceiverCreationError::UnixDatagramCreationError(
+                UnixDatagramCreationError::InsufficientPermissions,
+            )) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
+                    "{} since the rendezvous socket could not be created due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the rendezvous socket could not be created ({:?}).", msg, e);
+            }
+        };
+
+        // the storage is initialized before the creator releases it to the process, on
+        // failure it must be removed without calling drop on the uninitialized value
+        let mut storage = Storage {
+            _rendezvous: None,
+            mem_fd,
+            name: self.storage_name,
+            rendezvous_path,
+            has_ownership: AtomicBool::new(false),
+            _phantom_data: PhantomData,
+        };
+
+        // the rendezvous socket is removed as soon as it is no longer served
+        storage._rendezvous = Some(fail!(from self,
+            when FdRendezvousServer::new(rendezvous, storage.mem_fd.file_descriptor().clone(), self.peer_authorization.clone()),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the memfd cannot be served to other processes.", msg));
+
+        Ok(storage)
+    }
+
+    fn init_impl(
+        &mut self,
+        storage: Storage<T>,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to init dynamic_storage::memfd";
+        let value = storage.mem_fd.base_address().as_ptr() as *mut Data<T>;
+        let version_ptr = unsafe { core::ptr::addr_of_mut!((*value).version) };
+        unsafe { version_ptr.write(AtomicU64::new(0)) };
+
+        unsafe { core::ptr::addr_of_mut!((*value).data).write(initial_value) };
+
+        let supplementary_start = (storage.mem_fd.base_address().as_ptr() as usize
+            + std::mem::size_of::<Data<T>>()) as *mut u8;
+        let supplementary_len = storage.mem_fd.size() - std::mem::size_of::<Data<T>>();
+
+        let mut allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(supplementary_start) },
+            supplementary_len,
+        );
+
+        let origin = format!("{:?}", self);
+        if !self
+            .initializer
+            .call(unsafe { &mut (*value).data }, &mut allocator)
+        {
+            storage.remove_rendezvous_socket();
+            fail!(from origin, with DynamicStorageCreateError::InitializationFailed,
+                "{} since the initialization of the underlying construct failed.", msg);
+        }
+
+        //////////////////////////////////////////
+        // SYNC POINT: write Data<T>::data
+        //////////////////////////////////////////
+        unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
+
+        storage
+            .has_ownership
+            .store(self.has_ownership, Ordering::Relaxed);
+        Ok(storage)
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storage<T>>
+    for Builder<'builder, T>
+{
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn initializer<F: FnMut(&mut T, &mut BumpAllocator) -> bool + 'builder>(
+        mut self,
+        value: F,
+    ) -> Self {
+        self.initializer = Initializer::new(value);
+        self
+    }
+
+    fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+        self.memory_placement = value;
+        self
+    }
+
+    fn peer_authorization(mut self, value: PeerAuthorization) -> Self {
+        self.peer_authorization = Some(value);
+        self
+    }
+
+    fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let storage = self.create_impl()?;
+        self.init_impl(storage, initial_value)
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        self.open_impl()
+    }
+
+    fn open_or_create(
+        mut self,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
+        loop {
+            match self.open_impl() {
+                Ok(storage) => return Ok(storage),
+                Err(DynamicStorageOpenError::DoesNotExist) => match self.create_impl() {
+                    Ok(storage) => {
+                        return Ok(self.init_impl(storage, initial_value)?);
+                    }
+                    Err(DynamicStorageCreateError::AlreadyExists) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Implements [`DynamicStorage`] for an anonymous [`MemFd`]. It is built by [`Builder`].
+#[derive(Debug)]
+pub struct Storage<T: Debug + Send + Sync> {
+    mem_fd: MemFd,
+    name: FileName,
+    rendezvous_path: FilePath,
+    has_ownership: AtomicBool,
+    _rendezvous: Option<FdRendezvousServer>,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T: Debug + Send + Sync> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership() {
+            let data =
+                unsafe { &mut (*(self.mem_fd.base_address().as_ptr() as *mut Data<T>)).data };
+            unsafe { core::ptr::drop_in_place(data) };
+            self.remove_rendezvous_socket();
+        }
+    }
+}
+
+impl<T: Debug + Send + Sync> Storage<T> {
+    fn remove_rendezvous_socket(&self) {
+        if let Err(e) = File::remove(&self.rendezvous_path) {
+            warn!(from self, "Unable to remove the rendezvous socket \"{}\" ({:?}).",
+                self.rendezvous_path, e);
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration<T>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        match File::does_exist(&cfg.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from "dynamic_storage::memfd::Storage::does_exist_cfg()",
+                    with crate::static_storage::file::NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                    "Unable to check if dynamic_storage::memfd \"{}\" exists due to an internal failure ({:?}).",
+                    name, e);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all dynamic_storage::memfd";
+        let origin = "dynamic_storage::memfd::Storage::list_cfg()";
+
+        let directory = fail!(from origin, when Directory::new(&config.path),
+            map DirectoryOpenError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+            unmatched NamedConceptListError::InternalError,
+            "{} due to a failure while reading the directory (\"{}\").", msg, config.path);
+
+        let entries = fail!(from origin, when directory.contents(),
+            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+            unmatched NamedConceptListError::InternalError,
+            "{} due to a failure while reading the directory (\"{}\") contents.", msg, config.path);
+
+        let mut result = vec![];
+        for entry in &entries {
+            if let Some(entry_name) = config.extract_name_from_file(entry.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove dynamic_storage::memfd";
+        let origin = "dynamic_storage::memfd::Storage::remove_cfg()";
+
+        match Builder::<T>::new(name).config(cfg).open() {
+            Ok(s) => {
+                s.acquire_ownership();
+                return Ok(true);
+            }
+            // the rendezvous socket of a creator that is no longer alive may remain
+            Err(DynamicStorageOpenError::DoesNotExist) => (),
+            Err(e) => {
+                warn!(from origin,
+                    "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
+                    e, std::any::type_name::<T>());
+            }
+        }
+
+        match File::remove(&cfg.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                    "{} \"{}\" due to insufficient permissions.", msg, name);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} \"{}\" due to an internal failure ({:?}).", msg, name, v);
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
+    type Builder<'builder> = Builder<'builder, T>;
+
+    fn does_support_persistency() -> bool {
+        false
+    }
+
+    fn acquire_ownership(&self) {
+        self.has_ownership.store(true, Ordering::Relaxed)
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &(*(self.mem_fd.base_address().as_ptr() as *const Data<T>)).data }
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.has_ownership.load(Ordering::Relaxed)
+    }
+
+    fn release_ownership(&self) {
+        self.has_ownership.store(false, Ordering::Relaxed)
+    }
+}
@@ -54,10 +54,12 @@
 //! }
 //! ```
 
-use std::{fmt::Debug, time::Duration};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_bb_posix::socket_ancillary::SocketCred;
 use iceoryx2_bb_system_types::file_name::*;
 use tiny_fn::tiny_fn;
 
@@ -73,7 +75,39 @@ impl<T> Debug for Initializer<'_, T> {
     }
 }
 
+/// Decides which processes are allowed to open a [`DynamicStorage`] that cannot be opened by
+/// name but is handed over to other processes by passing its file descriptor. It is called
+/// with the [`SocketCred`] of the requesting process which are verified by the operating
+/// system.
+#[derive(Clone)]
+pub struct PeerAuthorization {
+    is_authorized: Arc<dyn Fn(&SocketCred) -> bool + Send + Sync>,
+}
+
+impl Debug for PeerAuthorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PeerAuthorization")
+    }
+}
+
+impl PeerAuthorization {
+    /// Creates a new [`PeerAuthorization`] that authorizes every process for which
+    /// `is_authorized` returns true.
+    pub fn new<F: Fn(&SocketCred) -> bool + Send + Sync + 'static>(is_authorized: F) -> Self {
+        Self {
+            is_authorized: Arc::new(is_authorized),
+        }
+    }
+
+    /// Returns true if the process with the provided [`SocketCred`] is authorized.
+    pub fn is_authorized(&self, credentials: &SocketCred) -> bool {
+        (self.is_authorized)(credentials)
+    }
+}
+
 pub(crate) mod dynamic_storage_configuration;
+#[cfg(target_os = "linux")]
+pub mod memfd;
 pub mod posix_shared_memory;
 pub mod process_local;
 
@@ -112,6 +146,15 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// Sets the size of the supplementary data
     fn supplementary_size(self, value: usize) -> Self;
 
+    /// Defines the [`MemoryPlacement`] of a newly created [`DynamicStorage`]. Implementations
+    /// that are not backed by mapped memory ignore it.
+    fn memory_placement(self, value: MemoryPlacement) -> Self;
+
+    /// Restricts which processes can open a newly created [`DynamicStorage`]. Processes of
+    /// other users are always rejected. Implementations that are opened by name rely on the
+    /// permissions of the underlying resource and ignore it.
+    fn peer_authorization(self, value: PeerAuthorization) -> Self;
+
     /// The timeout defines how long the [`DynamicStorageBuilder`] should wait for
     /// [`DynamicStorageBuilder::create()`]
     /// to finialize the initialization. This is required when the [`DynamicStorage`] is
@@ -45,6 +45,7 @@ use iceoryx2_bb_log::warn;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
 use iceoryx2_bb_posix::shared_memory::*;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -70,6 +71,7 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     has_ownership: bool,
     config: Configuration<T>,
     timeout: Duration,
+    memory_placement: MemoryPlacement,
     initializer: Initializer<'builder, T>,
     _phantom_data: PhantomData<T>,
 }
@@ -157,6 +159,7 @@ impl<'builder, T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Build
             supplementary_size: 0,
             config: Configuration::default(),
             timeout: Duration::ZERO,
+            memory_placement: MemoryPlacement::default(),
             initializer: Initializer::new(|_, _| true),
             _phantom_data: PhantomData,
         }
@@ -249,6 +252,7 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
             .permission(Permission::OWNER_WRITE)
             .zero_memory(false)
             .has_ownership(self.has_ownership)
+            .memory_placement(self.memory_placement)
             .create()
         {
             Ok(v) => v,
@@ -260,6 +264,10 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
                 fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
                     "{} due to insufficient permissions.", msg);
             }
+            Err(SharedMemoryCreationError::MemoryPlacementError(e)) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the memory placement could not be applied ({:?}).", msg, e);
+            }
             Err(_) => {
                 fail!(from self, with DynamicStorageCreateError::InternalError,
                     "{} since the underlying shared memory could not be created.", msg);
@@ -348,6 +356,15 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+        self.memory_placement = value;
+        self
+    }
+
+    fn peer_authorization(self, _value: PeerAuthorization) -> Self {
+        self
+    }
+
     fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
         let shm = self.create_impl()?;
         self.init_impl(shm, initial_value)
@@ -0,0 +1,639 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Linux specific [`Event`] that wakes up the [`Listener`] with an
+//! [`EventFd`](iceoryx2_bb_posix::event_fd::EventFd). The [`TriggerId`]s are tracked in a
+//! bitset that is stored in shared memory, the [`EventFd`] is only used to wake up the
+//! [`Listener`]. Therefore, a notification costs a single syscall without any copy and the
+//! [`Listener`] can be attached to a [`crate::reactor::Reactor`] via its [`FileDescriptor`].
+//!
+//! An [`EventFd`] cannot be opened by name. The [`Listener`] creates a rendezvous socket
+//! next to the shared memory and a background thread that sends the [`EventFd`] to every
+//! [`Notifier`] that requests it during [`NotifierBuilder::open()`](crate::event::NotifierBuilder::open()).
+//!
+//! The [`EventFd`] operates in counter mode since the bitset coalesces multiple notifications
+//! with the same [`TriggerId`] anyway, so a single read wakes up the [`Listener`] for all of
+//! them.
+
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+pub use crate::event::*;
+use crate::{
+    dynamic_storage::{
+        posix_shared_memory::Storage, DynamicStorage, DynamicStorageBuilder,
+        DynamicStorageCreateError, DynamicStorageOpenError,
+    },
+    event::id_tracker::IdTracker,
+    fd_rendezvous::{request_file_descriptor, FdRendezvousError, FdRendezvousServer},
+    static_storage::file::NamedConceptConfiguration,
+};
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::{
+    event_fd::*,
+    file::File,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    unique_system_id::UniqueSystemId,
+    unix_datagram_socket::*,
+};
+pub use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+const TRIGGER_ID_DEFAULT_MAX: TriggerId = TriggerId::new(u16::MAX as _);
+const RENDEZVOUS_TIMEOUT: Duration = Duration::from_secs(1);
+
+// Extends the suffix of the underlying resources so that they do not collide with the
+// resources of other events that use the same configuration.
+const STORAGE_SUFFIX: &[u8] = b".eventfd";
+const RENDEZVOUS_SUFFIX: &[u8] = b".rendezvous";
+const REPLY_SUFFIX: &[u8] = b".reply";
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Management {
+    id_tracker: RelocatableBitSet,
+    reference_counter: AtomicUsize,
+    has_listener: AtomicBool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: EventImpl::default_path_hint(),
+            suffix: EventImpl::default_suffix(),
+            prefix: EventImpl::default_prefix(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Configuration {
+    fn convert(&self) -> <Storage<Management> as NamedConceptMgmt>::Configuration {
+        let mut suffix = self.suffix;
+        fatal_panic!(from self, when suffix.push_bytes(STORAGE_SUFFIX),
+            "The suffix \"{}\" in combination with the storage suffix exceeds the maximum supported file name length.",
+            self.suffix);
+
+        <Storage<Management> as NamedConceptMgmt>::Configuration::default()
+            .prefix(self.prefix)
+            .suffix(suffix)
+            .path_hint(self.path)
+    }
+
+    fn extended_path_for(&self, name: &FileName, extension: &[u8]) -> FilePath {
+        let mut path = self.path_for(name);
+        fatal_panic!(from self, when path.push_bytes(extension),
+            "The path \"{}\" in combination with the extension exceeds the maximum supported path length of {}.",
+            path, FilePath::max_len());
+        path
+    }
+
+    fn rendezvous_path_for(&self, name: &FileName) -> FilePath {
+        self.extended_path_for(name, RENDEZVOUS_SUFFIX)
+    }
+
+    fn reply_path_for(&self, name: &FileName, id: UniqueSystemId) -> FilePath {
+        let mut extension = format!(".{}", id.value()).into_bytes();
+        extension.extend_from_slice(REPLY_SUFFIX);
+        self.extended_path_for(name, &extension)
+    }
+}
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        Ok(fail!(from "event::eventfd::EventImpl::does_exist_cfg()",
+                when Storage::<Management>::does_exist_cfg(name, &cfg.convert()),
+                "Failed to check if event \"{}\" exists.", name))
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        Ok(fail!(from "event::eventfd::EventImpl::list_cfg()",
+                when Storage::<Management>::list_cfg(&cfg.convert()),
+                "Failed to list all events."))
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        let origin = "event::eventfd::EventImpl::remove_cfg()";
+        let rendezvous_path = cfg.rendezvous_path_for(name);
+        if let Err(e) = File::remove(&rendezvous_path) {
+            warn!(from origin,
+                "Unable to remove the rendezvous socket \"{}\" of the event \"{}\" ({:?}).",
+                rendezvous_path, name, e);
+        }
+
+        Ok(fail!(from origin,
+                when Storage::<Management>::remove_cfg(name, &cfg.convert()),
+                "Failed to remove event \"{}\".", name))
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type NotifierBuilder = NotifierBuilder;
+    type Listener = Listener;
+    type ListenerBuilder = ListenerBuilder;
+
+    fn has_trigger_id_limit() -> bool {
+        true
+    }
+}
+
+#[derive(Debug)]
+pub struct Notifier {
+    storage: Storage<Management>,
+    event_fd: EventFd,
+}
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        if self
+            .storage
+            .get()
+            .reference_counter
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            self.storage.acquire_ownership();
+        }
+    }
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        self.storage.name()
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn trigger_id_max(&self) -> TriggerId {
+        self.storage.get().id_tracker.trigger_id_max()
+    }
+
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        let msg = "Failed to notify event::eventfd::Listener";
+        if !self.storage.get().has_listener.load(Ordering::Relaxed) {
+            fail!(from self, with NotifierNotifyError::Disconnected,
+                "{} since the listener is no longer connected.", msg);
+        }
+
+        if self.storage.get().id_tracker.trigger_id_max() < id {
+            fail!(from self, with NotifierNotifyError::TriggerIdOutOfBounds,
+                "{} since the TriggerId {:?} is greater than the max supported TriggerId {:?}.",
+                msg, id, self.storage.get().id_tracker.trigger_id_max());
+        }
+
+        unsafe { self.storage.get().id_tracker.add(id)? };
+        match self.event_fd.notify() {
+            Ok(()) => Ok(()),
+            Err(EventFdNotifyError::Overflow) => {
+                fail!(from self, with NotifierNotifyError::FailedToDeliverSignal,
+                    "{} since the counter of the event file descriptor would overflow.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NotifierNotifyError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    name: FileName,
+    config: Configuration,
+    creation_timeout: Duration,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            creation_timeout: Duration::ZERO,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl NotifierBuilder {
+    fn acquire_event_fd(&self) -> Result<EventFd, NotifierCreateError> {
+        let msg = "Unable to acquire the event file descriptor from the listener";
+
+        let reply_id = fail!(from self, when UniqueSystemId::new(),
+                with NotifierCreateError::InternalFailure,
+                "{} since no unique id for the reply socket could be generated.", msg);
+
+        match request_file_descriptor(
+            &self.config.rendezvous_path_for(&self.name),
+            &self.config.reply_path_for(&self.name, reply_id),
+            self.creation_timeout.max(RENDEZVOUS_TIMEOUT),
+        ) {
+            Ok(fd) => Ok(unsafe { EventFd::from_file_descriptor(fd) }),
+            Err(FdRendezvousError::DoesNotExist) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since the corresponding listener does not exist.", msg);
+            }
+            Err(FdRendezvousError::InsufficientPermissions) => {
+                fail!(from self, with NotifierCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.creation_timeout = timeout;
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        let msg = "Failed to open event::eventfd::Notifier";
+
+        let storage =
+            match <Storage<Management> as DynamicStorage<Management>>::Builder::new(&self.name)
+                .config(&self.config.convert())
+                .timeout(self.creation_timeout)
+                .open()
+            {
+                Ok(storage) => storage,
+                Err(DynamicStorageOpenError::DoesNotExist) => {
+                    fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since it does not exist.", msg);
+                }
+                Err(DynamicStorageOpenError::VersionMismatch) => {
+                    fail!(from self, with NotifierCreateError::VersionMismatch,
+                    "{} since the version of the existing construct does not match.", msg);
+                }
+                Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                    fail!(from self, with NotifierCreateError::InitializationNotYetFinalized,
+                    "{} since the initialization is after a timeout of {:?} still not finalized.",
+                    msg, self.creation_timeout);
+                }
+                Err(e) => {
+                    fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+                }
+            };
+
+        let mut ref_count = storage.get().reference_counter.load(Ordering::Relaxed);
+        loop {
+            if !storage.get().has_listener.load(Ordering::Relaxed) || ref_count == 0 {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since it has no listener and will no longer exist.", msg);
+            }
+
+            match storage.get().reference_counter.compare_exchange(
+                ref_count,
+                ref_count + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => ref_count = v,
+            };
+        }
+
+        // the notifier owns a reference from here on, the drop releases it on failure
+        let event_fd = match self.acquire_event_fd() {
+            Ok(event_fd) => event_fd,
+            Err(e) => {
+                if storage
+                    .get()
+                    .reference_counter
+                    .fetch_sub(1, Ordering::Relaxed)
+                    == 1
+                {
+                    storage.acquire_ownership();
+                }
+                fail!(from self, with e, "{} since the event file descriptor could not be acquired.", msg);
+            }
+        };
+
+        Ok(Notifier { storage, event_fd })
+    }
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    storage: Storage<Management>,
+    event_fd: EventFd,
+    _rendezvous: FdRendezvousServer,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.storage
+            .get()
+            .has_listener
+            .store(false, Ordering::Relaxed);
+
+        if self
+            .storage
+            .get()
+            .reference_counter
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            self.storage.acquire_ownership();
+        }
+    }
+}
+
+impl FileDescriptorBased for Listener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.event_fd.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for Listener {}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        self.storage.name()
+    }
+}
+
+impl Listener {
+    fn wait<F: FnMut(&Self) -> Result<u64, EventFdWaitError>>(
+        &self,
+        error_msg: &str,
+        mut wait_call: F,
+    ) -> Result<u64, ListenerWaitError> {
+        match wait_call(self) {
+            Ok(v) => Ok(v),
+            Err(EventFdWaitError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", error_msg);
+            }
+            Err(e) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", error_msg, e);
+            }
+        }
+    }
+
+    fn drain_signal(&self) -> Result<(), ListenerWaitError> {
+        self.wait(
+            "Unable to acquire signal on event::eventfd::Listener",
+            |this| this.event_fd.try_wait(),
+        )?;
+        Ok(())
+    }
+
+    fn timed_wait_for_signal(&self, timeout: Duration) -> Result<u64, ListenerWaitError> {
+        self.wait(
+            &format!(
+                "Unable to wait for signal with timeout {:?} on event::eventfd::Listener",
+                timeout
+            ),
+            |this| this.event_fd.timed_wait(timeout),
+        )
+    }
+
+    fn blocking_wait_for_signal(&self) -> Result<u64, ListenerWaitError> {
+        self.wait(
+            "Unable to blocking wait for signal on event::eventfd::Listener",
+            |this| this.event_fd.blocking_wait(),
+        )
+    }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        // the signal is acquired before the id so that a later timed or blocking wait does
+        // not miss a notification, this may lead to spurious wakeups
+        self.drain_signal()?;
+        Ok(unsafe { self.storage.get().id_tracker.acquire() })
+    }
+
+    fn timed_wait_one(&self, timeout: Duration) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        self.timed_wait_for_signal(timeout)?;
+        Ok(unsafe { self.storage.get().id_tracker.acquire() })
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        self.blocking_wait_for_signal()?;
+        Ok(unsafe { self.storage.get().id_tracker.acquire() })
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.drain_signal()?;
+        unsafe { self.storage.get().id_tracker.acquire_all(callback) };
+        Ok(())
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.timed_wait_for_signal(timeout)?;
+        unsafe { self.storage.get().id_tracker.acquire_all(callback) };
+        Ok(())
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.blocking_wait_for_signal()?;
+        unsafe { self.storage.get().id_tracker.acquire_all(callback) };
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    name: FileName,
+    config: Configuration,
+    trigger_id_max: TriggerId,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            trigger_id_max: TRIGGER_ID_DEFAULT_MAX,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl ListenerBuilder {
+    fn init(mgmt: &mut Management, allocator: &mut BumpAllocator) -> bool {
+        if unsafe { mgmt.id_tracker.init(allocator).is_err() } {
+            debug!(from "event::eventfd::ListenerBuilder::init()", "Unable to initialize IdTracker.");
+            return false;
+        }
+
+        true
+    }
+
+    fn create_event_fd(&self) -> Result<EventFd, ListenerCreateError> {
+        match EventFdBuilder::new().create() {
+            Ok(event_fd) => Ok(event_fd),
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "Unable to create event file descriptor ({:?}).", e);
+            }
+        }
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(mut self, id: TriggerId) -> Self {
+        self.trigger_id_max = id;
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        let msg = "Failed to create event::eventfd::Listener";
+        let id_tracker_capacity = self.trigger_id_max.as_value() + 1;
+
+        let storage =
+            match <Storage<Management> as DynamicStorage<Management>>::Builder::new(&self.name)
+                .config(&self.config.convert())
+                .supplementary_size(RelocatableBitSet::memory_size(id_tracker_capacity))
+                .initializer(Self::init)
+                .has_ownership(false)
+                .create(Management {
+                    id_tracker: unsafe { RelocatableBitSet::new_uninit(id_tracker_capacity) },
+                    reference_counter: AtomicUsize::new(1),
+                    has_listener: AtomicBool::new(true),
+                }) {
+                Ok(storage) => storage,
+                Err(DynamicStorageCreateError::AlreadyExists) => {
+                    fail!(from self, with ListenerCreateError::AlreadyExists,
+                    "{} since it already exists.", msg);
+                }
+                Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                    fail!(from self, with ListenerCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+                }
+            };
+        // the storage is removed when the creation fails from here on
+        storage.acquire_ownership();
+
+        let event_fd = self.create_event_fd()?;
+
+        // a stale socket of a crashed listener is removed, the storage guarantees
+        // that no other listener with the same name exists
+        let rendezvous = match UnixDatagramReceiverBuilder::new(
+            &self.config.rendezvous_path_for(&self.name),
+        )
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        {
+            Ok(rendezvous) => rendezvous,
+            Err(UnixDatagramReceiverCreationError::UnixDatagramCreationError(
+                UnixDatagramCreationError::InsufficientPermissions,
+            )) => {
+                fail!(from self, with ListenerCreateError::InsufficientPermissions,
+                    "{} since the rendezvous socket could not be created due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} since the rendezvous socket could not be created ({:?}).", msg, e);
+            }
+        };
+
+        let rendezvous = fail!(from self,
+            when FdRendezvousServer::new(rendezvous, event_fd.file_descriptor().clone(), None),
+            with ListenerCreateError::InternalFailure,
+            "{} since the rendezvous requests could not be served.", msg);
+
+        storage.release_ownership();
+        Ok(Listener {
+            storage,
+            event_fd,
+            _rendezvous: rendezvous,
+        })
+    }
+}
@@ -11,6 +11,10 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub mod common;
+#[cfg(target_os = "linux")]
+pub mod eventfd;
+#[cfg(target_os = "linux")]
+pub mod futex_bitset_posix_shared_memory;
 pub mod id_tracker;
 pub mod process_local;
 pub mod sem_bitset_posix_shared_memory;
@@ -18,16 +22,23 @@ pub mod sem_bitset_process_local;
 pub mod signal_mechanism;
 pub mod unix_datagram_socket;
 
-use std::{fmt::Debug, time::Duration};
+use std::{fmt::Debug, mem::MaybeUninit, time::Duration};
 
 pub use crate::named_concept::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_log::fail;
 pub use iceoryx2_bb_system_types::file_name::*;
 pub use iceoryx2_bb_system_types::path::Path;
 
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+/// The maximum size in bytes of a [`TriggerPayload`].
+pub const MAX_TRIGGER_PAYLOAD_SIZE: usize = 64;
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum NotifierNotifyError {
     FailedToDeliverSignal,
     TriggerIdOutOfBounds,
+    PayloadNotSupported,
     Disconnected,
     InternalFailure,
 }
@@ -57,11 +68,12 @@ impl std::fmt::Display for NotifierCreateError {
 
 impl std::error::Error for NotifierCreateError {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum ListenerWaitError {
     ContractViolation,
     InternalFailure,
     InterruptSignal,
+    PayloadNotSupported,
 }
 
 impl std::fmt::Display for ListenerWaitError {
@@ -100,11 +112,118 @@ impl TriggerId {
     }
 }
 
+/// A small value of at most [`MAX_TRIGGER_PAYLOAD_SIZE`] bytes that can be transported
+/// together with a [`TriggerId`] via [`Notifier::notify_with_payload()`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriggerPayload {
+    len: usize,
+    data: [u8; MAX_TRIGGER_PAYLOAD_SIZE],
+}
+
+impl Default for TriggerPayload {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            data: [0; MAX_TRIGGER_PAYLOAD_SIZE],
+        }
+    }
+}
+
+impl Debug for TriggerPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TriggerPayload {{ data: {:?} }}", self.as_bytes())
+    }
+}
+
+impl TriggerPayload {
+    /// Creates a new [`TriggerPayload`] from the provided bytes. If it contains more than
+    /// [`MAX_TRIGGER_PAYLOAD_SIZE`] bytes it returns [`None`].
+    pub fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > MAX_TRIGGER_PAYLOAD_SIZE {
+            return None;
+        }
+
+        let mut new_self = Self {
+            len: bytes.len(),
+            ..Self::default()
+        };
+        new_self.data[..bytes.len()].copy_from_slice(bytes);
+        Some(new_self)
+    }
+
+    /// Creates a new [`TriggerPayload`] that contains a copy of the provided value. If the
+    /// value is larger than [`MAX_TRIGGER_PAYLOAD_SIZE`] it returns [`None`].
+    pub fn from_value<T: ZeroCopySend + Copy>(value: &T) -> Option<Self> {
+        let len = core::mem::size_of::<T>();
+        if len > MAX_TRIGGER_PAYLOAD_SIZE {
+            return None;
+        }
+
+        // the value is copied bytewise since viewing a `T` with padding as `&[u8]` would read
+        // uninitialized bytes
+        let mut data = MaybeUninit::<[u8; MAX_TRIGGER_PAYLOAD_SIZE]>::zeroed();
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                (value as *const T).cast::<u8>(),
+                data.as_mut_ptr().cast::<u8>(),
+                len,
+            )
+        };
+
+        Some(Self {
+            len,
+            data: unsafe { data.assume_init() },
+        })
+    }
+
+    /// Returns the value that is stored in the [`TriggerPayload`]. If the size of `T` does not
+    /// match the size of the payload it returns [`None`].
+    ///
+    /// # Safety
+    ///
+    ///  * `T` must be the type the [`TriggerPayload`] was created from with
+    ///    [`TriggerPayload::from_value()`] or every bit pattern must be a valid `T`
+    pub unsafe fn value<T: ZeroCopySend + Copy>(&self) -> Option<T> {
+        if self.len != core::mem::size_of::<T>() {
+            return None;
+        }
+
+        Some(core::ptr::read_unaligned(self.data.as_ptr().cast()))
+    }
+
+    /// Returns the content of the [`TriggerPayload`] as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the number of bytes of the [`TriggerPayload`].
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the [`TriggerPayload`] does not contain any bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
 pub trait Notifier: NamedConcept + Debug {
     fn trigger_id_max(&self) -> TriggerId {
         TriggerId::new(usize::MAX)
     }
     fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError>;
+
+    /// Notifies the [`Listener`] with a [`TriggerId`] and a [`TriggerPayload`]. Fails with
+    /// [`NotifierNotifyError::PayloadNotSupported`] when the [`Event`] cannot transport
+    /// payloads, see [`Event::has_payload_support()`].
+    fn notify_with_payload(
+        &self,
+        _id: TriggerId,
+        _payload: &TriggerPayload,
+    ) -> Result<(), NotifierNotifyError> {
+        fail!(from self, with NotifierNotifyError::PayloadNotSupported,
+            "Unable to notify with payload since the event concept does not support payloads.");
+    }
 }
 
 pub trait NotifierBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -124,6 +243,40 @@ pub trait Listener: NamedConcept + Debug {
         timeout: Duration,
     ) -> Result<(), ListenerWaitError>;
     fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError>;
+
+    /// Like [`Listener::try_wait_all()`] but additionally provides the [`TriggerPayload`] that
+    /// was sent with [`Notifier::notify_with_payload()`]. A [`TriggerId`] that was sent with
+    /// [`Notifier::notify()`] comes with an empty [`TriggerPayload`]. Fails with
+    /// [`ListenerWaitError::PayloadNotSupported`] when the [`Event`] cannot transport payloads,
+    /// see [`Event::has_payload_support()`].
+    fn try_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        _callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        fail!(from self, with ListenerWaitError::PayloadNotSupported,
+            "Unable to wait for payloads since the event concept does not support payloads.");
+    }
+
+    /// Like [`Listener::timed_wait_all()`] but additionally provides the [`TriggerPayload`]
+    /// that was sent with [`Notifier::notify_with_payload()`].
+    fn timed_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        _callback: F,
+        _timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        fail!(from self, with ListenerWaitError::PayloadNotSupported,
+            "Unable to wait for payloads since the event concept does not support payloads.");
+    }
+
+    /// Like [`Listener::blocking_wait_all()`] but additionally provides the [`TriggerPayload`]
+    /// that was sent with [`Notifier::notify_with_payload()`].
+    fn blocking_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        _callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        fail!(from self, with ListenerWaitError::PayloadNotSupported,
+            "Unable to wait for payloads since the event concept does not support payloads.");
+    }
 }
 
 pub trait ListenerBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -145,4 +298,11 @@ pub trait Event: Sized + NamedConceptMgmt + Debug {
     fn has_trigger_id_limit() -> bool {
         false
     }
+
+    /// Returns true when the [`Event`] transports the [`TriggerPayload`] of
+    /// [`Notifier::notify_with_payload()`]. Events that coalesce [`TriggerId`]s cannot
+    /// transport payloads.
+    fn has_payload_support() -> bool {
+        false
+    }
 }
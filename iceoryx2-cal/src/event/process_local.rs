@@ -34,14 +34,16 @@ use ouroboros::self_referencing;
 
 const DEFAULT_CAPACITY: usize = 2048;
 
+type Notification = (TriggerId, TriggerPayload);
+
 #[self_referencing]
 #[derive(Debug)]
 struct Management {
     has_listener: AtomicBool,
-    mtx_handle: MutexHandle<ConditionVariableData<FixedSizeQueue<TriggerId, DEFAULT_CAPACITY>>>,
+    mtx_handle: MutexHandle<ConditionVariableData<FixedSizeQueue<Notification, DEFAULT_CAPACITY>>>,
     #[borrows(mtx_handle)]
     #[covariant]
-    cvar: ConditionVariable<'this, FixedSizeQueue<TriggerId, DEFAULT_CAPACITY>>,
+    cvar: ConditionVariable<'this, FixedSizeQueue<Notification, DEFAULT_CAPACITY>>,
 }
 
 #[derive(Debug)]
@@ -123,8 +125,8 @@ impl NamedConcept for Duplex {
     }
 }
 
-impl Notifier for Duplex {
-    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+impl Duplex {
+    fn push(&self, notification: Notification) -> Result<(), NotifierNotifyError> {
         let msg = "Unable to notify event::process_local::Listener";
         if !self
             .management
@@ -140,7 +142,9 @@ impl Notifier for Duplex {
         if self
             .management
             .borrow_cvar()
-            .modify_notify_one(|queue| push_successful.store(queue.push(id), Ordering::Relaxed))
+            .modify_notify_one(|queue| {
+                push_successful.store(queue.push(notification), Ordering::Relaxed)
+            })
             .is_err()
         {
             fail!(from self, with NotifierNotifyError::InternalFailure,
@@ -156,6 +160,20 @@ impl Notifier for Duplex {
     }
 }
 
+impl Notifier for Duplex {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        self.push((id, TriggerPayload::default()))
+    }
+
+    fn notify_with_payload(
+        &self,
+        id: TriggerId,
+        payload: &TriggerPayload,
+    ) -> Result<(), NotifierNotifyError> {
+        self.push((id, *payload))
+    }
+}
+
 impl Drop for Duplex {
     fn drop(&mut self) {
         if self.is_listener {
@@ -187,7 +205,7 @@ impl Listener for Duplex {
                         "{} due to an internal failure in the underlying condition variable ({:?}).", msg, v);
             }
             Ok(None) => Ok(None),
-            Ok(Some(mut guard)) => Ok(guard.value.pop()),
+            Ok(Some(mut guard)) => Ok(guard.value.pop().map(|(id, _)| id)),
         }
     }
 
@@ -198,18 +216,40 @@ impl Listener for Duplex {
                 fail!(from self, with ListenerWaitError::InternalFailure,
                         "{} due to an internal failure in the underlying condition variable ({:?}).", msg, v);
             }
-            Ok(mut guard) => Ok(guard.value.pop()),
+            Ok(mut guard) => Ok(guard.value.pop().map(|(id, _)| id)),
         }
     }
 
-    fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
-        self.timed_wait_all(callback, Duration::ZERO)
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, mut callback: F) -> Result<(), ListenerWaitError> {
+        self.try_wait_all_with_payload(|id, _| callback(id))
     }
 
     fn timed_wait_all<F: FnMut(TriggerId)>(
         &self,
         mut callback: F,
         timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.timed_wait_all_with_payload(|id, _| callback(id), timeout)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.blocking_wait_all_with_payload(|id, _| callback(id))
+    }
+
+    fn try_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.timed_wait_all_with_payload(callback, Duration::ZERO)
+    }
+
+    fn timed_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
         let msg = "Failed to try_wait";
         match self
@@ -224,15 +264,15 @@ impl Listener for Duplex {
             }
             Ok(None) => Ok(()),
             Ok(Some(mut guard)) => {
-                while let Some(id) = guard.value.pop() {
-                    callback(id)
+                while let Some((id, payload)) = guard.value.pop() {
+                    callback(id, &payload)
                 }
                 Ok(())
             }
         }
     }
 
-    fn blocking_wait_all<F: FnMut(TriggerId)>(
+    fn blocking_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
         &self,
         mut callback: F,
     ) -> Result<(), ListenerWaitError> {
@@ -243,8 +283,8 @@ impl Listener for Duplex {
                         "{} due to an internal failure in the underlying condition variable ({:?}).", msg, v);
             }
             Ok(mut guard) => {
-                while let Some(id) = guard.value.pop() {
-                    callback(id)
+                while let Some((id, payload)) = guard.value.pop() {
+                    callback(id, &payload)
                 }
                 Ok(())
             }
@@ -331,7 +371,7 @@ impl ListenerBuilder<EventImpl> for Builder {
                 has_listener: AtomicBool::new(true),
                 mtx_handle: MutexHandle::new(),
                 cvar_builder: |mtx_handle: &MutexHandle<
-                    ConditionVariableData<FixedSizeQueue<TriggerId, DEFAULT_CAPACITY>>,
+                    ConditionVariableData<FixedSizeQueue<Notification, DEFAULT_CAPACITY>>,
                 >| {
                     ConditionVariableBuilder::new()
                         .is_interprocess_capable(false)
@@ -385,6 +425,10 @@ impl crate::event::Event for EventImpl {
     type Listener = Duplex;
     type NotifierBuilder = Builder;
     type ListenerBuilder = Builder;
+
+    fn has_payload_support() -> bool {
+        true
+    }
 }
 
 impl NamedConceptMgmt for EventImpl {
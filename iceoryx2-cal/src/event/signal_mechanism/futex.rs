@@ -0,0 +1,156 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::{Duration, Instant},
+};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::futex::{self, FutexWaitError};
+
+use crate::event::{ListenerCreateError, ListenerWaitError, NotifierNotifyError};
+
+use super::SignalMechanism;
+
+/// Linux specific [`SignalMechanism`] that waits and wakes up directly on an atomic counter.
+/// Since it does not own any operating system resource a listener that dies while waiting
+/// leaves no inconsistent state behind.
+#[derive(Debug)]
+pub struct Futex {
+    counter: futex::Futex,
+    // the wake up syscall is only required when someone is actually waiting. A dead waiter
+    // leaves the number of waiters incremented which causes only superfluous wake up calls.
+    number_of_waiters: AtomicU32,
+}
+
+impl Futex {
+    fn wait<F: FnOnce(&futex::Futex) -> Result<bool, FutexWaitError>>(
+        &self,
+        wait_call: F,
+    ) -> Result<bool, FutexWaitError> {
+        self.number_of_waiters.fetch_add(1, Ordering::SeqCst);
+        let result = wait_call(&self.counter);
+        self.number_of_waiters.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+
+    fn handle_wait_result(
+        &self,
+        msg: &str,
+        result: Result<bool, FutexWaitError>,
+    ) -> Result<(), ListenerWaitError> {
+        match result {
+            Ok(_) => Ok(()),
+            Err(FutexWaitError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+impl SignalMechanism for Futex {
+    fn new() -> Self {
+        Self {
+            counter: futex::Futex::new(0),
+            number_of_waiters: AtomicU32::new(0),
+        }
+    }
+
+    unsafe fn init(&mut self) -> Result<(), ListenerCreateError> {
+        Ok(())
+    }
+
+    unsafe fn notify(&self) -> Result<(), NotifierNotifyError> {
+        let mut current_value = self.counter.value().load(Ordering::Relaxed);
+        loop {
+            if current_value == u32::MAX {
+                fail!(from self, with NotifierNotifyError::InternalFailure,
+                    "Failed to increment underlying futex since the counter would overflow.");
+            }
+
+            match self.counter.value().compare_exchange_weak(
+                current_value,
+                current_value + 1,
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => current_value = v,
+            }
+        }
+
+        if self.number_of_waiters.load(Ordering::SeqCst) != 0 {
+            self.counter.wake_one();
+        }
+
+        Ok(())
+    }
+
+    unsafe fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut current_value = self.counter.value().load(Ordering::Relaxed);
+        loop {
+            if current_value == 0 {
+                return Ok(false);
+            }
+
+            match self.counter.value().compare_exchange_weak(
+                current_value,
+                current_value - 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(true),
+                Err(v) => current_value = v,
+            }
+        }
+    }
+
+    unsafe fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        let msg = "Failed to decrement underlying futex with timeout";
+        let start = Instant::now();
+        loop {
+            if self.try_wait()? {
+                return Ok(true);
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(false);
+            }
+
+            self.handle_wait_result(
+                msg,
+                self.wait(|counter| counter.timed_wait(0, timeout - elapsed)),
+            )?;
+        }
+    }
+
+    unsafe fn blocking_wait(&self) -> Result<(), ListenerWaitError> {
+        let msg = "Failed to decrement underlying futex in blocking mode";
+        loop {
+            if self.try_wait()? {
+                return Ok(());
+            }
+
+            self.handle_wait_result(
+                msg,
+                self.wait(|counter| counter.blocking_wait(0).map(|_| true)),
+            )?;
+        }
+    }
+}
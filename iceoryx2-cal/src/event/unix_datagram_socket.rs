@@ -23,6 +23,14 @@ pub use iceoryx2_bb_system_types::file_name::FileName;
 
 const MAX_BATCH_SIZE: usize = 512;
 
+/// The datagram that is transferred. A notification without payload consists only of the
+/// [`TriggerId`], a notification with payload is followed by the bytes of the payload.
+#[repr(C)]
+struct Message {
+    id: TriggerId,
+    payload: [u8; MAX_TRIGGER_PAYLOAD_SIZE],
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Configuration {
     suffix: FileName,
@@ -113,6 +121,10 @@ impl crate::event::Event for EventImpl {
     type Listener = Listener;
     type NotifierBuilder = NotifierBuilder;
     type ListenerBuilder = ListenerBuilder;
+
+    fn has_payload_support() -> bool {
+        true
+    }
 }
 
 #[derive(Debug)]
@@ -127,14 +139,11 @@ impl NamedConcept for Notifier {
     }
 }
 
-impl crate::event::Notifier for Notifier {
-    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+impl Notifier {
+    fn send(&self, message: &Message, message_size: usize) -> Result<(), NotifierNotifyError> {
         let msg = "Failed to notify event::unix_datagram_socket::Listener";
         match self.sender.try_send(unsafe {
-            core::slice::from_raw_parts(
-                (&id as *const TriggerId).cast(),
-                core::mem::size_of::<TriggerId>(),
-            )
+            core::slice::from_raw_parts((message as *const Message).cast(), message_size)
         }) {
             Ok(true) => Ok(()),
             Ok(false) | Err(UnixDatagramSendError::MessagePartiallySend(_)) => {
@@ -155,6 +164,29 @@ impl crate::event::Notifier for Notifier {
     }
 }
 
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        let message = Message {
+            id,
+            payload: [0; MAX_TRIGGER_PAYLOAD_SIZE],
+        };
+        self.send(&message, core::mem::size_of::<TriggerId>())
+    }
+
+    fn notify_with_payload(
+        &self,
+        id: TriggerId,
+        payload: &TriggerPayload,
+    ) -> Result<(), NotifierNotifyError> {
+        let mut message = Message {
+            id,
+            payload: [0; MAX_TRIGGER_PAYLOAD_SIZE],
+        };
+        message.payload[..payload.len()].copy_from_slice(payload.as_bytes());
+        self.send(&message, core::mem::size_of::<TriggerId>() + payload.len())
+    }
+}
+
 #[derive(Debug)]
 pub struct NotifierBuilder {
     name: FileName,
@@ -230,12 +262,12 @@ impl Listener {
         &self,
         error_msg: &str,
         mut wait_call: F,
-    ) -> Result<Option<TriggerId>, ListenerWaitError> {
-        let mut id_buffer = MaybeUninit::uninit();
+    ) -> Result<Option<(TriggerId, TriggerPayload)>, ListenerWaitError> {
+        let mut message = MaybeUninit::<Message>::uninit();
         match wait_call(self, unsafe {
             core::slice::from_raw_parts_mut(
-                id_buffer.as_mut_ptr() as *mut u8,
-                core::mem::size_of::<TriggerId>(),
+                message.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<Message>(),
             )
         }) {
             Ok(v) => {
@@ -243,12 +275,31 @@ impl Listener {
                     return Ok(None);
                 }
 
-                if v as usize != core::mem::size_of::<TriggerId>() {
+                if (v as usize) < core::mem::size_of::<TriggerId>() {
                     fail!(from self, with ListenerWaitError::ContractViolation,
-                        "{} since the expected amount of received bytes {} does not match the expected amount of bytes {}.",
+                        "{} since the amount of received bytes {} is smaller than the expected minimum amount of bytes {}.",
                         error_msg, v, core::mem::size_of::<TriggerId>());
                 }
-                Ok(Some(unsafe { id_buffer.assume_init() }))
+
+                let message = message.as_ptr();
+                let payload_len = v as usize - core::mem::size_of::<TriggerId>();
+                let id = unsafe { core::ptr::addr_of!((*message).id).read() };
+                // only the received bytes of the payload are initialized
+                let payload = TriggerPayload::new(unsafe {
+                    core::slice::from_raw_parts(
+                        core::ptr::addr_of!((*message).payload).cast::<u8>(),
+                        payload_len,
+                    )
+                });
+
+                match payload {
+                    Some(payload) => Ok(Some((id, payload))),
+                    None => {
+                        fail!(from self, with ListenerWaitError::ContractViolation,
+                            "{} since the received payload of {} bytes exceeds the maximum payload size of {} bytes.",
+                            error_msg, payload_len, MAX_TRIGGER_PAYLOAD_SIZE);
+                    }
+                }
             }
             Err(v) => {
                 fail!(from self, with ListenerWaitError::InternalFailure,
@@ -258,35 +309,78 @@ impl Listener {
     }
 }
 
-impl crate::event::Listener for Listener {
-    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+impl Listener {
+    fn try_wait_one_with_payload(
+        &self,
+    ) -> Result<Option<(TriggerId, TriggerPayload)>, ListenerWaitError> {
         self.wait(
             "Unable to try wait for signal on event::unix_datagram_socket::Listener",
             |this, buffer| this.receiver.try_receive(buffer),
         )
     }
 
-    fn timed_wait_one(
+    fn timed_wait_one_with_payload(
         &self,
-        timeout: std::time::Duration,
-    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        timeout: Duration,
+    ) -> Result<Option<(TriggerId, TriggerPayload)>, ListenerWaitError> {
         self.wait(
            &format!("Unable to wait for signal with timeout {:?} on event::unix_datagram_socket::Listener", timeout),
             |this, buffer| this.receiver.timed_receive(buffer, timeout),
         )
     }
 
-    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+    fn blocking_wait_one_with_payload(
+        &self,
+    ) -> Result<Option<(TriggerId, TriggerPayload)>, ListenerWaitError> {
         self.wait(
             "Unable to blocking wait for signal on event::unix_datagram_socket::Listener",
             |this, buffer| this.receiver.blocking_receive(buffer),
         )
     }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        Ok(self.try_wait_one_with_payload()?.map(|(id, _)| id))
+    }
+
+    fn timed_wait_one(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        Ok(self.timed_wait_one_with_payload(timeout)?.map(|(id, _)| id))
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        Ok(self.blocking_wait_one_with_payload()?.map(|(id, _)| id))
+    }
 
     fn try_wait_all<F: FnMut(TriggerId)>(&self, mut callback: F) -> Result<(), ListenerWaitError> {
+        self.try_wait_all_with_payload(|id, _| callback(id))
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.timed_wait_all_with_payload(|id, _| callback(id), timeout)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.blocking_wait_all_with_payload(|id, _| callback(id))
+    }
+
+    fn try_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
         let mut counter = 0;
-        while let Some(id) = self.try_wait_one()? {
-            callback(id);
+        while let Some((id, payload)) = self.try_wait_one_with_payload()? {
+            callback(id, &payload);
 
             counter += 1;
             if counter == MAX_BATCH_SIZE {
@@ -297,25 +391,25 @@ impl crate::event::Listener for Listener {
         Ok(())
     }
 
-    fn timed_wait_all<F: FnMut(TriggerId)>(
+    fn timed_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
         &self,
         mut callback: F,
         timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
-        if let Some(id) = self.timed_wait_one(timeout)? {
-            callback(id);
+        if let Some((id, payload)) = self.timed_wait_one_with_payload(timeout)? {
+            callback(id, &payload);
         }
-        self.try_wait_all(callback)
+        self.try_wait_all_with_payload(callback)
     }
 
-    fn blocking_wait_all<F: FnMut(TriggerId)>(
+    fn blocking_wait_all_with_payload<F: FnMut(TriggerId, &TriggerPayload)>(
         &self,
         mut callback: F,
     ) -> Result<(), ListenerWaitError> {
-        if let Some(id) = self.blocking_wait_one()? {
-            callback(id);
+        if let Some((id, payload)) = self.blocking_wait_one_with_payload()? {
+            callback(id, &payload);
         }
-        self.try_wait_all(callback)
+        self.try_wait_all_with_payload(callback)
     }
 }
 
@@ -0,0 +1,274 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Hands over a [`FileDescriptor`] to other processes for resources that cannot be opened
+//! by name, like an [`EventFd`] or a [`iceoryx2_bb_posix::mem_fd::MemFd`]. The owner of
+//! the resource binds a rendezvous socket and starts a [`FdRendezvousServer`] that sends
+//! the [`FileDescriptor`] to the processes that call [`request_file_descriptor()`] with the
+//! path of the rendezvous socket.
+//!
+//! Every request carries the [`SocketCred`] of the requester which are verified by the
+//! operating system. The [`FileDescriptor`] is sent only to processes of the same user that
+//! are accepted by the optional [`PeerAuthorization`].
+
+use std::time::Duration;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::{
+    event_fd::*,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::{FileDescriptorSet, FileDescriptorSetWaitError, FileEvent},
+    socket_ancillary::{SocketAncillary, SocketCred},
+    thread::{Thread, ThreadBuilder},
+    unix_datagram_socket::*,
+};
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+use crate::dynamic_storage::PeerAuthorization;
+
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_secs(i16::MAX as _);
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) enum FdRendezvousError {
+    DoesNotExist,
+    InsufficientPermissions,
+    InternalFailure,
+}
+
+/// Sends the [`FileDescriptor`] to every authorized requester on the rendezvous socket in a
+/// background thread until it goes out of scope.
+#[derive(Debug)]
+pub(crate) struct FdRendezvousServer {
+    shutdown: EventFd,
+    _thread: Thread<'static>,
+}
+
+impl Drop for FdRendezvousServer {
+    fn drop(&mut self) {
+        // the thread is joined when it is dropped
+        if let Err(e) = self.shutdown.notify() {
+            fatal_panic!(from self,
+                "This should never happen! Unable to stop the rendezvous thread ({:?}).", e);
+        }
+    }
+}
+
+impl FdRendezvousServer {
+    pub(crate) fn new(
+        rendezvous: UnixDatagramReceiver,
+        file_descriptor: FileDescriptor,
+        peer_authorization: Option<PeerAuthorization>,
+    ) -> Result<Self, FdRendezvousError> {
+        let msg = "Unable to start serving rendezvous requests";
+        let origin = format!("FdRendezvousServer::new({:?})", rendezvous.name());
+
+        let shutdown = match EventFdBuilder::new().create() {
+            Ok(shutdown) => shutdown,
+            Err(e) => {
+                fail!(from origin, with FdRendezvousError::InternalFailure,
+                    "{} since the shutdown event file descriptor could not be created ({:?}).", msg, e);
+            }
+        };
+        let shutdown_trigger = shutdown.file_descriptor().clone();
+
+        let thread = match ThreadBuilder::new().spawn(move || {
+            serve_requests(rendezvous, file_descriptor, peer_authorization, shutdown)
+        }) {
+            Ok(thread) => thread,
+            Err(e) => {
+                fail!(from origin, with FdRendezvousError::InternalFailure,
+                    "{} since the rendezvous thread could not be started ({:?}).", msg, e);
+            }
+        };
+
+        Ok(Self {
+            shutdown: unsafe { EventFd::from_file_descriptor(shutdown_trigger) },
+            _thread: thread,
+        })
+    }
+}
+
+fn is_authorized(credentials: &SocketCred, peer_authorization: &Option<PeerAuthorization>) -> bool {
+    if credentials.get_uid() != SocketCred::new().get_uid() {
+        return false;
+    }
+
+    match peer_authorization {
+        Some(peer_authorization) => peer_authorization.is_authorized(credentials),
+        None => true,
+    }
+}
+
+fn serve_requests(
+    rendezvous: UnixDatagramReceiver,
+    file_descriptor: FileDescriptor,
+    peer_authorization: Option<PeerAuthorization>,
+    shutdown: EventFd,
+) {
+    let origin = "FdRendezvousServer::serve_requests()";
+    let fd_set = FileDescriptorSet::new();
+    // two file descriptors never exceed the capacity of the set
+    let _rendezvous_guard = fd_set.add(&rendezvous).unwrap();
+    let _shutdown_guard = fd_set.add(&shutdown).unwrap();
+
+    let mut request = SocketAncillary::new();
+    loop {
+        match shutdown.try_wait() {
+            Ok(0) => (),
+            Ok(_) => return,
+            Err(e) => {
+                warn!(from origin, "Stop serving rendezvous requests since the shutdown signal could not be acquired ({:?}).", e);
+                return;
+            }
+        }
+
+        match rendezvous.try_receive_msg(&mut request) {
+            Ok(false) => (),
+            Ok(true) => {
+                match request.get_creds() {
+                    Some(credentials) if is_authorized(&credentials, &peer_authorization) => (),
+                    Some(credentials) => {
+                        warn!(from origin, "Ignoring rendezvous request of the unauthorized process {}.", credentials);
+                        continue;
+                    }
+                    None => {
+                        warn!(from origin, "Ignoring rendezvous request without credentials.");
+                        continue;
+                    }
+                }
+
+                let reply_path = match FilePath::new(request.get_data()) {
+                    Ok(path) => path,
+                    Err(e) => {
+                        warn!(from origin, "Ignoring rendezvous request with an invalid reply path ({:?}).", e);
+                        continue;
+                    }
+                };
+
+                match UnixDatagramSenderBuilder::new(&reply_path).create() {
+                    Ok(reply) => {
+                        let mut reply_msg = SocketAncillary::new();
+                        reply_msg.add_fd(file_descriptor.clone());
+                        if let Err(e) = reply.try_send_msg(&mut reply_msg) {
+                            warn!(from origin, "Unable to send the file descriptor to \"{}\" ({:?}).", reply_path, e);
+                        }
+                    }
+                    Err(e) => {
+                        warn!(from origin, "Unable to connect to the reply socket \"{}\" ({:?}).", reply_path, e);
+                    }
+                }
+                continue;
+            }
+            Err(e) => {
+                warn!(from origin, "Unable to receive rendezvous request ({:?}).", e);
+            }
+        }
+
+        match fd_set.timed_wait(SHUTDOWN_POLL_INTERVAL, FileEvent::Read, |_| {}) {
+            Ok(()) | Err(FileDescriptorSetWaitError::Interrupt) => (),
+            Err(e) => {
+                warn!(from origin, "Stop serving rendezvous requests since waiting for requests failed ({:?}).", e);
+                return;
+            }
+        }
+    }
+}
+
+/// Requests the [`FileDescriptor`] from the [`FdRendezvousServer`] that serves the socket
+/// at `rendezvous_path`. The response is received on a temporary socket at `reply_path`
+/// that must be unique.
+pub(crate) fn request_file_descriptor(
+    rendezvous_path: &FilePath,
+    reply_path: &FilePath,
+    timeout: Duration,
+) -> Result<FileDescriptor, FdRendezvousError> {
+    let msg = "Unable to request the file descriptor";
+    let origin = format!("request_file_descriptor({:?})", rendezvous_path);
+
+    let reply = match UnixDatagramReceiverBuilder::new(reply_path)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+    {
+        Ok(reply) => reply,
+        Err(UnixDatagramReceiverCreationError::UnixDatagramCreationError(
+            UnixDatagramCreationError::InsufficientPermissions,
+        )) => {
+            fail!(from origin, with FdRendezvousError::InsufficientPermissions,
+                "{} since the reply socket could not be created due to insufficient permissions.", msg);
+        }
+        Err(e) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the reply socket could not be created ({:?}).", msg, e);
+        }
+    };
+
+    let rendezvous = match UnixDatagramSenderBuilder::new(rendezvous_path).create() {
+        Ok(rendezvous) => rendezvous,
+        Err(
+            UnixDatagramSenderCreationError::DoesNotExist
+            | UnixDatagramSenderCreationError::ConnectionRefused,
+        ) => {
+            fail!(from origin, with FdRendezvousError::DoesNotExist,
+                "{} since no one serves the rendezvous socket.", msg);
+        }
+        Err(UnixDatagramSenderCreationError::InsufficientPermissions) => {
+            fail!(from origin, with FdRendezvousError::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(e) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the rendezvous socket could not be connected ({:?}).", msg, e);
+        }
+    };
+
+    // the reply path is sent together with the credentials that authenticate the requester
+    let mut request = SocketAncillary::new();
+    request.set_creds(&SocketCred::new());
+    if !request.set_data(reply.name().as_bytes()) {
+        fail!(from origin, with FdRendezvousError::InternalFailure,
+            "{} since the reply path \"{}\" does not fit into the request.", msg, reply.name());
+    }
+
+    match rendezvous.timed_send_msg(&mut request, timeout) {
+        Ok(true) => (),
+        Ok(false) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the request could not be sent within {:?}.", msg, timeout);
+        }
+        Err(e) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the request could not be sent ({:?}).", msg, e);
+        }
+    }
+
+    let mut reply_msg = SocketAncillary::new();
+    match reply.timed_receive_msg(&mut reply_msg, timeout) {
+        Ok(true) => (),
+        Ok(false) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since no response was received within {:?}.", msg, timeout);
+        }
+        Err(e) => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the response could not be received ({:?}).", msg, e);
+        }
+    }
+
+    match reply_msg.extract_fds().pop() {
+        Some(fd) => Ok(fd),
+        None => {
+            fail!(from origin, with FdRendezvousError::InternalFailure,
+                "{} since the response does not contain a file descriptor.", msg);
+        }
+    }
+}
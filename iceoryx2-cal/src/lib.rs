@@ -13,6 +13,8 @@
 pub mod communication_channel;
 pub mod dynamic_storage;
 pub mod event;
+#[cfg(target_os = "linux")]
+pub(crate) mod fd_rendezvous;
 pub mod hash;
 pub mod monitoring;
 pub mod named_concept;
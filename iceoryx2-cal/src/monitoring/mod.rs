@@ -126,7 +126,7 @@ pub enum MonitoringStateError {
 }
 
 /// The token enables a process to be monitored by another process.
-pub trait MonitoringToken: NamedConcept {}
+pub trait MonitoringToken: NamedConcept + Send + Sync {}
 
 /// The cleaner owns the remains of a dead process and is the only one that is allowed to clean up
 /// those resources.
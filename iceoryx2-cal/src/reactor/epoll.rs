@@ -0,0 +1,150 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Linux specific [`crate::reactor::Reactor`] based on [`Epoll`]. In contrast to the
+//! [`crate::reactor::posix_select::Reactor`] its capacity is not limited by `FD_SETSIZE` but
+//! by the number of file descriptors a process is allowed to open.
+
+use std::{fmt::Debug, time::Duration};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    epoll::{Epoll, EpollAddError, EpollBuilder, EpollCreationError, EpollGuard, EpollWaitError},
+    file_descriptor::FileDescriptor,
+    file_descriptor_set::FileEvent,
+    system_configuration::ProcessResourceLimit,
+};
+
+use crate::reactor::{ReactorAttachError, ReactorCreateError, ReactorWaitError};
+
+impl crate::reactor::ReactorGuard<'_, '_> for EpollGuard<'_, '_> {}
+
+#[derive(Debug)]
+pub struct Reactor {
+    epoll: Epoll,
+}
+
+impl Reactor {
+    fn handle_wait_result(
+        &self,
+        result: Result<(), EpollWaitError>,
+    ) -> Result<(), ReactorWaitError> {
+        let msg = "Unable to wait on Reactor";
+        match result {
+            Ok(()) => Ok(()),
+            Err(EpollWaitError::Interrupt) => {
+                fail!(from self, with ReactorWaitError::Interrupt,
+                        "{} since an interrupt signal was received while waiting.",
+                        msg);
+            }
+            Err(v) => {
+                fail!(from self, with ReactorWaitError::UnknownError,
+                        "{} since an unknown failure occurred in the underlying Epoll ({:?}).",
+                        msg, v);
+            }
+        }
+    }
+}
+
+impl crate::reactor::Reactor for Reactor {
+    type Guard<'reactor, 'attachment> = EpollGuard<'reactor, 'attachment>;
+    type Builder = ReactorBuilder;
+
+    fn capacity() -> usize {
+        ProcessResourceLimit::MaxNumberOfOpenFileDescriptors
+            .soft_limit()
+            .min(usize::MAX as u64) as usize
+    }
+
+    fn len(&self) -> usize {
+        self.epoll.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.epoll.is_empty()
+    }
+
+    fn attach<
+        'reactor,
+        'attachment,
+        F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + Debug,
+    >(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, super::ReactorAttachError> {
+        let msg = "Unable to attach";
+        match self.epoll.add(value, FileEvent::Read) {
+            Ok(guard) => Ok(guard),
+            Err(EpollAddError::CapacityExceeded) => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                    "{} {:?} to reactor since the maximum number of epoll watches was exceeded.",
+                    msg, value);
+            }
+            Err(EpollAddError::UnknownError(v)) => {
+                fail!(from self, with ReactorAttachError::UnknownError(v),
+                    "{} {:?} to reactor since an unknown failure occurred in the underlying Epoll ({}).",
+                    msg, value, v);
+            }
+            Err(e) => {
+                fail!(from self, with ReactorAttachError::UnknownError(-1),
+                    "{} {:?} to reactor due to a failure in the underlying Epoll ({:?}).",
+                    msg, value, e);
+            }
+        }
+    }
+
+    fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.try_wait(fn_call))
+    }
+
+    fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.timed_wait(timeout, fn_call))
+    }
+
+    fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.blocking_wait(fn_call))
+    }
+}
+
+#[derive(Debug)]
+pub struct ReactorBuilder {}
+
+impl crate::reactor::ReactorBuilder<Reactor> for ReactorBuilder {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn create(self) -> Result<Reactor, ReactorCreateError> {
+        let msg = "Unable to create Reactor";
+        match EpollBuilder::new().create() {
+            Ok(epoll) => Ok(Reactor { epoll }),
+            Err(EpollCreationError::UnknownError(v)) => {
+                fail!(from self, with ReactorCreateError::UnknownError(v),
+                    "{} since an unknown failure occurred in the underlying Epoll ({}).", msg, v);
+            }
+            Err(e) => {
+                fail!(from self, with ReactorCreateError::UnknownError(-1),
+                    "{} due to a failure in the underlying Epoll ({:?}).", msg, e);
+            }
+        }
+    }
+}
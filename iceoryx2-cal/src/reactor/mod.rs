@@ -10,6 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(target_os = "linux")]
+pub mod epoll;
 pub mod posix_select;
 
 use std::{fmt::Debug, time::Duration};
@@ -38,7 +40,7 @@ pub enum ReactorWaitError {
 
 pub trait ReactorGuard<'reactor, 'attachment> {}
 
-pub trait Reactor: Sized {
+pub trait Reactor: Sized + Debug {
     type Guard<'reactor, 'attachment>: ReactorGuard<'reactor, 'attachment>
     where
         Self: 'reactor;
@@ -26,7 +26,8 @@ impl Serialize for Cdr {
     fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
         Ok(
             fail!(from "Cdr::serialize", when cdr::serialize::<_, _, CdrBe>(&value, Infinite),
-                with SerializeError::InternalError, "Failed to serialize object" ),
+                map cdr::Error::TypeNotSupported => SerializeError::UnsupportedType,
+                unmatched SerializeError::InternalError, "Failed to serialize object" ),
         )
     }
 
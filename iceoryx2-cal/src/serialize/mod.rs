@@ -41,14 +41,14 @@ pub mod cdr;
 pub mod toml;
 
 /// Failure emitted by [`Serialize::serialize()`]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum SerializeError {
     UnsupportedType,
     InternalError,
 }
 
 /// Failure emitted by [`Serialize::deserialize()`]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum DeserializeError {
     InternalError,
 }
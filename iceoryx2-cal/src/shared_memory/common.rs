@@ -18,6 +18,7 @@ use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
@@ -144,6 +145,8 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        memory_placement: MemoryPlacement,
+        peer_authorization: Option<PeerAuthorization>,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -156,6 +159,8 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                memory_placement: MemoryPlacement::default(),
+                peer_authorization: None,
             }
         }
 
@@ -220,6 +225,16 @@ pub mod details {
             self
         }
 
+        fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+            self.memory_placement = value;
+            self
+        }
+
+        fn peer_authorization(mut self, value: PeerAuthorization) -> Self {
+            self.peer_authorization = Some(value);
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -233,10 +248,17 @@ pub mod details {
 
             let allocator_mgmt_size = Allocator::management_size(self.size, allocator_config);
 
-            let storage = match Storage::Builder::new(&self.name)
+            let mut builder = Storage::Builder::new(&self.name)
                 .config(&self.config.convert())
                 .supplementary_size(self.size + allocator_mgmt_size)
                 .has_ownership(self.has_ownership)
+                .memory_placement(self.memory_placement);
+
+            if let Some(ref peer_authorization) = self.peer_authorization {
+                builder = builder.peer_authorization(peer_authorization.clone());
+            }
+
+            let storage = match builder
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
                 })
@@ -0,0 +1,25 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Linux specific [`crate::shared_memory::SharedMemory`] based on an anonymous memfd that is
+//! handed over to other processes via file descriptor passing, see
+//! [`crate::dynamic_storage::memfd`]. It can be opened only as long as its creator is alive
+//! and only by the processes that are accepted by the
+//! [`crate::shared_memory::SharedMemoryBuilder::peer_authorization()`]. The kernel releases
+//! it when the last process that has mapped it exits.
+
+use super::common::details::AllocatorDetails;
+
+pub type Memory<Allocator> = crate::shared_memory::common::details::Memory<
+    Allocator,
+    crate::dynamic_storage::memfd::Storage<AllocatorDetails<Allocator>>,
+>;
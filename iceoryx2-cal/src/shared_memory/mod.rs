@@ -55,13 +55,17 @@
 //! ```
 
 pub mod common;
+#[cfg(target_os = "linux")]
+pub mod memfd;
 pub mod posix;
 pub mod process_local;
 
 use std::{fmt::Debug, time::Duration};
 
+pub use crate::dynamic_storage::PeerAuthorization;
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
 use iceoryx2_bb_system_types::file_name::*;
 
 /// Failure returned by [`SharedMemoryBuilder::create()`]
@@ -105,6 +109,15 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// Sets the size of the [`SharedMemory`]
     fn size(self, value: usize) -> Self;
 
+    /// Defines the [`MemoryPlacement`] of a newly created [`SharedMemory`], for instance huge
+    /// pages or a NUMA node. Implementations that are not backed by mapped memory ignore it.
+    fn memory_placement(self, value: MemoryPlacement) -> Self;
+
+    /// Restricts which processes can open a newly created [`SharedMemory`]. Processes of other
+    /// users are always rejected. Implementations that are opened by name rely on the
+    /// permissions of the underlying resource and ignore it.
+    fn peer_authorization(self, value: PeerAuthorization) -> Self;
+
     /// The timeout defines how long the [`SharedMemoryBuilder`] should wait for
     /// [`SharedMemoryBuilder::create()`] to finialize
     /// the initialization. This is required when the [`SharedMemory`] is created and initialized
@@ -148,6 +148,74 @@ pub mod details {
         }
     }
 
+    fn remove_connection_port<Storage: DynamicStorage<SharedManagementData>>(
+        name: &FileName,
+        config: &Configuration<Storage>,
+        state_to_remove: State,
+    ) -> Result<(), ZeroCopyPortRemoveError> {
+        let origin = "ZeroCopyConnection::remove_port()";
+        let msg = format!(
+            "Unable to remove the {:?} from the connection \"{}\"",
+            state_to_remove, name
+        );
+        let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+            Storage,
+        >>::new(name)
+        .config(&config.convert())
+        .open()
+        {
+            Ok(storage) => storage,
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                fail!(from origin, with ZeroCopyPortRemoveError::DoesNotExist,
+                    "{} since the connection does not exist.", msg);
+            }
+            Err(DynamicStorageOpenError::VersionMismatch) => {
+                fail!(from origin, with ZeroCopyPortRemoveError::VersionMismatch,
+                    "{} since the version of the connection does not match.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with ZeroCopyPortRemoveError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        };
+
+        cleanup_shared_memory(&storage, state_to_remove);
+        Ok(())
+    }
+
+    fn number_of_buffered_samples<Storage: DynamicStorage<SharedManagementData>>(
+        name: &FileName,
+        config: &Configuration<Storage>,
+    ) -> Result<usize, ZeroCopyInspectError> {
+        let origin = "ZeroCopyConnection::number_of_buffered_samples()";
+        let msg = format!(
+            "Unable to acquire the number of buffered samples of the connection \"{}\"",
+            name
+        );
+        let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+            Storage,
+        >>::new(name)
+        .config(&config.convert())
+        .open()
+        {
+            Ok(storage) => storage,
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                fail!(from origin, with ZeroCopyInspectError::DoesNotExist,
+                    "{} since the connection does not exist.", msg);
+            }
+            Err(DynamicStorageOpenError::VersionMismatch) => {
+                fail!(from origin, with ZeroCopyInspectError::VersionMismatch,
+                    "{} since the version of the connection does not match.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with ZeroCopyInspectError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        };
+
+        Ok(storage.get().submission_channel.len())
+    }
+
     #[derive(Debug)]
     #[repr(C)]
     pub struct SharedManagementData {
@@ -681,5 +749,26 @@ pub mod details {
         fn has_configurable_buffer_size() -> bool {
             true
         }
+
+        unsafe fn remove_sender(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<(), ZeroCopyPortRemoveError> {
+            remove_connection_port(name, config, State::Sender)
+        }
+
+        unsafe fn remove_receiver(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<(), ZeroCopyPortRemoveError> {
+            remove_connection_port(name, config, State::Receiver)
+        }
+
+        fn number_of_buffered_samples(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<usize, ZeroCopyInspectError> {
+            number_of_buffered_samples(name, config)
+        }
     }
 }
@@ -99,6 +99,36 @@ impl std::fmt::Display for ZeroCopyReleaseError {
 
 impl std::error::Error for ZeroCopyReleaseError {}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyPortRemoveError {
+    InternalError,
+    VersionMismatch,
+    DoesNotExist,
+}
+
+impl std::fmt::Display for ZeroCopyPortRemoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyPortRemoveError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyInspectError {
+    InternalError,
+    VersionMismatch,
+    DoesNotExist,
+}
+
+impl std::fmt::Display for ZeroCopyInspectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyInspectError {}
+
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
@@ -161,4 +191,38 @@ pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".rx") }
     }
+
+    /// Removes the [`ZeroCopySender`] from the connection with the provided name. It is
+    /// required when the process that owned the [`ZeroCopySender`] died and is unable to
+    /// release its part of the connection. If the [`ZeroCopyReceiver`] is also gone the
+    /// connection is removed.
+    ///
+    /// # Safety
+    ///
+    ///  * the [`ZeroCopySender`] of the connection must not be in use anymore
+    unsafe fn remove_sender(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<(), ZeroCopyPortRemoveError>;
+
+    /// Removes the [`ZeroCopyReceiver`] from the connection with the provided name. It is
+    /// required when the process that owned the [`ZeroCopyReceiver`] died and is unable to
+    /// release its part of the connection. If the [`ZeroCopySender`] is also gone the
+    /// connection is removed.
+    ///
+    /// # Safety
+    ///
+    ///  * the [`ZeroCopyReceiver`] of the connection must not be in use anymore
+    unsafe fn remove_receiver(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<(), ZeroCopyPortRemoveError>;
+
+    /// Returns the number of samples that were sent by the [`ZeroCopySender`] of the connection
+    /// with the provided name but not yet received by the [`ZeroCopyReceiver`]. It does not
+    /// require to be a participant of the connection and can be used to observe its state.
+    fn number_of_buffered_samples(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<usize, ZeroCopyInspectError>;
 }
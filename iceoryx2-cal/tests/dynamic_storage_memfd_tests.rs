@@ -0,0 +1,74 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod dynamic_storage_memfd {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::dynamic_storage::*;
+    use iceoryx2_cal::named_concept::*;
+
+    type Sut = iceoryx2_cal::dynamic_storage::memfd::Storage<AtomicU64>;
+
+    fn generate_name() -> FileName {
+        let mut file = FileName::new(b"test_").unwrap();
+        file.push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+            .unwrap();
+        file
+    }
+
+    #[test]
+    fn open_fails_when_peer_is_not_authorized() {
+        let storage_name = generate_name();
+
+        let _sut = <Sut as DynamicStorage<AtomicU64>>::Builder::new(&storage_name)
+            .peer_authorization(PeerAuthorization::new(|_| false))
+            .create(AtomicU64::new(0))
+            .unwrap();
+
+        let sut = <Sut as DynamicStorage<AtomicU64>>::Builder::new(&storage_name).open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq DynamicStorageOpenError::InternalError);
+    }
+
+    #[test]
+    fn open_works_when_peer_is_authorized() {
+        let storage_name = generate_name();
+        let number_of_requests = Arc::new(AtomicU64::new(0));
+        let own_pid = Process::from_self().id();
+
+        let number_of_requests_clone = number_of_requests.clone();
+        let sut = <Sut as DynamicStorage<AtomicU64>>::Builder::new(&storage_name)
+            .peer_authorization(PeerAuthorization::new(move |credentials| {
+                number_of_requests_clone.fetch_add(1, Ordering::Relaxed);
+                credentials.get_pid() == own_pid
+            }))
+            .create(AtomicU64::new(0))
+            .unwrap();
+
+        let sut2 = <Sut as DynamicStorage<AtomicU64>>::Builder::new(&storage_name)
+            .open()
+            .unwrap();
+
+        sut2.get().store(891, Ordering::Relaxed);
+        assert_that!(sut.get().load(Ordering::Relaxed), eq 891);
+        assert_that!(number_of_requests.load(Ordering::Relaxed), eq 1);
+    }
+}
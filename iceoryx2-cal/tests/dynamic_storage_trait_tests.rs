@@ -677,6 +677,11 @@ mod dynamic_storage {
                          iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<u64>>)]
     mod posix_shared_memory {}
 
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::dynamic_storage::memfd::Storage<TestData>,
+                         iceoryx2_cal::dynamic_storage::memfd::Storage<u64>>)]
+    mod memfd {}
+
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>,
                          iceoryx2_cal::dynamic_storage::process_local::Storage<u64>>)]
     mod process_local {}
@@ -18,6 +18,7 @@ mod event {
     use std::time::{Duration, Instant};
 
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
     use iceoryx2_bb_posix::barrier::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_system_types::file_name::FileName;
@@ -629,6 +630,159 @@ mod event {
         }
     }
 
+    fn wait_all_with_payload_delivers_payload<
+        Sut: Event,
+        F: FnMut(&mut Vec<(TriggerId, TriggerPayload)>, &Sut::Listener),
+    >(
+        mut wait_call: F,
+    ) {
+        test_requires!(Sut::has_payload_support());
+
+        let _watchdog = Watchdog::new();
+        let name = generate_name();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name).create().unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name).open().unwrap();
+
+        let small_payload = TriggerPayload::from_value(&1234567u64).unwrap();
+        let large_payload = TriggerPayload::new(&[42u8; MAX_TRIGGER_PAYLOAD_SIZE]).unwrap();
+
+        sut_notifier
+            .notify_with_payload(TriggerId::new(1), &small_payload)
+            .unwrap();
+        sut_notifier.notify(TriggerId::new(2)).unwrap();
+        sut_notifier
+            .notify_with_payload(TriggerId::new(1), &large_payload)
+            .unwrap();
+
+        let mut notifications = vec![];
+        wait_call(&mut notifications, &sut_listener);
+
+        assert_that!(notifications, len 3);
+        assert_that!(notifications[0], eq(TriggerId::new(1), small_payload));
+        assert_that!(unsafe { notifications[0].1.value::<u64>() }, eq Some(1234567));
+        assert_that!(notifications[1].0, eq TriggerId::new(2));
+        assert_that!(notifications[1].1.is_empty(), eq true);
+        assert_that!(notifications[2], eq(TriggerId::new(1), large_payload));
+    }
+
+    #[test]
+    fn try_wait_all_with_payload_delivers_payload<Sut: Event>() {
+        wait_all_with_payload_delivers_payload::<Sut, _>(|v, sut: &Sut::Listener| {
+            sut.try_wait_all_with_payload(|id, payload| v.push((id, *payload)))
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn timed_wait_all_with_payload_delivers_payload<Sut: Event>() {
+        wait_all_with_payload_delivers_payload::<Sut, _>(|v, sut: &Sut::Listener| {
+            sut.timed_wait_all_with_payload(|id, payload| v.push((id, *payload)), TIMEOUT * 1000)
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn blocking_wait_all_with_payload_delivers_payload<Sut: Event>() {
+        wait_all_with_payload_delivers_payload::<Sut, _>(|v, sut: &Sut::Listener| {
+            sut.blocking_wait_all_with_payload(|id, payload| v.push((id, *payload)))
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn notification_with_payload_is_received_by_wait_without_payload<Sut: Event>() {
+        test_requires!(Sut::has_payload_support());
+
+        let name = generate_name();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name).create().unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name).open().unwrap();
+
+        sut_notifier
+            .notify_with_payload(
+                TriggerId::new(7),
+                &TriggerPayload::from_value(&99u32).unwrap(),
+            )
+            .unwrap();
+
+        assert_that!(sut_listener.try_wait_one().unwrap(), eq Some(TriggerId::new(7)));
+        assert_that!(sut_listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notify_with_payload_fails_when_payload_is_not_supported<Sut: Event>() {
+        test_requires!(!Sut::has_payload_support());
+
+        let name = generate_name();
+
+        let _sut_listener = Sut::ListenerBuilder::new(&name).create().unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name).open().unwrap();
+
+        let result =
+            sut_notifier.notify_with_payload(TriggerId::new(0), &TriggerPayload::default());
+        assert_that!(result.err(), eq Some(NotifierNotifyError::PayloadNotSupported));
+    }
+
+    #[test]
+    fn wait_all_with_payload_fails_when_payload_is_not_supported<Sut: Event>() {
+        test_requires!(!Sut::has_payload_support());
+
+        let name = generate_name();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name).create().unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name).open().unwrap();
+        sut_notifier.notify(TriggerId::new(0)).unwrap();
+
+        let result = sut_listener.try_wait_all_with_payload(|_, _| {});
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+        let result = sut_listener.timed_wait_all_with_payload(|_, _| {}, TIMEOUT);
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+        let result = sut_listener.blocking_wait_all_with_payload(|_, _| {});
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+
+        assert_that!(sut_listener.try_wait_one().unwrap(), eq Some(TriggerId::new(0)));
+    }
+
+    #[test]
+    fn trigger_payload_cannot_exceed_max_size<Sut: Event>() {
+        test_requires!(Sut::has_payload_support());
+
+        assert_that!(
+            TriggerPayload::new(&[0u8; MAX_TRIGGER_PAYLOAD_SIZE + 1]),
+            is_none
+        );
+        assert_that!(
+            TriggerPayload::from_value(&[0u8; MAX_TRIGGER_PAYLOAD_SIZE + 1]),
+            is_none
+        );
+
+        let sut = TriggerPayload::from_value(&[1u32, 2u32]).unwrap();
+        assert_that!(sut.len(), eq 8);
+        assert_that!(unsafe { sut.value::<u32>() }, is_none);
+        assert_that!(unsafe { sut.value::<[u32; 2]>() }, eq Some([1, 2]));
+    }
+
+    #[test]
+    fn trigger_payload_from_value_with_padding_works<Sut: Event>() {
+        test_requires!(Sut::has_payload_support());
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        struct Padded {
+            a: u8,
+            b: u64,
+        }
+
+        unsafe impl ZeroCopySend for Padded {}
+
+        let value = Padded { a: 12, b: 3456789 };
+        let sut = TriggerPayload::from_value(&value).unwrap();
+
+        assert_that!(sut.len(), eq core::mem::size_of::<Padded>());
+        assert_that!(unsafe { sut.value::<Padded>() }, eq Some(value));
+    }
+
     #[instantiate_tests(<iceoryx2_cal::event::unix_datagram_socket::EventImpl>)]
     mod unix_datagram {}
 
@@ -641,4 +795,12 @@ mod event {
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_posix_shared_memory::Event>)]
     mod sem_bitset_posix_shared_memory {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::event::eventfd::EventImpl>)]
+    mod eventfd {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::event::futex_bitset_posix_shared_memory::Event>)]
+    mod futex_bitset_posix_shared_memory {}
 }
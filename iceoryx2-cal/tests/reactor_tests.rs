@@ -353,6 +353,45 @@ mod reactor {
         assert_that!(triggered_fds, len 0);
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn eventfd_listener_triggers_until_all_notifications_are_consumed<Sut: Reactor>() {
+        use iceoryx2_cal::event::eventfd;
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+
+        let name = generate_name();
+        let listener = eventfd::ListenerBuilder::new(&name).create().unwrap();
+        let notifier = eventfd::NotifierBuilder::new(&name).open().unwrap();
+
+        let _guard = sut.attach(&listener);
+
+        notifier.notify(TriggerId::new(12)).unwrap();
+        notifier.notify(TriggerId::new(13)).unwrap();
+
+        let mut triggered_fds = vec![];
+        assert_that!(
+            sut.timed_wait(
+                |fd| triggered_fds.push(unsafe { fd.native_handle() }),
+                INFINITE_TIMEOUT
+            ),
+            is_ok
+        );
+        assert_that!(triggered_fds, len 1);
+        assert_that!(triggered_fds[0], eq unsafe { listener.file_descriptor().native_handle() });
+
+        let mut ids = vec![];
+        listener.try_wait_all(|id| ids.push(id)).unwrap();
+        assert_that!(ids, len 2);
+
+        triggered_fds.clear();
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            is_ok
+        );
+        assert_that!(triggered_fds, len 0);
+    }
+
     #[test]
     fn try_wait_triggers_until_all_data_is_consumed<Sut: Reactor>() {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
@@ -558,4 +597,8 @@ mod reactor {
 
     #[instantiate_tests(<iceoryx2_cal::reactor::posix_select::Reactor>)]
     mod posix_select {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::reactor::epoll::Reactor>)]
+    mod epoll {}
 }
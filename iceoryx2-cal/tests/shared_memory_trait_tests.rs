@@ -16,6 +16,7 @@ mod shared_memory {
 
     use iceoryx2_bb_container::semantic_string::*;
     use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
@@ -377,9 +378,31 @@ mod shared_memory {
         assert_that!(Sut::does_exist(&name), eq Ok(false));
     }
 
+    #[test]
+    fn allocation_with_populated_memory_works<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .memory_placement(MemoryPlacement::new().populate(true))
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).open().unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write(0xAB) };
+
+        let ptr = (sut_open.payload_start_address() + chunk.offset.value()) as *const u8;
+        assert_that!(unsafe { *ptr }, eq 0xAB);
+    }
+
     #[instantiate_tests(<iceoryx2_cal::shared_memory::posix::Memory<DefaultAllocator>>)]
     mod posix {}
 
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::memfd::Memory<DefaultAllocator>>)]
+    mod memfd {}
+
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
     mod process_local {}
 }
@@ -201,6 +201,89 @@ mod zero_copy_connection {
         assert_that!(Sut::does_exist(&name), eq Ok(false));
     }
 
+    #[test]
+    fn removing_sender_and_receiver_of_connection_removes_connection<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        // emulate dead processes that were unable to release their part of the connection
+        std::mem::forget(sut_sender);
+        std::mem::forget(sut_receiver);
+
+        assert_that!(unsafe { Sut::remove_sender(&name, &config) }, is_ok);
+        assert_that!(Sut::does_exist(&name), eq Ok(true));
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, is_ok);
+        assert_that!(Sut::does_exist(&name), eq Ok(false));
+    }
+
+    #[test]
+    fn removed_sender_can_be_replaced_by_another_sender<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        std::mem::forget(sut_sender);
+        assert_that!(unsafe { Sut::remove_sender(&name, &config) }, is_ok);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE);
+        assert_that!(sut_sender, is_ok);
+    }
+
+    #[test]
+    fn removing_port_of_non_existing_connection_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        assert_that!(unsafe { Sut::remove_sender(&name, &config) }, eq Err(ZeroCopyPortRemoveError::DoesNotExist));
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, eq Err(ZeroCopyPortRemoveError::DoesNotExist));
+    }
+
+    #[test]
+    fn number_of_buffered_samples_can_be_acquired_without_participating<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        assert_that!(Sut::number_of_buffered_samples(&name, &config), eq Err(ZeroCopyInspectError::DoesNotExist));
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(Sut::number_of_buffered_samples(&name, &config), eq Ok(0));
+
+        assert_that!(sut_sender.try_send(PointerOffset::new(0)), is_ok);
+        assert_that!(sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE)), is_ok);
+        assert_that!(Sut::number_of_buffered_samples(&name, &config), eq Ok(2));
+
+        let sample = sut_receiver.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(Sut::number_of_buffered_samples(&name, &config), eq Ok(1));
+    }
+
     #[test]
     fn connecting_with_incompatible_buffer_size_fails<Sut: ZeroCopyConnection>() {
         let name = generate_name();
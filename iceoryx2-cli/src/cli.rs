@@ -0,0 +1,54 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::{Parser, Subcommand};
+
+/// Command line tool to list, inspect and clean iceoryx2 services.
+#[derive(Parser, Debug)]
+#[clap(name = "iox2", version, about, long_about = None)]
+pub struct Cli {
+    /// Use the provided config file instead of the default one
+    #[clap(short, long, global = true)]
+    pub config: Option<String>,
+
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Lists, inspects and cleans services
+    #[clap(subcommand)]
+    Service(ServiceCommand),
+    /// Shows the configuration
+    #[clap(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ServiceCommand {
+    /// Lists all existing services
+    List,
+    /// Prints the static config and the number of connected ports of a service
+    Info {
+        /// The name of the service
+        service_name: String,
+    },
+    /// Removes the stale resources of dead nodes and all orphaned services
+    Clean,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Prints the effective configuration
+    Show,
+}
@@ -0,0 +1,58 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli;
+mod config;
+mod service;
+
+use clap::Parser;
+use cli::{Cli, Command, ConfigCommand, ServiceCommand};
+use iceoryx2::config::Config;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{get_log_level, logger::Logger, set_log_level, set_logger, LogLevel};
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+// The output of the commands is printed to stdout and may be processed by other tools,
+// therefore the log messages are printed to stderr.
+struct StderrLogger;
+
+impl Logger for StderrLogger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: std::fmt::Arguments,
+        formatted_message: std::fmt::Arguments,
+    ) {
+        if get_log_level() <= log_level as u8 {
+            eprintln!("[{:?}] {} | {}", log_level, origin, formatted_message);
+        }
+    }
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    set_logger(&LOGGER);
+    set_log_level(LogLevel::Warn);
+
+    if let Some(config_file) = &cli.config {
+        Config::setup_global_config_from_file(&FilePath::new(config_file.as_bytes())?)?;
+    }
+
+    match cli.command {
+        Command::Service(ServiceCommand::List) => service::list(),
+        Command::Service(ServiceCommand::Info { service_name }) => service::info(&service_name),
+        Command::Service(ServiceCommand::Clean) => service::clean(),
+        Command::Config(ConfigCommand::Show) => config::show(),
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::Config;
+use iceoryx2::introspection::Snapshot;
+use iceoryx2::node::NodeState;
+use iceoryx2::prelude::*;
+use iceoryx2::service::static_config::StaticConfig;
+
+pub(crate) fn list() -> Result<(), Box<dyn std::error::Error>> {
+    let mut services = zero_copy::Service::list()?;
+    services.sort_by(|lhs, rhs| lhs.service_name().as_str().cmp(rhs.service_name().as_str()));
+
+    for service in services {
+        println!(
+            "{:<18} {}",
+            service.messaging_pattern().to_string(),
+            service.service_name()
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) fn info(service_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(service_name)?;
+    let services: Vec<StaticConfig> = zero_copy::Service::list()?
+        .into_iter()
+        .filter(|s| *s.service_name() == service_name)
+        .collect();
+
+    if services.is_empty() {
+        return Err(format!("the service \"{}\" does not exist", service_name).into());
+    }
+
+    let snapshot = Snapshot::collect::<zero_copy::Service>(Config::get_global_config())?;
+    for static_config in services {
+        println!("{:#?}", static_config);
+        match snapshot
+            .services()
+            .iter()
+            .find(|s| s.uuid() == static_config.uuid())
+        {
+            Some(service) => {
+                for (port_type, number_of_ports) in service.number_of_ports() {
+                    println!("{:<13}{}", format!("{}:", port_type), number_of_ports);
+                }
+            }
+            None => println!("The service is not fully created or was removed in the meantime."),
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn clean() -> Result<(), Box<dyn std::error::Error>> {
+    for node in Node::<zero_copy::Service>::list()? {
+        if let NodeState::Dead(view) = node {
+            let id = view.id().value();
+            if view.remove_stale_resources()? {
+                println!("removed stale resources of dead node {}", id);
+            }
+        }
+    }
+
+    for service in zero_copy::Service::remove_orphaned_services()? {
+        println!(
+            "removed orphaned {} service {}",
+            service.messaging_pattern(),
+            service.service_name()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli {
+    use std::process::{Command, Output};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "cli_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn iox2(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_iox2"))
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn service_list_contains_existing_service() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<zero_copy::Service>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let output = iox2(&["service", "list"]);
+        assert_that!(output.status.success(), eq true);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_that!(
+            stdout
+                .lines()
+                .any(|l| l.starts_with("Event") && l.ends_with(service_name.as_str())),
+            eq true
+        );
+    }
+
+    #[test]
+    fn service_info_prints_number_of_ports_of_every_messaging_pattern() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<zero_copy::Service>().unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let _notifier = event.notifier().create().unwrap();
+        let request_response = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+        let _server = request_response.server().create().unwrap();
+
+        let output = iox2(&["service", "info", service_name.as_str()]);
+        assert_that!(output.status.success(), eq true);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_that!(lines, contains "notifiers:   1");
+        assert_that!(lines, contains "listeners:   0");
+        assert_that!(lines, contains "clients:     0");
+        assert_that!(lines, contains "servers:     1");
+    }
+
+    #[test]
+    fn service_info_fails_for_non_existing_service() {
+        let output = iox2(&["service", "info", generate_name().as_str()]);
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn service_clean_keeps_existing_service() {
+        let id = UniqueSystemId::new().unwrap().value();
+        let mut config = Config::default();
+        config.global.service.directory = format!("cli_tests_{}", id);
+        // the services of concurrently running tests are not visible to the cleanup
+        let config_file = std::env::temp_dir().join(format!("cli_tests_{}.toml", id));
+        std::fs::write(
+            &config_file,
+            toml::to_string(&toml::Value::try_from(&config).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<zero_copy::Service>()
+            .unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let output = iox2(&[
+            "--config",
+            config_file.to_str().unwrap(),
+            "service",
+            "clean",
+        ]);
+        std::fs::remove_file(&config_file).unwrap();
+        assert_that!(output.status.success(), eq true);
+        assert_that!(
+            zero_copy::Service::does_exist_with_custom_config(&service_name, &config),
+            eq Ok(true)
+        );
+    }
+
+    #[test]
+    fn config_show_prints_the_global_config() {
+        let output = iox2(&["config", "show"]);
+        assert_that!(output.status.success(), eq true);
+        let config: Config = toml::from_str(&String::from_utf8(output.stdout).unwrap()).unwrap();
+        assert_that!(config.global.prefix, eq Config::get_global_config().global.prefix);
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::c_int;
+
+use iceoryx2::service::builder::event::{EventCreateError, EventOpenError, EventOpenOrCreateError};
+use iceoryx2::service::port_factory::event::PortFactory;
+use iceoryx2::service::{self, process_local, zero_copy};
+
+use crate::{iox2_node_h, iox2_service_name_h, IntoCInt, NodeUnion, ServiceOpenMode, IOX2_OK};
+
+/// Failures of [`iox2_node_event_open_or_create()`], [`iox2_node_event_open()`] and
+/// [`iox2_node_event_create()`]. Values with the `O_` prefix correspond to [`EventOpenError`],
+/// values with the `C_` prefix to [`EventCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_event_open_or_create_error_e {
+    O_DOES_NOT_EXIST = IOX2_OK as isize + 1,
+    O_PERMISSION_DENIED,
+    O_EVENT_IN_CORRUPTED_STATE,
+    O_INCOMPATIBLE_MESSAGING_PATTERN,
+    O_INTERNAL_FAILURE,
+    O_HANGS_IN_CREATION,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NOTIFIERS,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_LISTENERS,
+    O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+    O_INCOMPATIBLE_COUNTING_BEHAVIOR,
+    O_INCOMPATIBLE_ATTRIBUTES,
+    O_EXCEEDS_MAX_NUMBER_OF_NODES,
+    O_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION,
+    C_CORRUPTED,
+    C_INTERNAL_FAILURE,
+    C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+    C_ALREADY_EXISTS,
+    C_PERMISSION_DENIED,
+    C_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION,
+    C_OLD_CONNECTIONS_STILL_ACTIVE,
+}
+
+impl IntoCInt for EventOpenError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            EventOpenError::DoesNotExist => iox2_event_open_or_create_error_e::O_DOES_NOT_EXIST,
+            EventOpenError::PermissionDenied => {
+                iox2_event_open_or_create_error_e::O_PERMISSION_DENIED
+            }
+            EventOpenError::EventInCorruptedState => {
+                iox2_event_open_or_create_error_e::O_EVENT_IN_CORRUPTED_STATE
+            }
+            EventOpenError::IncompatibleMessagingPattern => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN
+            }
+            EventOpenError::InternalFailure => {
+                iox2_event_open_or_create_error_e::O_INTERNAL_FAILURE
+            }
+            EventOpenError::HangsInCreation => {
+                iox2_event_open_or_create_error_e::O_HANGS_IN_CREATION
+            }
+            EventOpenError::DoesNotSupportRequestedAmountOfNotifiers => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NOTIFIERS
+            }
+            EventOpenError::DoesNotSupportRequestedAmountOfListeners => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_LISTENERS
+            }
+            EventOpenError::DoesNotSupportRequestedMaxEventId => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID
+            }
+            EventOpenError::DoesNotSupportRequestedAmountOfNodes => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
+            }
+            EventOpenError::IncompatibleCountingBehavior => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_COUNTING_BEHAVIOR
+            }
+            EventOpenError::IncompatibleAttributes => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_ATTRIBUTES
+            }
+            EventOpenError::ExceedsMaxNumberOfNodes => {
+                iox2_event_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES
+            }
+            EventOpenError::UnableToOpenDynamicServiceInformation => {
+                iox2_event_open_or_create_error_e::O_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION
+            }
+        }) as c_int
+    }
+}
+
+impl IntoCInt for EventCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            EventCreateError::Corrupted => iox2_event_open_or_create_error_e::C_CORRUPTED,
+            EventCreateError::InternalFailure => {
+                iox2_event_open_or_create_error_e::C_INTERNAL_FAILURE
+            }
+            EventCreateError::IsBeingCreatedByAnotherInstance => {
+                iox2_event_open_or_create_error_e::C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE
+            }
+            EventCreateError::AlreadyExists => iox2_event_open_or_create_error_e::C_ALREADY_EXISTS,
+            EventCreateError::PermissionDenied => {
+                iox2_event_open_or_create_error_e::C_PERMISSION_DENIED
+            }
+            EventCreateError::UnableToCreateStaticServiceInformation => {
+                iox2_event_open_or_create_error_e::C_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION
+            }
+            EventCreateError::OldConnectionsStillActive => {
+                iox2_event_open_or_create_error_e::C_OLD_CONNECTIONS_STILL_ACTIVE
+            }
+        }) as c_int
+    }
+}
+
+impl IntoCInt for EventOpenOrCreateError {
+    fn into_c_int(self) -> c_int {
+        match self {
+            EventOpenOrCreateError::EventOpenError(e) => e.into_c_int(),
+            EventOpenOrCreateError::EventCreateError(e) => e.into_c_int(),
+        }
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum PortFactoryEventUnion {
+    Ipc(PortFactory<zero_copy::Service>),
+    Local(PortFactory<process_local::Service>),
+}
+
+/// The opaque representation of an event [`PortFactory`].
+pub struct iox2_port_factory_event_t {
+    pub(crate) value: PortFactoryEventUnion,
+}
+
+/// The owning handle of a [`iox2_port_factory_event_t`]. Must be released with
+/// [`iox2_port_factory_event_drop()`].
+pub type iox2_port_factory_event_h = *mut iox2_port_factory_event_t;
+
+fn event_service<S: service::Service>(
+    node: &iceoryx2::node::Node<S>,
+    service_name: &service::service_name::ServiceName,
+    mode: ServiceOpenMode,
+) -> Result<PortFactory<S>, c_int> {
+    let builder = node.service_builder(service_name).event();
+
+    match mode {
+        ServiceOpenMode::Open => builder.open().map_err(|e| e.into_c_int()),
+        ServiceOpenMode::Create => builder.create().map_err(|e| e.into_c_int()),
+        ServiceOpenMode::OpenOrCreate => builder.open_or_create().map_err(|e| e.into_c_int()),
+    }
+}
+
+unsafe fn event_service_handle(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    port_factory_handle_ptr: *mut iox2_port_factory_event_h,
+    mode: ServiceOpenMode,
+) -> c_int {
+    debug_assert!(!node_handle.is_null());
+    debug_assert!(!service_name_handle.is_null());
+    debug_assert!(!port_factory_handle_ptr.is_null());
+
+    let service_name = &(*service_name_handle).value;
+    let value = match &(*node_handle).value {
+        NodeUnion::Ipc(node) => match event_service(node, service_name, mode) {
+            Ok(v) => PortFactoryEventUnion::Ipc(v),
+            Err(e) => return e,
+        },
+        NodeUnion::Local(node) => match event_service(node, service_name, mode) {
+            Ok(v) => PortFactoryEventUnion::Local(v),
+            Err(e) => return e,
+        },
+    };
+
+    port_factory_handle_ptr.write(Box::into_raw(Box::new(iox2_port_factory_event_t { value })));
+    IOX2_OK
+}
+
+/// Opens the event service `service_name` or creates it when it does not exist and stores
+/// its handle in `port_factory_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_event_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// * `node_handle` and `service_name_handle` must be valid handles
+/// * `port_factory_handle_ptr` must point to a valid [`iox2_port_factory_event_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_event_open_or_create(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    port_factory_handle_ptr: *mut iox2_port_factory_event_h,
+) -> c_int {
+    event_service_handle(
+        node_handle,
+        service_name_handle,
+        port_factory_handle_ptr,
+        ServiceOpenMode::OpenOrCreate,
+    )
+}
+
+/// Opens the existing event service `service_name` and stores its handle in
+/// `port_factory_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_event_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// See [`iox2_node_event_open_or_create()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_event_open(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    port_factory_handle_ptr: *mut iox2_port_factory_event_h,
+) -> c_int {
+    event_service_handle(
+        node_handle,
+        service_name_handle,
+        port_factory_handle_ptr,
+        ServiceOpenMode::Open,
+    )
+}
+
+/// Creates the new event service `service_name` and stores its handle in
+/// `port_factory_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_event_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// See [`iox2_node_event_open_or_create()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_event_create(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    port_factory_handle_ptr: *mut iox2_port_factory_event_h,
+) -> c_int {
+    event_service_handle(
+        node_handle,
+        service_name_handle,
+        port_factory_handle_ptr,
+        ServiceOpenMode::Create,
+    )
+}
+
+/// Releases the [`iox2_port_factory_event_t`]. All ports created by it remain valid.
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_event_drop(
+    port_factory_handle: iox2_port_factory_event_h,
+) {
+    debug_assert!(!port_factory_handle.is_null());
+    drop(Box::from_raw(port_factory_handle));
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! C language bindings for iceoryx2. Every entity of the Rust API, like the
+//! [`Node`](iceoryx2::node::Node), a service or a port, is represented by an opaque handle which
+//! is created by a `iox2_*_new`/`iox2_*_create`/`iox2_*_open` function and must be released
+//! with the corresponding `iox2_*_drop` function.
+//!
+//! Every fallible function returns [`IOX2_OK`] on success, otherwise one of the values of the
+//! error enum documented at the function. The C header is generated with
+//! [cbindgen](https://github.com/mozilla/cbindgen) into `target/ffi/include/iox2/iceoryx2.h`.
+//!
+//! # Example
+//!
+//! ```c
+//! #include "iox2/iceoryx2.h"
+//!
+//! iox2_node_h node = NULL;
+//! if (iox2_node_new(iox2_service_type_e_IPC, &node) != IOX2_OK) {
+//!     return -1;
+//! }
+//!
+//! // ... create services and ports
+//!
+//! iox2_node_drop(node);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use std::ffi::c_int;
+
+mod event;
+mod listener;
+mod node;
+mod notifier;
+mod publish_subscribe;
+mod publisher;
+mod sample;
+mod service_name;
+mod subscriber;
+
+pub use event::*;
+pub use listener::*;
+pub use node::*;
+pub use notifier::*;
+pub use publish_subscribe::*;
+pub use publisher::*;
+pub use sample::*;
+pub use service_name::*;
+pub use subscriber::*;
+
+/// Returned by every fallible function on success. All error enums start with `IOX2_OK + 1`.
+pub const IOX2_OK: c_int = 0;
+
+/// Defines the service variant a [`iox2_node_t`] and all entities created by it are based on.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_service_type_e {
+    /// Communication within a single process, based on
+    /// [`process_local::Service`](iceoryx2::service::process_local::Service).
+    LOCAL,
+    /// Inter-process communication, based on
+    /// [`zero_copy::Service`](iceoryx2::service::zero_copy::Service).
+    IPC,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ServiceOpenMode {
+    Open,
+    Create,
+    OpenOrCreate,
+}
+
+/// Converts a Rust error into the value of its C error enum.
+pub(crate) trait IntoCInt {
+    fn into_c_int(self) -> c_int;
+}
+
+/// Writes `value` into `ptr` when the optional out parameter `ptr` is not null.
+pub(crate) unsafe fn write_optional<T>(ptr: *mut T, value: T) {
+    if !ptr.is_null() {
+        ptr.write(value);
+    }
+}
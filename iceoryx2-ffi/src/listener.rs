@@ -0,0 +1,219 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::c_int;
+use std::time::Duration;
+
+use iceoryx2::port::event_id::EventId;
+use iceoryx2::port::listener::{Listener, ListenerCreateError};
+use iceoryx2::service::{process_local, zero_copy};
+use iceoryx2_cal::event::ListenerWaitError;
+
+use crate::{iox2_port_factory_event_h, IntoCInt, PortFactoryEventUnion, IOX2_OK};
+
+/// Failures of [`iox2_port_factory_event_listener_create()`], see [`ListenerCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_listener_create_error_e {
+    EXCEEDS_MAX_SUPPORTED_LISTENERS = IOX2_OK as isize + 1,
+    RESOURCE_CREATION_FAILED,
+}
+
+impl IntoCInt for ListenerCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            ListenerCreateError::ExceedsMaxSupportedListeners => {
+                iox2_listener_create_error_e::EXCEEDS_MAX_SUPPORTED_LISTENERS
+            }
+            ListenerCreateError::ResourceCreationFailed => {
+                iox2_listener_create_error_e::RESOURCE_CREATION_FAILED
+            }
+        }) as c_int
+    }
+}
+
+/// Failures of the `iox2_listener_*_wait_one()` functions, see [`ListenerWaitError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_listener_wait_error_e {
+    CONTRACT_VIOLATION = IOX2_OK as isize + 1,
+    INTERNAL_FAILURE,
+    INTERRUPT_SIGNAL,
+    PAYLOAD_NOT_SUPPORTED,
+}
+
+impl IntoCInt for ListenerWaitError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            ListenerWaitError::ContractViolation => iox2_listener_wait_error_e::CONTRACT_VIOLATION,
+            ListenerWaitError::InternalFailure => iox2_listener_wait_error_e::INTERNAL_FAILURE,
+            ListenerWaitError::InterruptSignal => iox2_listener_wait_error_e::INTERRUPT_SIGNAL,
+            ListenerWaitError::PayloadNotSupported => {
+                iox2_listener_wait_error_e::PAYLOAD_NOT_SUPPORTED
+            }
+        }) as c_int
+    }
+}
+
+pub(crate) enum ListenerUnion {
+    Ipc(Listener<zero_copy::Service>),
+    Local(Listener<process_local::Service>),
+}
+
+/// The opaque representation of a [`Listener`].
+pub struct iox2_listener_t {
+    value: ListenerUnion,
+}
+
+/// The owning handle of a [`iox2_listener_t`]. Must be released with [`iox2_listener_drop()`].
+pub type iox2_listener_h = *mut iox2_listener_t;
+
+/// Creates a new [`iox2_listener_t`] for the event service and stores its handle in
+/// `listener_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_listener_create_error_e`].
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle
+/// * `listener_handle_ptr` must point to a valid [`iox2_listener_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_event_listener_create(
+    port_factory_handle: iox2_port_factory_event_h,
+    listener_handle_ptr: *mut iox2_listener_h,
+) -> c_int {
+    debug_assert!(!port_factory_handle.is_null());
+    debug_assert!(!listener_handle_ptr.is_null());
+
+    let value = match &(*port_factory_handle).value {
+        PortFactoryEventUnion::Ipc(factory) => match factory.listener().create() {
+            Ok(v) => ListenerUnion::Ipc(v),
+            Err(e) => return e.into_c_int(),
+        },
+        PortFactoryEventUnion::Local(factory) => match factory.listener().create() {
+            Ok(v) => ListenerUnion::Local(v),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    listener_handle_ptr.write(Box::into_raw(Box::new(iox2_listener_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_listener_t`].
+///
+/// # Safety
+///
+/// * `listener_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_drop(listener_handle: iox2_listener_h) {
+    debug_assert!(!listener_handle.is_null());
+    drop(Box::from_raw(listener_handle));
+}
+
+unsafe fn wait_one<F: FnOnce(&ListenerUnion) -> Result<Option<EventId>, ListenerWaitError>>(
+    listener_handle: iox2_listener_h,
+    event_id_ptr: *mut usize,
+    has_received_one_ptr: *mut bool,
+    wait: F,
+) -> c_int {
+    debug_assert!(!listener_handle.is_null());
+    debug_assert!(!event_id_ptr.is_null());
+    debug_assert!(!has_received_one_ptr.is_null());
+
+    match wait(&(*listener_handle).value) {
+        Ok(Some(event_id)) => {
+            event_id_ptr.write(event_id.as_value());
+            has_received_one_ptr.write(true);
+            IOX2_OK
+        }
+        Ok(None) => {
+            has_received_one_ptr.write(false);
+            IOX2_OK
+        }
+        Err(e) => e.into_c_int(),
+    }
+}
+
+/// Checks without blocking if an event was received. If so, `has_received_one_ptr` is set to
+/// true and the event id is stored in `event_id_ptr`, otherwise `has_received_one_ptr` is set
+/// to false. Returns [`IOX2_OK`] on success, otherwise a [`iox2_listener_wait_error_e`].
+///
+/// # Safety
+///
+/// * `listener_handle` must be a valid handle
+/// * `event_id_ptr` must point to a valid `size_t`
+/// * `has_received_one_ptr` must point to a valid `bool`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_try_wait_one(
+    listener_handle: iox2_listener_h,
+    event_id_ptr: *mut usize,
+    has_received_one_ptr: *mut bool,
+) -> c_int {
+    wait_one(
+        listener_handle,
+        event_id_ptr,
+        has_received_one_ptr,
+        |listener| match listener {
+            ListenerUnion::Ipc(listener) => listener.try_wait_one(),
+            ListenerUnion::Local(listener) => listener.try_wait_one(),
+        },
+    )
+}
+
+/// Like [`iox2_listener_try_wait_one()`] but blocks until an event was received or the timeout
+/// of `timeout_secs` seconds plus `timeout_nsecs` nanoseconds has passed.
+///
+/// # Safety
+///
+/// See [`iox2_listener_try_wait_one()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_timed_wait_one(
+    listener_handle: iox2_listener_h,
+    timeout_secs: u64,
+    timeout_nsecs: u32,
+    event_id_ptr: *mut usize,
+    has_received_one_ptr: *mut bool,
+) -> c_int {
+    let timeout = Duration::new(timeout_secs, timeout_nsecs);
+    wait_one(
+        listener_handle,
+        event_id_ptr,
+        has_received_one_ptr,
+        |listener| match listener {
+            ListenerUnion::Ipc(listener) => listener.timed_wait_one(timeout),
+            ListenerUnion::Local(listener) => listener.timed_wait_one(timeout),
+        },
+    )
+}
+
+/// Like [`iox2_listener_try_wait_one()`] but blocks until an event was received. Returns
+/// without an event when the wait was interrupted.
+///
+/// # Safety
+///
+/// See [`iox2_listener_try_wait_one()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_blocking_wait_one(
+    listener_handle: iox2_listener_h,
+    event_id_ptr: *mut usize,
+    has_received_one_ptr: *mut bool,
+) -> c_int {
+    wait_one(
+        listener_handle,
+        event_id_ptr,
+        has_received_one_ptr,
+        |listener| match listener {
+            ListenerUnion::Ipc(listener) => listener.blocking_wait_one(),
+            ListenerUnion::Local(listener) => listener.blocking_wait_one(),
+        },
+    )
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::c_int;
+
+use iceoryx2::node::{Node, NodeBuilder, NodeCreationFailure};
+use iceoryx2::service::{process_local, zero_copy};
+
+use crate::{iox2_service_type_e, IntoCInt, IOX2_OK};
+
+/// Failures of [`iox2_node_new()`], see [`NodeCreationFailure`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_node_creation_failure_e {
+    INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
+    INTERNAL_ERROR,
+}
+
+impl IntoCInt for NodeCreationFailure {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            NodeCreationFailure::InsufficientPermissions => {
+                iox2_node_creation_failure_e::INSUFFICIENT_PERMISSIONS
+            }
+            NodeCreationFailure::InternalError => iox2_node_creation_failure_e::INTERNAL_ERROR,
+        }) as c_int
+    }
+}
+
+pub(crate) enum NodeUnion {
+    Ipc(Node<zero_copy::Service>),
+    Local(Node<process_local::Service>),
+}
+
+/// The opaque representation of a [`Node`].
+pub struct iox2_node_t {
+    pub(crate) value: NodeUnion,
+}
+
+/// The owning handle of a [`iox2_node_t`]. Must be released with [`iox2_node_drop()`].
+pub type iox2_node_h = *mut iox2_node_t;
+
+/// Creates a new [`iox2_node_t`] of the provided [`iox2_service_type_e`] and stores its handle in
+/// `node_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_node_creation_failure_e`].
+///
+/// # Safety
+///
+/// * `node_handle_ptr` must point to a valid [`iox2_node_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_new(
+    service_type: iox2_service_type_e,
+    node_handle_ptr: *mut iox2_node_h,
+) -> c_int {
+    debug_assert!(!node_handle_ptr.is_null());
+
+    let value = match service_type {
+        iox2_service_type_e::IPC => match NodeBuilder::new().create::<zero_copy::Service>() {
+            Ok(node) => NodeUnion::Ipc(node),
+            Err(e) => return e.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => match NodeBuilder::new().create::<process_local::Service>() {
+            Ok(node) => NodeUnion::Local(node),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    node_handle_ptr.write(Box::into_raw(Box::new(iox2_node_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_node_t`]. All services and ports created by the node remain valid.
+///
+/// # Safety
+///
+/// * `node_handle` must be a handle acquired with [`iox2_node_new()`] that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_drop(node_handle: iox2_node_h) {
+    debug_assert!(!node_handle.is_null());
+    drop(Box::from_raw(node_handle));
+}
@@ -0,0 +1,175 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::c_int;
+
+use iceoryx2::port::event_id::EventId;
+use iceoryx2::port::notifier::{Notifier, NotifierCreateError, NotifierNotifyError};
+use iceoryx2::service::{process_local, zero_copy};
+
+use crate::{iox2_port_factory_event_h, write_optional, IntoCInt, PortFactoryEventUnion, IOX2_OK};
+
+/// Failures of [`iox2_port_factory_event_notifier_create()`], see [`NotifierCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_notifier_create_error_e {
+    EXCEEDS_MAX_SUPPORTED_NOTIFIERS = IOX2_OK as isize + 1,
+}
+
+impl IntoCInt for NotifierCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            NotifierCreateError::ExceedsMaxSupportedNotifiers => {
+                iox2_notifier_create_error_e::EXCEEDS_MAX_SUPPORTED_NOTIFIERS
+            }
+        }) as c_int
+    }
+}
+
+/// Failures of [`iox2_notifier_notify()`] and [`iox2_notifier_notify_with_custom_event_id()`],
+/// see [`NotifierNotifyError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_notifier_notify_error_e {
+    EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
+    PAYLOAD_TOO_LARGE,
+    PAYLOAD_NOT_SUPPORTED,
+}
+
+impl IntoCInt for NotifierNotifyError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            NotifierNotifyError::EventIdOutOfBounds => {
+                iox2_notifier_notify_error_e::EVENT_ID_OUT_OF_BOUNDS
+            }
+            NotifierNotifyError::PayloadTooLarge => iox2_notifier_notify_error_e::PAYLOAD_TOO_LARGE,
+            NotifierNotifyError::PayloadNotSupported => {
+                iox2_notifier_notify_error_e::PAYLOAD_NOT_SUPPORTED
+            }
+        }) as c_int
+    }
+}
+
+pub(crate) enum NotifierUnion {
+    Ipc(Notifier<zero_copy::Service>),
+    Local(Notifier<process_local::Service>),
+}
+
+/// The opaque representation of a [`Notifier`].
+pub struct iox2_notifier_t {
+    value: NotifierUnion,
+}
+
+/// The owning handle of a [`iox2_notifier_t`]. Must be released with [`iox2_notifier_drop()`].
+pub type iox2_notifier_h = *mut iox2_notifier_t;
+
+/// Creates a new [`iox2_notifier_t`] for the event service and stores its handle in
+/// `notifier_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_notifier_create_error_e`].
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle
+/// * `notifier_handle_ptr` must point to a valid [`iox2_notifier_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_event_notifier_create(
+    port_factory_handle: iox2_port_factory_event_h,
+    notifier_handle_ptr: *mut iox2_notifier_h,
+) -> c_int {
+    debug_assert!(!port_factory_handle.is_null());
+    debug_assert!(!notifier_handle_ptr.is_null());
+
+    let value = match &(*port_factory_handle).value {
+        PortFactoryEventUnion::Ipc(factory) => match factory.notifier().create() {
+            Ok(v) => NotifierUnion::Ipc(v),
+            Err(e) => return e.into_c_int(),
+        },
+        PortFactoryEventUnion::Local(factory) => match factory.notifier().create() {
+            Ok(v) => NotifierUnion::Local(v),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    notifier_handle_ptr.write(Box::into_raw(Box::new(iox2_notifier_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_notifier_t`].
+///
+/// # Safety
+///
+/// * `notifier_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_drop(notifier_handle: iox2_notifier_h) {
+    debug_assert!(!notifier_handle.is_null());
+    drop(Box::from_raw(notifier_handle));
+}
+
+/// Notifies all connected listeners with the default event id. When
+/// `number_of_notified_listeners` is not null the number of notified listeners is stored in it.
+/// Returns [`IOX2_OK`] on success, otherwise a [`iox2_notifier_notify_error_e`].
+///
+/// # Safety
+///
+/// * `notifier_handle` must be a valid handle
+/// * `number_of_notified_listeners` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_notify(
+    notifier_handle: iox2_notifier_h,
+    number_of_notified_listeners: *mut usize,
+) -> c_int {
+    debug_assert!(!notifier_handle.is_null());
+
+    let result = match &(*notifier_handle).value {
+        NotifierUnion::Ipc(notifier) => notifier.notify(),
+        NotifierUnion::Local(notifier) => notifier.notify(),
+    };
+
+    match result {
+        Ok(v) => {
+            write_optional(number_of_notified_listeners, v);
+            IOX2_OK
+        }
+        Err(e) => e.into_c_int(),
+    }
+}
+
+/// Notifies all connected listeners with the provided `event_id`. When
+/// `number_of_notified_listeners` is not null the number of notified listeners is stored in it.
+/// Returns [`IOX2_OK`] on success, otherwise a [`iox2_notifier_notify_error_e`].
+///
+/// # Safety
+///
+/// * `notifier_handle` must be a valid handle
+/// * `number_of_notified_listeners` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_notify_with_custom_event_id(
+    notifier_handle: iox2_notifier_h,
+    event_id: usize,
+    number_of_notified_listeners: *mut usize,
+) -> c_int {
+    debug_assert!(!notifier_handle.is_null());
+
+    let event_id = EventId::new(event_id);
+    let result = match &(*notifier_handle).value {
+        NotifierUnion::Ipc(notifier) => notifier.notify_with_custom_event_id(event_id),
+        NotifierUnion::Local(notifier) => notifier.notify_with_custom_event_id(event_id),
+    };
+
+    match result {
+        Ok(v) => {
+            write_optional(number_of_notified_listeners, v);
+            IOX2_OK
+        }
+        Err(e) => e.into_c_int(),
+    }
+}
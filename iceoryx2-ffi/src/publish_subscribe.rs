@@ -0,0 +1,305 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_char, c_int, CStr};
+
+use iceoryx2::service::builder::publish_subscribe::{
+    PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+use iceoryx2::service::static_config::type_details::{
+    TypeDetails, TypeDetailsError, TypeVariant, Untyped,
+};
+use iceoryx2::service::{self, process_local, zero_copy};
+
+use crate::{iox2_node_h, iox2_service_name_h, IntoCInt, NodeUnion, ServiceOpenMode, IOX2_OK};
+
+/// Failures of [`iox2_node_pub_sub_open_or_create()`], [`iox2_node_pub_sub_open()`] and
+/// [`iox2_node_pub_sub_create()`]. Values with the `O_` prefix correspond to
+/// [`PublishSubscribeOpenError`], values with the `C_` prefix to [`PublishSubscribeCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_pub_sub_open_or_create_error_e {
+    O_DOES_NOT_EXIST = IOX2_OK as isize + 1,
+    O_INTERNAL_FAILURE,
+    O_INCOMPATIBLE_TYPES,
+    O_INCOMPATIBLE_MESSAGING_PATTERN,
+    O_DOES_NOT_SUPPORT_REQUESTED_MIN_BUFFER_SIZE,
+    O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_SIZE,
+    O_DOES_NOT_SUPPORT_REQUESTED_MIN_SUBSCRIBER_BORROWED_SAMPLES,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_PUBLISHERS,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SUBSCRIBERS,
+    O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    O_INACCESSIBLE,
+    O_PERMISSION_DENIED,
+    O_SERVICE_IN_CORRUPTED_STATE,
+    O_HANGS_IN_CREATION,
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+    O_INCOMPATIBLE_ATTRIBUTES,
+    O_EXCEEDS_MAX_NUMBER_OF_NODES,
+    O_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION,
+    C_CORRUPTED,
+    C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
+    C_ALREADY_EXISTS,
+    C_PERMISSION_DENIED,
+    C_INTERNAL_FAILURE,
+    C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+    C_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION,
+    C_OLD_CONNECTIONS_STILL_ACTIVE,
+    INVALID_TYPE_DETAILS,
+}
+
+impl IntoCInt for PublishSubscribeOpenError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublishSubscribeOpenError::DoesNotExist => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_EXIST,
+            PublishSubscribeOpenError::InternalFailure => iox2_pub_sub_open_or_create_error_e::O_INTERNAL_FAILURE,
+            PublishSubscribeOpenError::IncompatibleTypes => iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPES,
+            PublishSubscribeOpenError::IncompatibleMessagingPattern => iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN,
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MIN_BUFFER_SIZE,
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_SIZE,
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MIN_SUBSCRIBER_BORROWED_SAMPLES,
+            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_PUBLISHERS,
+            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SUBSCRIBERS,
+            PublishSubscribeOpenError::IncompatibleOverflowBehavior => iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+            PublishSubscribeOpenError::Inaccessible => iox2_pub_sub_open_or_create_error_e::O_INACCESSIBLE,
+            PublishSubscribeOpenError::PermissionDenied => iox2_pub_sub_open_or_create_error_e::O_PERMISSION_DENIED,
+            PublishSubscribeOpenError::ServiceInCorruptedState => iox2_pub_sub_open_or_create_error_e::O_SERVICE_IN_CORRUPTED_STATE,
+            PublishSubscribeOpenError::HangsInCreation => iox2_pub_sub_open_or_create_error_e::O_HANGS_IN_CREATION,
+            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes => iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+            PublishSubscribeOpenError::IncompatibleAttributes => iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_ATTRIBUTES,
+            PublishSubscribeOpenError::ExceedsMaxNumberOfNodes => iox2_pub_sub_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES,
+            PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation => iox2_pub_sub_open_or_create_error_e::O_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION,
+        }) as c_int
+    }
+}
+
+impl IntoCInt for PublishSubscribeCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublishSubscribeCreateError::Corrupted => iox2_pub_sub_open_or_create_error_e::C_CORRUPTED,
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize => iox2_pub_sub_open_or_create_error_e::C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
+            PublishSubscribeCreateError::AlreadyExists => iox2_pub_sub_open_or_create_error_e::C_ALREADY_EXISTS,
+            PublishSubscribeCreateError::PermissionDenied => iox2_pub_sub_open_or_create_error_e::C_PERMISSION_DENIED,
+            PublishSubscribeCreateError::InternalFailure => iox2_pub_sub_open_or_create_error_e::C_INTERNAL_FAILURE,
+            PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance => iox2_pub_sub_open_or_create_error_e::C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+            PublishSubscribeCreateError::UnableToCreateStaticServiceInformation => iox2_pub_sub_open_or_create_error_e::C_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION,
+            PublishSubscribeCreateError::OldConnectionsStillActive => iox2_pub_sub_open_or_create_error_e::C_OLD_CONNECTIONS_STILL_ACTIVE,
+        }) as c_int
+    }
+}
+
+impl IntoCInt for PublishSubscribeOpenOrCreateError {
+    fn into_c_int(self) -> c_int {
+        match self {
+            PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(e) => e.into_c_int(),
+            PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(e) => e.into_c_int(),
+        }
+    }
+}
+
+impl IntoCInt for TypeDetailsError {
+    fn into_c_int(self) -> c_int {
+        iox2_pub_sub_open_or_create_error_e::INVALID_TYPE_DETAILS as c_int
+    }
+}
+
+/// Defines if the payload is a fixed size type or a slice, see [`TypeVariant`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_type_variant_e {
+    FIXED_SIZE,
+    DYNAMIC,
+}
+
+/// Describes the payload type of a publish-subscribe service, see [`TypeDetails`]. When the
+/// variant is [`iox2_type_variant_e::DYNAMIC`] the size and alignment describe one slice
+/// element.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct iox2_type_details_t {
+    pub variant: iox2_type_variant_e,
+    /// null-terminated UTF-8 string
+    pub type_name: *const c_char,
+    pub size: usize,
+    pub alignment: usize,
+}
+
+impl iox2_type_details_t {
+    unsafe fn as_type_details(&self) -> Result<TypeDetails, c_int> {
+        let variant = match self.variant {
+            iox2_type_variant_e::FIXED_SIZE => TypeVariant::FixedSize,
+            iox2_type_variant_e::DYNAMIC => TypeVariant::Dynamic,
+        };
+
+        let type_name = match CStr::from_ptr(self.type_name).to_str() {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(iox2_pub_sub_open_or_create_error_e::INVALID_TYPE_DETAILS as c_int)
+            }
+        };
+
+        TypeDetails::new(variant, type_name, self.size, self.alignment).map_err(|e| e.into_c_int())
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum PortFactoryPubSubUnion {
+    Ipc(PortFactory<zero_copy::Service, Untyped>),
+    Local(PortFactory<process_local::Service, Untyped>),
+}
+
+/// The opaque representation of a publish-subscribe [`PortFactory`].
+pub struct iox2_port_factory_pub_sub_t {
+    pub(crate) value: PortFactoryPubSubUnion,
+}
+
+/// The owning handle of a [`iox2_port_factory_pub_sub_t`]. Must be released with
+/// [`iox2_port_factory_pub_sub_drop()`].
+pub type iox2_port_factory_pub_sub_h = *mut iox2_port_factory_pub_sub_t;
+
+fn pub_sub_service<S: service::Service>(
+    node: &iceoryx2::node::Node<S>,
+    service_name: &service::service_name::ServiceName,
+    type_details: &TypeDetails,
+    mode: ServiceOpenMode,
+) -> Result<PortFactory<S, Untyped>, c_int> {
+    let builder = node
+        .service_builder(service_name)
+        .publish_subscribe()
+        .untyped(type_details);
+
+    match mode {
+        ServiceOpenMode::Open => builder.open().map_err(|e| e.into_c_int()),
+        ServiceOpenMode::Create => builder.create().map_err(|e| e.into_c_int()),
+        ServiceOpenMode::OpenOrCreate => builder.open_or_create().map_err(|e| e.into_c_int()),
+    }
+}
+
+unsafe fn pub_sub_service_handle(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    type_details: *const iox2_type_details_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_pub_sub_h,
+    mode: ServiceOpenMode,
+) -> c_int {
+    debug_assert!(!node_handle.is_null());
+    debug_assert!(!service_name_handle.is_null());
+    debug_assert!(!type_details.is_null());
+    debug_assert!(!port_factory_handle_ptr.is_null());
+
+    let service_name = &(*service_name_handle).value;
+    let type_details = match (*type_details).as_type_details() {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let value = match &(*node_handle).value {
+        NodeUnion::Ipc(node) => match pub_sub_service(node, service_name, &type_details, mode) {
+            Ok(v) => PortFactoryPubSubUnion::Ipc(v),
+            Err(e) => return e,
+        },
+        NodeUnion::Local(node) => match pub_sub_service(node, service_name, &type_details, mode) {
+            Ok(v) => PortFactoryPubSubUnion::Local(v),
+            Err(e) => return e,
+        },
+    };
+
+    port_factory_handle_ptr.write(Box::into_raw(Box::new(iox2_port_factory_pub_sub_t {
+        value,
+    })));
+    IOX2_OK
+}
+
+/// Opens the publish-subscribe service `service_name` with the payload `type_details` or
+/// creates it when it does not exist and stores its handle in `port_factory_handle_ptr`.
+/// Returns [`IOX2_OK`] on success, otherwise a [`iox2_pub_sub_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// * `node_handle` and `service_name_handle` must be valid handles
+/// * `type_details` must point to a valid [`iox2_type_details_t`]
+/// * `port_factory_handle_ptr` must point to a valid [`iox2_port_factory_pub_sub_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_pub_sub_open_or_create(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    type_details: *const iox2_type_details_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_pub_sub_h,
+) -> c_int {
+    pub_sub_service_handle(
+        node_handle,
+        service_name_handle,
+        type_details,
+        port_factory_handle_ptr,
+        ServiceOpenMode::OpenOrCreate,
+    )
+}
+
+/// Opens the existing publish-subscribe service `service_name` with the payload `type_details`
+/// and stores its handle in `port_factory_handle_ptr`. Returns [`IOX2_OK`] on success,
+/// otherwise a [`iox2_pub_sub_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// See [`iox2_node_pub_sub_open_or_create()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_pub_sub_open(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    type_details: *const iox2_type_details_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_pub_sub_h,
+) -> c_int {
+    pub_sub_service_handle(
+        node_handle,
+        service_name_handle,
+        type_details,
+        port_factory_handle_ptr,
+        ServiceOpenMode::Open,
+    )
+}
+
+/// Creates the new publish-subscribe service `service_name` with the payload `type_details`
+/// and stores its handle in `port_factory_handle_ptr`. Returns [`IOX2_OK`] on success,
+/// otherwise a [`iox2_pub_sub_open_or_create_error_e`].
+///
+/// # Safety
+///
+/// See [`iox2_node_pub_sub_open_or_create()`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_pub_sub_create(
+    node_handle: iox2_node_h,
+    service_name_handle: iox2_service_name_h,
+    type_details: *const iox2_type_details_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_pub_sub_h,
+) -> c_int {
+    pub_sub_service_handle(
+        node_handle,
+        service_name_handle,
+        type_details,
+        port_factory_handle_ptr,
+        ServiceOpenMode::Create,
+    )
+}
+
+/// Releases the [`iox2_port_factory_pub_sub_t`]. All ports created by it remain valid.
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_pub_sub_drop(
+    port_factory_handle: iox2_port_factory_pub_sub_h,
+) {
+    debug_assert!(!port_factory_handle.is_null());
+    drop(Box::from_raw(port_factory_handle));
+}
@@ -0,0 +1,267 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_int, c_void};
+use std::mem::MaybeUninit;
+
+use iceoryx2::port::publisher::{
+    Publisher, PublisherCreateError, PublisherLoanError, PublisherSendError,
+};
+use iceoryx2::port::update_connections::ConnectionFailure;
+use iceoryx2::service::static_config::type_details::Untyped;
+use iceoryx2::service::{self, process_local, zero_copy};
+
+use crate::{
+    iox2_port_factory_pub_sub_h, iox2_sample_mut_h, iox2_sample_mut_t, write_optional, IntoCInt,
+    PortFactoryPubSubUnion, SampleMutUnion, IOX2_OK,
+};
+
+/// Failures of [`iox2_port_factory_pub_sub_publisher_create()`], see [`PublisherCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_publisher_create_error_e {
+    EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
+    UNABLE_TO_CREATE_DATA_SEGMENT,
+    UNSUPPORTED_CLOCK_TYPE,
+}
+
+impl IntoCInt for PublisherCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                iox2_publisher_create_error_e::EXCEEDS_MAX_SUPPORTED_PUBLISHERS
+            }
+            PublisherCreateError::UnableToCreateDataSegment => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
+            }
+            PublisherCreateError::UnsupportedClockType => {
+                iox2_publisher_create_error_e::UNSUPPORTED_CLOCK_TYPE
+            }
+        }) as c_int
+    }
+}
+
+/// Failures of [`iox2_publisher_loan()`], see [`PublisherLoanError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_publisher_loan_error_e {
+    OUT_OF_MEMORY = IOX2_OK as isize + 1,
+    EXCEEDS_MAX_LOANED_SAMPLES,
+    EXCEEDS_MAX_LOAN_SIZE,
+    INTERNAL_FAILURE,
+}
+
+impl IntoCInt for PublisherLoanError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublisherLoanError::OutOfMemory => iox2_publisher_loan_error_e::OUT_OF_MEMORY,
+            PublisherLoanError::ExceedsMaxLoanedChunks => {
+                iox2_publisher_loan_error_e::EXCEEDS_MAX_LOANED_SAMPLES
+            }
+            PublisherLoanError::ExceedsMaxLoanSize => {
+                iox2_publisher_loan_error_e::EXCEEDS_MAX_LOAN_SIZE
+            }
+            PublisherLoanError::InternalFailure => iox2_publisher_loan_error_e::INTERNAL_FAILURE,
+        }) as c_int
+    }
+}
+
+/// Failures of [`iox2_publisher_send_copy()`] and [`crate::iox2_sample_mut_send()`], see
+/// [`PublisherSendError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_publisher_send_error_e {
+    CONNECTION_BROKEN_SINCE_PUBLISHER_NO_LONGER_EXISTS = IOX2_OK as isize + 1,
+    CONNECTION_CORRUPTED,
+    LOAN_ERROR_OUT_OF_MEMORY,
+    LOAN_ERROR_EXCEEDS_MAX_LOANED_SAMPLES,
+    LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
+    LOAN_ERROR_INTERNAL_FAILURE,
+    CONNECTION_ERROR,
+}
+
+impl IntoCInt for PublisherSendError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists => {
+                iox2_publisher_send_error_e::CONNECTION_BROKEN_SINCE_PUBLISHER_NO_LONGER_EXISTS
+            }
+            PublisherSendError::ConnectionCorrupted => {
+                iox2_publisher_send_error_e::CONNECTION_CORRUPTED
+            }
+            PublisherSendError::LoanError(PublisherLoanError::OutOfMemory) => {
+                iox2_publisher_send_error_e::LOAN_ERROR_OUT_OF_MEMORY
+            }
+            PublisherSendError::LoanError(PublisherLoanError::ExceedsMaxLoanedChunks) => {
+                iox2_publisher_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOANED_SAMPLES
+            }
+            PublisherSendError::LoanError(PublisherLoanError::ExceedsMaxLoanSize) => {
+                iox2_publisher_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
+            }
+            PublisherSendError::LoanError(PublisherLoanError::InternalFailure) => {
+                iox2_publisher_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
+            }
+            PublisherSendError::ConnectionError(
+                ConnectionFailure::FailedToEstablishConnection(_)
+                | ConnectionFailure::UnableToMapPublishersDataSegment(_),
+            ) => iox2_publisher_send_error_e::CONNECTION_ERROR,
+        }) as c_int
+    }
+}
+
+pub(crate) enum PublisherUnion {
+    Ipc(Publisher<zero_copy::Service, Untyped>),
+    Local(Publisher<process_local::Service, Untyped>),
+}
+
+/// The opaque representation of an untyped [`Publisher`].
+pub struct iox2_publisher_t {
+    value: PublisherUnion,
+}
+
+/// The owning handle of a [`iox2_publisher_t`]. Must be released with
+/// [`iox2_publisher_drop()`].
+pub type iox2_publisher_h = *mut iox2_publisher_t;
+
+/// Creates a new [`iox2_publisher_t`] for the publish-subscribe service and stores its handle in
+/// `publisher_handle_ptr`. `max_slice_len` defines the maximum number of elements a sample can
+/// hold when the payload is a [`crate::iox2_type_variant_e::DYNAMIC`] type, otherwise it is
+/// ignored. Returns [`IOX2_OK`] on success, otherwise a [`iox2_publisher_create_error_e`].
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle
+/// * `publisher_handle_ptr` must point to a valid [`iox2_publisher_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_pub_sub_publisher_create(
+    port_factory_handle: iox2_port_factory_pub_sub_h,
+    max_slice_len: usize,
+    publisher_handle_ptr: *mut iox2_publisher_h,
+) -> c_int {
+    debug_assert!(!port_factory_handle.is_null());
+    debug_assert!(!publisher_handle_ptr.is_null());
+
+    let value = match &(*port_factory_handle).value {
+        PortFactoryPubSubUnion::Ipc(factory) => {
+            match factory.publisher().max_slice_len(max_slice_len).create() {
+                Ok(v) => PublisherUnion::Ipc(v),
+                Err(e) => return e.into_c_int(),
+            }
+        }
+        PortFactoryPubSubUnion::Local(factory) => {
+            match factory.publisher().max_slice_len(max_slice_len).create() {
+                Ok(v) => PublisherUnion::Local(v),
+                Err(e) => return e.into_c_int(),
+            }
+        }
+    };
+
+    publisher_handle_ptr.write(Box::into_raw(Box::new(iox2_publisher_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_publisher_t`].
+///
+/// # Safety
+///
+/// * `publisher_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_drop(publisher_handle: iox2_publisher_h) {
+    debug_assert!(!publisher_handle.is_null());
+    drop(Box::from_raw(publisher_handle));
+}
+
+/// Loans a sample with a payload of `number_of_elements` elements of the services payload
+/// type and stores its handle in `sample_handle_ptr`. The payload is uninitialized and can be
+/// accessed with [`crate::iox2_sample_mut_payload_mut()`]. Returns [`IOX2_OK`] on success,
+/// otherwise a [`iox2_publisher_loan_error_e`].
+///
+/// # Safety
+///
+/// * `publisher_handle` must be a valid handle
+/// * `sample_handle_ptr` must point to a valid [`iox2_sample_mut_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_loan(
+    publisher_handle: iox2_publisher_h,
+    number_of_elements: usize,
+    sample_handle_ptr: *mut iox2_sample_mut_h,
+) -> c_int {
+    debug_assert!(!publisher_handle.is_null());
+    debug_assert!(!sample_handle_ptr.is_null());
+
+    let value = match &(*publisher_handle).value {
+        PublisherUnion::Ipc(publisher) => match publisher.loan_bytes_uninit(number_of_elements) {
+            Ok(v) => SampleMutUnion::Ipc(v),
+            Err(e) => return e.into_c_int(),
+        },
+        PublisherUnion::Local(publisher) => match publisher.loan_bytes_uninit(number_of_elements) {
+            Ok(v) => SampleMutUnion::Local(v),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    sample_handle_ptr.write(Box::into_raw(Box::new(iox2_sample_mut_t { value })));
+    IOX2_OK
+}
+
+fn send_copy<S: service::Service>(
+    publisher: &Publisher<S, Untyped>,
+    data: *const c_void,
+    number_of_elements: usize,
+) -> Result<usize, PublisherSendError> {
+    let mut sample = publisher.loan_bytes_uninit(number_of_elements)?;
+    let payload = sample.payload_mut();
+    // SAFETY: the caller guarantees that `data` points to `number_of_elements` elements, which
+    //         are exactly `payload.len()` bytes
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            data as *const MaybeUninit<u8>,
+            payload.as_mut_ptr(),
+            payload.len(),
+        )
+    };
+    sample.send()
+}
+
+/// Copies `number_of_elements` elements of the services payload type from `data` into a new
+/// sample and sends it. When `number_of_recipients` is not null the number of subscribers that
+/// received the sample is stored in it. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_publisher_send_error_e`].
+///
+/// # Safety
+///
+/// * `publisher_handle` must be a valid handle
+/// * `data` must point to `number_of_elements` initialized elements of the payload type
+/// * `number_of_recipients` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_send_copy(
+    publisher_handle: iox2_publisher_h,
+    data: *const c_void,
+    number_of_elements: usize,
+    number_of_recipients: *mut usize,
+) -> c_int {
+    debug_assert!(!publisher_handle.is_null());
+    debug_assert!(!data.is_null());
+
+    let result = match &(*publisher_handle).value {
+        PublisherUnion::Ipc(publisher) => send_copy(publisher, data, number_of_elements),
+        PublisherUnion::Local(publisher) => send_copy(publisher, data, number_of_elements),
+    };
+
+    match result {
+        Ok(v) => {
+            write_optional(number_of_recipients, v);
+            IOX2_OK
+        }
+        Err(e) => e.into_c_int(),
+    }
+}
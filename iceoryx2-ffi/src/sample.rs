@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_int, c_void};
+use std::mem::MaybeUninit;
+
+use iceoryx2::sample::Sample;
+use iceoryx2::sample_mut::SampleMut;
+use iceoryx2::service::{process_local, zero_copy};
+
+use crate::{write_optional, IntoCInt, IOX2_OK};
+
+pub(crate) enum SampleMutUnion {
+    Ipc(SampleMut<[MaybeUninit<u8>], zero_copy::Service>),
+    Local(SampleMut<[MaybeUninit<u8>], process_local::Service>),
+}
+
+/// The opaque representation of a loaned [`SampleMut`].
+pub struct iox2_sample_mut_t {
+    pub(crate) value: SampleMutUnion,
+}
+
+/// The owning handle of a [`iox2_sample_mut_t`]. Must be either sent with
+/// [`iox2_sample_mut_send()`] or released with [`iox2_sample_mut_drop()`].
+pub type iox2_sample_mut_h = *mut iox2_sample_mut_t;
+
+pub(crate) enum SampleUnion {
+    Ipc(Sample<[u8], zero_copy::Service>),
+    Local(Sample<[u8], process_local::Service>),
+}
+
+/// The opaque representation of a received [`Sample`].
+pub struct iox2_sample_t {
+    pub(crate) value: SampleUnion,
+}
+
+/// The owning handle of a [`iox2_sample_t`]. Must be released with [`iox2_sample_drop()`].
+pub type iox2_sample_h = *mut iox2_sample_t;
+
+/// Stores the address of the uninitialized payload of the sample in `payload_ptr` and its
+/// size in bytes in `payload_len`.
+///
+/// # Safety
+///
+/// * `sample_handle` must be a valid handle
+/// * `payload_ptr` must point to a valid `void*`
+/// * `payload_len` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_payload_mut(
+    sample_handle: iox2_sample_mut_h,
+    payload_ptr: *mut *mut c_void,
+    payload_len: *mut usize,
+) {
+    debug_assert!(!sample_handle.is_null());
+    debug_assert!(!payload_ptr.is_null());
+
+    let payload = match &mut (*sample_handle).value {
+        SampleMutUnion::Ipc(sample) => sample.payload_mut(),
+        SampleMutUnion::Local(sample) => sample.payload_mut(),
+    };
+
+    payload_ptr.write(payload.as_mut_ptr() as *mut c_void);
+    write_optional(payload_len, payload.len());
+}
+
+/// Sends the sample to all connected subscribers and releases the handle, also in the error
+/// case. When `number_of_recipients` is not null the number of subscribers that received the
+/// sample is stored in it. Returns [`IOX2_OK`] on success, otherwise a
+/// [`crate::iox2_publisher_send_error_e`].
+///
+/// # Safety
+///
+/// * `sample_handle` must be a valid handle that was not yet released and whose payload was
+///   initialized
+/// * `number_of_recipients` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_send(
+    sample_handle: iox2_sample_mut_h,
+    number_of_recipients: *mut usize,
+) -> c_int {
+    debug_assert!(!sample_handle.is_null());
+
+    let result = match Box::from_raw(sample_handle).value {
+        SampleMutUnion::Ipc(sample) => sample.send(),
+        SampleMutUnion::Local(sample) => sample.send(),
+    };
+
+    match result {
+        Ok(v) => {
+            write_optional(number_of_recipients, v);
+            IOX2_OK
+        }
+        Err(e) => e.into_c_int(),
+    }
+}
+
+/// Releases the [`iox2_sample_mut_t`] without sending it.
+///
+/// # Safety
+///
+/// * `sample_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_drop(sample_handle: iox2_sample_mut_h) {
+    debug_assert!(!sample_handle.is_null());
+    drop(Box::from_raw(sample_handle));
+}
+
+/// Stores the address of the payload of the received sample in `payload_ptr` and its size in
+/// bytes in `payload_len`.
+///
+/// # Safety
+///
+/// * `sample_handle` must be a valid handle
+/// * `payload_ptr` must point to a valid `const void*`
+/// * `payload_len` must be null or point to a valid `size_t`
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_payload(
+    sample_handle: iox2_sample_h,
+    payload_ptr: *mut *const c_void,
+    payload_len: *mut usize,
+) {
+    debug_assert!(!sample_handle.is_null());
+    debug_assert!(!payload_ptr.is_null());
+
+    let payload = match &(*sample_handle).value {
+        SampleUnion::Ipc(sample) => sample.payload(),
+        SampleUnion::Local(sample) => sample.payload(),
+    };
+
+    payload_ptr.write(payload.as_ptr() as *const c_void);
+    write_optional(payload_len, payload.len());
+}
+
+/// Releases the [`iox2_sample_t`] and returns it to the publisher.
+///
+/// # Safety
+///
+/// * `sample_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_drop(sample_handle: iox2_sample_h) {
+    debug_assert!(!sample_handle.is_null());
+    drop(Box::from_raw(sample_handle));
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_char, c_int, CStr};
+
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2_bb_container::semantic_string::SemanticStringError;
+
+use crate::{IntoCInt, IOX2_OK};
+
+/// Failures of [`iox2_service_name_new()`], see [`SemanticStringError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_semantic_string_error_e {
+    INVALID_CONTENT = IOX2_OK as isize + 1,
+    EXCEEDS_MAXIMUM_LENGTH,
+}
+
+impl IntoCInt for SemanticStringError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            SemanticStringError::InvalidContent => iox2_semantic_string_error_e::INVALID_CONTENT,
+            SemanticStringError::ExceedsMaximumLength => {
+                iox2_semantic_string_error_e::EXCEEDS_MAXIMUM_LENGTH
+            }
+        }) as c_int
+    }
+}
+
+/// The opaque representation of a [`ServiceName`].
+pub struct iox2_service_name_t {
+    pub(crate) value: ServiceName,
+}
+
+/// The owning handle of a [`iox2_service_name_t`]. Must be released with
+/// [`iox2_service_name_drop()`].
+pub type iox2_service_name_h = *mut iox2_service_name_t;
+
+/// Creates a new [`iox2_service_name_t`] from the null-terminated UTF-8 string `service_name`
+/// and stores its handle in `service_name_handle_ptr`. Returns [`IOX2_OK`] on success,
+/// otherwise a [`iox2_semantic_string_error_e`].
+///
+/// # Safety
+///
+/// * `service_name` must be a valid null-terminated string
+/// * `service_name_handle_ptr` must point to a valid [`iox2_service_name_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_name_new(
+    service_name: *const c_char,
+    service_name_handle_ptr: *mut iox2_service_name_h,
+) -> c_int {
+    debug_assert!(!service_name.is_null());
+    debug_assert!(!service_name_handle_ptr.is_null());
+
+    let value = match CStr::from_ptr(service_name).to_str() {
+        Ok(v) => v,
+        Err(_) => return iox2_semantic_string_error_e::INVALID_CONTENT as c_int,
+    };
+
+    let value = match ServiceName::new(value) {
+        Ok(v) => v,
+        Err(e) => return e.into_c_int(),
+    };
+
+    service_name_handle_ptr.write(Box::into_raw(Box::new(iox2_service_name_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_service_name_t`].
+///
+/// # Safety
+///
+/// * `service_name_handle` must be a handle acquired with [`iox2_service_name_new()`] that was
+///   not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_name_drop(service_name_handle: iox2_service_name_h) {
+    debug_assert!(!service_name_handle.is_null());
+    drop(Box::from_raw(service_name_handle));
+}
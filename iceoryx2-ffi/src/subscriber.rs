@@ -0,0 +1,164 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::c_int;
+
+use iceoryx2::port::subscriber::{Subscriber, SubscriberCreateError, SubscriberReceiveError};
+use iceoryx2::port::update_connections::ConnectionFailure;
+use iceoryx2::service::static_config::type_details::Untyped;
+use iceoryx2::service::{process_local, zero_copy};
+
+use crate::{
+    iox2_port_factory_pub_sub_h, iox2_sample_h, iox2_sample_t, IntoCInt, PortFactoryPubSubUnion,
+    SampleUnion, IOX2_OK,
+};
+
+/// Failures of [`iox2_port_factory_pub_sub_subscriber_create()`], see
+/// [`SubscriberCreateError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_subscriber_create_error_e {
+    EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
+    BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    EXCEEDS_MAX_SUPPORTED_FILTER_KEYS,
+}
+
+impl IntoCInt for SubscriberCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers => {
+                iox2_subscriber_create_error_e::EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS
+            }
+            SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
+                iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
+            }
+            SubscriberCreateError::ExceedsMaxSupportedFilterKeys => {
+                iox2_subscriber_create_error_e::EXCEEDS_MAX_SUPPORTED_FILTER_KEYS
+            }
+        }) as c_int
+    }
+}
+
+/// Failures of [`iox2_subscriber_receive()`], see [`SubscriberReceiveError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_subscriber_receive_error_e {
+    EXCEEDS_MAX_BORROWED_SAMPLES = IOX2_OK as isize + 1,
+    FAILED_TO_ESTABLISH_CONNECTION,
+    UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+}
+
+impl IntoCInt for SubscriberReceiveError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            SubscriberReceiveError::ExceedsMaxBorrowedSamples => {
+                iox2_subscriber_receive_error_e::EXCEEDS_MAX_BORROWED_SAMPLES
+            }
+            SubscriberReceiveError::ConnectionFailure(
+                ConnectionFailure::FailedToEstablishConnection(_),
+            ) => iox2_subscriber_receive_error_e::FAILED_TO_ESTABLISH_CONNECTION,
+            SubscriberReceiveError::ConnectionFailure(
+                ConnectionFailure::UnableToMapPublishersDataSegment(_),
+            ) => iox2_subscriber_receive_error_e::UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+        }) as c_int
+    }
+}
+
+pub(crate) enum SubscriberUnion {
+    Ipc(Subscriber<zero_copy::Service, Untyped>),
+    Local(Subscriber<process_local::Service, Untyped>),
+}
+
+/// The opaque representation of an untyped [`Subscriber`].
+pub struct iox2_subscriber_t {
+    value: SubscriberUnion,
+}
+
+/// The owning handle of a [`iox2_subscriber_t`]. Must be released with
+/// [`iox2_subscriber_drop()`].
+pub type iox2_subscriber_h = *mut iox2_subscriber_t;
+
+/// Creates a new [`iox2_subscriber_t`] for the publish-subscribe service and stores its handle
+/// in `subscriber_handle_ptr`. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_subscriber_create_error_e`].
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be a valid handle
+/// * `subscriber_handle_ptr` must point to a valid [`iox2_subscriber_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_pub_sub_subscriber_create(
+    port_factory_handle: iox2_port_factory_pub_sub_h,
+    subscriber_handle_ptr: *mut iox2_subscriber_h,
+) -> c_int {
+    debug_assert!(!port_factory_handle.is_null());
+    debug_assert!(!subscriber_handle_ptr.is_null());
+
+    let value = match &(*port_factory_handle).value {
+        PortFactoryPubSubUnion::Ipc(factory) => match factory.subscriber().create() {
+            Ok(v) => SubscriberUnion::Ipc(v),
+            Err(e) => return e.into_c_int(),
+        },
+        PortFactoryPubSubUnion::Local(factory) => match factory.subscriber().create() {
+            Ok(v) => SubscriberUnion::Local(v),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    subscriber_handle_ptr.write(Box::into_raw(Box::new(iox2_subscriber_t { value })));
+    IOX2_OK
+}
+
+/// Releases the [`iox2_subscriber_t`].
+///
+/// # Safety
+///
+/// * `subscriber_handle` must be a valid handle that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_drop(subscriber_handle: iox2_subscriber_h) {
+    debug_assert!(!subscriber_handle.is_null());
+    drop(Box::from_raw(subscriber_handle));
+}
+
+/// Receives the next sample and stores its handle in `sample_handle_ptr`. When no sample is
+/// available `sample_handle_ptr` is set to null. Returns [`IOX2_OK`] on success, otherwise a
+/// [`iox2_subscriber_receive_error_e`].
+///
+/// # Safety
+///
+/// * `subscriber_handle` must be a valid handle
+/// * `sample_handle_ptr` must point to a valid [`iox2_sample_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_receive(
+    subscriber_handle: iox2_subscriber_h,
+    sample_handle_ptr: *mut iox2_sample_h,
+) -> c_int {
+    debug_assert!(!subscriber_handle.is_null());
+    debug_assert!(!sample_handle_ptr.is_null());
+
+    let value = match &(*subscriber_handle).value {
+        SubscriberUnion::Ipc(subscriber) => match subscriber.receive() {
+            Ok(v) => v.map(SampleUnion::Ipc),
+            Err(e) => return e.into_c_int(),
+        },
+        SubscriberUnion::Local(subscriber) => match subscriber.receive() {
+            Ok(v) => v.map(SampleUnion::Local),
+            Err(e) => return e.into_c_int(),
+        },
+    };
+
+    sample_handle_ptr.write(match value {
+        Some(value) => Box::into_raw(Box::new(iox2_sample_t { value })),
+        None => core::ptr::null_mut(),
+    });
+    IOX2_OK
+}
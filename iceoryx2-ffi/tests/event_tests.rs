@@ -0,0 +1,112 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_int, CString};
+
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_ffi::*;
+
+const SERVICE_TYPES: [iox2_service_type_e; 2] =
+    [iox2_service_type_e::LOCAL, iox2_service_type_e::IPC];
+
+fn generate_name() -> CString {
+    CString::new(format!(
+        "ffi_event_tests_{}",
+        UniqueSystemId::new().unwrap().value()
+    ))
+    .unwrap()
+}
+
+struct TestFixture {
+    node: iox2_node_h,
+    service_name: iox2_service_name_h,
+}
+
+impl TestFixture {
+    fn new(service_type: iox2_service_type_e) -> Self {
+        let mut node: iox2_node_h = core::ptr::null_mut();
+        let mut service_name: iox2_service_name_h = core::ptr::null_mut();
+        let name = generate_name();
+
+        unsafe {
+            assert_that!(iox2_node_new(service_type, &mut node), eq IOX2_OK);
+            assert_that!(iox2_service_name_new(name.as_ptr(), &mut service_name), eq IOX2_OK);
+        }
+
+        Self { node, service_name }
+    }
+}
+
+impl Drop for TestFixture {
+    fn drop(&mut self) {
+        unsafe {
+            iox2_service_name_drop(self.service_name);
+            iox2_node_drop(self.node);
+        }
+    }
+}
+
+#[test]
+fn ffi_event_service_create_fails_when_service_exists() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+
+        unsafe {
+            let mut service: iox2_port_factory_event_h = core::ptr::null_mut();
+            assert_that!(iox2_node_event_create(sut.node, sut.service_name, &mut service), eq IOX2_OK);
+
+            let mut service2: iox2_port_factory_event_h = core::ptr::null_mut();
+            let result = iox2_node_event_create(sut.node, sut.service_name, &mut service2);
+            assert_that!(result, eq iox2_event_open_or_create_error_e::C_ALREADY_EXISTS as c_int);
+
+            assert_that!(iox2_node_event_open(sut.node, sut.service_name, &mut service2), eq IOX2_OK);
+
+            iox2_port_factory_event_drop(service2);
+            iox2_port_factory_event_drop(service);
+        }
+    }
+}
+
+#[test]
+fn ffi_event_notification_is_received() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+
+        unsafe {
+            let mut service: iox2_port_factory_event_h = core::ptr::null_mut();
+            assert_that!(iox2_node_event_open_or_create(sut.node, sut.service_name, &mut service), eq IOX2_OK);
+
+            let mut notifier: iox2_notifier_h = core::ptr::null_mut();
+            let mut listener: iox2_listener_h = core::ptr::null_mut();
+            assert_that!(iox2_port_factory_event_notifier_create(service, &mut notifier), eq IOX2_OK);
+            assert_that!(iox2_port_factory_event_listener_create(service, &mut listener), eq IOX2_OK);
+
+            let mut event_id = 0;
+            let mut has_received_one = true;
+            assert_that!(iox2_listener_try_wait_one(listener, &mut event_id, &mut has_received_one), eq IOX2_OK);
+            assert_that!(has_received_one, eq false);
+
+            let mut number_of_notified_listeners = 0;
+            assert_that!(iox2_notifier_notify_with_custom_event_id(notifier, 12, &mut number_of_notified_listeners), eq IOX2_OK);
+            assert_that!(number_of_notified_listeners, eq 1);
+
+            assert_that!(iox2_listener_timed_wait_one(listener, 1, 0, &mut event_id, &mut has_received_one), eq IOX2_OK);
+            assert_that!(has_received_one, eq true);
+            assert_that!(event_id, eq 12);
+
+            iox2_listener_drop(listener);
+            iox2_notifier_drop(notifier);
+            iox2_port_factory_event_drop(service);
+        }
+    }
+}
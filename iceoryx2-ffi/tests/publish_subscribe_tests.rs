@@ -0,0 +1,230 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ffi::{c_int, c_void, CString};
+
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_ffi::*;
+
+const SERVICE_TYPES: [iox2_service_type_e; 2] =
+    [iox2_service_type_e::LOCAL, iox2_service_type_e::IPC];
+
+fn generate_name() -> CString {
+    CString::new(format!(
+        "ffi_publish_subscribe_tests_{}",
+        UniqueSystemId::new().unwrap().value()
+    ))
+    .unwrap()
+}
+
+struct TestFixture {
+    node: iox2_node_h,
+    service_name: iox2_service_name_h,
+}
+
+impl TestFixture {
+    fn new(service_type: iox2_service_type_e) -> Self {
+        let mut node: iox2_node_h = core::ptr::null_mut();
+        let mut service_name: iox2_service_name_h = core::ptr::null_mut();
+        let name = generate_name();
+
+        unsafe {
+            assert_that!(iox2_node_new(service_type, &mut node), eq IOX2_OK);
+            assert_that!(iox2_service_name_new(name.as_ptr(), &mut service_name), eq IOX2_OK);
+        }
+
+        Self { node, service_name }
+    }
+
+    fn open_or_create(&self, type_details: &iox2_type_details_t) -> iox2_port_factory_pub_sub_h {
+        let mut service: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        unsafe {
+            assert_that!(iox2_node_pub_sub_open_or_create(self.node, self.service_name, type_details, &mut service), eq IOX2_OK);
+        }
+        service
+    }
+}
+
+impl Drop for TestFixture {
+    fn drop(&mut self) {
+        unsafe {
+            iox2_service_name_drop(self.service_name);
+            iox2_node_drop(self.node);
+        }
+    }
+}
+
+fn u64_type_details(type_name: &CString) -> iox2_type_details_t {
+    iox2_type_details_t {
+        variant: iox2_type_variant_e::FIXED_SIZE,
+        type_name: type_name.as_ptr(),
+        size: 8,
+        alignment: 8,
+    }
+}
+
+#[test]
+fn ffi_service_name_with_invalid_content_cannot_be_created() {
+    let mut service_name: iox2_service_name_h = core::ptr::null_mut();
+    let name = CString::new("").unwrap();
+
+    let result = unsafe { iox2_service_name_new(name.as_ptr(), &mut service_name) };
+    assert_that!(result, eq iox2_semantic_string_error_e::INVALID_CONTENT as c_int);
+}
+
+#[test]
+fn ffi_pub_sub_service_with_invalid_type_details_cannot_be_created() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+        let type_name = CString::new("u64").unwrap();
+        let mut type_details = u64_type_details(&type_name);
+        type_details.alignment = 3;
+
+        let mut service: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        let result = unsafe {
+            iox2_node_pub_sub_create(sut.node, sut.service_name, &type_details, &mut service)
+        };
+        assert_that!(result, eq iox2_pub_sub_open_or_create_error_e::INVALID_TYPE_DETAILS as c_int);
+    }
+}
+
+#[test]
+fn ffi_pub_sub_service_open_fails_when_service_does_not_exist() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+        let type_name = CString::new("u64").unwrap();
+        let type_details = u64_type_details(&type_name);
+
+        let mut service: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        let result = unsafe {
+            iox2_node_pub_sub_open(sut.node, sut.service_name, &type_details, &mut service)
+        };
+        assert_that!(result, eq iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_EXIST as c_int);
+    }
+}
+
+#[test]
+fn ffi_pub_sub_service_open_fails_with_incompatible_type_details() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+        let type_name = CString::new("u64").unwrap();
+        let type_details = u64_type_details(&type_name);
+        let service = sut.open_or_create(&type_details);
+
+        let other_type_name = CString::new("i64").unwrap();
+        let other_type_details = u64_type_details(&other_type_name);
+        let mut service2: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        let result = unsafe {
+            iox2_node_pub_sub_open(
+                sut.node,
+                sut.service_name,
+                &other_type_details,
+                &mut service2,
+            )
+        };
+        assert_that!(result, eq iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPES as c_int);
+
+        unsafe { iox2_port_factory_pub_sub_drop(service) };
+    }
+}
+
+#[test]
+fn ffi_pub_sub_loaned_sample_is_received() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+        let type_name = CString::new("u64").unwrap();
+        let service = sut.open_or_create(&u64_type_details(&type_name));
+
+        unsafe {
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            assert_that!(iox2_port_factory_pub_sub_publisher_create(service, 1, &mut publisher), eq IOX2_OK);
+            assert_that!(iox2_port_factory_pub_sub_subscriber_create(service, &mut subscriber), eq IOX2_OK);
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            assert_that!(iox2_publisher_loan(publisher, 1, &mut sample), eq IOX2_OK);
+
+            let mut payload: *mut c_void = core::ptr::null_mut();
+            let mut payload_len = 0;
+            iox2_sample_mut_payload_mut(sample, &mut payload, &mut payload_len);
+            assert_that!(payload_len, eq 8);
+            (payload as *mut u64).write(8127389);
+
+            let mut number_of_recipients = 0;
+            assert_that!(iox2_sample_mut_send(sample, &mut number_of_recipients), eq IOX2_OK);
+            assert_that!(number_of_recipients, eq 1);
+
+            let mut received: iox2_sample_h = core::ptr::null_mut();
+            assert_that!(iox2_subscriber_receive(subscriber, &mut received), eq IOX2_OK);
+            assert_that!(received, ne core::ptr::null_mut());
+
+            let mut payload: *const c_void = core::ptr::null();
+            let mut payload_len = 0;
+            iox2_sample_payload(received, &mut payload, &mut payload_len);
+            assert_that!(payload_len, eq 8);
+            assert_that!(*(payload as *const u64), eq 8127389);
+            iox2_sample_drop(received);
+
+            assert_that!(iox2_subscriber_receive(subscriber, &mut received), eq IOX2_OK);
+            assert_that!(received, eq core::ptr::null_mut());
+
+            iox2_subscriber_drop(subscriber);
+            iox2_publisher_drop(publisher);
+            iox2_port_factory_pub_sub_drop(service);
+        }
+    }
+}
+
+#[test]
+fn ffi_pub_sub_send_copy_of_slice_is_received() {
+    for service_type in SERVICE_TYPES {
+        let sut = TestFixture::new(service_type);
+        let type_name = CString::new("[u32]").unwrap();
+        let type_details = iox2_type_details_t {
+            variant: iox2_type_variant_e::DYNAMIC,
+            type_name: type_name.as_ptr(),
+            size: 4,
+            alignment: 4,
+        };
+        let service = sut.open_or_create(&type_details);
+        let data: [u32; 5] = [1, 2, 3, 4, 5];
+
+        unsafe {
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            assert_that!(iox2_port_factory_pub_sub_publisher_create(service, data.len(), &mut publisher), eq IOX2_OK);
+            assert_that!(iox2_port_factory_pub_sub_subscriber_create(service, &mut subscriber), eq IOX2_OK);
+
+            assert_that!(iox2_publisher_send_copy(publisher, data.as_ptr() as *const c_void, 3, core::ptr::null_mut()), eq IOX2_OK);
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            let result = iox2_publisher_loan(publisher, data.len() + 1, &mut sample);
+            assert_that!(result, eq iox2_publisher_loan_error_e::EXCEEDS_MAX_LOAN_SIZE as c_int);
+
+            let mut received: iox2_sample_h = core::ptr::null_mut();
+            assert_that!(iox2_subscriber_receive(subscriber, &mut received), eq IOX2_OK);
+            assert_that!(received, ne core::ptr::null_mut());
+
+            let mut payload: *const c_void = core::ptr::null();
+            let mut payload_len = 0;
+            iox2_sample_payload(received, &mut payload, &mut payload_len);
+            assert_that!(payload_len, eq 12);
+            assert_that!(core::slice::from_raw_parts(payload as *const u32, 3), eq [1, 2, 3]);
+            iox2_sample_drop(received);
+
+            iox2_subscriber_drop(subscriber);
+            iox2_publisher_drop(publisher);
+            iox2_port_factory_pub_sub_drop(service);
+        }
+    }
+}
@@ -25,7 +25,13 @@
 #if defined(IOX2_ACL_SUPPORT) && !defined(IOX2_DOCS_RS_SUPPORT)
 #include <acl/libacl.h>
 #endif
+#include <linux/futex.h>
+#include <linux/memfd.h>
+#include <linux/mempolicy.h>
 #include <mqueue.h>
+#include <sys/epoll.h>
+#include <sys/eventfd.h>
+#include <sys/syscall.h>
 #endif
 
 #ifndef _WIN64
@@ -40,6 +40,34 @@ pub const F_GETLK: int = crate::internal::F_GETLK as _;
 pub const F_SETLK: int = crate::internal::F_SETLK as _;
 pub const F_SETLKW: int = crate::internal::F_SETLKW as _;
 
+pub const EFD_CLOEXEC: int = crate::internal::EFD_CLOEXEC as _;
+pub const EFD_NONBLOCK: int = crate::internal::EFD_NONBLOCK as _;
+pub const EFD_SEMAPHORE: int = crate::internal::EFD_SEMAPHORE as _;
+
+pub const EPOLL_CLOEXEC: int = crate::internal::EPOLL_CLOEXEC as _;
+pub const EPOLL_CTL_ADD: int = crate::internal::EPOLL_CTL_ADD as _;
+pub const EPOLL_CTL_DEL: int = crate::internal::EPOLL_CTL_DEL as _;
+pub const EPOLL_CTL_MOD: int = crate::internal::EPOLL_CTL_MOD as _;
+pub const EPOLLIN: u32 = 0x001;
+pub const EPOLLPRI: u32 = 0x002;
+pub const EPOLLOUT: u32 = 0x004;
+pub const EPOLLERR: u32 = 0x008;
+pub const EPOLLHUP: u32 = 0x010;
+
+pub const FUTEX_WAIT: int = crate::internal::FUTEX_WAIT as _;
+pub const FUTEX_WAKE: int = crate::internal::FUTEX_WAKE as _;
+
+pub const MFD_CLOEXEC: uint = crate::internal::MFD_CLOEXEC as _;
+pub const MFD_ALLOW_SEALING: uint = crate::internal::MFD_ALLOW_SEALING as _;
+pub const MFD_HUGETLB: uint = crate::internal::MFD_HUGETLB as _;
+pub const MFD_HUGE_2MB: uint = crate::internal::MFD_HUGE_2MB as _;
+pub const MFD_HUGE_1GB: uint = crate::internal::MFD_HUGE_1GB as _;
+pub const F_ADD_SEALS: int = crate::internal::F_ADD_SEALS as _;
+pub const F_GET_SEALS: int = crate::internal::F_GET_SEALS as _;
+pub const F_SEAL_SEAL: int = crate::internal::F_SEAL_SEAL as _;
+pub const F_SEAL_SHRINK: int = crate::internal::F_SEAL_SHRINK as _;
+pub const F_SEAL_GROW: int = crate::internal::F_SEAL_GROW as _;
+
 pub const PROT_NONE: int = crate::internal::PROT_NONE as _;
 pub const PROT_READ: int = crate::internal::PROT_READ as _;
 pub const PROT_WRITE: int = crate::internal::PROT_WRITE as _;
@@ -47,7 +75,10 @@ pub const PROT_EXEC: int = crate::internal::PROT_EXEC as _;
 pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
+pub const MAP_POPULATE: int = crate::internal::MAP_POPULATE as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MPOL_PREFERRED: int = crate::internal::MPOL_PREFERRED as _;
+pub const MPOL_BIND: int = crate::internal::MPOL_BIND as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = crate::internal::PTHREAD_EXPLICIT_SCHED as _;
@@ -0,0 +1,47 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use core::sync::atomic::AtomicU32;
+
+use crate::posix::constants::*;
+use crate::posix::types::*;
+
+pub unsafe fn futex_wait(
+    address: *const AtomicU32,
+    expected_value: u32,
+    timeout: *const timespec,
+) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_futex as _,
+        address,
+        FUTEX_WAIT,
+        expected_value,
+        timeout,
+        core::ptr::null::<u32>(),
+        0u32,
+    ) as _
+}
+
+pub unsafe fn futex_wake(address: *const AtomicU32, number_of_waiters: u32) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_futex as _,
+        address,
+        FUTEX_WAKE,
+        number_of_waiters,
+        core::ptr::null::<timespec>(),
+        core::ptr::null::<u32>(),
+        0u32,
+    ) as _
+}
@@ -39,6 +39,29 @@ pub unsafe fn shm_unlink(name: *const c_char) -> int {
     crate::internal::shm_unlink(name)
 }
 
+pub unsafe fn memfd_create(name: *const c_char, flags: uint) -> int {
+    crate::internal::memfd_create(name, flags)
+}
+
+pub unsafe fn mbind(
+    addr: *mut void,
+    len: size_t,
+    mode: int,
+    nodemask: *const ulong,
+    maxnode: ulong,
+    flags: uint,
+) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_mbind as _,
+        addr,
+        len,
+        mode,
+        nodemask,
+        maxnode,
+        flags,
+    ) as _
+}
+
 pub unsafe fn shm_list() -> Vec<[i8; 256]> {
     let mut result = vec![];
     let dir = opendir(b"/dev/shm/\0".as_ptr().cast());
@@ -14,8 +14,11 @@
 pub mod acl;
 pub mod constants;
 pub mod dirent;
+pub mod epoll;
 pub mod errno;
+pub mod eventfd;
 pub mod fcntl;
+pub mod futex;
 pub mod inet;
 pub mod mman;
 pub mod mqueue;
@@ -40,8 +43,11 @@ pub mod unistd;
 pub use crate::linux::acl::*;
 pub use crate::linux::constants::*;
 pub use crate::linux::dirent::*;
+pub use crate::linux::epoll::*;
 pub use crate::linux::errno::*;
+pub use crate::linux::eventfd::*;
 pub use crate::linux::fcntl::*;
+pub use crate::linux::futex::*;
 pub use crate::linux::inet::*;
 pub use crate::linux::mman::*;
 pub use crate::linux::mqueue::*;
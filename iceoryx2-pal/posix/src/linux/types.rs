@@ -26,6 +26,16 @@ pub struct ucred {
 
 impl Struct for ucred {}
 
+#[repr(C)]
+#[cfg_attr(target_arch = "x86_64", repr(packed))]
+#[derive(Clone, Copy)]
+pub struct epoll_event {
+    pub events: u32,
+    pub data: u64,
+}
+
+impl Struct for epoll_event {}
+
 pub type DIR = crate::internal::DIR;
 
 pub type blkcnt_t = crate::internal::blkcnt_t;
@@ -0,0 +1,194 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #   .request_response()
+//! #   .typed::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("received: {:?}", *request);
+//!     println!("header client id {:?}", request.header().client_id());
+//!
+//!     request.send_copy(*request * 2)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::{fmt::Debug, ops::Deref};
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::shm_allocator::ShmAllocationError;
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+use crate::message::Message;
+use crate::port::port_identifiers::UniqueClientId;
+use crate::port::server::{ServerLoanError, ServerSendError, ServerSharedState};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::response_mut::ResponseMut;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+
+/// It stores the payload of a request and is acquired by the
+/// [`Server`](crate::port::server::Server) whenever it receives a request from a
+/// [`Client`](crate::port::client::Client) via
+/// [`Server::receive()`](crate::port::server::Server::receive()).
+/// As long as the [`ActiveRequest`] exists, responses can be loaned and sent to the
+/// [`Client`](crate::port::client::Client) that sent the request.
+#[derive(Debug)]
+pub struct ActiveRequest<RequestType: Debug, ResponseType: Debug, Service: crate::service::Service>
+{
+    pub(crate) shared_state: Arc<ServerSharedState<Service>>,
+    pub(crate) ptr: RawSample<RequestHeader, RequestType>,
+    pub(crate) channel_id: usize,
+    pub(crate) offset: PointerOffset,
+    pub(crate) origin: UniqueClientId,
+    pub(crate) request_id: u64,
+    pub(crate) _phantom_response_type: PhantomData<ResponseType>,
+}
+
+impl<RequestType: Debug, ResponseType: Debug, Service: crate::service::Service> Deref
+    for ActiveRequest<RequestType, ResponseType, Service>
+{
+    type Target = RequestType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<RequestType: Debug, ResponseType: Debug, Service: crate::service::Service> Drop
+    for ActiveRequest<RequestType, ResponseType, Service>
+{
+    fn drop(&mut self) {
+        self.shared_state
+            .release_request(self.channel_id, self.origin, self.offset);
+    }
+}
+
+impl<RequestType: Debug, ResponseType: Debug, Service: crate::service::Service>
+    ActiveRequest<RequestType, ResponseType, Service>
+{
+    /// Returns a reference to the payload of the [`ActiveRequest`]
+    pub fn payload(&self) -> &RequestType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the [`ActiveRequest`].
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client)
+    pub fn origin(&self) -> UniqueClientId {
+        self.origin
+    }
+
+    /// Loans/allocates a [`ResponseMut`] from the underlying data segment of the
+    /// [`Server`](crate::port::server::Server). The user has to initialize the payload before
+    /// it can be sent.
+    ///
+    /// On failure it returns [`ServerLoanError`] describing the failure.
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<ResponseMut<MaybeUninit<ResponseType>, Service>, ServerLoanError> {
+        let msg = "Unable to loan Response";
+        let shared_state = &self.shared_state;
+
+        if shared_state.loan_counter.load(Ordering::Relaxed)
+            >= shared_state.config.max_loaned_responses
+        {
+            fail!(from self, with ServerLoanError::ExceedsMaxLoanedResponses,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, shared_state.loan_counter.load(Ordering::Relaxed), shared_state.config.max_loaned_responses);
+        }
+
+        match shared_state.data_segment.allocate() {
+            Ok(chunk) => {
+                let message = chunk.data_ptr
+                    as *mut MaybeUninit<Message<ResponseHeader, MaybeUninit<ResponseType>>>;
+
+                let response = unsafe {
+                    (*message).write(Message {
+                        header: ResponseHeader::new(shared_state.server_id, self.request_id),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<ResponseHeader, MaybeUninit<ResponseType>>,
+                    )
+                };
+
+                shared_state.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(ResponseMut::new(
+                    shared_state,
+                    response,
+                    chunk.offset,
+                    self.channel_id,
+                    self.origin,
+                ))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ServerLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ServerLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    /// Copies the input `value` into a [`ResponseMut`] and sends it to the
+    /// [`Client`](crate::port::client::Client) that sent the request.
+    ///
+    /// On failure it returns [`ServerSendError`] describing the failure.
+    pub fn send_copy(&self, value: ResponseType) -> Result<(), ServerSendError> {
+        let msg = "Unable to send copy of response";
+        let response = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a response failed.", msg);
+
+        fail!(from self, when response.write_payload(value).send(),
+            "{} since the underlying send operation failed.", msg);
+
+        Ok(())
+    }
+}
+
+impl<RequestType: Debug, ResponseType: Default + Debug, Service: crate::service::Service>
+    ActiveRequest<RequestType, ResponseType, Service>
+{
+    /// Loans/allocates a [`ResponseMut`] from the underlying data segment of the
+    /// [`Server`](crate::port::server::Server) and initializes it with the default value.
+    ///
+    /// On failure it returns [`ServerLoanError`] describing the failure.
+    pub fn loan(&self) -> Result<ResponseMut<ResponseType, Service>, ServerLoanError> {
+        Ok(self.loan_uninit()?.write_payload(ResponseType::default()))
+    }
+}
@@ -15,7 +15,9 @@
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
 ///
-/// let service = zero_copy::Service::new(&service_name)
+/// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+///
+/// let service = node.service_builder(&service_name)
 ///     .publish_subscribe()
 ///     .open_or_create::<u64>()?;
 ///
@@ -40,7 +42,9 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
 ///
-/// let service = zero_copy::Service::new(&service_name)
+/// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+///
+/// let service = node.service_builder(&service_name)
 ///     .publish_subscribe()
 ///     .open_or_create::<Wrapper>()?;
 ///
@@ -53,3 +57,28 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// ```
 #[cfg(doctest)]
 fn loan_with_type_not_implementing_default_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug)]
+/// #[repr(C)]
+/// struct Message {
+///     text: String,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+///
+/// let service = node.service_builder(&service_name)
+///     .publish_subscribe()
+///     .typed::<Message>() // should fail to compile since 'Message' does not implement 'ZeroCopySend'
+///     .open_or_create()?;
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn payload_not_implementing_zero_copy_send_fails_to_compile() {}
@@ -25,8 +25,10 @@
 //! custom_config.defaults.publish_subscribe.max_publishers = 5;
 //! custom_config.global.service.directory = "another_service_dir".to_string();
 //!
-//! let service = zero_copy::Service::new(&service_name)
-//!     .publish_subscribe_with_custom_config(&custom_config)
+//! let node = NodeBuilder::new().config(&custom_config).create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
 //!
@@ -47,8 +49,10 @@
 //! custom_config.defaults.event.max_notifiers = 5;
 //! custom_config.global.service.directory = "custom_service_dir".to_string();
 //!
-//! let event = zero_copy::Service::new(&event_name)
-//!     .event_with_custom_config(&custom_config)
+//! let node = NodeBuilder::new().config(&custom_config).create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
+//!     .event()
 //!     .open_or_create()?;
 //!
 //! # Ok(())
@@ -100,6 +104,7 @@ use std::time::Duration;
 use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::static_config::pipeline::DistributionStrategy;
 
 /// Path to the default config file
 pub const DEFAULT_CONFIG_FILE: &[u8] = b"config/iceoryx2.toml";
@@ -138,6 +143,8 @@ pub struct Service {
     pub creation_timeout: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: String,
+    /// The suffix of the monitoring token of a [`crate::node::Node`]
+    pub node_monitor_suffix: String,
 }
 
 /// The global settings
@@ -189,6 +196,12 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
+    /// Default settings for the messaging pattern blackboard
+    pub blackboard: Blackboard,
+    /// Default settings for the messaging pattern pipeline
+    pub pipeline: Pipeline,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -200,6 +213,9 @@ pub struct PublishSubscribe {
     pub max_subscribers: usize,
     /// The maximum amount of supported [`crate::port::publisher::Publisher`]
     pub max_publishers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
     /// The maximum buffer size a [`crate::port::subscriber::Subscriber`] can have
     pub subscriber_max_buffer_size: usize,
     /// The maximum amount of [`crate::sample::Sample`]s a [`crate::port::subscriber::Subscriber`] can
@@ -208,6 +224,11 @@ pub struct PublishSubscribe {
     /// The maximum amount of [`crate::sample_mut::SampleMut`]s a [`crate::port::publisher::Publisher`] can
     /// loan in parallel.
     pub publisher_max_loaned_samples: usize,
+    /// The maximum number of bytes a serialized value can occupy so that a
+    /// [`crate::port::publisher::Publisher`] of a service with a
+    /// [`Serialized`](crate::service::static_config::type_details::Serialized) payload can send
+    /// it.
+    pub max_serialized_size: usize,
     /// The maximum history size a [`crate::port::subscriber::Subscriber`] can request from a
     /// [`crate::port::publisher::Publisher`].
     pub publisher_history_size: usize,
@@ -230,8 +251,84 @@ pub struct Event {
     pub max_listeners: usize,
     /// The maximum amount of supported [`crate::port::notifier::Notifier`]
     pub max_notifiers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
     /// The largest event id supported by the event service
     pub event_id_max_value: usize,
+    /// Defines if the service counts how often every event id was notified, see
+    /// [`crate::port::listener::Listener::try_wait_all_with_count()`]
+    pub enable_counting: bool,
+}
+
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    pub max_servers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum amount of requests a [`crate::port::client::Client`] can have in flight
+    /// towards a single [`crate::port::server::Server`]. Defines also the buffer size of the
+    /// response channel.
+    pub max_active_requests: usize,
+    /// The maximum amount of [`crate::active_request::ActiveRequest`]s a
+    /// [`crate::port::server::Server`] can hold in parallel per connected
+    /// [`crate::port::client::Client`].
+    pub server_max_borrowed_requests: usize,
+    /// The maximum amount of [`crate::response::Response`]s a [`crate::port::client::Client`]
+    /// can hold in parallel per connected [`crate::port::server::Server`].
+    pub client_max_borrowed_responses: usize,
+    /// The maximum amount of [`crate::request_mut::RequestMut`]s a
+    /// [`crate::port::client::Client`] can loan in parallel.
+    pub client_max_loaned_requests: usize,
+    /// The maximum amount of [`crate::response_mut::ResponseMut`]s a
+    /// [`crate::port::server::Server`] can loan in parallel.
+    pub server_max_loaned_responses: usize,
+}
+
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+}
+
+/// Default settings for the pipeline messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pipeline {
+    /// The maximum amount of supported [`crate::port::producer::Producer`]
+    pub max_producers: usize,
+    /// The maximum amount of supported [`crate::port::consumer::Consumer`]
+    pub max_consumers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum amount of work items a [`crate::port::consumer::Consumer`] can buffer per
+    /// connected [`crate::port::producer::Producer`]
+    pub consumer_max_buffer_size: usize,
+    /// The maximum amount of [`crate::work_item::WorkItem`]s a
+    /// [`crate::port::consumer::Consumer`] can hold in parallel per connected
+    /// [`crate::port::producer::Producer`].
+    pub consumer_max_borrowed_items: usize,
+    /// The maximum amount of [`crate::work_item_mut::WorkItemMut`]s a
+    /// [`crate::port::producer::Producer`] can loan in parallel.
+    pub producer_max_loaned_items: usize,
+    /// Defines how the [`crate::port::producer::Producer`] selects the
+    /// [`crate::port::consumer::Consumer`] that receives the next work item.
+    pub distribution_strategy: DistributionStrategy,
 }
 
 /// Represents the configuration that Iceoryx2 will utilize. It is divided into two sections:
@@ -263,23 +360,51 @@ impl Default for Config {
                     dynamic_config_storage_suffix: ".dynamic".to_string(),
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: ".connection".to_string(),
+                    node_monitor_suffix: ".node_monitor".to_string(),
                 },
             },
             defaults: Defaults {
                 publish_subscribe: PublishSubscribe {
                     max_subscribers: 8,
                     max_publishers: 2,
+                    max_nodes: 20,
                     publisher_history_size: 1,
                     subscriber_max_buffer_size: 2,
                     subscriber_max_borrowed_samples: 2,
                     publisher_max_loaned_samples: 2,
+                    max_serialized_size: 4096,
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                 },
                 event: Event {
                     max_listeners: 1,
                     max_notifiers: 16,
+                    max_nodes: 20,
                     event_id_max_value: 32,
+                    enable_counting: false,
+                },
+                request_response: RequestResponse {
+                    max_clients: 8,
+                    max_servers: 2,
+                    max_nodes: 20,
+                    max_active_requests: 4,
+                    server_max_borrowed_requests: 2,
+                    client_max_borrowed_responses: 2,
+                    client_max_loaned_requests: 2,
+                    server_max_loaned_responses: 2,
+                },
+                blackboard: Blackboard {
+                    max_readers: 8,
+                    max_nodes: 20,
+                },
+                pipeline: Pipeline {
+                    max_producers: 2,
+                    max_consumers: 8,
+                    max_nodes: 20,
+                    consumer_max_buffer_size: 4,
+                    consumer_max_borrowed_items: 2,
+                    producer_max_loaned_items: 2,
+                    distribution_strategy: DistributionStrategy::RoundRobin,
                 },
             },
         }
@@ -0,0 +1,506 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The built-in introspection services of iceoryx2. Their names start with
+//! [`SERVICE_NAME_PREFIX`] and they are created like any other
+//! [`Service`](crate::service::Service).
+//!
+//! The [`IntrospectionPublisher`] collects a [`Snapshot`] of every
+//! [`Service`](crate::service::Service) and publishes it on the service with the name
+//! [`services_service_name()`]. A [`Snapshot`] contains the ports of a
+//! [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! based service, which [`Publisher`](crate::port::publisher::Publisher) is connected to
+//! which [`Subscriber`](crate::port::subscriber::Subscriber), how many samples are buffered
+//! in every connection and how many samples every
+//! [`Publisher`](crate::port::publisher::Publisher) has loaned.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::introspection::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! // the monitoring side, for instance a dashboard
+//! let introspection_service = node
+//!     .service_builder(&services_service_name())
+//!     .publish_subscribe()
+//!     .serialized::<Snapshot, SnapshotSerializer>()
+//!     .open_or_create()?;
+//! let subscriber = introspection_service.subscriber().create()?;
+//!
+//! // the publishing side, usually a process that is running the whole time
+//! let introspection = IntrospectionPublisherBuilder::new(&node).create()?;
+//! introspection.publish()?;
+//!
+//! if let Some(snapshot) = subscriber.receive()? {
+//!     for service in snapshot.services() {
+//!         println!("service: {}", service.service_name());
+//!         for connection in service.connections() {
+//!             println!("  {:?} -> {:?}, buffered samples: {}",
+//!                 connection.publisher_id(), connection.subscriber_id(),
+//!                 connection.number_of_buffered_samples());
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::Ordering;
+
+use crate::config::Config;
+use crate::node::{Node, NodeId};
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::publisher::{Publisher, PublisherSendSerializedError};
+use crate::service::config_scheme::{connection_config, dynamic_config_storage_config};
+use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::dynamic_config::DynamicConfig;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::naming_scheme::{connection_name, dynamic_config_storage_name};
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::type_details::Serialized;
+use crate::service::static_config::StaticConfig;
+use crate::service::{Service, ServiceListError};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
+use serde::{Deserialize, Serialize};
+
+/// Every built-in introspection service name starts with this prefix.
+pub const SERVICE_NAME_PREFIX: &str = "iox2://introspection/";
+
+/// The default maximum size of a serialized [`Snapshot`] of the [`IntrospectionPublisher`].
+pub const DEFAULT_MAX_SNAPSHOT_SIZE: usize = 65536;
+
+/// The serializer that is used for the [`Snapshot`]s of the introspection services.
+pub type SnapshotSerializer = iceoryx2_cal::serialize::cdr::Cdr;
+
+/// Returns the name of the introspection service on which the [`IntrospectionPublisher`]
+/// publishes the [`Snapshot`] of all services.
+pub fn services_service_name() -> ServiceName {
+    let name = format!("{}services", SERVICE_NAME_PREFIX);
+    fatal_panic!(from "introspection::services_service_name()",
+        when ServiceName::new(&name),
+        "This should never happen! The introspection service name \"{}\" is invalid.", name)
+}
+
+/// Failures that can occur when a [`Snapshot`] is collected with [`Snapshot::collect()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Failures that can occur when an [`IntrospectionPublisher`] is created with
+/// [`IntrospectionPublisherBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntrospectionPublisherCreateError {
+    /// The introspection service could not be opened or created.
+    UnableToOpenOrCreateService,
+    /// The [`Publisher`] of the introspection service could not be created.
+    UnableToCreatePublisher,
+}
+
+impl std::fmt::Display for IntrospectionPublisherCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for IntrospectionPublisherCreateError {}
+
+enum_gen! {
+    /// Failures that can occur when a [`Snapshot`] is published with
+    /// [`IntrospectionPublisher::publish()`].
+    IntrospectionPublishError
+  mapping:
+    SnapshotError to SnapshotFailure,
+    PublisherSendSerializedError to SendFailure
+}
+
+impl std::fmt::Display for IntrospectionPublishError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for IntrospectionPublishError {}
+
+/// The state of a [`Publisher`] at the time the [`Snapshot`] was collected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublisherSnapshot {
+    id: UniquePublisherId,
+    node_id: NodeId,
+    number_of_loaned_samples: usize,
+}
+
+impl PublisherSnapshot {
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`] that owns the [`Publisher`]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the number of samples the [`Publisher`] has currently loaned
+    pub fn number_of_loaned_samples(&self) -> usize {
+        self.number_of_loaned_samples
+    }
+}
+
+/// The state of a [`Subscriber`](crate::port::subscriber::Subscriber) at the time the
+/// [`Snapshot`] was collected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriberSnapshot {
+    id: UniqueSubscriberId,
+    node_id: NodeId,
+    buffer_size: usize,
+}
+
+impl SubscriberSnapshot {
+    /// Returns the [`UniqueSubscriberId`] of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`] that owns the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the size of the buffer of every connection of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+}
+
+/// The state of the connection between a [`Publisher`] and a
+/// [`Subscriber`](crate::port::subscriber::Subscriber) at the time the [`Snapshot`] was
+/// collected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionSnapshot {
+    publisher_id: UniquePublisherId,
+    subscriber_id: UniqueSubscriberId,
+    number_of_buffered_samples: usize,
+}
+
+impl ConnectionSnapshot {
+    /// Returns the [`UniquePublisherId`] of the sending side of the connection
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the receiving side of the connection
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the number of samples that were sent but not yet received by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn number_of_buffered_samples(&self) -> usize {
+        self.number_of_buffered_samples
+    }
+}
+
+/// The state of a single [`Service`] at the time the [`Snapshot`] was collected. The ports
+/// and connections are only collected for [`MessagingPattern::PublishSubscribe`] based
+/// services, for all other messaging patterns they are empty. The number of ports is
+/// collected for every messaging pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceSnapshot {
+    service_name: ServiceName,
+    uuid: String,
+    messaging_pattern: String,
+    number_of_ports: Vec<(String, usize)>,
+    publishers: Vec<PublisherSnapshot>,
+    subscribers: Vec<SubscriberSnapshot>,
+    connections: Vec<ConnectionSnapshot>,
+}
+
+impl ServiceSnapshot {
+    /// Returns the [`ServiceName`] of the [`Service`]
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the uuid of the [`Service`]
+    pub fn uuid(&self) -> &str {
+        &self.uuid
+    }
+
+    /// Returns the name of the [`MessagingPattern`] of the [`Service`], for instance
+    /// `"PublishSubscribe"`. The full [`StaticConfig`] is acquired with
+    /// [`Service::list()`](crate::service::Service::list()).
+    pub fn messaging_pattern(&self) -> &str {
+        &self.messaging_pattern
+    }
+
+    /// Returns the number of ports of every port type of the [`Service`], for instance
+    /// `("publishers", 2)`.
+    pub fn number_of_ports(&self) -> &[(String, usize)] {
+        &self.number_of_ports
+    }
+
+    /// Returns the [`PublisherSnapshot`]s of all [`Publisher`]s of the [`Service`]
+    pub fn publishers(&self) -> &[PublisherSnapshot] {
+        &self.publishers
+    }
+
+    /// Returns the [`SubscriberSnapshot`]s of all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s of the [`Service`]
+    pub fn subscribers(&self) -> &[SubscriberSnapshot] {
+        &self.subscribers
+    }
+
+    /// Returns the [`ConnectionSnapshot`]s of all established connections of the [`Service`]
+    pub fn connections(&self) -> &[ConnectionSnapshot] {
+        &self.connections
+    }
+}
+
+/// The state of all [`Service`]s at the time the [`Snapshot`] was collected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    services: Vec<ServiceSnapshot>,
+}
+
+impl Snapshot {
+    /// Collects the [`Snapshot`] of all [`Service`]s that use the provided [`Config`].
+    /// Services that are removed or not yet fully created while the [`Snapshot`] is
+    /// collected are not contained.
+    pub fn collect<S: Service>(config: &Config) -> Result<Self, SnapshotError> {
+        let msg = "Unable to collect the introspection snapshot";
+        let static_configs = fail!(from "Snapshot::collect()",
+            when S::list_with_custom_config(config),
+            map ServiceListError::InsufficientPermissions => SnapshotError::InsufficientPermissions,
+            unmatched SnapshotError::InternalError,
+            "{} since the services could not be listed.", msg);
+
+        let mut services = Vec::with_capacity(static_configs.len());
+        for static_config in &static_configs {
+            if let Some(service) = Self::collect_service::<S>(config, static_config) {
+                services.push(service);
+            }
+        }
+
+        Ok(Self { services })
+    }
+
+    /// Returns the [`ServiceSnapshot`]s of all [`Service`]s
+    pub fn services(&self) -> &[ServiceSnapshot] {
+        &self.services
+    }
+
+    fn collect_service<S: Service>(
+        config: &Config,
+        static_config: &StaticConfig,
+    ) -> Option<ServiceSnapshot> {
+        let dynamic_storage = <<S::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder<
+            '_,
+        > as NamedConceptBuilder<S::DynamicStorage>>::new(
+            &dynamic_config_storage_name(static_config),
+        )
+        .config(&dynamic_config_storage_config::<S>(config))
+        .has_ownership(false)
+        .open()
+        .ok()?;
+
+        let dynamic_config = dynamic_storage.get();
+        let number_of_ports = match static_config.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(_) => vec![
+                (
+                    "publishers",
+                    dynamic_config.publish_subscribe().number_of_publishers(),
+                ),
+                (
+                    "subscribers",
+                    dynamic_config.publish_subscribe().number_of_subscribers(),
+                ),
+            ],
+            MessagingPattern::Event(_) => vec![
+                ("notifiers", dynamic_config.event().number_of_notifiers()),
+                ("listeners", dynamic_config.event().number_of_listeners()),
+            ],
+            MessagingPattern::RequestResponse(_) => vec![
+                (
+                    "clients",
+                    dynamic_config.request_response().number_of_clients(),
+                ),
+                (
+                    "servers",
+                    dynamic_config.request_response().number_of_servers(),
+                ),
+            ],
+            MessagingPattern::Blackboard(_) => vec![
+                ("writers", dynamic_config.blackboard().number_of_writers()),
+                ("readers", dynamic_config.blackboard().number_of_readers()),
+            ],
+            MessagingPattern::Pipeline(_) => vec![
+                ("producers", dynamic_config.pipeline().number_of_producers()),
+                ("consumers", dynamic_config.pipeline().number_of_consumers()),
+            ],
+        };
+
+        let mut service = ServiceSnapshot {
+            service_name: *static_config.service_name(),
+            uuid: static_config.uuid().to_string(),
+            messaging_pattern: static_config.messaging_pattern().to_string(),
+            number_of_ports: number_of_ports
+                .into_iter()
+                .map(|(port_type, number)| (port_type.to_string(), number))
+                .collect(),
+            publishers: vec![],
+            subscribers: vec![],
+            connections: vec![],
+        };
+
+        if let MessagingPattern::PublishSubscribe(_) = static_config.messaging_pattern() {
+            let dynamic_config = dynamic_config.publish_subscribe();
+
+            unsafe { dynamic_config.publishers.get_state() }.for_each(
+                |index, details: &PublisherDetails| {
+                    service.publishers.push(PublisherSnapshot {
+                        id: details.publisher_id,
+                        node_id: details.node_id,
+                        number_of_loaned_samples: dynamic_config
+                            .publisher_loan_counter(index)
+                            .load(Ordering::Relaxed),
+                    })
+                },
+            );
+
+            unsafe { dynamic_config.subscribers.get_state() }.for_each(
+                |_, details: &SubscriberDetails| {
+                    service.subscribers.push(SubscriberSnapshot {
+                        id: details.port_id,
+                        node_id: details.node_id,
+                        buffer_size: details.buffer_size,
+                    })
+                },
+            );
+
+            let connection_config = connection_config::<S>(config);
+            for publisher in &service.publishers {
+                for subscriber in &service.subscribers {
+                    if let Ok(number_of_buffered_samples) =
+                        <S::Connection as ZeroCopyConnection>::number_of_buffered_samples(
+                            &connection_name(publisher.id, subscriber.id),
+                            &connection_config,
+                        )
+                    {
+                        service.connections.push(ConnectionSnapshot {
+                            publisher_id: publisher.id,
+                            subscriber_id: subscriber.id,
+                            number_of_buffered_samples,
+                        });
+                    }
+                }
+            }
+        }
+
+        Some(service)
+    }
+}
+
+/// Creates an [`IntrospectionPublisher`].
+#[derive(Debug)]
+pub struct IntrospectionPublisherBuilder<'a, S: Service> {
+    node: &'a Node<S>,
+    max_snapshot_size: usize,
+}
+
+impl<'a, S: Service> IntrospectionPublisherBuilder<'a, S> {
+    /// Creates a new builder for an [`IntrospectionPublisher`] that uses the provided [`Node`].
+    pub fn new(node: &'a Node<S>) -> Self {
+        Self {
+            node,
+            max_snapshot_size: DEFAULT_MAX_SNAPSHOT_SIZE,
+        }
+    }
+
+    /// Defines the maximum size of a serialized [`Snapshot`]. A [`Snapshot`] that exceeds it
+    /// cannot be published.
+    pub fn max_snapshot_size(mut self, value: usize) -> Self {
+        self.max_snapshot_size = value;
+        self
+    }
+
+    /// Opens or creates the introspection service with the name [`services_service_name()`]
+    /// and creates the [`IntrospectionPublisher`].
+    pub fn create(self) -> Result<IntrospectionPublisher<S>, IntrospectionPublisherCreateError> {
+        let msg = "Unable to create the introspection publisher";
+        let service = fail!(from self,
+            when self.node
+                .service_builder(&services_service_name())
+                .publish_subscribe()
+                .serialized::<Snapshot, SnapshotSerializer>()
+                .open_or_create(),
+            with IntrospectionPublisherCreateError::UnableToOpenOrCreateService,
+            "{} since the introspection service could not be opened or created.", msg);
+
+        let publisher = fail!(from self,
+            when service
+                .publisher()
+                .max_serialized_size(self.max_snapshot_size)
+                .create(),
+            with IntrospectionPublisherCreateError::UnableToCreatePublisher,
+            "{} since the publisher of the introspection service could not be created.", msg);
+
+        Ok(IntrospectionPublisher {
+            config: self.node.config().clone(),
+            publisher,
+            _service: service,
+        })
+    }
+}
+
+/// Publishes the [`Snapshot`] of all [`Service`]s on the introspection service with the name
+/// [`services_service_name()`].
+#[derive(Debug)]
+pub struct IntrospectionPublisher<S: Service> {
+    config: Config,
+    publisher: Publisher<S, Serialized<Snapshot, SnapshotSerializer>>,
+    _service: PortFactory<S, Serialized<Snapshot, SnapshotSerializer>>,
+}
+
+impl<S: Service> IntrospectionPublisher<S> {
+    /// Collects the current [`Snapshot`] of all [`Service`]s and publishes it. Returns the
+    /// number of [`Subscriber`](crate::port::subscriber::Subscriber)s that received it.
+    pub fn publish(&self) -> Result<usize, IntrospectionPublishError> {
+        let msg = "Unable to publish the introspection snapshot";
+        let snapshot = fail!(from self, when Snapshot::collect::<S>(&self.config),
+            "{} since the snapshot could not be collected.", msg);
+
+        Ok(fail!(from self, when self.publisher.send(&snapshot),
+            "{} since the snapshot could not be sent.", msg))
+    }
+}
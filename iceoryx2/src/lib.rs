@@ -20,9 +20,9 @@
 //!
 //! - Publish-Subscribe
 //! - Events
-//! - Request-Response (planned)
-//! - Pipeline (planned)
-//! - Blackboard (planned)
+//! - Request-Response
+//! - Pipeline
+//! - Blackboard
 //!
 //! For a comprehensive list of all planned features, please refer to the
 //! [GitHub Roadmap](https://github.com/eclipse-iceoryx/iceoryx2/blob/main/ROADMAP.md).
@@ -66,7 +66,9 @@
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
 //! // create our port factory by creating or opening the service
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -93,7 +95,9 @@
 //! let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
 //!
 //! // create our port factory by creating or opening the service
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -126,7 +130,9 @@
 //! const CYCLE_TIME: Duration = Duration::from_secs(1);
 //! let event_name = ServiceName::new("MyEventName")?;
 //!
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -152,7 +158,9 @@
 //! const CYCLE_TIME: Duration = Duration::from_secs(1);
 //! let event_name = ServiceName::new("MyEventName")?;
 //!
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -187,7 +195,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("PubSubQos")?;
 //!
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .enable_safe_overflow(true)
 //!     // how many samples a subscriber can borrow in parallel
@@ -215,7 +225,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("EventQos")?;
 //!
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     // the maximum amount of notifiers of this service
 //!     .max_notifiers(2)
@@ -230,6 +242,35 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("ReqResQos")?;
+//!
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .request_response()
+//!     // the maximum amount of requests a client can have in flight per server
+//!     .max_active_requests(4)
+//!     // how many requests a server can borrow in parallel
+//!     .server_max_borrowed_requests(2)
+//!     // how many responses a client can borrow in parallel
+//!     .client_max_borrowed_responses(2)
+//!     // the maximum amount of clients of this service
+//!     .max_clients(5)
+//!     // the maximum amount of servers of this service
+//!     .max_servers(2)
+//!     .typed::<u64, u64>()
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Port Behavior
 //!
 //! Certain ports in iceoryx2 provide users with the flexibility to define custom behaviors in
@@ -245,7 +286,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .enable_safe_overflow(false)
 //!     .typed::<u64>()
@@ -280,26 +323,52 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// The request that is received by a [`Server`](crate::port::server::Server). Responses are
+/// loaned and sent with it.
+pub mod active_request;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// The built-in introspection services that publish the state of all services
+pub mod introspection;
+
 /// Central instance that handles all incoming events, the event loop
 pub mod iox2;
 
 #[doc(hidden)]
 pub mod message;
 
+/// The central entry point of iceoryx2. Every [`Service`](crate::service::Service) is created
+/// via the [`Node`](crate::node::Node).
+pub mod node;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// The payload that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut;
+
+/// The answer that is received by a [`Client`](crate::port::client::Client).
+pub mod response;
+
+/// The answer that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
 /// The payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut;
 
+/// The payload that is received by a [`Consumer`](crate::port::consumer::Consumer).
+pub mod work_item;
+
+/// The payload that is sent by a [`Producer`](crate::port::producer::Producer).
+pub mod work_item_mut;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
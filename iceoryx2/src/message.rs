@@ -14,16 +14,16 @@ use core::fmt;
 
 #[doc(hidden)]
 #[repr(C)]
-pub struct Message<Header, Data> {
+pub struct Message<Header, Data: ?Sized> {
     pub(crate) header: Header,
     pub(crate) data: Data,
 }
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for Message<Header, Data> {
+impl<Header: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug for Message<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Message<Header, Data>")
             .field("header", &self.header)
-            .field("data", &self.data)
+            .field("data", &&self.data)
             .finish()
     }
 }
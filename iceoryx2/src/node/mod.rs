@@ -0,0 +1,621 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Node`] is the central entry point of every iceoryx2 application. It is created first
+//! and all [`Service`]s and their ports are created via the [`Node`]. Every [`Node`] owns a
+//! monitoring token so that other processes can detect when the [`Node`] died and can remove all
+//! of its stale resources.
+//!
+//! # Example
+//!
+//! ## Create A Node
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Cleanup Stale Resources Of Dead Nodes
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::NodeState;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! for node in Node::<zero_copy::Service>::list()? {
+//!     match node {
+//!         NodeState::Alive(view) => println!("node {:?} is alive", view.id()),
+//!         NodeState::Dead(view) => {
+//!             println!("node {:?} is dead, cleaning up", view.id());
+//!             view.remove_stale_resources()?;
+//!         }
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::config::Config;
+use crate::port::port_identifiers::UniquePortId;
+use crate::service::builder::Builder;
+use crate::service::config_scheme::{
+    connection_config, data_segment_config, dynamic_config_storage_config, node_monitoring_config,
+    static_config_storage_config,
+};
+use crate::service::dynamic_config::{DecrementReferenceCounterResult, DynamicConfig};
+use crate::service::naming_scheme::{
+    client_data_segment_name, connection_sender_and_receiver, data_segment_name,
+    dynamic_config_storage_name, event_concept_name, node_monitor_name, producer_data_segment_name,
+    server_data_segment_name, static_config_storage_name,
+};
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
+use iceoryx2_cal::monitoring::*;
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptMgmt};
+use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
+use serde::{Deserialize, Serialize};
+
+/// Contains the [`NodeId`]s of all [`Node`]s that were created in the current process. Since
+/// the [`MonitoringMonitor`] of some [`Monitoring`] concepts cannot detect the
+/// [`MonitoringToken`] of its own process, the [`Node`]s of the current process are tracked here.
+static NODES_OF_CURRENT_PROCESS: Mutex<Vec<NodeId>> = Mutex::new(Vec::new());
+
+/// The system-wide unique id of a [`Node`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NodeId(UniqueSystemId);
+
+impl NodeId {
+    /// Returns the underlying value of the [`NodeId`]
+    pub fn value(&self) -> u128 {
+        self.0.value()
+    }
+
+    /// Returns the [`ProcessId`] of the process that created the [`Node`]
+    pub fn pid(&self) -> ProcessId {
+        self.0.pid()
+    }
+
+    /// Returns the time when the [`Node`] was created
+    pub fn creation_time(&self) -> Time {
+        self.0.creation_time()
+    }
+}
+
+/// Failures that can occur when a [`Node`] is created with [`NodeBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeCreationFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeCreationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeCreationFailure {}
+
+/// Failures that can occur when all [`Node`]s are listed with [`Node::list()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeListFailure {
+    InsufficientPermissions,
+    Interrupt,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeListFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeListFailure {}
+
+/// Failures that can occur when the stale resources of a dead [`Node`] are removed with
+/// [`DeadNodeView::remove_stale_resources()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeCleanupFailure {
+    InsufficientPermissions,
+    Interrupt,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeCleanupFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeCleanupFailure {}
+
+/// The state of a [`Node`] acquired with [`Node::list()`].
+#[derive(Debug)]
+pub enum NodeState<S: Service> {
+    /// The [`Node`] is alive and its process is running.
+    Alive(AliveNodeView<S>),
+    /// The process of the [`Node`] died without cleaning up its resources.
+    Dead(DeadNodeView<S>),
+}
+
+impl<S: Service> NodeState<S> {
+    /// Acquires the [`NodeState`] of the [`Node`] with the provided [`NodeId`]. Returns [`None`]
+    /// when the [`Node`] does not exist or is currently created or cleaned up.
+    pub(crate) fn acquire(
+        id: NodeId,
+        config: &Arc<Config>,
+    ) -> Result<Option<Self>, NodeListFailure> {
+        let msg = "Unable to acquire the node state";
+        let origin = "NodeState::acquire()";
+
+        // Nodes of the current process are never monitored since a monitor could
+        // release the underlying monitoring token of this process.
+        if id.pid() == Process::from_self().id() && is_node_of_current_process(&id) {
+            return Ok(Some(NodeState::Alive(AliveNodeView {
+                id,
+                _service: PhantomData,
+            })));
+        }
+
+        let monitor = match <S::Monitoring as Monitoring>::Builder::new(&node_monitor_name(&id))
+            .config(&node_monitoring_config::<S>(config))
+            .monitor()
+        {
+            Ok(monitor) => monitor,
+            Err(MonitoringCreateMonitorError::InsufficientPermissions) => {
+                fail!(from origin, with NodeListFailure::InsufficientPermissions,
+                    "{} due to insufficient permissions to monitor the node {:?}.", msg, id);
+            }
+            Err(MonitoringCreateMonitorError::Interrupt) => {
+                fail!(from origin, with NodeListFailure::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with NodeListFailure::InternalError,
+                    "{} due to an internal failure ({:?}) while monitoring the node {:?}.", msg, e, id);
+            }
+        };
+
+        match monitor.state() {
+            Ok(State::Alive) => Ok(Some(NodeState::Alive(AliveNodeView {
+                id,
+                _service: PhantomData,
+            }))),
+            Ok(State::Dead) => Ok(Some(NodeState::Dead(DeadNodeView {
+                id,
+                config: config.clone(),
+                _service: PhantomData,
+            }))),
+            // the node is either being created or cleaned up
+            Ok(State::DoesNotExist) => Ok(None),
+            Err(MonitoringStateError::Interrupt) => {
+                fail!(from origin, with NodeListFailure::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with NodeListFailure::InternalError,
+                    "{} due to an internal failure ({:?}) while acquiring the state of the node {:?}.", msg, e, id);
+            }
+        }
+    }
+}
+
+/// Represents a [`Node`] that is still alive.
+#[derive(Debug)]
+pub struct AliveNodeView<S: Service> {
+    id: NodeId,
+    _service: PhantomData<S>,
+}
+
+impl<S: Service> AliveNodeView<S> {
+    /// Returns the [`NodeId`] of the [`Node`].
+    pub fn id(&self) -> &NodeId {
+        &self.id
+    }
+}
+
+/// Represents a [`Node`] whose process died. Its stale resources can be removed with
+/// [`DeadNodeView::remove_stale_resources()`].
+#[derive(Debug)]
+pub struct DeadNodeView<S: Service> {
+    id: NodeId,
+    config: Arc<Config>,
+    _service: PhantomData<S>,
+}
+
+impl<S: Service> DeadNodeView<S> {
+    /// Returns the [`NodeId`] of the [`Node`].
+    pub fn id(&self) -> &NodeId {
+        &self.id
+    }
+
+    /// Removes all ports of the dead [`Node`] from every [`Service`], deletes their underlying
+    /// resources like connections and data segments and removes every [`Service`] that is no
+    /// longer used by any other [`Node`]. Returns `true` when the resources were removed and
+    /// `false` when another process is already cleaning them up or when the [`Node`] is no
+    /// longer dead.
+    pub fn remove_stale_resources(self) -> Result<bool, NodeCleanupFailure> {
+        let msg = "Unable to remove stale resources";
+        let monitor_name = node_monitor_name(&self.id);
+        let cleaner = match <S::Monitoring as Monitoring>::Builder::new(&monitor_name)
+            .config(&node_monitoring_config::<S>(&self.config))
+            .cleaner()
+        {
+            Ok(cleaner) => cleaner,
+            Err(MonitoringCreateCleanerError::AlreadyOwnedByAnotherInstance)
+            | Err(MonitoringCreateCleanerError::DoesNotExist)
+            | Err(MonitoringCreateCleanerError::InstanceStillAlive) => return Ok(false),
+            Err(MonitoringCreateCleanerError::Interrupt) => {
+                fail!(from self, with NodeCleanupFailure::Interrupt,
+                    "{} since an interrupt signal was received while acquiring the cleaner.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NodeCleanupFailure::InternalError,
+                    "{} due to an internal failure ({:?}) while acquiring the cleaner.", msg, e);
+            }
+        };
+
+        let services = match S::list_with_custom_config(&self.config) {
+            Ok(services) => services,
+            Err(crate::service::ServiceListError::InsufficientPermissions) => {
+                fail!(from self, with NodeCleanupFailure::InsufficientPermissions,
+                    "{} due to insufficient permissions to list all services.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NodeCleanupFailure::InternalError,
+                    "{} due to an internal failure ({:?}) while listing all services.", msg, e);
+            }
+        };
+
+        for service in services {
+            let dynamic_storage = match <<S::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder<'_> as NamedConceptBuilder<S::DynamicStorage>>::new(
+                    &dynamic_config_storage_name(&service),
+                )
+                .config(&dynamic_config_storage_config::<S>(&self.config))
+                .has_ownership(false)
+                .open()
+            {
+                Ok(dynamic_storage) => dynamic_storage,
+                Err(e) => {
+                    warn!(from self, "Unable to open the dynamic config of the service {:?} ({:?}). Stale resources of the node may remain.",
+                        service.service_name(), e);
+                    continue;
+                }
+            };
+
+            let result = unsafe {
+                dynamic_storage
+                    .get()
+                    .remove_dead_node_id(&self.id, |port_id| self.remove_port_resources(port_id))
+            };
+
+            if result == DecrementReferenceCounterResult::NoMoreOwners {
+                if let Err(e) = unsafe {
+                    <S::StaticStorage as NamedConceptMgmt>::remove_cfg(
+                        &static_config_storage_name(service.uuid()),
+                        &static_config_storage_config::<S>(&self.config),
+                    )
+                } {
+                    warn!(from self, "Unable to remove the static config of the service {:?} ({:?}).",
+                        service.service_name(), e);
+                }
+                dynamic_storage.acquire_ownership();
+                trace!(from self, "remove service {:?}", service.service_name());
+            }
+        }
+
+        drop(cleaner);
+        trace!(from self, "removed all stale resources");
+
+        Ok(true)
+    }
+
+    fn remove_port_resources(&self, port_id: UniquePortId) {
+        let port_value = match port_id {
+            UniquePortId::Publisher(ref id) => id.0.value(),
+            UniquePortId::Subscriber(ref id) => id.0.value(),
+            UniquePortId::Notifier(ref id) => id.0.value(),
+            UniquePortId::Listener(ref id) => id.0.value(),
+            UniquePortId::Client(ref id) => id.0.value(),
+            UniquePortId::Server(ref id) => id.0.value(),
+            UniquePortId::Reader(ref id) => id.0.value(),
+            UniquePortId::Writer(ref id) => id.0.value(),
+            UniquePortId::Producer(ref id) => id.0.value(),
+            UniquePortId::Consumer(ref id) => id.0.value(),
+        };
+
+        let connection_config = connection_config::<S>(&self.config);
+        match <S::Connection as NamedConceptMgmt>::list_cfg(&connection_config) {
+            Ok(connections) => {
+                for connection in connections {
+                    let (sender, receiver) = match connection_sender_and_receiver(&connection) {
+                        Some(v) => v,
+                        None => continue,
+                    };
+
+                    if sender == port_value {
+                        if let Err(e) =
+                            unsafe { S::Connection::remove_sender(&connection, &connection_config) }
+                        {
+                            warn!(from self, "Unable to remove the sender of the connection {} ({:?}).", connection, e);
+                        }
+                    }
+
+                    if receiver == port_value {
+                        if let Err(e) = unsafe {
+                            S::Connection::remove_receiver(&connection, &connection_config)
+                        } {
+                            warn!(from self, "Unable to remove the receiver of the connection {} ({:?}).", connection, e);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(from self, "Unable to list all connections ({:?}). The connections of the port {:?} may remain.", e, port_id);
+            }
+        }
+
+        let data_segment = match port_id {
+            UniquePortId::Publisher(id) => Some(data_segment_name(id)),
+            UniquePortId::Client(id) => Some(client_data_segment_name(id)),
+            UniquePortId::Server(id) => Some(server_data_segment_name(id)),
+            UniquePortId::Producer(id) => Some(producer_data_segment_name(id)),
+            _ => None,
+        };
+
+        if let Some(data_segment) = data_segment {
+            if let Err(e) = unsafe {
+                <S::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                    &data_segment,
+                    &data_segment_config::<S>(&self.config),
+                )
+            } {
+                warn!(from self, "Unable to remove the data segment of the port {:?} ({:?}).", port_id, e);
+            }
+        }
+
+        if let UniquePortId::Listener(ref id) = port_id {
+            if let Err(e) =
+                unsafe { <S::Event as NamedConceptMgmt>::remove(&event_concept_name(id)) }
+            {
+                warn!(from self, "Unable to remove the event concept of the port {:?} ({:?}).", port_id, e);
+            }
+        }
+    }
+}
+
+pub(crate) struct SharedNode<S: Service> {
+    id: NodeId,
+    config: Config,
+    monitoring_token: Option<<S::Monitoring as Monitoring>::Token>,
+}
+
+impl<S: Service> std::fmt::Debug for SharedNode<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SharedNode<{}> {{ id: {:?}, config: {:?} }}",
+            std::any::type_name::<S>(),
+            self.id,
+            self.config
+        )
+    }
+}
+
+impl<S: Service> SharedNode<S> {
+    pub(crate) fn id(&self) -> &NodeId {
+        &self.id
+    }
+
+    pub(crate) fn config(&self) -> &Config {
+        &self.config
+    }
+}
+
+impl<S: Service> Drop for SharedNode<S> {
+    fn drop(&mut self) {
+        // the token must be removed first, otherwise other nodes of this process could
+        // consider this node as a dead node of a previous process with the same process id
+        self.monitoring_token.take();
+        deregister_node_of_current_process(&self.id);
+        trace!(from self, "remove node");
+    }
+}
+
+fn register_node_of_current_process(id: &NodeId) {
+    let mut guard = fatal_panic!(from "register_node_of_current_process()",
+        when NODES_OF_CURRENT_PROCESS.lock(),
+        "This should never happen! Unable to acquire the lock of the process local node registry.");
+    guard.push(*id);
+}
+
+fn deregister_node_of_current_process(id: &NodeId) {
+    let mut guard = fatal_panic!(from "deregister_node_of_current_process()",
+        when NODES_OF_CURRENT_PROCESS.lock(),
+        "This should never happen! Unable to acquire the lock of the process local node registry.");
+    guard.retain(|v| v != id);
+}
+
+fn is_node_of_current_process(id: &NodeId) -> bool {
+    let guard = fatal_panic!(from "is_node_of_current_process()",
+        when NODES_OF_CURRENT_PROCESS.lock(),
+        "This should never happen! Unable to acquire the lock of the process local node registry.");
+    guard.contains(id)
+}
+
+/// The central entry point of iceoryx2. Every [`Service`] is created via the [`Node`] and as
+/// long as the [`Node`] or one of its [`Service`]s or ports is in scope, it is registered in the
+/// system and can be monitored by other processes.
+#[derive(Debug)]
+pub struct Node<S: Service> {
+    shared: Arc<SharedNode<S>>,
+}
+
+impl<S: Service> Node<S> {
+    /// Returns the system-wide unique id of the [`Node`].
+    pub fn id(&self) -> &NodeId {
+        self.shared.id()
+    }
+
+    /// Returns the [`Config`] the [`Node`] and all of its [`Service`]s are using.
+    pub fn config(&self) -> &Config {
+        self.shared.config()
+    }
+
+    /// Returns the [`Builder`] to create or open a [`Service`] with the given [`ServiceName`].
+    pub fn service_builder(&self, name: &ServiceName) -> Builder<S> {
+        Builder::new(name, self.shared.clone())
+    }
+
+    /// Returns a list of all [`Node`]s in the system and their [`NodeState`].
+    pub fn list() -> Result<Vec<NodeState<S>>, NodeListFailure> {
+        Self::list_with_custom_config(Config::get_global_config())
+    }
+
+    /// Returns a list of all [`Node`]s that were created with the given [`Config`] and their
+    /// [`NodeState`].
+    pub fn list_with_custom_config(config: &Config) -> Result<Vec<NodeState<S>>, NodeListFailure> {
+        let msg = "Unable to list all nodes";
+        let origin = "Node::list_with_custom_config()";
+        let monitoring_config = node_monitoring_config::<S>(config);
+
+        let node_names = fail!(from origin,
+                when <S::Monitoring as NamedConceptMgmt>::list_cfg(&monitoring_config),
+                map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+                unmatched NodeListFailure::InternalError,
+                "{} since the monitoring tokens could not be listed.", msg);
+
+        let shared_config = Arc::new(config.clone());
+        let mut nodes = vec![];
+        for node_name in node_names {
+            let id = match core::str::from_utf8(node_name.as_bytes())
+                .ok()
+                .and_then(|v| v.parse::<u128>().ok())
+            {
+                Some(value) => NodeId(UniqueSystemId::from(value)),
+                None => {
+                    warn!(from origin, "Skipping the monitoring token \"{}\" since it does not represent a node.", node_name);
+                    continue;
+                }
+            };
+
+            if let Some(state) = NodeState::acquire(id, &shared_config)? {
+                nodes.push(state);
+            }
+        }
+
+        Ok(nodes)
+    }
+}
+
+/// Creates a new [`Node`].
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::config::Config;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut custom_config = Config::default();
+/// custom_config.global.service.directory = "custom_services".to_string();
+///
+/// let node = NodeBuilder::new()
+///     .config(&custom_config)
+///     .create::<zero_copy::Service>()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct NodeBuilder {
+    config: Option<Config>,
+}
+
+impl NodeBuilder {
+    /// Creates a new [`NodeBuilder`] that uses the global [`Config`] by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a custom [`Config`] that is used by the [`Node`] and all of its [`Service`]s.
+    pub fn config(mut self, config: &Config) -> Self {
+        self.config = Some(config.clone());
+        self
+    }
+
+    /// Creates a new [`Node`] and registers it in the system so that it can be monitored.
+    pub fn create<S: Service>(self) -> Result<Node<S>, NodeCreationFailure> {
+        let msg = "Unable to create node";
+        let config = match self.config {
+            Some(ref config) => config.clone(),
+            None => Config::get_global_config().clone(),
+        };
+
+        let id = NodeId(fail!(from self, when UniqueSystemId::new(),
+                with NodeCreationFailure::InternalError,
+                "{} since the unique node id could not be generated.", msg));
+
+        // register before the token exists, so that a concurrent Node::list() never
+        // monitors the token of this process
+        register_node_of_current_process(&id);
+        let monitoring_token = match <S::Monitoring as Monitoring>::Builder::new(
+            &node_monitor_name(&id),
+        )
+        .config(&node_monitoring_config::<S>(&config))
+        .token()
+        {
+            Ok(token) => token,
+            Err(e) => {
+                deregister_node_of_current_process(&id);
+                match e {
+                    MonitoringCreateTokenError::InsufficientPermissions => {
+                        fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                            "{} due to insufficient permissions to create the monitoring token.", msg);
+                    }
+                    e => {
+                        fail!(from self, with NodeCreationFailure::InternalError,
+                            "{} due to an internal failure ({:?}) while creating the monitoring token.", msg, e);
+                    }
+                }
+            }
+        };
+
+        let shared = Arc::new(SharedNode {
+            id,
+            config,
+            monitoring_token: Some(monitoring_token),
+        });
+        trace!(from shared, "create node");
+
+        Ok(Node { shared })
+    }
+}
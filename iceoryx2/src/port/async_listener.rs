@@ -0,0 +1,237 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`AsyncListener`] integrates a [`Listener`] into the tokio runtime. It registers the
+//! file descriptor of the underlying event concept at the tokio reactor so that a task can
+//! `.await` [`EventId`]s without occupying a thread. Additionally, it implements
+//! [`Stream`] so that it can be used with any stream combinator.
+//!
+//! Requires the `async` feature and is available on unix platforms only.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_socket;
+//! use iceoryx2::port::async_listener::AsyncListener;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event_name = ServiceName::new("MyEventName")?;
+//! let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = AsyncListener::new(event.listener().create()?)?;
+//! let notifier = event.notifier().create()?;
+//!
+//! notifier.notify_with_custom_event_id(EventId::new(12))?;
+//!
+//! let event_id = listener.wait_one().await?;
+//! println!("event was triggered with id: {:?}", event_id);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::os::fd::{AsRawFd, RawFd};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+use tokio::io::unix::{AsyncFd, AsyncFdReadyGuard};
+
+use super::event_id::EventId;
+use super::listener::Listener;
+use super::port_identifiers::UniqueListenerId;
+use crate::service;
+
+/// Defines the failures that can occur when an [`AsyncListener`] is created with
+/// [`AsyncListener::new()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncListenerCreateError {
+    UnableToRegisterAtReactor,
+}
+
+impl std::fmt::Display for AsyncListenerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncListenerCreateError {}
+
+#[derive(Debug)]
+struct ReactorHandle<Service: service::Service>(Listener<Service>)
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased;
+
+impl<Service: service::Service> AsRawFd for ReactorHandle<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn as_raw_fd(&self) -> RawFd {
+        unsafe { self.0.file_descriptor().native_handle() }
+    }
+}
+
+/// Receiving endpoint of an event based communication that waits asynchronously for
+/// [`EventId`]s. It wraps a [`Listener`] and is woken up by the tokio reactor as soon as an
+/// [`EventId`] arrives.
+#[derive(Debug)]
+pub struct AsyncListener<Service: service::Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    handle: AsyncFd<ReactorHandle<Service>>,
+}
+
+impl<Service: service::Service> AsyncListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Registers the [`Listener`] at the reactor of the current tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// When it is called outside of a tokio runtime.
+    pub fn new(listener: Listener<Service>) -> Result<Self, AsyncListenerCreateError> {
+        let origin = format!("AsyncListener::new({:?})", listener.id());
+        // the file descriptor is owned by the listener which is owned by the AsyncFd, therefore
+        // it stays valid until it is deregistered from the reactor
+        let handle = fail!(from origin, when unsafe { AsyncFd::register(ReactorHandle(listener)) },
+                with AsyncListenerCreateError::UnableToRegisterAtReactor,
+                "Unable to create async listener since the listener could not be registered at the tokio reactor.");
+
+        Ok(Self { handle })
+    }
+
+    /// Returns the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.handle.get_ref().0
+    }
+
+    /// Deregisters the [`Listener`] from the reactor and returns it.
+    pub fn into_inner(self) -> Listener<Service> {
+        self.handle.into_inner().0
+    }
+
+    /// Returns the [`UniqueListenerId`] of the underlying [`Listener`]
+    pub fn id(&self) -> UniqueListenerId {
+        self.listener().id()
+    }
+
+    /// Non-blocking wait for a new [`EventId`]. See [`Listener::try_wait_one()`].
+    pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+        self.listener().try_wait_one()
+    }
+
+    /// Non-blocking wait for new [`EventId`]s. See [`Listener::try_wait_all()`].
+    pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.listener().try_wait_all(callback)
+    }
+
+    /// Waits asynchronously until an [`EventId`] was received and returns it.
+    /// On error it returns [`ListenerWaitError`] which describes the error in detail.
+    pub async fn wait_one(&self) -> Result<EventId, ListenerWaitError> {
+        std::future::poll_fn(|cx| self.poll_wait_one(cx)).await
+    }
+
+    /// Waits asynchronously until at least one [`EventId`] was received. Collects either all
+    /// [`EventId`]s that were received until then or a reasonable batch that represent the
+    /// currently available [`EventId`]s in buffer.
+    /// For every received [`EventId`] the provided callback is called with the [`EventId`] as
+    /// input argument.
+    pub async fn wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        std::future::poll_fn(|cx| self.poll_wait_all(cx, &mut callback)).await
+    }
+
+    /// Polls the [`AsyncListener`] for a new [`EventId`]. When no [`EventId`] is available, the
+    /// waker of the provided [`Context`] is registered and woken up as soon as one arrives.
+    pub fn poll_wait_one(&self, cx: &mut Context<'_>) -> Poll<Result<EventId, ListenerWaitError>> {
+        loop {
+            let mut guard = match self.poll_read_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(guard) => guard?,
+            };
+
+            match self.listener().try_wait_one()? {
+                Some(event_id) => return Poll::Ready(Ok(event_id)),
+                None => guard.clear_ready(),
+            }
+        }
+    }
+
+    /// Polls the [`AsyncListener`] for new [`EventId`]s and calls the provided callback for every
+    /// received [`EventId`]. When no [`EventId`] is available, the waker of the provided
+    /// [`Context`] is registered and woken up as soon as one arrives.
+    pub fn poll_wait_all<F: FnMut(EventId)>(
+        &self,
+        cx: &mut Context<'_>,
+        mut callback: F,
+    ) -> Poll<Result<(), ListenerWaitError>> {
+        loop {
+            let mut guard = match self.poll_read_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(guard) => guard?,
+            };
+
+            let mut number_of_events = 0;
+            self.listener().try_wait_all(|event_id| {
+                number_of_events += 1;
+                callback(event_id)
+            })?;
+
+            // the readiness is only cleared when the socket is drained, otherwise a notification
+            // that arrived after the last batch would be lost
+            if number_of_events != 0 {
+                return Poll::Ready(Ok(()));
+            }
+
+            guard.clear_ready();
+        }
+    }
+
+    fn poll_read_ready<'a>(
+        &'a self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<AsyncFdReadyGuard<'a, ReactorHandle<Service>>, ListenerWaitError>> {
+        match self.handle.poll_read_ready(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Ok(guard)) => Poll::Ready(Ok(guard)),
+            Poll::Ready(Err(e)) => {
+                debug!(from self,
+                    "Failed to wait for events since the readiness of the listener could not be acquired ({:?}).", e);
+                Poll::Ready(Err(ListenerWaitError::InternalFailure))
+            }
+        }
+    }
+}
+
+impl<Service: service::Service> Stream for AsyncListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    type Item = Result<EventId, ListenerWaitError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_wait_one(cx).map(Some)
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`AsyncSubscriber`] integrates a [`Subscriber`] into the tokio runtime. A
+//! [`Subscriber`] has no file descriptor on its own, therefore the publish-subscribe service is
+//! combined with an event service. The publisher side notifies with a
+//! [`Notifier`](crate::port::notifier::Notifier) after sending a sample and the
+//! [`AsyncSubscriber`] waits asynchronously on the corresponding
+//! [`Listener`] whenever no sample is available.
+//!
+//! Additionally, it implements [`Stream`] so that it can be used with any stream combinator.
+//!
+//! Requires the `async` feature and is available on unix platforms only.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_socket;
+//! use iceoryx2::port::async_subscriber::AsyncSubscriber;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
+//!     .publish_subscribe()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//! let event = node.service_builder(&service_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let subscriber = AsyncSubscriber::new(
+//!     pubsub.subscriber().create()?,
+//!     event.listener().create()?,
+//! )?;
+//!
+//! let publisher = pubsub.publisher().create()?;
+//! let notifier = event.notifier().create()?;
+//!
+//! publisher.send_copy(1234)?;
+//! notifier.notify()?;
+//!
+//! let sample = subscriber.receive().await?;
+//! println!("received: {:?}", *sample);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+
+use super::async_listener::{AsyncListener, AsyncListenerCreateError};
+use super::listener::Listener;
+use super::port_identifiers::UniqueSubscriberId;
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::sample::Sample;
+use crate::service;
+use crate::service::static_config::type_details::Untyped;
+
+enum_gen! {
+    /// Failure that can be emitted when a [`Sample`] is received with
+    /// [`AsyncSubscriber::receive()`].
+    AsyncSubscriberReceiveError
+  mapping:
+    SubscriberReceiveError to ReceiveError,
+    ListenerWaitError to WaitError
+}
+
+impl std::fmt::Display for AsyncSubscriberReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncSubscriberReceiveError {}
+
+/// The receiving endpoint of a publish-subscribe communication that waits asynchronously for
+/// [`Sample`]s. It consists of a [`Subscriber`] and an [`AsyncListener`] that is woken up
+/// whenever the publisher side notifies about a new [`Sample`].
+#[derive(Debug)]
+pub struct AsyncSubscriber<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    subscriber: Subscriber<Service, MessageType>,
+    listener: AsyncListener<Service>,
+}
+
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
+    AsyncSubscriber<Service, MessageType>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Combines the [`Subscriber`] with the [`Listener`] of the event service that is notified
+    /// whenever a new [`Sample`] was sent and registers the [`Listener`] at the reactor of the
+    /// current tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// When it is called outside of a tokio runtime.
+    pub fn new(
+        subscriber: Subscriber<Service, MessageType>,
+        listener: Listener<Service>,
+    ) -> Result<Self, AsyncListenerCreateError> {
+        Ok(Self {
+            subscriber,
+            listener: AsyncListener::new(listener)?,
+        })
+    }
+
+    /// Returns the underlying [`Subscriber`].
+    pub fn subscriber(&self) -> &Subscriber<Service, MessageType> {
+        &self.subscriber
+    }
+
+    /// Returns the underlying [`AsyncListener`].
+    pub fn listener(&self) -> &AsyncListener<Service> {
+        &self.listener
+    }
+
+    /// Deregisters the [`Listener`] from the reactor and returns the [`Subscriber`] and the
+    /// [`Listener`].
+    pub fn into_inner(self) -> (Subscriber<Service, MessageType>, Listener<Service>) {
+        (self.subscriber, self.listener.into_inner())
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the underlying [`Subscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    fn poll_receive_with<
+        T,
+        F: Fn(&Subscriber<Service, MessageType>) -> Result<Option<T>, SubscriberReceiveError>,
+    >(
+        &self,
+        cx: &mut Context<'_>,
+        receive: F,
+    ) -> Poll<Result<T, AsyncSubscriberReceiveError>> {
+        loop {
+            if let Some(sample) = receive(&self.subscriber)? {
+                return Poll::Ready(Ok(sample));
+            }
+
+            // the events are only used as wake up, the samples are acquired from the subscriber
+            match self.listener.poll_wait_all(cx, |_| {}) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => result?,
+            }
+        }
+    }
+}
+
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend>
+    AsyncSubscriber<Service, MessageType>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Waits asynchronously until a [`Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`] and returns it. If a failure occurs
+    /// [`AsyncSubscriberReceiveError`] is returned.
+    pub async fn receive(
+        &self,
+    ) -> Result<Sample<MessageType, Service>, AsyncSubscriberReceiveError> {
+        std::future::poll_fn(|cx| self.poll_receive(cx)).await
+    }
+
+    /// Polls the [`AsyncSubscriber`] for a new [`Sample`]. When no [`Sample`] is available, the
+    /// waker of the provided [`Context`] is registered and woken up as soon as the publisher
+    /// side notifies.
+    pub fn poll_receive(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Sample<MessageType, Service>, AsyncSubscriberReceiveError>> {
+        self.poll_receive_with(cx, |subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, ElementType: Debug + ZeroCopySend>
+    AsyncSubscriber<Service, [ElementType]>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Waits asynchronously until a [`Sample`] with a slice payload was received from a
+    /// [`crate::port::publisher::Publisher`] and returns it. If a failure occurs
+    /// [`AsyncSubscriberReceiveError`] is returned.
+    pub async fn receive(
+        &self,
+    ) -> Result<Sample<[ElementType], Service>, AsyncSubscriberReceiveError> {
+        std::future::poll_fn(|cx| self.poll_receive(cx)).await
+    }
+
+    /// Polls the [`AsyncSubscriber`] for a new [`Sample`] with a slice payload. When no
+    /// [`Sample`] is available, the waker of the provided [`Context`] is registered and woken up
+    /// as soon as the publisher side notifies.
+    pub fn poll_receive(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Sample<[ElementType], Service>, AsyncSubscriberReceiveError>> {
+        self.poll_receive_with(cx, |subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service> AsyncSubscriber<Service, Untyped>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Waits asynchronously until a [`Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`] and returns it with its payload as raw bytes. If a
+    /// failure occurs [`AsyncSubscriberReceiveError`] is returned.
+    pub async fn receive(&self) -> Result<Sample<[u8], Service>, AsyncSubscriberReceiveError> {
+        std::future::poll_fn(|cx| self.poll_receive(cx)).await
+    }
+
+    /// Polls the [`AsyncSubscriber`] for a new [`Sample`] with a raw byte payload. When no
+    /// [`Sample`] is available, the waker of the provided [`Context`] is registered and woken up
+    /// as soon as the publisher side notifies.
+    pub fn poll_receive(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Sample<[u8], Service>, AsyncSubscriberReceiveError>> {
+        self.poll_receive_with(cx, |subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend> Stream
+    for AsyncSubscriber<Service, MessageType>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    type Item = Result<Sample<MessageType, Service>, AsyncSubscriberReceiveError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_receive(cx).map(Some)
+    }
+}
+
+impl<Service: service::Service, ElementType: Debug + ZeroCopySend> Stream
+    for AsyncSubscriber<Service, [ElementType]>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    type Item = Result<Sample<[ElementType], Service>, AsyncSubscriberReceiveError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_receive(cx).map(Some)
+    }
+}
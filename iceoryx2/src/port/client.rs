@@ -0,0 +1,733 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client().create()?;
+//! let server = service.server().create()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1337);
+//! request.send()?;
+//!
+//! // send a copy of the value
+//! client.send_copy(313)?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     request.send_copy(*request + 1)?;
+//! }
+//!
+//! while let Some(response) = client.receive()? {
+//!     println!("received response {:?} for request {:?}",
+//!         *response, response.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::*;
+
+use super::details::data_segment::{service_peer_authorization, DataSegment};
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::config;
+use crate::message::Message;
+use crate::node::SharedNode;
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::request_mut::RequestMut;
+use crate::response::Response;
+use crate::service;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::{
+    client_data_segment_name, request_connection_name, response_connection_name,
+    server_data_segment_name,
+};
+use crate::service::port_factory::client::LocalClientConfig;
+use crate::service::static_config::request_response::StaticConfig;
+
+/// Defines a failure that can occur when a [`Client`] is created with
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    ExceedsMaxSupportedClients,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+/// Defines a failure that can occur in [`Client::loan()`] and [`Client::loan_uninit()`]
+/// or is part of [`ClientSendError`] emitted in [`Client::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ClientLoanError {
+    OutOfMemory,
+    ExceedsMaxLoanedRequests,
+    InternalFailure,
+}
+
+impl std::fmt::Display for ClientLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientLoanError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`RequestMut`] is sent via [`RequestMut::send()`] or
+    /// [`Client::send_copy()`].
+    ClientSendError
+  entry:
+    ConnectionCorrupted
+  mapping:
+    ClientLoanError to LoanError,
+    ConnectionFailure to ConnectionError
+}
+
+impl std::fmt::Display for ClientSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientSendError {}
+
+/// Defines the failure that can occur when receiving a [`Response`] with [`Client::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientReceiveError {
+    ExceedsMaxBorrowedResponses,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ClientReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientReceiveError {}
+
+#[derive(Debug)]
+pub(crate) struct ServerConnection<Service: service::Service> {
+    pub(crate) request_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) response_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) server_id: UniqueServerId,
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientSharedState<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    server_connections: Vec<UnsafeCell<Option<ServerConnection<Service>>>>,
+    server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    global_config: Arc<config::Config>,
+    static_config: StaticConfig,
+    config: LocalClientConfig,
+    client_id: UniqueClientId,
+    number_of_requests: usize,
+    loan_counter: AtomicUsize,
+    request_id_counter: AtomicU64,
+}
+
+impl<Service: service::Service> ClientSharedState<Service> {
+    fn get(&self, index: usize) -> &Option<ServerConnection<Service>> {
+        unsafe { &*self.server_connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<ServerConnection<Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.server_connections[index].get()
+        }
+    }
+
+    fn create_connection(
+        &self,
+        server_details: &ServerDetails,
+    ) -> Result<ServerConnection<Service>, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to server {:?} from client {:?}",
+            server_details.server_id, self.client_id
+        );
+
+        let request_sender = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&request_connection_name(self.client_id, server_details.server_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(self.static_config.server_max_borrowed_requests)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(self.number_of_requests)
+                                .create_sender(self.static_config.request_type_size),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_receiver = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&response_connection_name(server_details.server_id, self.client_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(self.static_config.client_max_borrowed_responses)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(server_details.number_of_responses)
+                                .create_receiver(self.static_config.response_type_size),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from self,
+                        when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                            Builder::new(&server_data_segment_name(server_details.server_id))
+                                .config(&data_segment_config::<Service>(self.global_config.as_ref()))
+                                .open(),
+                        "{} since the servers data segment could not be mapped into the process.", msg);
+
+        Ok(ServerConnection {
+            request_sender,
+            response_receiver,
+            data_segment,
+            server_id: server_details.server_id,
+        })
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.data_segment.release_sample(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.get(i) {
+            // # SAFETY: the server no longer exist, therefore we can
+            //           reacquire all delivered requests
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+
+            *self.get_mut(i) = None;
+        }
+    }
+
+    fn populate_server_connections(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.len(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(server_details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.server_id != server_details.server_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        match self.create_connection(server_details) {
+                            Ok(connection) => *self.get_mut(i) = Some(connection),
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new server {:?}.",
+                                    server_details.server_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.dynamic_storage
+                .get()
+                .request_response()
+                .servers
+                .update_state(&mut *self.server_list_state.get())
+        } {
+            fail!(from self, when self.populate_server_connections(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn return_loaned_request(&self, distance_to_chunk: PointerOffset) {
+        self.data_segment.release_sample(distance_to_chunk);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_request(
+        &self,
+        distance_to_chunk: PointerOffset,
+    ) -> Result<usize, ClientSendError> {
+        let msg = "Unable to send request";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_requests();
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.get(i) {
+                match connection.request_sender.try_send(distance_to_chunk) {
+                    Ok(_) => {
+                        self.data_segment.borrow_sample(distance_to_chunk);
+                        number_of_recipients += 1;
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => {
+                        warn!(from self,
+                            "The request {:?} was not delivered to server {:?} since it has already the maximum number of active requests.",
+                            distance_to_chunk, connection.server_id);
+                    }
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with ClientSendError::ConnectionCorrupted,
+                            "{} {:?} since a corrupted connection was detected with server {:?}.",
+                            msg, distance_to_chunk, connection.server_id);
+                    }
+                }
+            }
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn release_response(
+        &self,
+        channel_id: usize,
+        origin: UniqueServerId,
+        distance_to_chunk: PointerOffset,
+    ) {
+        match self.get(channel_id) {
+            Some(connection) => {
+                if connection.server_id == origin {
+                    match connection.response_receiver.release(distance_to_chunk) {
+                        Ok(()) => (),
+                        Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                            fatal_panic!(from self, "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+                        }
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release response since the connection is broken. The response will be discarded and has to be reclaimed manually by the server.");
+            }
+        }
+    }
+}
+
+/// The requesting endpoint of a request-response based communication.
+#[derive(Debug)]
+pub struct Client<
+    Service: service::Service,
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+> {
+    pub(crate) shared_state: Arc<ClientSharedState<Service>>,
+    dynamic_client_handle: Option<ContainerHandle>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Drop for Client<Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_client_handle {
+            self.shared_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_client_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Client<Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let client_id = UniqueClientId::new();
+        let server_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests =
+            static_config.required_amount_of_requests_per_data_segment(config.max_loaned_requests);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(&client_data_segment_name(client_id),
+                        service.state().global_config.as_ref(),
+                        number_of_requests,
+                        // # SAFETY: type_size and type_alignment are acquired via
+                        //           core::mem::{size_of|align_of}
+                        unsafe { Layout::from_size_align_unchecked(
+                            static_config.request_type_size,
+                            static_config.request_type_alignment) },
+                        MemoryPlacement::default(),
+                        service_peer_authorization::<Service>(&service.state().dynamic_storage)),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let shared_state = Arc::new(ClientSharedState {
+            data_segment,
+            server_connections: (0..server_list.capacity())
+                .map(|_| UnsafeCell::new(None))
+                .collect(),
+            server_list_state: unsafe { UnsafeCell::new(server_list.get_state()) },
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            global_config: Arc::clone(&service.state().global_config),
+            static_config: static_config.clone(),
+            config,
+            client_id,
+            number_of_requests,
+            loan_counter: AtomicUsize::new(0),
+            request_id_counter: AtomicU64::new(0),
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_client_handle: None,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_server_connections() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_client_handle = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(ClientDetails {
+                client_id,
+                number_of_requests,
+                node_id: *service.state().shared_node.id(),
+            }) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                            "{} since it would exceed the maximum supported amount of clients of {}.",
+                            msg, static_config.max_clients);
+            }
+        };
+
+        new_self.dynamic_client_handle = Some(dynamic_client_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.shared_state.client_id
+    }
+
+    /// Copies the input `value` into a [`RequestMut`] and sends it to all connected
+    /// [`crate::port::server::Server`]s.
+    /// On success it returns the number of [`crate::port::server::Server`]s that received
+    /// the request, otherwise a [`ClientSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .request_response()
+    /// #     .typed::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// client.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: RequestType) -> Result<usize, ClientSendError> {
+        let msg = "Unable to send copy of request";
+        let request = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a request failed.", msg);
+
+        Ok(fail!(from self, when request.write_payload(value).send(),
+            "{} since the underlying send operation failed.", msg))
+    }
+
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`ClientLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .request_response()
+    /// #     .typed::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(42);
+    ///
+    /// request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<RequestMut<MaybeUninit<RequestType>, Service>, ClientLoanError> {
+        let msg = "Unable to loan Request";
+        let shared_state = &self.shared_state;
+
+        if shared_state.loan_counter.load(Ordering::Relaxed)
+            >= shared_state.config.max_loaned_requests
+        {
+            fail!(from self, with ClientLoanError::ExceedsMaxLoanedRequests,
+                "{} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.",
+                msg, shared_state.loan_counter.load(Ordering::Relaxed), shared_state.config.max_loaned_requests);
+        }
+
+        shared_state.retrieve_returned_requests();
+
+        match shared_state.data_segment.allocate() {
+            Ok(chunk) => {
+                let message = chunk.data_ptr
+                    as *mut MaybeUninit<Message<RequestHeader, MaybeUninit<RequestType>>>;
+
+                let request_id = shared_state
+                    .request_id_counter
+                    .fetch_add(1, Ordering::Relaxed);
+
+                let request = unsafe {
+                    (*message).write(Message {
+                        header: RequestHeader::new(shared_state.client_id, request_id),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<RequestHeader, MaybeUninit<RequestType>>,
+                    )
+                };
+
+                shared_state.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(RequestMut::new(shared_state, request, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ClientLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ClientLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    /// Receives a [`Response`] from a [`crate::port::server::Server`]. If no response could be
+    /// received [`None`] is returned. If a failure occurs [`ClientReceiveError`] is returned.
+    /// Only responses to requests of this [`Client`] are received.
+    pub fn receive(&self) -> Result<Option<Response<ResponseType, Service>>, ClientReceiveError> {
+        let msg = "Unable to receive another response";
+        if let Err(e) = self.shared_state.update_connections() {
+            fail!(from self,
+                with ClientReceiveError::ConnectionFailure(e),
+                "Some responses are not being received since not all connections to servers could be established.");
+        }
+
+        for id in 0..self.shared_state.server_connections.len() {
+            if let Some(ref connection) = self.shared_state.get(id) {
+                match connection.response_receiver.receive() {
+                    Ok(Some(offset)) => {
+                        let absolute_address =
+                            offset.value() + connection.data_segment.payload_start_address();
+                        return Ok(Some(Response {
+                            shared_state: Arc::clone(&self.shared_state),
+                            channel_id: id,
+                            ptr: unsafe {
+                                RawSample::new_unchecked(
+                                    absolute_address
+                                        as *const Message<ResponseHeader, ResponseType>,
+                                )
+                            },
+                            offset,
+                            origin: connection.server_id,
+                        }));
+                    }
+                    Ok(None) => (),
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ClientReceiveError::ExceedsMaxBorrowedResponses,
+                            "{} since it would exceed the maximum {} of borrowed responses.",
+                            msg, connection.response_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Default + Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Client<Service, RequestType, ResponseType>
+{
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Client::loan_uninit`] can be used to loan a [`core::mem::MaybeUninit<RequestType>`].
+    ///
+    /// On failure it returns [`ClientLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .request_response()
+    /// #     .typed::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 42;
+    ///
+    /// request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<RequestMut<RequestType, Service>, ClientLoanError> {
+        Ok(self.loan_uninit()?.write_payload(RequestType::default()))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > UpdateConnections for Client<Service, RequestType, ResponseType>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = service.consumer().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received work item {:?} from producer {:?}",
+//!         *work_item, work_item.origin());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_cal::zero_copy_connection::*;
+
+use super::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::config;
+use crate::message::Message;
+use crate::node::SharedNode;
+use crate::raw_sample::RawSample;
+use crate::service;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::header::pipeline::Header;
+use crate::service::naming_scheme::{producer_data_segment_name, work_item_connection_name};
+use crate::service::static_config::pipeline::StaticConfig;
+use crate::work_item::WorkItem;
+
+/// Defines a failure that can occur when a [`Consumer`] is created with
+/// [`crate::service::port_factory::consumer::PortFactoryConsumer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConsumerCreateError {
+    ExceedsMaxSupportedConsumers,
+}
+
+impl std::fmt::Display for ConsumerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ConsumerCreateError {}
+
+/// Defines the failure that can occur when receiving a [`WorkItem`] with
+/// [`Consumer::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConsumerReceiveError {
+    ExceedsMaxBorrowedItems,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ConsumerReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ConsumerReceiveError {}
+
+#[derive(Debug)]
+pub(crate) struct ProducerConnection<Service: service::Service> {
+    pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) producer_id: UniqueProducerId,
+}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerSharedState<Service: service::Service> {
+    producer_connections: Vec<UnsafeCell<Option<ProducerConnection<Service>>>>,
+    producer_list_state: UnsafeCell<ContainerState<ProducerDetails>>,
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    global_config: Arc<config::Config>,
+    static_config: StaticConfig,
+    consumer_id: UniqueConsumerId,
+}
+
+impl<Service: service::Service> ConsumerSharedState<Service> {
+    fn get(&self, index: usize) -> &Option<ProducerConnection<Service>> {
+        unsafe { &*self.producer_connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<ProducerConnection<Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.producer_connections[index].get()
+        }
+    }
+
+    fn create_connection(
+        &self,
+        producer_details: &ProducerDetails,
+    ) -> Result<ProducerConnection<Service>, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to producer {:?} from consumer {:?}",
+            producer_details.producer_id, self.consumer_id
+        );
+
+        let receiver = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&work_item_connection_name(producer_details.producer_id, self.consumer_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.consumer_max_buffer_size)
+                                .receiver_max_borrowed_samples(self.static_config.consumer_max_borrowed_items)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(producer_details.number_of_items)
+                                .create_receiver(self.static_config.type_size),
+                        "{} since the work item connection could not be established.", msg);
+
+        let data_segment = fail!(from self,
+                        when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                            Builder::new(&producer_data_segment_name(producer_details.producer_id))
+                                .config(&data_segment_config::<Service>(self.global_config.as_ref()))
+                                .open(),
+                        "{} since the producers data segment could not be mapped into the process.", msg);
+
+        Ok(ProducerConnection {
+            receiver,
+            data_segment,
+            producer_id: producer_details.producer_id,
+        })
+    }
+
+    fn populate_producer_connections(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.producer_connections.len(), None);
+
+        unsafe {
+            (*self.producer_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(producer_details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => connection.producer_id != producer_details.producer_id,
+                    };
+
+                    if create_connection {
+                        match self.create_connection(producer_details) {
+                            Ok(connection) => *self.get_mut(i) = Some(connection),
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new producer {:?}.",
+                                    producer_details.producer_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => *self.get_mut(i) = None,
+            }
+        }
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.dynamic_storage
+                .get()
+                .pipeline()
+                .producers
+                .update_state(&mut *self.producer_list_state.get())
+        } {
+            fail!(from self, when self.populate_producer_connections(),
+                "Connections were updated only partially since at least one connection to a Producer port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn release_item(
+        &self,
+        channel_id: usize,
+        origin: UniqueProducerId,
+        distance_to_chunk: PointerOffset,
+    ) {
+        match self.get(channel_id) {
+            Some(connection) => {
+                if connection.producer_id == origin {
+                    match connection.receiver.release(distance_to_chunk) {
+                        Ok(()) => (),
+                        Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                            fatal_panic!(from self, "This should never happen! The producers retrieve channel is full and the work item cannot be returned.");
+                        }
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release work item since the connection is broken. The work item will be discarded and has to be reclaimed manually by the producer.");
+            }
+        }
+    }
+}
+
+/// The receiving endpoint of a pipeline based communication. It receives only the work items
+/// that the [`crate::port::producer::Producer`]s assigned to it.
+#[derive(Debug)]
+pub struct Consumer<Service: service::Service, PayloadType: Debug + ZeroCopySend> {
+    shared_state: Arc<ConsumerSharedState<Service>>,
+    dynamic_consumer_handle: Option<ContainerHandle>,
+    _phantom_payload_type: PhantomData<PayloadType>,
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Drop
+    for Consumer<Service, PayloadType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_consumer_handle {
+            self.shared_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_consumer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Consumer<Service, PayloadType> {
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+    ) -> Result<Self, ConsumerCreateError> {
+        let msg = "Unable to create Consumer port";
+        let origin = "Consumer::new()";
+        let consumer_id = UniqueConsumerId::new();
+        let producer_list = &service.state().dynamic_storage.get().pipeline().producers;
+
+        let shared_state = Arc::new(ConsumerSharedState {
+            producer_connections: (0..producer_list.capacity())
+                .map(|_| UnsafeCell::new(None))
+                .collect(),
+            producer_list_state: unsafe { UnsafeCell::new(producer_list.get_state()) },
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            global_config: Arc::clone(&service.state().global_config),
+            static_config: static_config.clone(),
+            consumer_id,
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_consumer_handle: None,
+            _phantom_payload_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_producer_connections() {
+            warn!(from new_self, "The new Consumer port is unable to connect to every Producer port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a consumer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_consumer_handle = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_consumer_id(ConsumerDetails {
+                consumer_id,
+                node_id: *service.state().shared_node.id(),
+            }) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ConsumerCreateError::ExceedsMaxSupportedConsumers,
+                            "{} since it would exceed the maximum supported amount of consumers of {}.",
+                            msg, static_config.max_consumers);
+            }
+        };
+
+        new_self.dynamic_consumer_handle = Some(dynamic_consumer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueConsumerId`] of the [`Consumer`]
+    pub fn id(&self) -> UniqueConsumerId {
+        self.shared_state.consumer_id
+    }
+
+    /// Receives a [`WorkItem`] from a [`crate::port::producer::Producer`]. If no work item
+    /// could be received [`None`] is returned. If a failure occurs [`ConsumerReceiveError`] is
+    /// returned.
+    pub fn receive(&self) -> Result<Option<WorkItem<PayloadType, Service>>, ConsumerReceiveError> {
+        let msg = "Unable to receive another work item";
+        if let Err(e) = self.shared_state.update_connections() {
+            fail!(from self,
+                with ConsumerReceiveError::ConnectionFailure(e),
+                "Some work items are not being received since not all connections to producers could be established.");
+        }
+
+        for id in 0..self.shared_state.producer_connections.len() {
+            if let Some(ref connection) = self.shared_state.get(id) {
+                match connection.receiver.receive() {
+                    Ok(Some(offset)) => {
+                        let absolute_address =
+                            offset.value() + connection.data_segment.payload_start_address();
+                        return Ok(Some(WorkItem {
+                            shared_state: Arc::clone(&self.shared_state),
+                            channel_id: id,
+                            ptr: unsafe {
+                                RawSample::new_unchecked(
+                                    absolute_address as *const Message<Header, PayloadType>,
+                                )
+                            },
+                            offset,
+                            origin: connection.producer_id,
+                        }));
+                    }
+                    Ok(None) => (),
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ConsumerReceiveError::ExceedsMaxBorrowedItems,
+                            "{} since it would exceed the maximum {} of borrowed work items.",
+                            msg, connection.receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> UpdateConnections
+    for Consumer<Service, PayloadType>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
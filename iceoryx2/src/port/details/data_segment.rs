@@ -0,0 +1,140 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_bb_posix::process::ProcessId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{
+    PeerAuthorization, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
+};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+
+use crate::config;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+
+struct ServiceNodes<Service: service::Service> {
+    dynamic_storage: Arc<Service::DynamicStorage>,
+}
+
+// the dynamic config is designed for concurrent inter-process access and is only read here
+unsafe impl<Service: service::Service> Send for ServiceNodes<Service> {}
+unsafe impl<Service: service::Service> Sync for ServiceNodes<Service> {}
+
+impl<Service: service::Service> ServiceNodes<Service> {
+    fn has_node_of_process(&self, pid: ProcessId) -> bool {
+        self.dynamic_storage.get().has_node_of_process(pid)
+    }
+}
+
+/// Authorizes only processes that have a [`Node`](crate::node::Node) registered at the
+/// service. Data segments that are shared by handing over a file descriptor are therefore
+/// not handed over to unrelated processes of the same user.
+pub(crate) fn service_peer_authorization<Service: service::Service>(
+    dynamic_storage: &Arc<Service::DynamicStorage>,
+) -> PeerAuthorization {
+    let service_nodes = ServiceNodes::<Service> {
+        dynamic_storage: Arc::clone(dynamic_storage),
+    };
+
+    PeerAuthorization::new(move |credentials| {
+        service_nodes.has_node_of_process(credentials.get_pid())
+    })
+}
+
+/// The data segment of a sending port. All samples are loaned from it and a reference counter
+/// tracks how many receivers still hold a sample. When the last reference is released the sample
+/// is returned to the underlying allocator.
+#[derive(Debug)]
+pub(crate) struct DataSegment<Service: service::Service> {
+    memory: Service::SharedMemory,
+    sample_reference_counter: Vec<AtomicU64>,
+    sample_layout: Layout,
+}
+
+impl<Service: service::Service> DataSegment<Service> {
+    pub(crate) fn create(
+        name: &FileName,
+        global_config: &config::Config,
+        number_of_samples: usize,
+        sample_layout: Layout,
+        memory_placement: MemoryPlacement,
+        peer_authorization: PeerAuthorization,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: sample_layout,
+        };
+
+        let memory = fail!(from "DataSegment::create()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(name)
+                .config(&data_segment_config::<Service>(global_config))
+                .size(sample_layout.size() * number_of_samples + sample_layout.align() - 1)
+                .memory_placement(memory_placement)
+                .peer_authorization(peer_authorization)
+                .create(&allocator_config),
+            "Unable to create the data segment.");
+
+        Ok(Self {
+            memory,
+            sample_reference_counter: (0..number_of_samples).map(|_| AtomicU64::new(0)).collect(),
+            sample_layout,
+        })
+    }
+
+    pub(crate) fn payload_start_address(&self) -> usize {
+        self.memory.payload_start_address()
+    }
+
+    fn sample_index(&self, distance_to_chunk: usize) -> usize {
+        distance_to_chunk / self.sample_layout.size()
+    }
+
+    pub(crate) fn allocate(&self) -> Result<ShmPointer, ShmAllocationError> {
+        let ptr = self.memory.allocate(self.sample_layout)?;
+        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
+            .fetch_add(1, Ordering::Relaxed)
+            != 0
+        {
+            fatal_panic!(from self,
+                "Unable to allocate sample since the allocated sample is already in use! This should never happen!");
+        }
+
+        Ok(ptr)
+    }
+
+    pub(crate) fn borrow_sample(&self, distance_to_chunk: PointerOffset) {
+        self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn release_sample(&self, distance_to_chunk: PointerOffset) {
+        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                self.memory
+                    .deallocate(distance_to_chunk, self.sample_layout);
+            }
+        }
+    }
+}
@@ -45,12 +45,17 @@ impl<Service: service::Service> Connection<Service> {
         this: &PublisherConnections<Service>,
         publisher_id: UniquePublisherId,
         number_of_samples: usize,
+        max_slice_len: usize,
     ) -> Result<Self, ConnectionFailure> {
         let msg = format!(
             "Unable to establish connection to publisher {:?} from subscriber {:?}.",
             publisher_id, this.subscriber_id
         );
 
+        let sample_layout = fail!(from this, when this.static_config.message_layout(max_slice_len),
+                        with ConnectionFailure::FailedToEstablishConnection(ZeroCopyCreationError::InvalidSampleSize),
+                        "{} since the sample size for a max slice length of {} exceeds the supported range.", msg, max_slice_len);
+
         let receiver = fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(publisher_id, this.subscriber_id))
@@ -59,7 +64,7 @@ impl<Service: service::Service> Connection<Service> {
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                     .number_of_samples(number_of_samples)
-                                    .create_receiver(this.static_config.type_size),
+                                    .create_receiver(sample_layout.size()),
                         "{} since the zero copy connection could not be established.", msg);
 
         let data_segment = fail!(from this,
@@ -124,8 +129,14 @@ impl<Service: service::Service> PublisherConnections<Service> {
         index: usize,
         publisher_id: UniquePublisherId,
         number_of_samples: usize,
+        max_slice_len: usize,
     ) -> Result<(), ConnectionFailure> {
-        *self.get_mut(index) = Some(Connection::new(self, publisher_id, number_of_samples)?);
+        *self.get_mut(index) = Some(Connection::new(
+            self,
+            publisher_id,
+            number_of_samples,
+            max_slice_len,
+        )?);
 
         Ok(())
     }
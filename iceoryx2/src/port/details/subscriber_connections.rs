@@ -10,15 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
+use crate::node::NodeId;
+use crate::port::details::subscriber_filter::SubscriberFilter;
 use crate::service::config_scheme::connection_config;
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
 use crate::{
@@ -28,10 +32,19 @@ use crate::{
     service::{naming_scheme::connection_name, static_config::publish_subscribe::StaticConfig},
 };
 
+/// The minimum time between two liveness checks of the same subscriber.
+const LIVENESS_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) subscriber_node_id: NodeId,
+    pub(crate) filter: SubscriberFilter,
+    last_liveness_check: Cell<Option<Instant>>,
+    /// Samples that could not be delivered since the buffer of the subscriber was full. Only
+    /// used with [`crate::service::port_factory::publisher::UnableToDeliverStrategy::RetryLater`].
+    pending_samples: UnsafeCell<Queue<usize>>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -39,6 +52,7 @@ impl<Service: service::Service> Connection<Service> {
         this: &SubscriberConnections<Service>,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
+        sample_size: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
         let msg = format!(
             "Unable to establish connection to subscriber {:?} from publisher {:?}",
@@ -57,14 +71,44 @@ impl<Service: service::Service> Connection<Service> {
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                 .number_of_samples(number_of_samples)
-                                .create_sender(this.static_config.type_size),
+                                .create_sender(sample_size),
                         "{}.", msg);
 
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.port_id,
+            subscriber_node_id: subscriber_details.node_id,
+            filter: subscriber_details.filter,
+            last_liveness_check: Cell::new(None),
+            pending_samples: UnsafeCell::new(Queue::new(subscriber_details.buffer_size)),
         })
     }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn pending_samples(&self) -> &mut Queue<usize> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut (*self.pending_samples.get())
+        }
+    }
+
+    pub(crate) fn has_pending_samples(&self) -> bool {
+        unsafe { !(*self.pending_samples.get()).is_empty() }
+    }
+
+    /// Returns true when the liveness of the subscriber shall be checked. The checks are
+    /// rate limited so that a slow but alive subscriber is not checked on every send or loan.
+    pub(crate) fn is_liveness_check_due(&self) -> bool {
+        let now = Instant::now();
+        match self.last_liveness_check.get() {
+            Some(last_check) if now.duration_since(last_check) < LIVENESS_CHECK_INTERVAL => false,
+            _ => {
+                self.last_liveness_check.set(Some(now));
+                true
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -74,6 +118,7 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     config: Arc<config::Config>,
     static_config: StaticConfig,
     number_of_samples: usize,
+    sample_size: usize,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -83,6 +128,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
         number_of_samples: usize,
+        sample_size: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
@@ -90,9 +136,14 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             port_id,
             static_config: static_config.clone(),
             number_of_samples,
+            sample_size,
         }
     }
 
+    pub(crate) fn config(&self) -> &Arc<config::Config> {
+        &self.config
+    }
+
     pub(crate) fn get(&self, index: usize) -> &Option<Connection<Service>> {
         unsafe { &(*self.connections[index].get()) }
     }
@@ -119,6 +170,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             self,
             subscriber_details,
             self.number_of_samples,
+            self.sample_size,
         )?);
 
         Ok(())
@@ -0,0 +1,66 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::ops::RangeInclusive;
+
+use crate::service::port_factory::subscriber::MAX_NUMBER_OF_FILTER_KEYS;
+
+/// The filter of a [`crate::port::subscriber::Subscriber`]. It is stored in the dynamic config
+/// of the service so that every [`crate::port::publisher::Publisher`] can evaluate it before a
+/// sample is delivered, therefore it is a plain value and not a callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubscriberFilter {
+    AcceptAll,
+    FilterKeys {
+        keys: [u64; MAX_NUMBER_OF_FILTER_KEYS],
+        number_of_keys: usize,
+    },
+    FilterKeyRange {
+        min: u64,
+        max: u64,
+    },
+}
+
+impl SubscriberFilter {
+    /// Returns [`None`] when more than [`MAX_NUMBER_OF_FILTER_KEYS`] are provided.
+    pub(crate) fn filter_keys(filter_keys: &[u64]) -> Option<Self> {
+        if filter_keys.len() > MAX_NUMBER_OF_FILTER_KEYS {
+            return None;
+        }
+
+        let mut keys = [0; MAX_NUMBER_OF_FILTER_KEYS];
+        keys[..filter_keys.len()].copy_from_slice(filter_keys);
+        Some(Self::FilterKeys {
+            keys,
+            number_of_keys: filter_keys.len(),
+        })
+    }
+
+    pub(crate) fn filter_key_range(range: &RangeInclusive<u64>) -> Self {
+        Self::FilterKeyRange {
+            min: *range.start(),
+            max: *range.end(),
+        }
+    }
+
+    /// Returns true when a sample with the provided filter key shall be delivered.
+    pub(crate) fn accepts(&self, filter_key: u64) -> bool {
+        match self {
+            Self::AcceptAll => true,
+            Self::FilterKeys {
+                keys,
+                number_of_keys,
+            } => keys[..*number_of_keys].contains(&filter_key),
+            Self::FilterKeyRange { min, max } => *min <= filter_key && filter_key <= *max,
+        }
+    }
+}
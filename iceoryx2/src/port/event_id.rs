@@ -16,7 +16,9 @@
 //! use iceoryx2::prelude::*;
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! # let event_name = ServiceName::new("MyEventName")?;
-//! # let event = zero_copy::Service::new(&event_name)
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let event = node.service_builder(&event_name)
 //! #     .event()
 //! #     .open_or_create()?;
 //!
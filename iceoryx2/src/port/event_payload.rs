@@ -0,0 +1,48 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_socket;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let event_name = ServiceName::new("MyEventName")?;
+//! // only event services whose event mechanism can transport payloads support them
+//! let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+//! #
+//! # let event = node.service_builder(&event_name)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let listener = event.listener().create()?;
+//! let notifier = event.notifier().create()?;
+//!
+//! // attach an error code to the event
+//! notifier.notify_with_payload(EventId::new(3), &1234u64)?;
+//!
+//! listener.try_wait_all_with_payload(|event_id, payload| {
+//!     // the payload type must match the type the notifier has sent
+//!     let error_code = unsafe { payload.value::<u64>() };
+//!     println!("event {:?} was triggered with error code {:?}", event_id, error_code);
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+/// The maximum size in bytes of an [`EventPayload`].
+pub const MAX_EVENT_PAYLOAD_SIZE: usize = iceoryx2_cal::event::MAX_TRIGGER_PAYLOAD_SIZE;
+
+/// Small fixed-size value that is transported together with an
+/// [`EventId`](crate::port::event_id::EventId).
+pub type EventPayload = iceoryx2_cal::event::TriggerPayload;
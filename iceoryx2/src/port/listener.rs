@@ -24,7 +24,9 @@
 //! use iceoryx2::prelude::*;
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -44,7 +46,9 @@
 //! use iceoryx2::prelude::*;
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -60,10 +64,14 @@
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 
+use crate::node::SharedNode;
+use crate::service::dynamic_config::event::ListenerDetails;
 use crate::service::naming_scheme::event_concept_name;
 use crate::{port::port_identifiers::UniqueListenerId, service};
 use std::sync::atomic::Ordering;
@@ -71,6 +79,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use super::event_id::EventId;
+use super::event_payload::EventPayload;
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -94,7 +103,9 @@ pub struct Listener<Service: service::Service> {
     dynamic_listener_handle: Option<ContainerHandle>,
     listener: <Service::Event as iceoryx2_cal::event::Event>::Listener,
     dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
     port_id: UniqueListenerId,
+    enable_counting: bool,
 }
 
 impl<Service: service::Service> Drop for Listener<Service> {
@@ -108,6 +119,20 @@ impl<Service: service::Service> Drop for Listener<Service> {
     }
 }
 
+impl<Service: service::Service> FileDescriptorBased for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for Listener<Service> where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing
+{
+}
+
 impl<Service: service::Service> Listener<Service> {
     pub(crate) fn new(service: &Service) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
@@ -126,9 +151,11 @@ impl<Service: service::Service> Listener<Service> {
 
         let mut new_self = Self {
             dynamic_storage,
+            _shared_node: service.state().shared_node.clone(),
             dynamic_listener_handle: None,
             listener,
             port_id,
+            enable_counting: service.state().static_config.event().enable_counting,
         };
 
         std::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -140,8 +167,10 @@ impl<Service: service::Service> Listener<Service> {
             .dynamic_storage
             .get()
             .event()
-            .add_listener_id(port_id)
-        {
+            .add_listener_id(ListenerDetails {
+                listener_id: port_id,
+                node_id: *service.state().shared_node.id(),
+            }) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with ListenerCreateError::ExceedsMaxSupportedListeners,
@@ -201,6 +230,169 @@ impl<Service: service::Service> Listener<Service> {
         )
     }
 
+    /// Non-blocking wait for new [`EventId`]s and their [`EventPayload`]s. Works like
+    /// [`Listener::try_wait_all()`] but the provided callback additionally receives the
+    /// [`EventPayload`] that was sent with
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    /// When the [`EventId`] was sent without payload, the [`EventPayload`] is empty.
+    /// When the underlying event mechanism of the service cannot transport payloads, it
+    /// fails with [`ListenerWaitError::PayloadNotSupported`].
+    pub fn try_wait_all_with_payload<F: FnMut(EventId, &EventPayload)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        self.verify_payload_support()?;
+        fail!(from self, when self.listener.try_wait_all_with_payload(callback),
+            "Failed to while calling try_wait on underlying event::Listener");
+        Ok(())
+    }
+
+    /// Blocking wait for new [`EventId`]s and their [`EventPayload`]s until the provided timeout
+    /// has passed. Works like [`Listener::timed_wait_all()`] but the provided callback
+    /// additionally receives the [`EventPayload`].
+    pub fn timed_wait_all_with_payload<F: FnMut(EventId, &EventPayload)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        self.verify_payload_support()?;
+        fail!(from self, when self.listener.timed_wait_all_with_payload(callback, timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+        Ok(())
+    }
+
+    /// Blocking wait for new [`EventId`]s and their [`EventPayload`]s. Works like
+    /// [`Listener::blocking_wait_all()`] but the provided callback additionally receives the
+    /// [`EventPayload`].
+    pub fn blocking_wait_all_with_payload<F: FnMut(EventId, &EventPayload)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        self.verify_payload_support()?;
+        fail!(from self, when self.listener.blocking_wait_all_with_payload(callback),
+            "Failed to while calling blocking_wait on underlying event::Listener");
+        Ok(())
+    }
+
+    fn verify_payload_support(&self) -> Result<(), ListenerWaitError> {
+        if !<Service::Event as iceoryx2_cal::event::Event>::has_payload_support() {
+            fail!(from self, with ListenerWaitError::PayloadNotSupported,
+                "Unable to wait for payloads since the underlying event mechanism does not support payloads.");
+        }
+
+        Ok(())
+    }
+
+    /// Non-blocking wait for new [`EventId`]s. Works like [`Listener::try_wait_all()`] but the
+    /// provided callback is called once for every received [`EventId`] together with the
+    /// number of times it was notified since the last call.
+    ///
+    /// When the service was created with
+    /// [`crate::service::builder::event::Builder::enable_counting()`], the count is acquired
+    /// from the counters of the service and contains also the notifications whose wake up was
+    /// coalesced or lost since the buffer of the [`Listener`] was full. Otherwise, only the
+    /// received notifications are counted.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let event_name = ServiceName::new("MyEventName")?;
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let event = node.service_builder(&event_name)
+    ///     .event()
+    ///     .enable_counting(true)
+    ///     .open_or_create()?;
+    ///
+    /// let listener = event.listener().create()?;
+    ///
+    /// listener.try_wait_all_with_count(|id, count| {
+    ///     println!("event {:?} was triggered {} times", id, count);
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_wait_all_with_count<F: FnMut(EventId, u64)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut received_event_ids = vec![];
+        fail!(from self, when self.listener.try_wait_all(|id| received_event_ids.push(id)),
+            "Failed to while calling try_wait on underlying event::Listener");
+        self.report_event_counts(received_event_ids, callback);
+        Ok(())
+    }
+
+    /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Works like
+    /// [`Listener::timed_wait_all()`] but the provided callback is called once for every
+    /// received [`EventId`] together with the number of times it was notified, see
+    /// [`Listener::try_wait_all_with_count()`].
+    pub fn timed_wait_all_with_count<F: FnMut(EventId, u64)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut received_event_ids = vec![];
+        fail!(from self, when self.listener.timed_wait_all(|id| received_event_ids.push(id), timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+        self.report_event_counts(received_event_ids, callback);
+        Ok(())
+    }
+
+    /// Blocking wait for new [`EventId`]s. Works like [`Listener::blocking_wait_all()`] but the
+    /// provided callback is called once for every received [`EventId`] together with the number
+    /// of times it was notified, see [`Listener::try_wait_all_with_count()`].
+    pub fn blocking_wait_all_with_count<F: FnMut(EventId, u64)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut received_event_ids = vec![];
+        fail!(from self, when self.listener.blocking_wait_all(|id| received_event_ids.push(id)),
+            "Failed to while calling blocking_wait on underlying event::Listener");
+        self.report_event_counts(received_event_ids, callback);
+        Ok(())
+    }
+
+    fn report_event_counts<F: FnMut(EventId, u64)>(
+        &self,
+        mut received_event_ids: Vec<EventId>,
+        mut callback: F,
+    ) {
+        match self.dynamic_listener_handle {
+            Some(handle) if self.enable_counting => {
+                let event_counters = self
+                    .dynamic_storage
+                    .get()
+                    .event()
+                    .event_counters(handle.index());
+
+                for (id, counter) in event_counters.iter().enumerate() {
+                    let count = counter.swap(0, Ordering::Acquire);
+                    if count != 0 {
+                        callback(EventId::new(id), count);
+                    }
+                }
+            }
+            _ => {
+                received_event_ids.sort_unstable();
+                let mut start = 0;
+                for end in 1..=received_event_ids.len() {
+                    if end == received_event_ids.len()
+                        || received_event_ids[end] != received_event_ids[start]
+                    {
+                        callback(received_event_ids[start], (end - start) as u64);
+                        start = end;
+                    }
+                }
+            }
+        }
+    }
+
     /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
@@ -16,21 +16,46 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Receiving endpoint (port) for event based communication that waits asynchronously in a
+/// tokio runtime
+#[cfg(all(feature = "async", unix))]
+pub mod async_listener;
+/// Receiving endpoint (port) for publish-subscribe based communication that waits asynchronously
+/// in a tokio runtime
+#[cfg(all(feature = "async", unix))]
+pub mod async_subscriber;
+/// Sending endpoint (port) for request-response based communication
+pub mod client;
+/// Receiving endpoint (port) for pipeline based communication
+pub mod consumer;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
+/// Defines the payload that can be transported together with an event id.
+pub mod event_payload;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
+/// Sending endpoint (port) for pipeline based communication
+pub mod producer;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Reading endpoint (port) for blackboard based communication
+pub mod reader;
+/// Receiving endpoint (port) for request-response based communication
+pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
+/// Multiplexes notifications, deadlines and intervals and waits on all of them in one blocking
+/// call
+pub mod waitset;
+/// Writing endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -14,9 +14,12 @@
 //!
 //! ```
 //! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_socket;
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -31,15 +34,28 @@
 //! // notify with some custom event id
 //! notifier.notify_with_custom_event_id(EventId::new(6))?;
 //!
+//! // notify with some custom event id and a payload
+//! notifier.notify_with_payload(EventId::new(7), &123u64)?;
+//!
 //! # Ok(())
 //! # }
 //! ```
 
-use super::{event_id::EventId, port_identifiers::UniqueListenerId};
+use super::{
+    event_id::EventId,
+    event_payload::{EventPayload, MAX_EVENT_PAYLOAD_SIZE},
+    port_identifiers::UniqueListenerId,
+};
 use crate::{
+    node::SharedNode,
     port::port_identifiers::UniqueNotifierId,
-    service::{self, naming_scheme::event_concept_name},
+    service::{
+        self,
+        dynamic_config::event::{ListenerDetails, NotifierDetails},
+        naming_scheme::event_concept_name,
+    },
 };
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
@@ -68,6 +84,8 @@ impl std::error::Error for NotifierCreateError {}
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NotifierNotifyError {
     EventIdOutOfBounds,
+    PayloadTooLarge,
+    PayloadNotSupported,
 }
 
 impl std::fmt::Display for NotifierNotifyError {
@@ -158,10 +176,12 @@ impl<Service: service::Service> ListenerConnections<Service> {
 #[derive(Debug)]
 pub struct Notifier<Service: service::Service> {
     listener_connections: ListenerConnections<Service>,
-    listener_list_state: UnsafeCell<ContainerState<UniqueListenerId>>,
+    listener_list_state: UnsafeCell<ContainerState<ListenerDetails>>,
     default_event_id: EventId,
     event_id_max_value: usize,
+    enable_counting: bool,
     dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
     dynamic_notifier_handle: Option<ContainerHandle>,
     port_id: UniqueNotifierId,
 }
@@ -194,7 +214,9 @@ impl<Service: service::Service> Notifier<Service> {
             default_event_id,
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
             dynamic_storage,
+            _shared_node: service.state().shared_node.clone(),
             event_id_max_value: service.state().static_config.event().event_id_max_value,
+            enable_counting: service.state().static_config.event().enable_counting,
             dynamic_notifier_handle: None,
             port_id,
         };
@@ -205,12 +227,12 @@ impl<Service: service::Service> Notifier<Service> {
 
         // !MUST! be the last task otherwise a notifier is added to the dynamic config without
         // the creation of all required channels
-        let dynamic_notifier_handle = match new_self
-            .dynamic_storage
-            .get()
-            .event()
-            .add_notifier_id(port_id)
-        {
+        let dynamic_notifier_handle = match new_self.dynamic_storage.get().event().add_notifier_id(
+            NotifierDetails {
+                notifier_id: port_id,
+                node_id: *service.state().shared_node.id(),
+            },
+        ) {
             Some(handle) => handle,
             None => {
                 fail!(from origin, with NotifierCreateError::ExceedsMaxSupportedNotifiers,
@@ -240,8 +262,8 @@ impl<Service: service::Service> Notifier<Service> {
         visited_indices.resize(self.listener_connections.len(), None);
 
         unsafe {
-            (*self.listener_list_state.get()).for_each(|index, listener_id| {
-                visited_indices[index as usize] = Some(*listener_id);
+            (*self.listener_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(details.listener_id);
             })
         };
 
@@ -290,6 +312,42 @@ impl<Service: service::Service> Notifier<Service> {
     pub fn notify_with_custom_event_id(
         &self,
         value: EventId,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(value, None)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`] and a copy of the provided payload. The payload is received with
+    /// [`crate::port::listener::Listener::try_wait_all_with_payload()`] and must not be larger
+    /// than [`MAX_EVENT_PAYLOAD_SIZE`]. When the underlying event mechanism of the service
+    /// cannot transport payloads, it fails with [`NotifierNotifyError::PayloadNotSupported`].
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload<PayloadType: ZeroCopySend + Copy>(
+        &self,
+        value: EventId,
+        payload: &PayloadType,
+    ) -> Result<usize, NotifierNotifyError> {
+        if !<Service::Event as Event>::has_payload_support() {
+            fail!(from self, with NotifierNotifyError::PayloadNotSupported,
+                "Unable to notify event since the underlying event mechanism does not support payloads.");
+        }
+
+        match EventPayload::from_value(payload) {
+            Some(payload) => self.notify_impl(value, Some(&payload)),
+            None => {
+                fail!(from self, with NotifierNotifyError::PayloadTooLarge,
+                    "Unable to notify event since the payload size of {} bytes exceeds the maximum supported payload size of {} bytes.",
+                    core::mem::size_of::<PayloadType>(), MAX_EVENT_PAYLOAD_SIZE);
+            }
+        }
+    }
+
+    fn notify_impl(
+        &self,
+        value: EventId,
+        payload: Option<&EventPayload>,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         self.update_connections();
@@ -305,18 +363,34 @@ impl<Service: service::Service> Notifier<Service> {
 
         for i in 0..self.listener_connections.len() {
             match self.listener_connections.get(i) {
-                Some(ref connection) => match connection.notifier.notify(value) {
-                    Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
-                        self.listener_connections.remove(i);
-                    }
-                    Err(e) => {
-                        warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
-                            connection, e)
+                Some(ref connection) => {
+                    // the counter is increased before the notification is sent, so that the
+                    // listener sees the count when it is woken up and that a notification that
+                    // could not be delivered is still counted
+                    if self.enable_counting {
+                        self.dynamic_storage.get().event().event_counters(i as u32)
+                            [value.as_value()]
+                        .fetch_add(1, Ordering::Release);
                     }
-                    Ok(_) => {
-                        number_of_triggered_listeners += 1;
+
+                    let result = match payload {
+                        Some(payload) => connection.notifier.notify_with_payload(value, payload),
+                        None => connection.notifier.notify(value),
+                    };
+
+                    match result {
+                        Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
+                            self.listener_connections.remove(i);
+                        }
+                        Err(e) => {
+                            warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
+                                connection, e)
+                        }
+                        Ok(_) => {
+                            number_of_triggered_listeners += 1;
+                        }
                     }
-                },
+                }
                 None => (),
             }
         }
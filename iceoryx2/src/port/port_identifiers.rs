@@ -12,12 +12,13 @@
 
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use serde::{Deserialize, Serialize};
 
 macro_rules! generate_id {
     { $(#[$documentation:meta])*
         $id_name:ident } => {
         $(#[$documentation])*
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         pub struct $id_name(pub(crate) UniqueSystemId);
 
         impl Default for $id_name {
@@ -33,6 +34,11 @@ macro_rules! generate_id {
             pub fn new() -> Self {
                 Self::default()
             }
+
+            /// Returns the underlying value of the id
+            pub fn value(&self) -> u128 {
+                self.0.value()
+            }
         }
     };
 }
@@ -53,3 +59,42 @@ generate_id! {
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     UniqueListenerId
 }
+generate_id! {
+    /// The system-wide unique id of a [`Client`](crate::port::client::Client).
+    UniqueClientId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Server`](crate::port::server::Server).
+    UniqueServerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Reader`](crate::port::reader::Reader).
+    UniqueReaderId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
+    UniqueWriterId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Producer`](crate::port::producer::Producer).
+    UniqueProducerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Consumer`](crate::port::consumer::Consumer).
+    UniqueConsumerId
+}
+
+/// The system-wide unique id of any port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UniquePortId {
+    Publisher(UniquePublisherId),
+    Subscriber(UniqueSubscriberId),
+    Notifier(UniqueNotifierId),
+    Listener(UniqueListenerId),
+    Client(UniqueClientId),
+    Server(UniqueServerId),
+    Reader(UniqueReaderId),
+    Writer(UniqueWriterId),
+    Producer(UniqueProducerId),
+    Consumer(UniqueConsumerId),
+}
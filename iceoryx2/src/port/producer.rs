@@ -0,0 +1,652 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = service.producer().create()?;
+//! let consumer_1 = service.consumer().create()?;
+//! let consumer_2 = service.consumer().create()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1337);
+//! work_item.send()?;
+//!
+//! // send a copy of the value
+//! producer.send_copy(313)?;
+//!
+//! // every work item is received by exactly one consumer
+//! while let Some(work_item) = consumer_1.receive()? {
+//!     println!("consumer 1 received: {:?}", *work_item);
+//! }
+//!
+//! while let Some(work_item) = consumer_2.receive()? {
+//!     println!("consumer 2 received: {:?}", *work_item);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::{Cell, UnsafeCell};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::*;
+
+use super::details::data_segment::{service_peer_authorization, DataSegment};
+use super::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::config;
+use crate::message::Message;
+use crate::node::SharedNode;
+use crate::raw_sample::RawSampleMut;
+use crate::service;
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::header::pipeline::Header;
+use crate::service::naming_scheme::{producer_data_segment_name, work_item_connection_name};
+use crate::service::port_factory::producer::LocalProducerConfig;
+use crate::service::static_config::pipeline::{DistributionStrategy, StaticConfig};
+use crate::work_item_mut::WorkItemMut;
+
+/// Defines a failure that can occur when a [`Producer`] is created with
+/// [`crate::service::port_factory::producer::PortFactoryProducer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProducerCreateError {
+    ExceedsMaxSupportedProducers,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ProducerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerCreateError {}
+
+/// Defines a failure that can occur in [`Producer::loan()`] and [`Producer::loan_uninit()`]
+/// or is part of [`ProducerSendError`] emitted in [`Producer::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ProducerLoanError {
+    OutOfMemory,
+    ExceedsMaxLoanedItems,
+    InternalFailure,
+}
+
+impl std::fmt::Display for ProducerLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerLoanError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`WorkItemMut`] is sent via [`WorkItemMut::send()`] or
+    /// [`Producer::send_copy()`].
+    ProducerSendError
+  entry:
+    ConnectionCorrupted,
+    AllConsumerBuffersFull
+  mapping:
+    ProducerLoanError to LoanError,
+    ConnectionFailure to ConnectionError
+}
+
+impl std::fmt::Display for ProducerSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerSendError {}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerConnection<Service: service::Service> {
+    pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) consumer_id: UniqueConsumerId,
+    // work items that were delivered to the consumer and are not yet returned, either buffered
+    // or borrowed by the consumer
+    pub(crate) number_of_pending_items: Cell<usize>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ProducerSharedState<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    consumer_connections: Vec<UnsafeCell<Option<ConsumerConnection<Service>>>>,
+    consumer_list_state: UnsafeCell<ContainerState<ConsumerDetails>>,
+    delivery_order: UnsafeCell<Vec<usize>>,
+    next_consumer: Cell<usize>,
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    global_config: Arc<config::Config>,
+    static_config: StaticConfig,
+    config: LocalProducerConfig,
+    producer_id: UniqueProducerId,
+    number_of_items: usize,
+    loan_counter: AtomicUsize,
+    item_id_counter: AtomicU64,
+}
+
+impl<Service: service::Service> ProducerSharedState<Service> {
+    fn get(&self, index: usize) -> &Option<ConsumerConnection<Service>> {
+        unsafe { &*self.consumer_connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<ConsumerConnection<Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.consumer_connections[index].get()
+        }
+    }
+
+    fn create_connection(
+        &self,
+        consumer_details: &ConsumerDetails,
+    ) -> Result<ConsumerConnection<Service>, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to consumer {:?} from producer {:?}",
+            consumer_details.consumer_id, self.producer_id
+        );
+
+        let sender = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&work_item_connection_name(self.producer_id, consumer_details.consumer_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.consumer_max_buffer_size)
+                                .receiver_max_borrowed_samples(self.static_config.consumer_max_borrowed_items)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(self.number_of_items)
+                                .create_sender(self.static_config.type_size),
+                        "{}.", msg);
+
+        Ok(ConsumerConnection {
+            sender,
+            consumer_id: consumer_details.consumer_id,
+            number_of_pending_items: Cell::new(0),
+        })
+    }
+
+    fn retrieve_returned_items(&self) {
+        for i in 0..self.consumer_connections.len() {
+            if let Some(ref connection) = self.get(i) {
+                loop {
+                    match connection.sender.reclaim() {
+                        Ok(Some(ptr_dist)) => {
+                            self.data_segment.release_sample(ptr_dist);
+                            connection
+                                .number_of_pending_items
+                                .set(connection.number_of_pending_items.get() - 1);
+                        }
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim work items from connection {:?} due to {:?}. This may lead to a situation where no more work items will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.get(i) {
+            // # SAFETY: the consumer no longer exist, therefore we can
+            //           reacquire all delivered work items
+            unsafe {
+                connection
+                    .sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+
+            *self.get_mut(i) = None;
+        }
+    }
+
+    fn populate_consumer_connections(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.consumer_connections.len(), None);
+
+        unsafe {
+            (*self.consumer_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(consumer_details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated =
+                                connection.consumer_id != consumer_details.consumer_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        match self.create_connection(consumer_details) {
+                            Ok(connection) => *self.get_mut(i) = Some(connection),
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new consumer {:?}.",
+                                    consumer_details.consumer_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.dynamic_storage
+                .get()
+                .pipeline()
+                .consumers
+                .update_state(&mut *self.consumer_list_state.get())
+        } {
+            fail!(from self, when self.populate_consumer_connections(),
+                "Connections were updated only partially since at least one connection to a Consumer port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn return_loaned_item(&self, distance_to_chunk: PointerOffset) {
+        self.data_segment.release_sample(distance_to_chunk);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    // Fills the delivery order with the indices of all connected consumers, starting with the
+    // one that is next in line. With least-loaded distribution the consumers are ordered by
+    // their pending work items, consumers with the same load remain in round-robin order.
+    #[allow(clippy::mut_from_ref)]
+    fn acquire_delivery_order(&self) -> &mut Vec<usize> {
+        let number_of_connections = self.consumer_connections.len();
+        #[deny(clippy::mut_from_ref)]
+        let delivery_order = unsafe { &mut *self.delivery_order.get() };
+        delivery_order.clear();
+
+        for n in 0..number_of_connections {
+            let i = (self.next_consumer.get() + n) % number_of_connections;
+            if self.get(i).is_some() {
+                delivery_order.push(i);
+            }
+        }
+
+        if self.static_config.distribution_strategy == DistributionStrategy::LeastLoaded {
+            delivery_order.sort_by_key(|i| match self.get(*i) {
+                Some(connection) => connection.number_of_pending_items.get(),
+                None => usize::MAX,
+            });
+        }
+
+        delivery_order
+    }
+
+    pub(crate) fn send_item(
+        &self,
+        distance_to_chunk: PointerOffset,
+    ) -> Result<Option<UniqueConsumerId>, ProducerSendError> {
+        let msg = "Unable to send work item";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_items();
+
+        let delivery_order = self.acquire_delivery_order();
+        if delivery_order.is_empty() {
+            return Ok(None);
+        }
+
+        for i in delivery_order.iter() {
+            if let Some(ref connection) = self.get(*i) {
+                match connection.sender.try_send(distance_to_chunk) {
+                    Ok(_) => {
+                        self.data_segment.borrow_sample(distance_to_chunk);
+                        connection
+                            .number_of_pending_items
+                            .set(connection.number_of_pending_items.get() + 1);
+                        self.next_consumer
+                            .set((*i + 1) % self.consumer_connections.len());
+                        return Ok(Some(connection.consumer_id));
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => (),
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with ProducerSendError::ConnectionCorrupted,
+                            "{} {:?} since a corrupted connection was detected with consumer {:?}.",
+                            msg, distance_to_chunk, connection.consumer_id);
+                    }
+                }
+            }
+        }
+
+        fail!(from self, with ProducerSendError::AllConsumerBuffersFull,
+            "{} {:?} since the buffers of all {} connected consumers are full.",
+            msg, distance_to_chunk, delivery_order.len());
+    }
+}
+
+/// The sending endpoint of a pipeline based communication. Every work item is delivered to
+/// exactly one [`crate::port::consumer::Consumer`].
+#[derive(Debug)]
+pub struct Producer<Service: service::Service, PayloadType: Debug + ZeroCopySend> {
+    pub(crate) shared_state: Arc<ProducerSharedState<Service>>,
+    dynamic_producer_handle: Option<ContainerHandle>,
+    _phantom_payload_type: PhantomData<PayloadType>,
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Drop
+    for Producer<Service, PayloadType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_producer_handle {
+            self.shared_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_producer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Producer<Service, PayloadType> {
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalProducerConfig,
+    ) -> Result<Self, ProducerCreateError> {
+        let msg = "Unable to create Producer port";
+        let origin = "Producer::new()";
+        let producer_id = UniqueProducerId::new();
+        let consumer_list = &service.state().dynamic_storage.get().pipeline().consumers;
+
+        let number_of_items =
+            static_config.required_amount_of_items_per_data_segment(config.max_loaned_items);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(&producer_data_segment_name(producer_id),
+                        service.state().global_config.as_ref(),
+                        number_of_items,
+                        // # SAFETY: type_size and type_alignment are acquired via
+                        //           core::mem::{size_of|align_of}
+                        unsafe { Layout::from_size_align_unchecked(
+                            static_config.type_size,
+                            static_config.type_alignment) },
+                        MemoryPlacement::default(),
+                        service_peer_authorization::<Service>(&service.state().dynamic_storage)),
+                with ProducerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let shared_state = Arc::new(ProducerSharedState {
+            data_segment,
+            consumer_connections: (0..consumer_list.capacity())
+                .map(|_| UnsafeCell::new(None))
+                .collect(),
+            consumer_list_state: unsafe { UnsafeCell::new(consumer_list.get_state()) },
+            delivery_order: UnsafeCell::new(Vec::with_capacity(consumer_list.capacity())),
+            next_consumer: Cell::new(0),
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            global_config: Arc::clone(&service.state().global_config),
+            static_config: static_config.clone(),
+            config,
+            producer_id,
+            number_of_items,
+            loan_counter: AtomicUsize::new(0),
+            item_id_counter: AtomicU64::new(0),
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_producer_handle: None,
+            _phantom_payload_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_consumer_connections() {
+            warn!(from new_self, "The new Producer port is unable to connect to every Consumer port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a producer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_producer_handle = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_producer_id(ProducerDetails {
+                producer_id,
+                number_of_items,
+                node_id: *service.state().shared_node.id(),
+            }) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ProducerCreateError::ExceedsMaxSupportedProducers,
+                            "{} since it would exceed the maximum supported amount of producers of {}.",
+                            msg, static_config.max_producers);
+            }
+        };
+
+        new_self.dynamic_producer_handle = Some(dynamic_producer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`]
+    pub fn id(&self) -> UniqueProducerId {
+        self.shared_state.producer_id
+    }
+
+    /// Copies the input `value` into a [`WorkItemMut`] and sends it to one of the connected
+    /// [`crate::port::consumer::Consumer`]s.
+    /// On success it returns the [`UniqueConsumerId`] of the
+    /// [`crate::port::consumer::Consumer`] that received the work item or [`None`] when no
+    /// [`crate::port::consumer::Consumer`] is connected. Otherwise a [`ProducerSendError`]
+    /// describing the failure is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .pipeline()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    ///
+    /// producer.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(
+        &self,
+        value: PayloadType,
+    ) -> Result<Option<UniqueConsumerId>, ProducerSendError> {
+        let msg = "Unable to send copy of work item";
+        let work_item = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a work item failed.", msg);
+
+        Ok(fail!(from self, when work_item.write_payload(value).send(),
+            "{} since the underlying send operation failed.", msg))
+    }
+
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`ProducerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .pipeline()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(42);
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<WorkItemMut<MaybeUninit<PayloadType>, Service>, ProducerLoanError> {
+        let msg = "Unable to loan work item";
+        let shared_state = &self.shared_state;
+
+        if shared_state.loan_counter.load(Ordering::Relaxed) >= shared_state.config.max_loaned_items
+        {
+            fail!(from self, with ProducerLoanError::ExceedsMaxLoanedItems,
+                "{} since already {} work items were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned work item to loan another work item.",
+                msg, shared_state.loan_counter.load(Ordering::Relaxed), shared_state.config.max_loaned_items);
+        }
+
+        shared_state.retrieve_returned_items();
+
+        match shared_state.data_segment.allocate() {
+            Ok(chunk) => {
+                let message =
+                    chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<PayloadType>>>;
+
+                let item_id = shared_state.item_id_counter.fetch_add(1, Ordering::Relaxed);
+
+                let work_item = unsafe {
+                    (*message).write(Message {
+                        header: Header::new(shared_state.producer_id, item_id),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<Header, MaybeUninit<PayloadType>>,
+                    )
+                };
+
+                shared_state.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(WorkItemMut::new(shared_state, work_item, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ProducerLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ProducerLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl<Service: service::Service, PayloadType: Default + Debug + ZeroCopySend>
+    Producer<Service, PayloadType>
+{
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Producer::loan_uninit`] can be used to loan a [`core::mem::MaybeUninit<PayloadType>`].
+    ///
+    /// On failure it returns [`ProducerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .pipeline()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    ///
+    /// let mut work_item = producer.loan()?;
+    /// *work_item.payload_mut() = 42;
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<WorkItemMut<PayloadType, Service>, ProducerLoanError> {
+        Ok(self.loan_uninit()?.write_payload(PayloadType::default()))
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> UpdateConnections
+    for Producer<Service, PayloadType>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
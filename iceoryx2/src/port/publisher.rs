@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -56,38 +58,40 @@
 
 use std::cell::UnsafeCell;
 use std::fmt::Debug;
-use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+use std::{marker::PhantomData, mem::MaybeUninit};
 
 use super::port_identifiers::UniquePublisherId;
 use crate::message::Message;
+use crate::node::{NodeState, SharedNode};
+use crate::port::details::data_segment::{service_peer_authorization, DataSegment};
 use crate::port::details::subscriber_connections::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::port::DegrationAction;
 use crate::raw_sample::RawSampleMut;
+use crate::sample_mut::SampleMut;
 use crate::service;
-use crate::service::config_scheme::data_segment_config;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
 use crate::service::static_config::publish_subscribe;
-use crate::{config, sample_mut::SampleMut};
+use crate::service::static_config::type_details::{Serialized, TypeVariant, Untyped};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
-use iceoryx2_cal::shared_memory::{
-    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
-};
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::serialize::SerializeError;
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
+    ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
 };
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
@@ -96,6 +100,7 @@ use iceoryx2_cal::zero_copy_connection::{
 pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     UnableToCreateDataSegment,
+    UnsupportedClockType,
 }
 
 impl std::fmt::Display for PublisherCreateError {
@@ -106,12 +111,15 @@ impl std::fmt::Display for PublisherCreateError {
 
 impl std::error::Error for PublisherCreateError {}
 
-/// Defines a failure that can occur in [`Publisher::loan()`] and [`Publisher::loan_uninit()`]
-/// or is part of [`PublisherSendError`] emitted in [`Publisher::send_copy()`].
+/// Defines a failure that can occur in [`Publisher::loan()`], [`Publisher::loan_uninit()`],
+/// [`Publisher::loan_slice()`] and [`Publisher::loan_slice_uninit()`] or is part of
+/// [`PublisherSendError`] emitted in [`Publisher::send_copy()`] and
+/// [`Publisher::send_slice_copy()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum PublisherLoanError {
     OutOfMemory,
     ExceedsMaxLoanedChunks,
+    ExceedsMaxLoanSize,
     InternalFailure,
 }
 
@@ -142,59 +150,54 @@ impl std::fmt::Display for PublisherSendError {
 
 impl std::error::Error for PublisherSendError {}
 
+enum_gen! {
+    /// Failure that can be emitted when a value is sent via [`Publisher::send()`] of a service
+    /// with a [`Serialized`] payload.
+    PublisherSendSerializedError
+  mapping:
+    SerializeError to SerializationFailure,
+    PublisherSendError to SendError
+}
+
+impl std::fmt::Display for PublisherSendSerializedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PublisherSendSerializedError {}
+
+const UNREGISTERED_PUBLISHER_INDEX: u32 = u32::MAX;
+
 #[derive(Debug)]
-pub(crate) struct DataSegment<Service: service::Service> {
-    sample_reference_counter: Vec<AtomicU64>,
-    memory: Service::SharedMemory,
-    message_size: usize,
-    message_type_layout: Layout,
+pub(crate) struct PublisherBackend<Service: service::Service> {
+    data_segment: DataSegment<Service>,
     port_id: UniquePublisherId,
     config: LocalPublisherConfig,
     dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
 
     subscriber_connections: SubscriberConnections<Service>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<usize>>>,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: AtomicUsize,
+    dynamic_publisher_index: AtomicU32,
+    sequence_number: AtomicU64,
     is_active: AtomicBool,
 }
 
-impl<Service: service::Service> DataSegment<Service> {
-    fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        distance_to_chunk / self.message_size
-    }
-
-    fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
+impl<Service: service::Service> PublisherBackend<Service> {
+    fn allocate(&self) -> Result<ShmPointer, ShmAllocationError> {
         self.retrieve_returned_samples();
 
-        let msg = "Unable to allocate Sample";
-        let ptr = self.memory.allocate(layout)?;
-        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
-            .fetch_add(1, Ordering::Relaxed)
-            != 0
-        {
-            fatal_panic!(from self,
-                "{} since the allocated sample is already in use! This should never happen!", msg);
-        }
-
-        Ok(ptr)
-    }
-
-    fn borrow_sample(&self, distance_to_chunk: usize) {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_add(1, Ordering::Relaxed);
-    }
-
-    fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
-            .fetch_sub(1, Ordering::Relaxed)
-            == 1
-        {
-            unsafe {
-                self.memory
-                    .deallocate(distance_to_chunk, self.message_type_layout);
+        match self.data_segment.allocate() {
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory))
+                if self.reclaim_samples_of_dead_subscribers() =>
+            {
+                self.data_segment.allocate()
             }
+            result => result,
         }
     }
 
@@ -204,7 +207,7 @@ impl<Service: service::Service> DataSegment<Service> {
                 Some(ref connection) => loop {
                     match connection.sender.reclaim() {
                         Ok(Some(ptr_dist)) => {
-                            self.release_sample(ptr_dist);
+                            self.data_segment.release_sample(ptr_dist);
                         }
                         Ok(None) => break,
                         Err(e) => {
@@ -224,16 +227,34 @@ impl<Service: service::Service> DataSegment<Service> {
             unsafe {
                 connection
                     .sender
-                    .acquire_used_offsets(|offset| self.release_sample(offset))
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
             };
 
+            while let Some(offset) = connection.pending_samples().pop() {
+                self.data_segment.release_sample(PointerOffset::new(offset));
+            }
+
             self.subscriber_connections.remove(i);
         }
     }
 
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
-        self.release_sample(distance_to_chunk);
-        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        self.data_segment.release_sample(distance_to_chunk);
+        let loan_counter = self.loan_counter.fetch_sub(1, Ordering::Relaxed) - 1;
+        self.share_loan_counter(loan_counter);
+    }
+
+    /// Stores the number of loaned samples in the dynamic config so that it can be observed by
+    /// other processes, for instance via the [`crate::introspection`].
+    fn share_loan_counter(&self, value: usize) {
+        let index = self.dynamic_publisher_index.load(Ordering::Relaxed);
+        if index != UNREGISTERED_PUBLISHER_INDEX {
+            self.dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publisher_loan_counter(index)
+                .store(value, Ordering::Relaxed);
+        }
     }
 
     fn add_sample_to_history(&self, address_to_chunk: usize) {
@@ -241,81 +262,229 @@ impl<Service: service::Service> DataSegment<Service> {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.borrow_sample(address_to_chunk);
+                self.data_segment
+                    .borrow_sample(PointerOffset::new(address_to_chunk));
                 match history.push_with_overflow(address_to_chunk) {
                     None => (),
-                    Some(old) => self.release_sample(PointerOffset::new(old)),
+                    Some(old) => self.data_segment.release_sample(PointerOffset::new(old)),
                 }
             }
         }
     }
 
-    fn deliver_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
-        self.retrieve_returned_samples();
+    fn filter_key(&self, address_to_chunk: usize) -> u64 {
+        let header =
+            (self.data_segment.payload_start_address() + address_to_chunk) as *const Header;
+        unsafe { (*header).filter_key() }
+    }
 
-        let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
+    /// Stores the sample in the retry queue of the connection. When the queue is full, the
+    /// oldest pending sample is discarded.
+    fn enqueue_pending_sample(&self, connection: &Connection<Service>, address_to_chunk: usize) {
+        self.data_segment
+            .borrow_sample(PointerOffset::new(address_to_chunk));
+        if let Some(old) = connection
+            .pending_samples()
+            .push_with_overflow(address_to_chunk)
+        {
+            warn!(from self,
+                "The oldest pending sample {:?} for subscriber {:?} is discarded since its retry queue is full.",
+                old, connection.subscriber_id);
+            self.data_segment.release_sample(PointerOffset::new(old));
+        }
+    }
+
+    /// Delivers the pending samples of the connection in the order they were sent until the
+    /// buffer of the subscriber is full. Returns the number of delivered samples.
+    fn deliver_pending_samples(&self, connection: &Connection<Service>) -> usize {
+        let pending_samples = connection.pending_samples();
+        let mut number_of_delivered_samples = 0;
+        while !pending_samples.is_empty() {
+            let address_to_chunk = pending_samples.get(0);
+            match connection
+                .sender
+                .try_send(PointerOffset::new(address_to_chunk))
+            {
+                Ok(overflow) => {
+                    // the sample was already borrowed when it was enqueued
+                    pending_samples.pop();
+                    number_of_delivered_samples += 1;
+
+                    if let Some(old) = overflow {
+                        self.data_segment.release_sample(old)
+                    }
+                }
+                Err(ZeroCopySendError::ReceiveBufferFull)
+                | Err(ZeroCopySendError::UsedChunkListFull) => break,
+                Err(ZeroCopySendError::ConnectionCorrupted) => {
+                    warn!(from self,
+                        "Unable to deliver pending samples since a corrupted connection was detected with subscriber {:?}.",
+                        connection.subscriber_id);
+                    break;
+                }
             }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
+        }
+
+        number_of_delivered_samples
+    }
+
+    fn deliver_all_pending_samples(&self) -> usize {
+        let mut number_of_delivered_samples = 0;
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(ref connection) = self.subscriber_connections.get(i) {
+                number_of_delivered_samples += self.deliver_pending_samples(connection);
             }
-        };
+        }
+        number_of_delivered_samples
+    }
+
+    pub(crate) fn flush_pending_samples(&self) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to deliver pending samples";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                "{} since the connections could not be updated.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_samples();
+        Ok(self.deliver_all_pending_samples())
+    }
+
+    fn deliver_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
+        self.retrieve_returned_samples();
+        if self.config.unable_to_deliver_strategy == UnableToDeliverStrategy::RetryLater {
+            self.deliver_all_pending_samples();
+        }
 
+        let filter_key = self.filter_key(address_to_chunk);
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
-            match self.subscriber_connections.get(i) {
-                Some(ref connection) => {
-                    match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
-                        Err(ZeroCopySendError::ReceiveBufferFull)
-                        | Err(ZeroCopySendError::UsedChunkListFull) => {
-                            /* causes no problem
-                             *   blocking_send => can never happen
-                             *   try_send => we tried and expect that the buffer is full
-                             * */
-                        }
-                        Err(ZeroCopySendError::ConnectionCorrupted) => {
-                            match &self.config.degration_callback {
-                                Some(c) => match c.call(
-                                    self.static_config.clone(),
-                                    self.port_id,
-                                    connection.subscriber_id,
-                                ) {
-                                    DegrationAction::Ignore => (),
-                                    DegrationAction::Warn => {
+            let mut adaptive_wait = None;
+            loop {
+                let mut is_buffer_full = false;
+                match self.subscriber_connections.get(i) {
+                    // samples that the subscriber filtered out must not occupy its buffer
+                    Some(ref connection) if !connection.filter.accepts(filter_key) => (),
+                    // the sample must not overtake the samples that are still pending
+                    Some(ref connection) if connection.has_pending_samples() => {
+                        self.enqueue_pending_sample(connection, address_to_chunk);
+                        is_buffer_full = true;
+                    }
+                    Some(ref connection) => {
+                        match connection
+                            .sender
+                            .try_send(PointerOffset::new(address_to_chunk))
+                        {
+                            Err(ZeroCopySendError::ReceiveBufferFull)
+                            | Err(ZeroCopySendError::UsedChunkListFull) => {
+                                if self.config.unable_to_deliver_strategy
+                                    == UnableToDeliverStrategy::RetryLater
+                                {
+                                    self.enqueue_pending_sample(connection, address_to_chunk);
+                                }
+                                is_buffer_full = true;
+                            }
+                            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                                match &self.config.degration_callback {
+                                    Some(c) => match c.call(
+                                        self.static_config.clone(),
+                                        self.port_id,
+                                        connection.subscriber_id,
+                                    ) {
+                                        DegrationAction::Ignore => (),
+                                        DegrationAction::Warn => {
+                                            error!(from self,
+                                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                                address_to_chunk, connection.subscriber_id);
+                                        }
+                                        DegrationAction::Fail => {
+                                            fail!(from self, with PublisherSendError::ConnectionCorrupted,
+                                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                                address_to_chunk, connection.subscriber_id);
+                                        }
+                                    },
+                                    None => {
                                         error!(from self,
                                             "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
                                             address_to_chunk, connection.subscriber_id);
                                     }
-                                    DegrationAction::Fail => {
-                                        fail!(from self, with PublisherSendError::ConnectionCorrupted,
-                                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                            address_to_chunk, connection.subscriber_id);
-                                    }
-                                },
-                                None => {
-                                    error!(from self,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
                                 }
                             }
-                        }
-                        Ok(overflow) => {
-                            self.borrow_sample(address_to_chunk);
-                            number_of_recipients += 1;
+                            Ok(overflow) => {
+                                self.data_segment
+                                    .borrow_sample(PointerOffset::new(address_to_chunk));
+                                number_of_recipients += 1;
 
-                            if let Some(old) = overflow {
-                                self.release_sample(old)
+                                if let Some(old) = overflow {
+                                    self.data_segment.release_sample(old)
+                                }
                             }
                         }
                     }
+                    None => (),
                 }
-                None => (),
+
+                // a dead subscriber never frees its buffer, therefore its liveness is checked
+                // periodically, also while the publisher is blocking on it
+                if is_buffer_full
+                    && !self.reclaim_samples_of_dead_subscriber(i)
+                    && self.config.unable_to_deliver_strategy == UnableToDeliverStrategy::Block
+                {
+                    let adaptive_wait = adaptive_wait.get_or_insert_with(|| {
+                        fatal_panic!(from self, when AdaptiveWaitBuilder::new().create(),
+                            "Unable to create the adaptive wait that is required to block until the subscriber has consumed a sample.")
+                    });
+                    fatal_panic!(from self, when adaptive_wait.wait(),
+                        "Unable to wait until the subscriber has consumed a sample.");
+                    continue;
+                }
+                break;
             }
         }
         Ok(number_of_recipients)
     }
 
+    /// Checks with the node monitoring whether the [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// of the connection belongs to a dead [`Node`](crate::node::Node). In that case every
+    /// sample the subscriber still owns is reclaimed via the used chunk list of the connection
+    /// and the connection is removed. The check is rate limited per connection. Returns true
+    /// when the subscriber was dead.
+    ///
+    /// The stale resources of the dead [`Node`](crate::node::Node) are not removed, this is
+    /// done with [`DeadNodeView::remove_stale_resources()`](crate::node::DeadNodeView::remove_stale_resources()).
+    fn reclaim_samples_of_dead_subscriber(&self, i: usize) -> bool {
+        let (subscriber_id, node_id) = match self.subscriber_connections.get(i) {
+            Some(connection) if connection.is_liveness_check_due() => {
+                (connection.subscriber_id, connection.subscriber_node_id)
+            }
+            _ => return false,
+        };
+
+        match NodeState::<Service>::acquire(node_id, self.subscriber_connections.config()) {
+            Ok(Some(NodeState::Dead(_))) => {
+                warn!(from self, "The subscriber {:?} died. Reclaiming all samples it still owns.", subscriber_id);
+                self.remove_connection(i);
+                true
+            }
+            Ok(_) => false,
+            Err(e) => {
+                warn!(from self, "Unable to acquire the state of the node {:?} of the subscriber {:?} ({:?}).",
+                    node_id, subscriber_id, e);
+                false
+            }
+        }
+    }
+
+    fn reclaim_samples_of_dead_subscribers(&self) -> bool {
+        let mut has_reclaimed_samples = false;
+        for i in 0..self.subscriber_connections.len() {
+            has_reclaimed_samples |= self.reclaim_samples_of_dead_subscriber(i);
+        }
+        has_reclaimed_samples
+    }
+
     fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
         let mut visited_indices = vec![];
         visited_indices.resize(self.subscriber_connections.capacity(), None);
@@ -405,9 +574,14 @@ impl<Service: service::Service> DataSegment<Service> {
                 let history = unsafe { &mut *history.get() };
                 for i in 0..history.len() {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
+                    if !connection.filter.accepts(self.filter_key(ptr_distance)) {
+                        continue;
+                    }
 
                     match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => self.borrow_sample(ptr_distance),
+                        Ok(_) => self
+                            .data_segment
+                            .borrow_sample(PointerOffset::new(ptr_distance)),
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                         }
@@ -427,6 +601,16 @@ impl<Service: service::Service> DataSegment<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        // the clock was verified when the publisher was created
+        let timestamp = Time::now_with_clock(self.config.clock_type).unwrap_or_default();
+        let header = (self.data_segment.payload_start_address() + address_to_chunk) as *mut Header;
+        unsafe {
+            (*header).set_send_details(
+                self.sequence_number.fetch_add(1, Ordering::Relaxed),
+                timestamp,
+            )
+        };
+
         self.add_sample_to_history(address_to_chunk);
         self.deliver_sample(address_to_chunk)
     }
@@ -434,16 +618,23 @@ impl<Service: service::Service> DataSegment<Service> {
 
 /// Sending endpoint of a publish-subscriber based communication.
 #[derive(Debug)]
-pub struct Publisher<Service: service::Service, MessageType: Debug> {
-    pub(crate) data_segment: Arc<DataSegment<Service>>,
+pub struct Publisher<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> {
+    pub(crate) backend: Arc<PublisherBackend<Service>>,
     dynamic_publisher_handle: Option<ContainerHandle>,
     _phantom_message_type: PhantomData<MessageType>,
 }
 
-impl<Service: service::Service, MessageType: Debug> Drop for Publisher<Service, MessageType> {
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> Drop
+    for Publisher<Service, MessageType>
+{
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_publisher_handle {
-            self.data_segment
+            // samples that are returned after the publisher is gone must not modify the loan
+            // counter of a publisher that reuses the slot in the dynamic config
+            self.backend
+                .dynamic_publisher_index
+                .store(UNREGISTERED_PUBLISHER_INDEX, Ordering::Relaxed);
+            self.backend
                 .dynamic_storage
                 .get()
                 .publish_subscribe()
@@ -452,11 +643,13 @@ impl<Service: service::Service, MessageType: Debug> Drop for Publisher<Service,
     }
 }
 
-impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageType> {
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
+    Publisher<Service, MessageType>
+{
     pub(crate) fn new(
         service: &Service,
         static_config: &publish_subscribe::StaticConfig,
-        config: LocalPublisherConfig,
+        mut config: LocalPublisherConfig,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
@@ -473,25 +666,43 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
             .state()
             .static_config
             .messaging_pattern
-            .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+            .required_amount_of_samples_per_data_segment(config.max_loaned_samples)
+            + match config.unable_to_deliver_strategy {
+                // every subscriber can have as many pending samples as fit into its buffer
+                UnableToDeliverStrategy::RetryLater => {
+                    static_config.max_subscribers * static_config.subscriber_max_buffer_size
+                }
+                UnableToDeliverStrategy::Block | UnableToDeliverStrategy::DiscardSample => 0,
+            };
+
+        if static_config.type_details().variant() == TypeVariant::FixedSize {
+            config.max_slice_len = 1;
+        }
+        let max_slice_len = config.max_slice_len;
+
+        fail!(from origin, when Time::now_with_clock(config.clock_type),
+                with PublisherCreateError::UnsupportedClockType,
+                "{} since the clock {:?} that shall be used for the sample timestamps is not supported.", msg, config.clock_type);
 
-        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config.as_ref(), number_of_samples, static_config),
+        let sample_layout = fail!(from origin, when static_config.message_layout(max_slice_len),
+                with PublisherCreateError::UnableToCreateDataSegment,
+                "{} since the sample size for a max slice length of {} exceeds the supported range.", msg, max_slice_len);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(&data_segment_name(port_id),
+                        service.state().global_config.as_ref(),
+                        number_of_samples,
+                        sample_layout,
+                        config.memory_placement,
+                        service_peer_authorization::<Service>(&service.state().dynamic_storage)),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
-        let data_segment = Arc::new(DataSegment {
+        let backend = Arc::new(PublisherBackend {
             is_active: AtomicBool::new(true),
-            memory: data_segment,
-            message_size: std::mem::size_of::<Message<Header, MessageType>>(),
-            message_type_layout: Layout::new::<MessageType>(),
-            sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
-                    v.push(AtomicU64::new(0));
-                }
-                v
-            },
+            data_segment,
             dynamic_storage,
+            _shared_node: service.state().shared_node.clone(),
             port_id,
             subscriber_connections: SubscriberConnections::new(
                 subscriber_list.capacity(),
@@ -499,6 +710,7 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
                 port_id,
                 static_config,
                 number_of_samples,
+                sample_layout.size(),
             ),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
@@ -508,15 +720,17 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
             },
             static_config: service.state().static_config.clone(),
             loan_counter: AtomicUsize::new(0),
+            dynamic_publisher_index: AtomicU32::new(UNREGISTERED_PUBLISHER_INDEX),
+            sequence_number: AtomicU64::new(0),
         });
 
         let mut new_self = Self {
-            data_segment,
+            backend,
             dynamic_publisher_handle: None,
             _phantom_message_type: PhantomData,
         };
 
-        if let Err(e) = new_self.data_segment.populate_subscriber_channels() {
+        if let Err(e) = new_self.backend.populate_subscriber_channels() {
             warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
         }
 
@@ -531,7 +745,9 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
             .publish_subscribe()
             .add_publisher_id(PublisherDetails {
                 publisher_id: port_id,
+                node_id: *service.state().shared_node.id(),
                 number_of_samples,
+                max_slice_len,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -542,43 +758,121 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        new_self
+            .backend
+            .dynamic_publisher_index
+            .store(dynamic_publisher_handle.index(), Ordering::Relaxed);
 
         Ok(new_self)
     }
 
-    fn create_data_segment(
-        port_id: UniquePublisherId,
-        global_config: &config::Config,
-        number_of_samples: usize,
-        static_config: &publish_subscribe::StaticConfig,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let allocator_config = shm_allocator::pool_allocator::Config {
-            bucket_layout:
-                // # SAFETY: type_size and type_alignment are acquired via
-                //           core::mem::{size_of|align_of}
-                unsafe {
-                Layout::from_size_align_unchecked(
-                    static_config.type_size,
-                    static_config.type_alignment,
-                )
-            },
-        };
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.backend.port_id
+    }
+
+    /// Delivers the [`crate::sample::Sample`]s that could not be delivered with
+    /// [`UnableToDeliverStrategy::RetryLater`] since the buffer of a
+    /// [`crate::port::subscriber::Subscriber`] was full. Pending samples are delivered in the
+    /// order they were sent, the ones that still do not fit remain pending. On success it
+    /// returns the number of delivered [`crate::sample::Sample`]s, otherwise a
+    /// [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher()
+    ///     .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryLater)
+    ///     .create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    /// let number_of_delivered_samples = publisher.flush_pending()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush_pending(&self) -> Result<usize, PublisherSendError> {
+        self.backend.flush_pending_samples()
+    }
 
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(static_config.type_size * number_of_samples + static_config.type_alignment - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
+    fn allocate(&self) -> Result<ShmPointer, PublisherLoanError> {
+        let msg = "Unable to loan Sample";
+
+        if self.backend.loan_counter.load(Ordering::Relaxed)
+            >= self.backend.config.max_loaned_samples
+        {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
+                msg, self.backend.loan_counter.load(Ordering::Relaxed), self.backend.config.max_loaned_samples);
+        }
+
+        match self.backend.allocate() {
+            Ok(chunk) => {
+                let loan_counter = self.backend.loan_counter.fetch_add(1, Ordering::Relaxed) + 1;
+                self.backend.share_loan_counter(loan_counter);
+                Ok(chunk)
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with PublisherLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
     }
 
-    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
-    pub fn id(&self) -> UniquePublisherId {
-        self.data_segment.port_id
+    fn loan_raw_bytes_uninit(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<[MaybeUninit<u8>], Service>, PublisherLoanError> {
+        let msg = "Unable to loan Sample as raw bytes";
+
+        if self.backend.config.max_slice_len < number_of_elements {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                "{} since the requested number of elements {} exceeds the max slice length of {}.",
+                msg, number_of_elements, self.backend.config.max_slice_len);
+        }
+
+        let static_config = self.backend.static_config.publish_subscribe();
+        let payload_offset = static_config.payload_offset();
+        let number_of_bytes = number_of_elements * static_config.type_size();
+
+        let chunk = self.allocate()?;
+        let header = chunk.data_ptr as *mut MaybeUninit<Header>;
+
+        let sample = unsafe {
+            (*header).write(Header::new(self.backend.port_id, number_of_elements as u64));
+            RawSampleMut::new_from_parts_unchecked(
+                header as *mut Header,
+                core::ptr::slice_from_raw_parts_mut(
+                    chunk.data_ptr.add(payload_offset) as *mut MaybeUninit<u8>,
+                    number_of_bytes,
+                ),
+            )
+        };
+
+        Ok(SampleMut::new(&self.backend, sample, chunk.offset))
     }
+}
 
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend> Publisher<Service, MessageType> {
     /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
     /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
     /// the data, otherwise a [`PublisherSendError`] describing the failure.
@@ -590,7 +884,9 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -608,7 +904,7 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
 
         sample.payload_mut().write(value);
         Ok(
-            fail!(from self, when self.data_segment.send_sample(sample.offset_to_chunk.value()),
+            fail!(from self, when self.backend.send_sample(sample.offset_to_chunk.value()),
             "{} since the underlying send operation failed.", msg),
         )
     }
@@ -625,7 +921,9 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -643,56 +941,24 @@ impl<Service: service::Service, MessageType: Debug> Publisher<Service, MessageTy
     pub fn loan_uninit(
         &self,
     ) -> Result<SampleMut<MaybeUninit<MessageType>, Service>, PublisherLoanError> {
-        let msg = "Unable to loan Sample";
-
-        if self.data_segment.loan_counter.load(Ordering::Relaxed)
-            >= self.data_segment.config.max_loaned_samples
-        {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedChunks,
-                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
-                msg, self.data_segment.loan_counter.load(Ordering::Relaxed), self.data_segment.config.max_loaned_samples);
-        }
-
-        match self
-            .data_segment
-            .allocate(Layout::new::<Message<Header, MessageType>>())
-        {
-            Ok(chunk) => {
-                let message =
-                    chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<MessageType>>>;
-
-                let sample = unsafe {
-                    (*message).write(Message {
-                        header: Header::new(self.data_segment.port_id),
-                        data: MaybeUninit::uninit(),
-                    });
-                    RawSampleMut::new_unchecked(
-                        message as *mut Message<Header, MaybeUninit<MessageType>>,
-                    )
-                };
+        let chunk = self.allocate()?;
+        let message = chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<MessageType>>>;
+
+        let sample = unsafe {
+            (*message).write(Message {
+                header: Header::new(self.backend.port_id, 1),
+                data: MaybeUninit::uninit(),
+            });
+            RawSampleMut::new_unchecked(message as *mut Message<Header, MaybeUninit<MessageType>>)
+        };
 
-                self.data_segment
-                    .loan_counter
-                    .fetch_add(1, Ordering::Relaxed);
-                Ok(SampleMut::new(&self.data_segment, sample, chunk.offset))
-            }
-            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
-                fail!(from self, with PublisherLoanError::OutOfMemory,
-                    "{} since the underlying shared memory is out of memory.", msg);
-            }
-            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
-            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
-            }
-            Err(v) => {
-                fail!(from self, with PublisherLoanError::InternalFailure,
-                    "{} since an internal failure occurred ({:?}).", msg, v);
-            }
-        }
+        Ok(SampleMut::new(&self.backend, sample, chunk.offset))
     }
 }
 
-impl<Service: service::Service, MessageType: Default + Debug> Publisher<Service, MessageType> {
+impl<Service: service::Service, MessageType: Default + Debug + ZeroCopySend>
+    Publisher<Service, MessageType>
+{
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`]
     /// and initialize it with the default value. This can be a performance hit and [`Publisher::loan_uninit`]
     /// can be used to loan a [`core::mem::MaybeUninit<MessageType>`].
@@ -706,7 +972,9 @@ impl<Service: service::Service, MessageType: Default + Debug> Publisher<Service,
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -726,10 +994,287 @@ impl<Service: service::Service, MessageType: Default + Debug> Publisher<Service,
     }
 }
 
-impl<Service: service::Service, MessageType: Debug> UpdateConnections
+impl<Service: service::Service, ElementType: Debug + ZeroCopySend>
+    Publisher<Service, [ElementType]>
+{
+    /// Returns the maximum length of a slice that can be loaned with
+    /// [`Publisher::loan_slice()`] or [`Publisher::loan_slice_uninit()`].
+    pub fn max_slice_len(&self) -> usize {
+        self.backend.config.max_slice_len
+    }
+
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_slice_len(16).create()?;
+    ///
+    /// publisher.send_slice_copy(&[1, 2, 3, 4])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy(&self, value: &[ElementType]) -> Result<usize, PublisherSendError>
+    where
+        ElementType: Copy,
+    {
+        let msg = "Unable to send copy of slice";
+        let sample = fail!(from self, when self.loan_slice_uninit(value.len()),
+                                    "{} since the loan of a sample failed.", msg);
+
+        let sample = sample.write_from_slice(value);
+        Ok(
+            fail!(from self, when self.backend.send_sample(sample.offset_to_chunk.value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] with a slice payload of `slice_len`
+    /// elements from the underlying data segment of the [`Publisher`]. The user has to
+    /// initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure. If `slice_len`
+    /// exceeds [`Publisher::max_slice_len()`] it fails with
+    /// [`PublisherLoanError::ExceedsMaxLoanSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_slice_len(16).create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(8)?;
+    /// let sample = sample.write_from_fn(|index| index as u64 + 42);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_uninit(
+        &self,
+        slice_len: usize,
+    ) -> Result<SampleMut<[MaybeUninit<ElementType>], Service>, PublisherLoanError> {
+        let msg = "Unable to loan slice Sample";
+
+        if self.max_slice_len() < slice_len {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                "{} since the requested slice length of {} exceeds the max slice length of {}.",
+                msg, slice_len, self.max_slice_len());
+        }
+
+        let chunk = self.allocate()?;
+        let header = chunk.data_ptr as *mut MaybeUninit<Header>;
+        let message = core::ptr::slice_from_raw_parts_mut(
+            chunk.data_ptr as *mut MaybeUninit<ElementType>,
+            slice_len,
+        ) as *mut Message<Header, [MaybeUninit<ElementType>]>;
+
+        let sample = unsafe {
+            (*header).write(Header::new(self.backend.port_id, slice_len as u64));
+            RawSampleMut::new_unchecked(message)
+        };
+
+        Ok(SampleMut::new(&self.backend, sample, chunk.offset))
+    }
+}
+
+impl<Service: service::Service, ElementType: Default + Debug + ZeroCopySend>
+    Publisher<Service, [ElementType]>
+{
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] with a slice payload of `slice_len`
+    /// elements from the underlying data segment of the [`Publisher`] and initializes every
+    /// element with the default value. This can be a performance hit and
+    /// [`Publisher::loan_slice_uninit`] can be used to loan a slice of
+    /// [`core::mem::MaybeUninit<ElementType>`].
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_slice_len(16).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(4)?;
+    /// sample.payload_mut()[2] = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice(
+        &self,
+        slice_len: usize,
+    ) -> Result<SampleMut<[ElementType], Service>, PublisherLoanError> {
+        Ok(self
+            .loan_slice_uninit(slice_len)?
+            .write_from_fn(|_| ElementType::default()))
+    }
+}
+
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> UpdateConnections
     for Publisher<Service, MessageType>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
-        self.data_segment.update_connections()
+        self.backend.update_connections()
+    }
+}
+
+impl<Service: service::Service> Publisher<Service, Untyped> {
+    /// Returns the maximum number of elements that can be loaned with
+    /// [`Publisher::loan_bytes_uninit()`]. It is always 1 when the service has a
+    /// [`TypeVariant::FixedSize`] payload.
+    pub fn max_slice_len(&self) -> usize {
+        self.backend.config.max_slice_len
+    }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of
+    /// the [`Publisher`] whose payload consists of `number_of_elements` elements of the type
+    /// described by the services
+    /// [`TypeDetails`](crate::service::static_config::type_details::TypeDetails) and provides
+    /// it as uninitialized bytes. The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure. If
+    /// `number_of_elements` exceeds [`Publisher::max_slice_len()`] it fails with
+    /// [`PublisherLoanError::ExceedsMaxLoanSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::static_config::type_details::TypeDetails;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .untyped(&TypeDetails::from_type::<u64>())
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_bytes_uninit(1)?;
+    /// let sample = sample.write_from_slice(&1234u64.to_ne_bytes());
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_bytes_uninit(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<[MaybeUninit<u8>], Service>, PublisherLoanError> {
+        self.loan_raw_bytes_uninit(number_of_elements)
+    }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] like [`Publisher::loan_bytes_uninit()`]
+    /// but initializes all bytes of the payload with zero.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    pub fn loan_bytes(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<[u8], Service>, PublisherLoanError> {
+        Ok(self
+            .loan_bytes_uninit(number_of_elements)?
+            .write_from_fn(|_| 0))
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    > Publisher<Service, Serialized<T, Serializer>>
+{
+    /// Returns the maximum number of bytes a serialized value can occupy so that it can be sent
+    /// with [`Publisher::send()`].
+    pub fn max_serialized_size(&self) -> usize {
+        // the serialized value is stored as slice of bytes
+        self.backend.config.max_slice_len
+    }
+
+    /// Serializes `value` into a [`crate::sample_mut::SampleMut`] and delivers it. On success it
+    /// returns the number of [`crate::port::subscriber::Subscriber`]s that received the data,
+    /// otherwise a [`PublisherSendSerializedError`] describing the failure. When the serialized
+    /// value exceeds [`Publisher::max_serialized_size()`] it fails with
+    /// [`PublisherLoanError::ExceedsMaxLoanSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::toml::Toml;
+    /// use std::collections::HashMap;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .serialized::<HashMap<String, String>, Toml>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_serialized_size(1024).create()?;
+    ///
+    /// let mut settings = HashMap::new();
+    /// settings.insert("log_level".to_string(), "debug".to_string());
+    /// publisher.send(&settings)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(&self, value: &T) -> Result<usize, PublisherSendSerializedError> {
+        let msg = "Unable to send serialized value";
+        let bytes = fail!(from self, when Serializer::serialize(value),
+                            "{} since the value could not be serialized.", msg);
+
+        let sample = fail!(from self, when self.loan_raw_bytes_uninit(bytes.len()).map_err(PublisherSendError::LoanError),
+                            "{} since the loan of a sample with {} bytes failed.", msg, bytes.len());
+        let sample = sample.write_from_slice(&bytes);
+
+        Ok(
+            fail!(from self, when self.backend.send_sample(sample.offset_to_chunk.value()),
+            "{} since the underlying send operation failed.", msg),
+        )
     }
 }
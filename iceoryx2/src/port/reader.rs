@@ -0,0 +1,208 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .create()?;
+//!
+//! let reader = blackboard.reader().create()?;
+//!
+//! let entry = reader.entry::<f32>(&0)?;
+//! println!("current value: {}", entry.get());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::SharedNode;
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service;
+use crate::service::dynamic_config::blackboard::{value_type_id, ReaderDetails};
+use crate::service::static_config::type_details::TypeDetails;
+
+/// Failures that can occur when a new [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Failures that can occur when an [`EntryHandle`] is acquired with [`Reader::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleError {
+    /// The blackboard does not contain an entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry exists but stores a value of a different type.
+    WrongValueType,
+}
+
+impl std::fmt::Display for EntryHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for EntryHandleError {}
+
+/// Reading endpoint of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Reads the latest value of an entry lock-free via an [`EntryHandle`].
+#[derive(Debug)]
+pub struct Reader<
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+> {
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    dynamic_reader_handle: Option<ContainerHandle>,
+    reader_id: UniqueReaderId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Drop for Reader<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            self.dynamic_storage
+                .get()
+                .blackboard()
+                .release_reader_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Reader<Service, KeyType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Unable to create Reader port";
+        let origin = "Reader::new()";
+        let reader_id = UniqueReaderId::new();
+
+        let mut new_self = Self {
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            dynamic_reader_handle: None,
+            reader_id,
+            _key: PhantomData,
+        };
+
+        let dynamic_reader_handle =
+            match new_self
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_reader_id(ReaderDetails {
+                    reader_id,
+                    node_id: *service.state().shared_node.id(),
+                }) {
+                Some(handle) => handle,
+                None => {
+                    fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, service.state().static_config.blackboard().max_readers);
+                }
+            };
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    /// Returns an [`EntryHandle`] to read the value of the entry with the provided `key`.
+    pub fn entry<ValueType: Copy + ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandle<'_, ValueType>, EntryHandleError> {
+        let msg = "Unable to acquire entry handle";
+        let blackboard = self.dynamic_storage.get().blackboard();
+
+        let entry = match unsafe { blackboard.entry(key) } {
+            Some(entry) => entry,
+            None => {
+                fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                    "{} since the blackboard does not contain an entry with the key {:?}.", msg, key);
+            }
+        };
+
+        if entry.value_type
+            != value_type_id::<Service::ServiceNameHasher>(&TypeDetails::from_type::<ValueType>())
+        {
+            fail!(from self, with EntryHandleError::WrongValueType,
+                "{} since the entry with the key {:?} does not store values of type {}.",
+                msg, key, std::any::type_name::<ValueType>());
+        }
+
+        Ok(EntryHandle {
+            atomic: unsafe { blackboard.entry_value::<ValueType>(entry) },
+        })
+    }
+}
+
+/// Reads the value of a single blackboard entry. Acquired with [`Reader::entry()`].
+pub struct EntryHandle<'a, ValueType: Copy + ZeroCopySend + 'static> {
+    atomic: &'a UnrestrictedAtomic<ValueType>,
+}
+
+impl<ValueType: Copy + ZeroCopySend + 'static> Debug for EntryHandle<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandle<{}> {{ }}",
+            std::any::type_name::<ValueType>()
+        )
+    }
+}
+
+impl<ValueType: Copy + ZeroCopySend + 'static> EntryHandle<'_, ValueType> {
+    /// Returns a copy of the latest value of the entry.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+}
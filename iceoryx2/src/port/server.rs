@@ -0,0 +1,575 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("received request: {:?}", *request);
+//!
+//!     // the response is delivered only to the client that sent the request
+//!     let response = request.loan_uninit()?;
+//!     let response = response.write_payload(*request * 2);
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_cal::zero_copy_connection::*;
+
+use super::details::data_segment::{service_peer_authorization, DataSegment};
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::active_request::ActiveRequest;
+use crate::config;
+use crate::message::Message;
+use crate::node::SharedNode;
+use crate::raw_sample::RawSample;
+use crate::service;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::RequestHeader;
+use crate::service::naming_scheme::{
+    client_data_segment_name, request_connection_name, response_connection_name,
+    server_data_segment_name,
+};
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::request_response::StaticConfig;
+
+/// Defines a failure that can occur when a [`Server`] is created with
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    ExceedsMaxSupportedServers,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// Defines a failure that can occur in [`ActiveRequest::loan()`] and
+/// [`ActiveRequest::loan_uninit()`] or is part of [`ServerSendError`] emitted in
+/// [`ActiveRequest::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ServerLoanError {
+    OutOfMemory,
+    ExceedsMaxLoanedResponses,
+    InternalFailure,
+}
+
+impl std::fmt::Display for ServerLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerLoanError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::response_mut::ResponseMut`] is sent via
+    /// [`crate::response_mut::ResponseMut::send()`] or [`ActiveRequest::send_copy()`].
+    ServerSendError
+  entry:
+    ConnectionBrokenSinceClientNoLongerExists,
+    ConnectionCorrupted,
+    ReceiveBufferFull
+  mapping:
+    ServerLoanError to LoanError
+}
+
+impl std::fmt::Display for ServerSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerSendError {}
+
+/// Defines the failure that can occur when receiving an [`ActiveRequest`] with
+/// [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveError {
+    ExceedsMaxBorrowedRequests,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ServerReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerReceiveError {}
+
+#[derive(Debug)]
+pub(crate) struct ClientConnection<Service: service::Service> {
+    pub(crate) request_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) response_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) client_id: UniqueClientId,
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerSharedState<Service: service::Service> {
+    pub(crate) data_segment: DataSegment<Service>,
+    client_connections: Vec<UnsafeCell<Option<ClientConnection<Service>>>>,
+    client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    global_config: Arc<config::Config>,
+    static_config: StaticConfig,
+    pub(crate) config: LocalServerConfig,
+    pub(crate) server_id: UniqueServerId,
+    number_of_responses: usize,
+    pub(crate) loan_counter: AtomicUsize,
+}
+
+impl<Service: service::Service> ServerSharedState<Service> {
+    pub(crate) fn get(&self, index: usize) -> &Option<ClientConnection<Service>> {
+        unsafe { &*self.client_connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<ClientConnection<Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.client_connections[index].get()
+        }
+    }
+
+    fn create_connection(
+        &self,
+        client_details: &ClientDetails,
+    ) -> Result<ClientConnection<Service>, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to client {:?} from server {:?}",
+            client_details.client_id, self.server_id
+        );
+
+        let request_receiver = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&request_connection_name(client_details.client_id, self.server_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(self.static_config.server_max_borrowed_requests)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(client_details.number_of_requests)
+                                .create_receiver(self.static_config.request_type_size),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_sender = fail!(from self,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&response_connection_name(self.server_id, client_details.client_id))
+                                .config(&connection_config::<Service>(self.global_config.as_ref()))
+                                .buffer_size(self.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(self.static_config.client_max_borrowed_responses)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(self.number_of_responses)
+                                .create_sender(self.static_config.response_type_size),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from self,
+                        when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                            Builder::new(&client_data_segment_name(client_details.client_id))
+                                .config(&data_segment_config::<Service>(self.global_config.as_ref()))
+                                .open(),
+                        "{} since the clients data segment could not be mapped into the process.", msg);
+
+        Ok(ClientConnection {
+            request_receiver,
+            response_sender,
+            data_segment,
+            client_id: client_details.client_id,
+        })
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.data_segment.release_sample(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.get(i) {
+            // # SAFETY: the client no longer exist, therefore we can
+            //           reacquire all delivered responses
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+
+            *self.get_mut(i) = None;
+        }
+    }
+
+    fn populate_client_connections(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.len(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(client_details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.client_id != client_details.client_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        match self.create_connection(client_details) {
+                            Ok(connection) => *self.get_mut(i) = Some(connection),
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new client {:?}.",
+                                    client_details.client_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.dynamic_storage
+                .get()
+                .request_response()
+                .clients
+                .update_state(&mut *self.client_list_state.get())
+        } {
+            fail!(from self, when self.populate_client_connections(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn return_loaned_response(&self, distance_to_chunk: PointerOffset) {
+        self.data_segment.release_sample(distance_to_chunk);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_response(
+        &self,
+        channel_id: usize,
+        client_id: UniqueClientId,
+        distance_to_chunk: PointerOffset,
+    ) -> Result<(), ServerSendError> {
+        let msg = "Unable to send response";
+        self.retrieve_returned_responses();
+
+        match self.get(channel_id) {
+            Some(ref connection) if connection.client_id == client_id => {
+                match connection.response_sender.try_send(distance_to_chunk) {
+                    Ok(_) => {
+                        self.data_segment.borrow_sample(distance_to_chunk);
+                        Ok(())
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => {
+                        fail!(from self, with ServerSendError::ReceiveBufferFull,
+                            "{} {:?} since the buffer of client {:?} is full.",
+                            msg, distance_to_chunk, client_id);
+                    }
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with ServerSendError::ConnectionCorrupted,
+                            "{} {:?} since a corrupted connection was detected with client {:?}.",
+                            msg, distance_to_chunk, client_id);
+                    }
+                }
+            }
+            _ => {
+                fail!(from self, with ServerSendError::ConnectionBrokenSinceClientNoLongerExists,
+                    "{} {:?} since the client {:?} no longer exists.",
+                    msg, distance_to_chunk, client_id);
+            }
+        }
+    }
+
+    pub(crate) fn release_request(
+        &self,
+        channel_id: usize,
+        origin: UniqueClientId,
+        distance_to_chunk: PointerOffset,
+    ) {
+        match self.get(channel_id) {
+            Some(connection) => {
+                if connection.client_id == origin {
+                    match connection.request_receiver.release(distance_to_chunk) {
+                        Ok(()) => (),
+                        Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                            fatal_panic!(from self, "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+                        }
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release request since the connection is broken. The request will be discarded and has to be reclaimed manually by the client.");
+            }
+        }
+    }
+}
+
+/// The responding endpoint of a request-response based communication.
+#[derive(Debug)]
+pub struct Server<
+    Service: service::Service,
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+> {
+    shared_state: Arc<ServerSharedState<Service>>,
+    dynamic_server_handle: Option<ContainerHandle>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Drop for Server<Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_server_handle {
+            self.shared_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_server_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Server<Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let server_id = UniqueServerId::new();
+        let client_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config
+            .required_amount_of_responses_per_data_segment(config.max_loaned_responses);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(&server_data_segment_name(server_id),
+                        service.state().global_config.as_ref(),
+                        number_of_responses,
+                        // # SAFETY: type_size and type_alignment are acquired via
+                        //           core::mem::{size_of|align_of}
+                        unsafe { Layout::from_size_align_unchecked(
+                            static_config.response_type_size,
+                            static_config.response_type_alignment) },
+                        MemoryPlacement::default(),
+                        service_peer_authorization::<Service>(&service.state().dynamic_storage)),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let shared_state = Arc::new(ServerSharedState {
+            data_segment,
+            client_connections: (0..client_list.capacity())
+                .map(|_| UnsafeCell::new(None))
+                .collect(),
+            client_list_state: unsafe { UnsafeCell::new(client_list.get_state()) },
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            global_config: Arc::clone(&service.state().global_config),
+            static_config: static_config.clone(),
+            config,
+            server_id,
+            number_of_responses,
+            loan_counter: AtomicUsize::new(0),
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_server_handle: None,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_client_connections() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_server_handle = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(ServerDetails {
+                server_id,
+                number_of_responses,
+                node_id: *service.state().shared_node.id(),
+            }) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                            "{} since it would exceed the maximum supported amount of servers of {}.",
+                            msg, static_config.max_servers);
+            }
+        };
+
+        new_self.dynamic_server_handle = Some(dynamic_server_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.shared_state.server_id
+    }
+
+    /// Receives an [`ActiveRequest`] from a [`crate::port::client::Client`]. If no request
+    /// could be received [`None`] is returned. If a failure occurs [`ServerReceiveError`] is
+    /// returned. The responses to the request are sent via the [`ActiveRequest`] and are
+    /// delivered only to the [`crate::port::client::Client`] that sent the request.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<ActiveRequest<RequestType, ResponseType, Service>>, ServerReceiveError> {
+        let msg = "Unable to receive another request";
+        if let Err(e) = self.shared_state.update_connections() {
+            fail!(from self,
+                with ServerReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        for id in 0..self.shared_state.client_connections.len() {
+            if let Some(ref connection) = self.shared_state.get(id) {
+                match connection.request_receiver.receive() {
+                    Ok(Some(offset)) => {
+                        let absolute_address =
+                            offset.value() + connection.data_segment.payload_start_address();
+                        let ptr = unsafe {
+                            RawSample::new_unchecked(
+                                absolute_address as *const Message<RequestHeader, RequestType>,
+                            )
+                        };
+                        return Ok(Some(ActiveRequest {
+                            shared_state: Arc::clone(&self.shared_state),
+                            channel_id: id,
+                            ptr,
+                            offset,
+                            origin: connection.client_id,
+                            // the request id is stored locally so that responses can be loaned
+                            // even when the client is gone and its data segment is unmapped
+                            request_id: ptr.as_header_ref().request_id(),
+                            _phantom_response_type: PhantomData,
+                        }));
+                    }
+                    Ok(None) => (),
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ServerReceiveError::ExceedsMaxBorrowedRequests,
+                            "{} since it would exceed the maximum {} of borrowed requests.",
+                            msg, connection.request_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > UpdateConnections for Server<Service, RequestType, ResponseType>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
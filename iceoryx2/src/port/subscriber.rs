@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -38,27 +40,33 @@ use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::serialize::DeserializeError;
 use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
 
+use crate::node::SharedNode;
 use crate::port::DegrationAction;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
-use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::port_factory::subscriber::{SubscriberConfig, MAX_NUMBER_OF_FILTER_KEYS};
 use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::service::static_config::type_details::{Serialized, Untyped};
 use crate::{
     message::Message, raw_sample::RawSample, sample::Sample, service,
     service::header::publish_subscribe::Header,
 };
 
 use super::details::publisher_connections::{Connection, PublisherConnections};
+use super::details::subscriber_filter::SubscriberFilter;
 use super::port_identifiers::UniqueSubscriberId;
 use super::update_connections::ConnectionFailure;
 use super::DegrationCallback;
 
 /// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum SubscriberReceiveError {
     ExceedsMaxBorrowedSamples,
     ConnectionFailure(ConnectionFailure),
@@ -72,12 +80,30 @@ impl std::fmt::Display for SubscriberReceiveError {
 
 impl std::error::Error for SubscriberReceiveError {}
 
+enum_gen! {
+    /// Failure that can be emitted when a value is received via [`Subscriber::receive()`] of a
+    /// service with a [`Serialized`] payload.
+    SubscriberReceiveSerializedError
+  mapping:
+    SubscriberReceiveError to ReceiveError,
+    DeserializeError to DeserializationFailure
+}
+
+impl std::fmt::Display for SubscriberReceiveSerializedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SubscriberReceiveSerializedError {}
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberCreateError {
     ExceedsMaxSupportedSubscribers,
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    ExceedsMaxSupportedFilterKeys,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -98,10 +124,11 @@ pub(crate) mod internal {
 
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
-pub struct Subscriber<Service: service::Service, MessageType: Debug> {
+pub struct Subscriber<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> {
     dynamic_subscriber_handle: Option<ContainerHandle>,
     publisher_connections: Arc<PublisherConnections<Service>>,
     dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
     static_config: crate::service::static_config::StaticConfig,
     degration_callback: Option<DegrationCallback<'static>>,
 
@@ -109,7 +136,9 @@ pub struct Subscriber<Service: service::Service, MessageType: Debug> {
     _phantom_message_type: PhantomData<MessageType>,
 }
 
-impl<Service: service::Service, MessageType: Debug> Drop for Subscriber<Service, MessageType> {
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> Drop
+    for Subscriber<Service, MessageType>
+{
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_subscriber_handle {
             self.dynamic_storage
@@ -120,7 +149,9 @@ impl<Service: service::Service, MessageType: Debug> Drop for Subscriber<Service,
     }
 }
 
-impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageType> {
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
+    Subscriber<Service, MessageType>
+{
     pub(crate) fn new(
         service: &Service,
         static_config: &StaticConfig,
@@ -151,6 +182,19 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let filter = match (&config.filter_keys, &config.filter_key_range) {
+            (Some(keys), _) => match SubscriberFilter::filter_keys(keys) {
+                Some(filter) => filter,
+                None => {
+                    fail!(from origin, with SubscriberCreateError::ExceedsMaxSupportedFilterKeys,
+                        "{} since the number of filter keys {} exceeds the max supported number of filter keys of {}.",
+                        msg, keys.len(), MAX_NUMBER_OF_FILTER_KEYS);
+                }
+            },
+            (None, Some(range)) => SubscriberFilter::filter_key_range(range),
+            (None, None) => SubscriberFilter::AcceptAll,
+        };
+
         let publisher_connections = Arc::new(PublisherConnections::new(
             publisher_list.capacity(),
             port_id,
@@ -163,6 +207,7 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
             degration_callback: config.degration_callback,
             publisher_connections,
             dynamic_storage,
+            _shared_node: service.state().shared_node.clone(),
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
             static_config: service.state().static_config.clone(),
@@ -185,6 +230,8 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
             .add_subscriber_id(SubscriberDetails {
                 port_id,
                 buffer_size,
+                node_id: *service.state().shared_node.id(),
+                filter,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -223,6 +270,7 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
                             i,
                             details.publisher_id,
                             details.number_of_samples,
+                            details.max_slice_len,
                         ) {
                             Ok(()) => (),
                             Err(e) => match &self.degration_callback {
@@ -257,11 +305,15 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
         Ok(())
     }
 
-    fn receive_from_connection(
+    fn receive_from_connection<
+        Payload: Debug + ?Sized,
+        F: Fn(usize) -> RawSample<Header, Payload>,
+    >(
         &self,
         channel_id: usize,
         connection: &mut Connection<Service>,
-    ) -> Result<Option<Sample<MessageType, Service>>, SubscriberReceiveError> {
+        to_raw_sample: &F,
+    ) -> Result<Option<Sample<Payload, Service>>, SubscriberReceiveError> {
         let msg = "Unable to receive another sample";
         match connection.receiver.receive() {
             Ok(data) => match data {
@@ -272,11 +324,7 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
                     Ok(Some(Sample {
                         publisher_connections: Arc::clone(&self.publisher_connections),
                         channel_id,
-                        ptr: unsafe {
-                            RawSample::new_unchecked(
-                                absolute_address as *mut Message<Header, MessageType>,
-                            )
-                        },
+                        ptr: to_raw_sample(absolute_address),
                         offset,
                         origin: connection.publisher_id,
                     }))
@@ -295,9 +343,10 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
         self.publisher_connections.subscriber_id()
     }
 
-    /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
-    /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
-    pub fn receive(&self) -> Result<Option<Sample<MessageType, Service>>, SubscriberReceiveError> {
+    fn receive_impl<Payload: Debug + ?Sized, F: Fn(usize) -> RawSample<Header, Payload>>(
+        &self,
+        to_raw_sample: F,
+    ) -> Result<Option<Sample<Payload, Service>>, SubscriberReceiveError> {
         if let Err(e) = self.update_connections() {
             fail!(from self,
                 with SubscriberReceiveError::ConnectionFailure(e),
@@ -307,7 +356,9 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
         for id in 0..self.publisher_connections.len() {
             match &mut self.publisher_connections.get_mut(id) {
                 Some(ref mut connection) => {
-                    if let Some(sample) = self.receive_from_connection(id, connection)? {
+                    if let Some(sample) =
+                        self.receive_from_connection(id, connection, &to_raw_sample)?
+                    {
                         return Ok(Some(sample));
                     }
                 }
@@ -341,4 +392,90 @@ impl<Service: service::Service, MessageType: Debug> Subscriber<Service, MessageT
 
         Ok(())
     }
+
+    fn receive_raw_bytes(&self) -> Result<Option<Sample<[u8], Service>>, SubscriberReceiveError> {
+        let static_config = self.static_config.publish_subscribe();
+        let payload_offset = static_config.payload_offset();
+        let type_size = static_config.type_size();
+
+        self.receive_impl(|absolute_address| unsafe {
+            let header = absolute_address as *const Header;
+            let number_of_elements = (*header).number_of_elements() as usize;
+            RawSample::new_from_parts_unchecked(
+                header,
+                core::ptr::slice_from_raw_parts(
+                    (absolute_address + payload_offset) as *const u8,
+                    number_of_elements * type_size,
+                ),
+            )
+        })
+    }
+}
+
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend>
+    Subscriber<Service, MessageType>
+{
+    /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
+    /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn receive(&self) -> Result<Option<Sample<MessageType, Service>>, SubscriberReceiveError> {
+        self.receive_impl(|absolute_address| unsafe {
+            RawSample::new_unchecked(absolute_address as *const Message<Header, MessageType>)
+        })
+    }
+}
+
+impl<Service: service::Service, ElementType: Debug + ZeroCopySend>
+    Subscriber<Service, [ElementType]>
+{
+    /// Receives a [`crate::sample::Sample`] with a slice payload from
+    /// [`crate::port::publisher::Publisher`]. The length of the slice is the length that was
+    /// loaned by the [`crate::port::publisher::Publisher`]. If no sample could be
+    /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<[ElementType], Service>>, SubscriberReceiveError> {
+        self.receive_impl(|absolute_address| unsafe {
+            let number_of_elements =
+                (*(absolute_address as *const Header)).number_of_elements() as usize;
+            RawSample::new_unchecked(core::ptr::slice_from_raw_parts(
+                absolute_address as *const ElementType,
+                number_of_elements,
+            ) as *const Message<Header, [ElementType]>)
+        })
+    }
+}
+
+impl<Service: service::Service> Subscriber<Service, Untyped> {
+    /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`] and
+    /// provides its payload as raw bytes. The number of bytes is the size of the payload type
+    /// multiplied with the number of elements that were loaned by the
+    /// [`crate::port::publisher::Publisher`]. If no sample could be
+    /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn receive(&self) -> Result<Option<Sample<[u8], Service>>, SubscriberReceiveError> {
+        self.receive_raw_bytes()
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    > Subscriber<Service, Serialized<T, Serializer>>
+{
+    /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`] and
+    /// returns its deserialized payload. The underlying sample is released right after the
+    /// deserialization. If no sample could be received [`None`] is returned. If a failure
+    /// occurs [`SubscriberReceiveSerializedError`] is returned.
+    pub fn receive(&self) -> Result<Option<T>, SubscriberReceiveSerializedError> {
+        let msg = "Unable to receive serialized value";
+        match fail!(from self, when self.receive_raw_bytes(),
+                    "{} since the underlying receive operation failed.", msg)
+        {
+            None => Ok(None),
+            Some(sample) => Ok(Some(
+                fail!(from self, when Serializer::deserialize(sample.payload()),
+                    "{} since the received payload could not be deserialized.", msg),
+            )),
+        }
+    }
 }
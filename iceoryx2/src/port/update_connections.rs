@@ -47,7 +47,9 @@ pub trait UpdateConnections {
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
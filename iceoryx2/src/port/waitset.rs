@@ -0,0 +1,564 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`WaitSet`] multiplexes multiple event sources and waits on all of them in one blocking
+//! call. Possible attachments are
+//!
+//!  * notifications - any object that implements [`SynchronousMultiplexing`], like the
+//!    [`Listener`](crate::port::listener::Listener), wakes up the [`WaitSet`] when it received an
+//!    event,
+//!  * deadlines - a notification that additionally wakes up the [`WaitSet`] when it did not
+//!    receive an event within the provided time,
+//!  * subscribers - a [`Subscriber`] together with the [`Listener`] of the event service that is
+//!    notified whenever a new sample was sent, wakes up the [`WaitSet`] when new samples arrived,
+//!  * intervals - wake up the [`WaitSet`] periodically.
+//!
+//! Every attachment returns a [`WaitSetGuard`] that detaches it when it goes out of scope. When
+//! the [`WaitSet`] wakes up it calls the provided callback for every attachment that fired with
+//! a [`WaitSetAttachmentId`] which identifies the attachment and the reason of the wake up.
+//!
+//! **Note:** The [`WaitSet`] only signals that an event has arrived, it does not consume it. The
+//! events of a [`Listener`](crate::port::listener::Listener) must be collected in the callback,
+//! for instance with [`Listener::try_wait_all()`](crate::port::listener::Listener::try_wait_all()),
+//! otherwise the [`WaitSet`] wakes up immediately again.
+//!
+//! A [`Subscriber`] has no file descriptor on its own, therefore the publish-subscribe service
+//! is combined with an event service. The publisher side notifies with a
+//! [`Notifier`](crate::port::notifier::Notifier) after sending a sample and the subscriber side
+//! attaches the [`Subscriber`] together with the corresponding [`Listener`] with
+//! [`WaitSet::attach_subscriber()`]. For those attachments
+//! [`WaitSetAttachmentId::has_event_from()`] collects the notifications of the [`Listener`], so
+//! that the callback only has to receive all available samples.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_socket;
+//! use iceoryx2::port::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy_socket::Service>()?;
+//!
+//! let event_1 = node.service_builder(&ServiceName::new("MyEventName1")?)
+//!     .event()
+//!     .open_or_create()?;
+//! let event_2 = node.service_builder(&ServiceName::new("MyEventName2")?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener_1 = event_1.listener().create()?;
+//! let listener_2 = event_2.listener().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<zero_copy_socket::Service>()?;
+//!
+//! let guard_1 = waitset.attach_notification(&listener_1)?;
+//! let guard_2 = waitset.attach_deadline(&listener_2, Duration::from_secs(2))?;
+//! let tick_guard = waitset.attach_interval(Duration::from_millis(500))?;
+//!
+//! waitset.blocking_wait(|attachment_id| {
+//!     if attachment_id.has_event_from(&guard_1) {
+//!         listener_1.try_wait_all(|event_id| println!("listener 1 received {:?}", event_id)).ok();
+//!     } else if attachment_id.has_event_from(&guard_2) {
+//!         listener_2.try_wait_all(|event_id| println!("listener 2 received {:?}", event_id)).ok();
+//!     } else if attachment_id.has_missed_deadline(&guard_2) {
+//!         println!("listener 2 did not receive an event within 2s");
+//!     } else if attachment_id.has_event_from(&tick_guard) {
+//!         println!("tick");
+//!     }
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::reactor::{Reactor, ReactorAttachError, ReactorBuilder, ReactorWaitError};
+
+use crate::port::listener::Listener;
+use crate::port::subscriber::Subscriber;
+use crate::service;
+
+/// Defines the failures that can occur when a [`WaitSet`] is created with the
+/// [`WaitSetBuilder`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetCreateError {
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetCreateError {}
+
+/// Defines the failures that can occur when an attachment is added to the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetAttachmentError {
+    AlreadyAttached,
+    InsufficientCapacity,
+    InvalidTimeout,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Defines the failures that can occur while waiting on the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetWaitError {
+    NoAttachments,
+    InsufficientPermissions,
+    Interrupt,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetWaitError {}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum AttachmentKind {
+    Notification { fd: i32 },
+    Deadline { fd: i32, deadline: Duration },
+    Interval { period: Duration },
+}
+
+impl AttachmentKind {
+    fn file_descriptor(&self) -> Option<i32> {
+        match self {
+            AttachmentKind::Notification { fd } | AttachmentKind::Deadline { fd, .. } => Some(*fd),
+            AttachmentKind::Interval { .. } => None,
+        }
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        match self {
+            AttachmentKind::Notification { .. } => None,
+            AttachmentKind::Deadline { deadline, .. } => Some(*deadline),
+            AttachmentKind::Interval { period } => Some(*period),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Attachment {
+    kind: AttachmentKind,
+    last_activity: Instant,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum AttachmentEvent {
+    Activity,
+    MissedDeadline,
+}
+
+/// Identifies the attachment that woke up the [`WaitSet`] and the reason of the wake up. It is
+/// provided to the callback of [`WaitSet::try_wait()`], [`WaitSet::timed_wait()`] or
+/// [`WaitSet::blocking_wait()`].
+#[derive(Debug)]
+pub struct WaitSetAttachmentId<Service: service::Service> {
+    index: u64,
+    event: AttachmentEvent,
+    _service: PhantomData<Service>,
+}
+
+impl<Service: service::Service> WaitSetAttachmentId<Service> {
+    /// Returns true when the attachment of the [`WaitSetGuard`] woke up the [`WaitSet`] since
+    /// it received an event or, in the case of an interval, since the interval has passed.
+    ///
+    /// When the attachment is a [`Subscriber`] the pending notifications of its [`Listener`]
+    /// are collected. Afterwards, all available samples must be received from the
+    /// [`Subscriber`] since the [`WaitSet`] wakes up again only when new samples arrive.
+    pub fn has_event_from(&self, guard: &WaitSetGuard<Service>) -> bool {
+        if self.index != guard.index || self.event != AttachmentEvent::Activity {
+            return false;
+        }
+
+        // the notifications are collected before the samples are received so that every
+        // sample whose notification is discarded is already available in the subscriber
+        if let Some(listener) = guard.subscriber_listener {
+            if let Err(e) = listener.try_wait_all(|_| {}) {
+                warn!(from self, "Unable to collect the notifications of {:?} ({:?}).", listener, e);
+            }
+        }
+
+        true
+    }
+
+    /// Returns true when the attachment of the [`WaitSetGuard`] woke up the [`WaitSet`] since
+    /// it did not receive an event within its deadline.
+    pub fn has_missed_deadline(&self, guard: &WaitSetGuard<Service>) -> bool {
+        self.index == guard.index && self.event == AttachmentEvent::MissedDeadline
+    }
+}
+
+/// Is returned when something is attached to the [`WaitSet`]. As soon as it goes out of scope
+/// the attachment is detached from the [`WaitSet`].
+pub struct WaitSetGuard<'waitset, 'attachment, Service: service::Service>
+where
+    Service::Reactor: 'waitset,
+{
+    waitset: &'waitset WaitSet<Service>,
+    index: u64,
+    subscriber_listener: Option<&'attachment Listener<Service>>,
+    _reactor_guard: Option<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>>,
+}
+
+impl<'waitset, 'attachment, Service: service::Service> Debug
+    for WaitSetGuard<'waitset, 'attachment, Service>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitSetGuard<{}> {{ index: {} }}",
+            core::any::type_name::<Service>(),
+            self.index
+        )
+    }
+}
+
+impl<'waitset, 'attachment, Service: service::Service> Drop
+    for WaitSetGuard<'waitset, 'attachment, Service>
+{
+    fn drop(&mut self) {
+        self.waitset.attachments.borrow_mut().remove(&self.index);
+    }
+}
+
+/// Creates a new [`WaitSet`].
+#[derive(Debug, Default)]
+pub struct WaitSetBuilder {}
+
+impl WaitSetBuilder {
+    /// Creates a new [`WaitSetBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the [`WaitSet`] for the provided [`Service`](crate::service::Service) type.
+    pub fn create<Service: service::Service>(self) -> Result<WaitSet<Service>, WaitSetCreateError> {
+        let msg = "Unable to create WaitSet";
+        let reactor = fail!(from self, when <Service::Reactor as Reactor>::Builder::new().create(),
+                with WaitSetCreateError::InternalError,
+                "{} since the underlying reactor could not be created.", msg);
+
+        Ok(WaitSet {
+            reactor,
+            attachments: RefCell::new(BTreeMap::new()),
+            next_index: Cell::new(0),
+        })
+    }
+}
+
+/// Multiplexes notifications, deadlines and intervals and waits on all of them in one blocking
+/// call. See the [module documentation](crate::port::waitset) for an example.
+#[derive(Debug)]
+pub struct WaitSet<Service: service::Service> {
+    reactor: Service::Reactor,
+    attachments: RefCell<BTreeMap<u64, Attachment>>,
+    next_index: Cell<u64>,
+}
+
+impl<Service: service::Service> WaitSet<Service> {
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.attachments.borrow().len()
+    }
+
+    /// Returns true when the [`WaitSet`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.borrow().is_empty()
+    }
+
+    /// Returns the maximum number of notifications and deadlines that can be attached.
+    pub fn capacity(&self) -> usize {
+        <Service::Reactor as Reactor>::capacity()
+    }
+
+    /// Attaches an object as notification. Whenever it receives an event, the [`WaitSet`] wakes
+    /// up.
+    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        let reactor_guard = self.attach_to_reactor(attachment)?;
+        Ok(self.add_attachment(AttachmentKind::Notification { fd }, Some(reactor_guard)))
+    }
+
+    /// Attaches an object as notification with a deadline. Whenever it receives an event, the
+    /// [`WaitSet`] wakes up. When it does not receive an event for the duration of the
+    /// provided deadline, the [`WaitSet`] wakes up and reports a missed deadline.
+    pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        if deadline.is_zero() {
+            fail!(from self, with WaitSetAttachmentError::InvalidTimeout,
+                "Unable to attach {:?} with a deadline of zero.", attachment);
+        }
+
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        let reactor_guard = self.attach_to_reactor(attachment)?;
+        Ok(self.add_attachment(
+            AttachmentKind::Deadline { fd, deadline },
+            Some(reactor_guard),
+        ))
+    }
+
+    /// Attaches a [`Subscriber`] together with the [`Listener`] of the event service that is
+    /// notified whenever a new sample was sent. The [`WaitSet`] wakes up whenever new samples
+    /// arrived.
+    pub fn attach_subscriber<'waitset, 'attachment, MessageType: Debug + ZeroCopySend + ?Sized>(
+        &'waitset self,
+        subscriber: &'attachment Subscriber<Service, MessageType>,
+        listener: &'attachment Listener<Service>,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError>
+    where
+        Listener<Service>: SynchronousMultiplexing,
+    {
+        let fd = unsafe { listener.file_descriptor().native_handle() };
+        let reactor_guard = fail!(from self, when self.attach_to_reactor(listener),
+                "Unable to attach {:?} since its listener could not be attached.", subscriber);
+
+        let mut guard =
+            self.add_attachment(AttachmentKind::Notification { fd }, Some(reactor_guard));
+        guard.subscriber_listener = Some(listener);
+        Ok(guard)
+    }
+
+    /// Attaches an interval. The [`WaitSet`] wakes up whenever the interval has passed.
+    pub fn attach_interval(
+        &self,
+        interval: Duration,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        if interval.is_zero() {
+            fail!(from self, with WaitSetAttachmentError::InvalidTimeout,
+                "Unable to attach an interval of zero.");
+        }
+
+        Ok(self.add_attachment(AttachmentKind::Interval { period: interval }, None))
+    }
+
+    /// Checks without blocking if an attachment fired and calls the provided callback for every
+    /// [`WaitSetAttachmentId`] that woke up the [`WaitSet`].
+    pub fn try_wait<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, Some(Duration::ZERO))
+    }
+
+    /// Blocks until either an attachment fired or the timeout has passed. Calls the provided
+    /// callback for every [`WaitSetAttachmentId`] that woke up the [`WaitSet`].
+    pub fn timed_wait<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, Some(timeout))
+    }
+
+    /// Blocks until an attachment fired. Calls the provided callback for every
+    /// [`WaitSetAttachmentId`] that woke up the [`WaitSet`].
+    pub fn blocking_wait<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, None)
+    }
+
+    fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>, WaitSetAttachmentError>
+    {
+        let msg = "Unable to attach";
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        if self
+            .attachments
+            .borrow()
+            .values()
+            .any(|a| a.kind.file_descriptor() == Some(fd))
+        {
+            fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                "{} {:?} since it is already attached.", msg, attachment);
+        }
+
+        match self.reactor.attach(attachment) {
+            Ok(guard) => Ok(guard),
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                    "{} {:?} since it would exceed the capacity of {}.",
+                    msg, attachment, self.capacity());
+            }
+            Err(ReactorAttachError::UnknownError(e)) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{} {:?} due to an internal error in the reactor ({}).", msg, attachment, e);
+            }
+        }
+    }
+
+    fn add_attachment<'waitset, 'attachment>(
+        &'waitset self,
+        kind: AttachmentKind,
+        reactor_guard: Option<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>>,
+    ) -> WaitSetGuard<'waitset, 'attachment, Service> {
+        let index = self.next_index.get();
+        self.next_index.set(index + 1);
+        self.attachments.borrow_mut().insert(
+            index,
+            Attachment {
+                kind,
+                last_activity: Instant::now(),
+            },
+        );
+
+        WaitSetGuard {
+            waitset: self,
+            index,
+            subscriber_listener: None,
+            _reactor_guard: reactor_guard,
+        }
+    }
+
+    fn time_until_next_timeout(&self, now: Instant) -> Option<Duration> {
+        self.attachments
+            .borrow()
+            .values()
+            .filter_map(|a| {
+                a.kind
+                    .timeout()
+                    .map(|t| (a.last_activity + t).saturating_duration_since(now))
+            })
+            .min()
+    }
+
+    fn wait<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        mut fn_call: F,
+        timeout: Option<Duration>,
+    ) -> Result<(), WaitSetWaitError> {
+        let msg = "Unable to wait on WaitSet";
+        if self.is_empty() {
+            fail!(from self, with WaitSetWaitError::NoAttachments,
+                "{} since it has no attachments.", msg);
+        }
+
+        let wait_timeout = match (timeout, self.time_until_next_timeout(Instant::now())) {
+            (Some(timeout), Some(next_timeout)) => Some(timeout.min(next_timeout)),
+            (Some(timeout), None) => Some(timeout),
+            (None, next_timeout) => next_timeout,
+        };
+
+        let mut fired_fds = vec![];
+        let collect_fds = |fd: &iceoryx2_bb_posix::file_descriptor::FileDescriptor| {
+            fired_fds.push(unsafe { fd.native_handle() })
+        };
+        let result = match wait_timeout {
+            Some(wait_timeout) => self.reactor.timed_wait(collect_fds, wait_timeout),
+            None => self.reactor.blocking_wait(collect_fds),
+        };
+
+        match result {
+            Ok(()) => (),
+            Err(ReactorWaitError::Interrupt) => {
+                fail!(from self, with WaitSetWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(ReactorWaitError::InsufficientPermissions) => {
+                fail!(from self, with WaitSetWaitError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with WaitSetWaitError::InternalError,
+                    "{} due to an internal error in the reactor ({:?}).", msg, e);
+            }
+        }
+
+        let now = Instant::now();
+        let mut fired_attachments = vec![];
+        for (index, attachment) in self.attachments.borrow_mut().iter_mut() {
+            let has_activity = attachment
+                .kind
+                .file_descriptor()
+                .map(|fd| fired_fds.contains(&fd))
+                .unwrap_or(false);
+
+            let event = match attachment.kind {
+                AttachmentKind::Notification { .. } if has_activity => {
+                    Some(AttachmentEvent::Activity)
+                }
+                AttachmentKind::Notification { .. } => None,
+                AttachmentKind::Deadline { .. } if has_activity => {
+                    attachment.last_activity = now;
+                    Some(AttachmentEvent::Activity)
+                }
+                AttachmentKind::Deadline { deadline, .. } => {
+                    if now.duration_since(attachment.last_activity) >= deadline {
+                        attachment.last_activity = now;
+                        Some(AttachmentEvent::MissedDeadline)
+                    } else {
+                        None
+                    }
+                }
+                AttachmentKind::Interval { period } => {
+                    if now.duration_since(attachment.last_activity) >= period {
+                        attachment.last_activity = now;
+                        Some(AttachmentEvent::Activity)
+                    } else {
+                        None
+                    }
+                }
+            };
+
+            if let Some(event) = event {
+                fired_attachments.push(WaitSetAttachmentId {
+                    index: *index,
+                    event,
+                    _service: PhantomData,
+                });
+            }
+        }
+
+        // the callback is called after the attachments are released so that guards can be
+        // dropped or new attachments can be added inside of it
+        for attachment_id in fired_attachments {
+            fn_call(attachment_id);
+        }
+
+        Ok(())
+    }
+}
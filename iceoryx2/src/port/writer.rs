@@ -0,0 +1,217 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .create()?;
+//!
+//! let writer = blackboard.writer().create()?;
+//!
+//! let entry = writer.entry::<f32>(&0)?;
+//! entry.update_with_copy(2.5);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::Producer;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::SharedNode;
+use crate::port::port_identifiers::UniqueWriterId;
+use crate::service;
+use crate::service::dynamic_config::blackboard::{value_type_id, WriterDetails};
+use crate::service::static_config::type_details::TypeDetails;
+
+/// Failures that can occur when a new [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterCreateError {
+    /// A blackboard is owned by exactly one [`Writer`] and it already exists.
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Failures that can occur when an [`EntryHandleMut`] is acquired with [`Writer::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleMutError {
+    /// The blackboard does not contain an entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry exists but stores a value of a different type.
+    WrongValueType,
+    /// Another [`EntryHandleMut`] of the same entry still exists.
+    HandleAlreadyExists,
+}
+
+impl std::fmt::Display for EntryHandleMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for EntryHandleMutError {}
+
+/// Writing endpoint of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Every blackboard has at most one [`Writer`] which updates the values of the
+/// entries via [`EntryHandleMut`]s.
+#[derive(Debug)]
+pub struct Writer<
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+> {
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    _shared_node: Arc<SharedNode<Service>>,
+    dynamic_writer_handle: Option<ContainerHandle>,
+    writer_id: UniqueWriterId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Drop for Writer<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            self.dynamic_storage
+                .get()
+                .blackboard()
+                .release_writer_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Writer<Service, KeyType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, WriterCreateError> {
+        let msg = "Unable to create Writer port";
+        let origin = "Writer::new()";
+        let writer_id = UniqueWriterId::new();
+
+        let mut new_self = Self {
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            _shared_node: service.state().shared_node.clone(),
+            dynamic_writer_handle: None,
+            writer_id,
+            _key: PhantomData,
+        };
+
+        let dynamic_writer_handle =
+            match new_self
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_writer_id(WriterDetails {
+                    writer_id,
+                    node_id: *service.state().shared_node.id(),
+                }) {
+                Some(handle) => handle,
+                None => {
+                    fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                    "{} since the blackboard already has a writer.", msg);
+                }
+            };
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.writer_id
+    }
+
+    /// Returns an [`EntryHandleMut`] to update the value of the entry with the provided `key`.
+    /// There can be only one [`EntryHandleMut`] per entry at a time.
+    pub fn entry<ValueType: Copy + ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandleMut<'_, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to acquire entry handle";
+        let blackboard = self.dynamic_storage.get().blackboard();
+
+        let entry = match unsafe { blackboard.entry(key) } {
+            Some(entry) => entry,
+            None => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since the blackboard does not contain an entry with the key {:?}.", msg, key);
+            }
+        };
+
+        if entry.value_type
+            != value_type_id::<Service::ServiceNameHasher>(&TypeDetails::from_type::<ValueType>())
+        {
+            fail!(from self, with EntryHandleMutError::WrongValueType,
+                "{} since the entry with the key {:?} does not store values of type {}.",
+                msg, key, std::any::type_name::<ValueType>());
+        }
+
+        match unsafe { blackboard.entry_value::<ValueType>(entry) }.acquire_producer() {
+            Some(producer) => Ok(EntryHandleMut { producer }),
+            None => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since another handle for the entry with the key {:?} already exists.", msg, key);
+            }
+        }
+    }
+}
+
+/// Updates the value of a single blackboard entry. Acquired with [`Writer::entry()`].
+pub struct EntryHandleMut<'a, ValueType: Copy + ZeroCopySend + 'static> {
+    producer: Producer<'a, ValueType>,
+}
+
+impl<ValueType: Copy + ZeroCopySend + 'static> Debug for EntryHandleMut<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandleMut<{}> {{ }}",
+            std::any::type_name::<ValueType>()
+        )
+    }
+}
+
+impl<ValueType: Copy + ZeroCopySend + 'static> EntryHandleMut<'_, ValueType> {
+    /// Stores a copy of `value` in the entry. Every [`crate::port::reader::Reader`] will read it
+    /// until it is updated again.
+    pub fn update_with_copy(&self, value: ValueType) {
+        self.producer.store(value);
+    }
+}
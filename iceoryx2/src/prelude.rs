@@ -12,5 +12,9 @@
 
 pub use crate::iox2::Iox2;
 pub use crate::iox2::Iox2Event;
+pub use crate::node::{Node, NodeBuilder};
 pub use crate::port::event_id::EventId;
+pub use crate::port::event_payload::EventPayload;
 pub use crate::service::{process_local, service_name::ServiceName, zero_copy, Service};
+pub use iceoryx2_bb_derive_macros::ZeroCopySend;
+pub use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
@@ -14,13 +14,15 @@ use crate::message::Message;
 
 use core::fmt;
 
-/// A `*const Message<Header, Data>` non-zero sample pointer to the message.
-#[repr(transparent)]
-pub(crate) struct RawSample<Header, Data> {
-    message: *const Message<Header, Data>,
+/// A `*const Header` and `*const Data` non-zero sample pointer pair to the message. The header
+/// and the data are usually part of one [`Message`] but the data can also be located at a
+/// custom offset, when the payload type is only known at runtime.
+pub(crate) struct RawSample<Header, Data: ?Sized> {
+    header: *const Header,
+    data: *const Data,
 }
 
-impl<Header, Data> RawSample<Header, Data> {
+impl<Header, Data: ?Sized> RawSample<Header, Data> {
     /// Creates a new `RawSample`.
     ///
     /// # Safety
@@ -32,7 +34,27 @@ impl<Header, Data> RawSample<Header, Data> {
             !message.is_null(),
             "RawSample::new_unchecked requires that the message pointer is non-null"
         );
-        Self { message }
+        Self {
+            header: core::ptr::addr_of!((*message).header),
+            data: core::ptr::addr_of!((*message).data),
+        }
+    }
+
+    /// Creates a new `RawSample` from a separate header and data pointer.
+    ///
+    /// # Safety
+    ///
+    /// `header` and `data` must be non-null.
+    #[inline]
+    pub(crate) unsafe fn new_from_parts_unchecked(
+        header: *const Header,
+        data: *const Data,
+    ) -> Self {
+        debug_assert!(
+            !header.is_null() && !data.is_null(),
+            "RawSample::new_from_parts_unchecked requires that the header and data pointer are non-null"
+        );
+        Self { header, data }
     }
 
     /// Creates a new `RawSample`.
@@ -47,64 +69,53 @@ impl<Header, Data> RawSample<Header, Data> {
         }
     }
 
-    /// Acquires the underlying message as `*const` pointer.
-    #[must_use]
-    #[inline(always)]
-    pub(crate) fn as_ptr(self) -> *const Message<Header, Data> {
-        self.message
-    }
-
-    /// Acquires the underlying message as reference.
-    #[must_use]
-    #[inline(always)]
-    pub(crate) fn as_ref(&self) -> &Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &(*self.as_ptr()) }
-    }
-
     /// Acquires the underlying header as reference.
     #[must_use]
     #[inline(always)]
     pub(crate) fn as_header_ref(&self) -> &Header {
-        &self.as_ref().header
+        // SAFETY: `self.header` is non-null and points to an initialized header
+        unsafe { &*self.header }
     }
 
     /// Acquires the underlying data as reference.
     #[must_use]
     #[inline(always)]
     pub(crate) fn as_data_ref(&self) -> &Data {
-        &self.as_ref().data
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to the data safe
+        unsafe { &*self.data }
     }
 }
 
-impl<Header, Data> Clone for RawSample<Header, Data> {
+impl<Header, Data: ?Sized> Clone for RawSample<Header, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSample<Header, Data> {}
+impl<Header, Data: ?Sized> Copy for RawSample<Header, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSample<Header, Data> {
+impl<Header: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug for RawSample<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.message, f)
+        fmt::Pointer::fmt(&self.header, f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSample<Header, Data> {
+impl<Header, Data: ?Sized> fmt::Pointer for RawSample<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.message, f)
+        fmt::Pointer::fmt(&self.header, f)
     }
 }
 
-/// A `*mut Message<Header, Data>` non-zero sample pointer to the message.
-#[repr(transparent)]
-pub(crate) struct RawSampleMut<Header, Data> {
-    message: *mut Message<Header, Data>,
+/// A `*mut Header` and `*mut Data` non-zero sample pointer pair to the message. The header
+/// and the data are usually part of one [`Message`] but the data can also be located at a
+/// custom offset, when the payload type is only known at runtime.
+pub(crate) struct RawSampleMut<Header, Data: ?Sized> {
+    header: *mut Header,
+    data: *mut Data,
 }
 
-impl<Header, Data> RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> RawSampleMut<Header, Data> {
     /// Creates a new `RawSampleMut`.
     ///
     /// # Safety
@@ -116,7 +127,24 @@ impl<Header, Data> RawSampleMut<Header, Data> {
             !message.is_null(),
             "RawSampleMut::new_unchecked requires that the message pointer is non-null"
         );
-        Self { message }
+        Self {
+            header: core::ptr::addr_of_mut!((*message).header),
+            data: core::ptr::addr_of_mut!((*message).data),
+        }
+    }
+
+    /// Creates a new `RawSampleMut` from a separate header and data pointer.
+    ///
+    /// # Safety
+    ///
+    /// `header` and `data` must be non-null.
+    #[inline]
+    pub(crate) unsafe fn new_from_parts_unchecked(header: *mut Header, data: *mut Data) -> Self {
+        debug_assert!(
+            !header.is_null() && !data.is_null(),
+            "RawSampleMut::new_from_parts_unchecked requires that the header and data pointer are non-null"
+        );
+        Self { header, data }
     }
 
     /// Creates a new `RawSampleMut`.
@@ -131,75 +159,56 @@ impl<Header, Data> RawSampleMut<Header, Data> {
         }
     }
 
-    /// Acquires the underlying message as `*const` pointer.
-    #[must_use]
-    #[inline(always)]
-    pub(crate) fn as_ptr(self) -> *const Message<Header, Data> {
-        self.message
-    }
-
-    /// Acquires the underlying message as `*mut` pointer.
-    #[must_use]
-    #[inline(always)]
-    pub(crate) fn as_mut_ptr(self) -> *mut Message<Header, Data> {
-        self.message
-    }
-
-    /// Acquires the underlying message as reference.
-    #[must_use]
-    #[inline(always)]
-    pub(crate) fn as_ref(&self) -> &Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &(*self.as_ptr()) }
-    }
-
-    /// Acquires the underlying message as mut reference.
+    /// Acquires the underlying header as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_mut(&mut self) -> &mut Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &mut (*self.as_mut_ptr()) }
+    pub(crate) fn as_header_ref(&self) -> &Header {
+        // SAFETY: `self.header` is non-null and points to an initialized header
+        unsafe { &*self.header }
     }
 
-    /// Acquires the underlying header as reference.
+    /// Acquires the underlying header as mut reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_header_ref(&self) -> &Header {
-        &self.as_ref().header
+    pub(crate) fn as_header_mut(&mut self) -> &mut Header {
+        // SAFETY: `self.header` is non-null and points to an initialized header
+        unsafe { &mut *self.header }
     }
 
     /// Acquires the underlying data as reference.
     #[must_use]
     #[inline(always)]
     pub(crate) fn as_data_ref(&self) -> &Data {
-        &self.as_ref().data
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to the data safe
+        unsafe { &*self.data }
     }
 
     /// Acquires the underlying data as mut reference.
     #[must_use]
     #[inline(always)]
     pub(crate) fn as_data_mut(&mut self) -> &mut Data {
-        &mut self.as_mut().data
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to the data safe
+        unsafe { &mut *self.data }
     }
 }
 
-impl<Header, Data> Clone for RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> Clone for RawSampleMut<Header, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSampleMut<Header, Data> {}
+impl<Header, Data: ?Sized> Copy for RawSampleMut<Header, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSampleMut<Header, Data> {
+impl<Header: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug for RawSampleMut<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.as_ptr(), f)
+        fmt::Pointer::fmt(&self.header, f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> fmt::Pointer for RawSampleMut<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.as_ptr(), f)
+        fmt::Pointer::fmt(&self.header, f)
     }
 }
@@ -0,0 +1,195 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #     .request_response()
+//! #     .typed::<u64, u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let client = service.client().create()?;
+//!
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1234);
+//!
+//! println!("request id: {:?}", request.header().request_id());
+//! request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::client::{ClientSendError, ClientSharedState},
+    raw_sample::RawSampleMut,
+    service::header::request_response::RequestHeader,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+
+/// Acquired by a [`crate::port::client::Client`] via
+/// [`crate::port::client::Client::loan()`] or
+/// [`crate::port::client::Client::loan_uninit()`]. It stores the payload of the request that
+/// will be sent to all connected [`crate::port::server::Server`]s. If the [`RequestMut`] is not
+/// sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the
+/// [`crate::port::client::Client`] and the [`crate::port::client::Client`] is not thread-safe!
+///
+/// The generic parameter `RequestType` is either a `RequestType` or a
+/// [`core::mem::MaybeUninit<RequestType>`], depending which API is used to obtain the request.
+#[derive(Debug)]
+pub struct RequestMut<RequestType: Debug, Service: crate::service::Service> {
+    shared_state: Arc<ClientSharedState<Service>>,
+    ptr: RawSampleMut<RequestHeader, RequestType>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<RequestType: Debug, Service: crate::service::Service> Drop
+    for RequestMut<RequestType, Service>
+{
+    fn drop(&mut self) {
+        self.shared_state
+            .return_loaned_request(self.offset_to_chunk);
+    }
+}
+
+impl<RequestType: Debug, Service: crate::service::Service>
+    RequestMut<MaybeUninit<RequestType>, Service>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ClientSharedState<Service>>,
+        ptr: RawSampleMut<RequestHeader, MaybeUninit<RequestType>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the request and labels the request as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .request_response()
+    /// #     .typed::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(1234);
+    ///
+    /// request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(mut self, value: RequestType) -> RequestMut<RequestType, Service> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<RequestType>`] container and labels
+    /// the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<RequestType>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .request_response()
+    /// #     .typed::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let mut request = client.loan_uninit()?;
+    /// request.payload_mut().write(1234);
+    /// let request = unsafe { request.assume_init() };
+    ///
+    /// request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> RequestMut<RequestType, Service> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        RequestType: Debug, // `RequestType` is either a `RequestType` or a `MaybeUninit<RequestType>`
+        Service: crate::service::Service,
+    > RequestMut<RequestType, Service>
+{
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    pub fn payload_mut(&mut self) -> &mut RequestType {
+        self.ptr.as_data_mut()
+    }
+
+    /// Send a previously loaned [`crate::port::client::Client::loan_uninit()`] or
+    /// [`crate::port::client::Client::loan()`] [`RequestMut`] to all connected
+    /// [`crate::port::server::Server`]s of the service.
+    ///
+    /// The payload of the [`RequestMut`] must be initialized before it can be sent. Have a look
+    /// at [`RequestMut::write_payload()`] and [`RequestMut::assume_init()`]
+    /// for more details.
+    ///
+    /// On success the number of [`crate::port::server::Server`]s that received
+    /// the request is returned, otherwise a [`ClientSendError`] describing the failure.
+    pub fn send(self) -> Result<usize, ClientSendError> {
+        self.shared_state.send_request(self.offset_to_chunk)
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #   .request_response()
+//! #   .typed::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let client = service.client().create()?;
+//!
+//! while let Some(response) = client.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("header server id {:?}", response.header().server_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::{fmt::Debug, ops::Deref};
+
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+use crate::port::client::ClientSharedState;
+use crate::port::port_identifiers::UniqueServerId;
+use crate::raw_sample::RawSample;
+use crate::service::header::request_response::ResponseHeader;
+
+/// It stores the payload of a response and is acquired by the
+/// [`Client`](crate::port::client::Client) whenever it receives a response from a
+/// [`Server`](crate::port::server::Server) via
+/// [`Client::receive()`](crate::port::client::Client::receive()).
+#[derive(Debug)]
+pub struct Response<ResponseType: Debug, Service: crate::service::Service> {
+    pub(crate) shared_state: Arc<ClientSharedState<Service>>,
+    pub(crate) ptr: RawSample<ResponseHeader, ResponseType>,
+    pub(crate) channel_id: usize,
+    pub(crate) offset: PointerOffset,
+    pub(crate) origin: UniqueServerId,
+}
+
+impl<ResponseType: Debug, Service: crate::service::Service> Deref
+    for Response<ResponseType, Service>
+{
+    type Target = ResponseType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<ResponseType: Debug, Service: crate::service::Service> Drop
+    for Response<ResponseType, Service>
+{
+    fn drop(&mut self) {
+        self.shared_state
+            .release_response(self.channel_id, self.origin, self.offset);
+    }
+}
+
+impl<ResponseType: Debug, Service: crate::service::Service> Response<ResponseType, Service> {
+    /// Returns a reference to the payload of the [`Response`]
+    pub fn payload(&self) -> &ResponseType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the [`Response`].
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`](crate::port::server::Server)
+    pub fn origin(&self) -> UniqueServerId {
+        self.origin
+    }
+}
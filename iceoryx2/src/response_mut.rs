@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #     .request_response()
+//! #     .typed::<u64, u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     let response = request.loan_uninit()?;
+//!     let response = response.write_payload(*request + 1);
+//!
+//!     println!("response for request id: {:?}", response.header().request_id());
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::{
+        port_identifiers::UniqueClientId,
+        server::{ServerSendError, ServerSharedState},
+    },
+    raw_sample::RawSampleMut,
+    service::header::request_response::ResponseHeader,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+
+/// Acquired by an [`crate::active_request::ActiveRequest`] via
+/// [`crate::active_request::ActiveRequest::loan()`] or
+/// [`crate::active_request::ActiveRequest::loan_uninit()`]. It stores the payload of the
+/// response that will be sent to the [`crate::port::client::Client`] that sent the request.
+/// If the [`ResponseMut`] is not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the
+/// [`crate::port::server::Server`] and the [`crate::port::server::Server`] is not thread-safe!
+///
+/// The generic parameter `ResponseType` is either a `ResponseType` or a
+/// [`core::mem::MaybeUninit<ResponseType>`], depending which API is used to obtain the response.
+#[derive(Debug)]
+pub struct ResponseMut<ResponseType: Debug, Service: crate::service::Service> {
+    shared_state: Arc<ServerSharedState<Service>>,
+    ptr: RawSampleMut<ResponseHeader, ResponseType>,
+    offset_to_chunk: PointerOffset,
+    channel_id: usize,
+    destination: UniqueClientId,
+}
+
+impl<ResponseType: Debug, Service: crate::service::Service> Drop
+    for ResponseMut<ResponseType, Service>
+{
+    fn drop(&mut self) {
+        self.shared_state
+            .return_loaned_response(self.offset_to_chunk);
+    }
+}
+
+impl<ResponseType: Debug, Service: crate::service::Service>
+    ResponseMut<MaybeUninit<ResponseType>, Service>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ServerSharedState<Service>>,
+        ptr: RawSampleMut<ResponseHeader, MaybeUninit<ResponseType>>,
+        offset_to_chunk: PointerOffset,
+        channel_id: usize,
+        destination: UniqueClientId,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            ptr,
+            offset_to_chunk,
+            channel_id,
+            destination,
+        }
+    }
+
+    /// Writes the payload to the response and labels the response as initialized
+    pub fn write_payload(mut self, value: ResponseType) -> ResponseMut<ResponseType, Service> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<ResponseType>`] container and labels
+    /// the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<ResponseType>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(self) -> ResponseMut<ResponseType, Service> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        ResponseType: Debug, // `ResponseType` is either a `ResponseType` or a `MaybeUninit<ResponseType>`
+        Service: crate::service::Service,
+    > ResponseMut<ResponseType, Service>
+{
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the response.
+    pub fn payload(&self) -> &ResponseType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    pub fn payload_mut(&mut self) -> &mut ResponseType {
+        self.ptr.as_data_mut()
+    }
+
+    /// Sends the [`ResponseMut`] to the [`crate::port::client::Client`] that sent the
+    /// corresponding request. The payload must be initialized before it can be sent. Have a
+    /// look at [`ResponseMut::write_payload()`] and [`ResponseMut::assume_init()`] for more
+    /// details.
+    ///
+    /// On failure a [`ServerSendError`] describing the failure is returned.
+    pub fn send(self) -> Result<(), ServerSendError> {
+        self.shared_state
+            .send_response(self.channel_id, self.destination, self.offset_to_chunk)
+    }
+}
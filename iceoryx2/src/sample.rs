@@ -16,7 +16,9 @@
 //! use iceoryx2::prelude::*;
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! # let service = zero_copy::Service::new(&service_name)
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
 //! #   .publish_subscribe()
 //! #   .typed::<u64>()
 //! #   .open_or_create()?;
@@ -46,7 +48,7 @@ use crate::service::header::publish_subscribe::Header;
 /// it receives new data from a [`Publisher`](crate::port::publisher::Publisher) via
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
 #[derive(Debug)]
-pub struct Sample<MessageType: Debug, Service: crate::service::Service> {
+pub struct Sample<MessageType: Debug + ?Sized, Service: crate::service::Service> {
     pub(crate) publisher_connections: Arc<PublisherConnections<Service>>,
     pub(crate) ptr: RawSample<Header, MessageType>,
     pub(crate) channel_id: usize,
@@ -54,14 +56,18 @@ pub struct Sample<MessageType: Debug, Service: crate::service::Service> {
     pub(crate) origin: UniquePublisherId,
 }
 
-impl<MessageType: Debug, Service: crate::service::Service> Deref for Sample<MessageType, Service> {
+impl<MessageType: Debug + ?Sized, Service: crate::service::Service> Deref
+    for Sample<MessageType, Service>
+{
     type Target = MessageType;
     fn deref(&self) -> &Self::Target {
         self.ptr.as_data_ref()
     }
 }
 
-impl<MessageType: Debug, Service: crate::service::Service> Drop for Sample<MessageType, Service> {
+impl<MessageType: Debug + ?Sized, Service: crate::service::Service> Drop
+    for Sample<MessageType, Service>
+{
     fn drop(&mut self) {
         match self.publisher_connections.get(self.channel_id) {
             Some(c) => {
@@ -81,7 +87,7 @@ impl<MessageType: Debug, Service: crate::service::Service> Drop for Sample<Messa
     }
 }
 
-impl<MessageType: Debug, Service: crate::service::Service> Sample<MessageType, Service> {
+impl<MessageType: Debug + ?Sized, Service: crate::service::Service> Sample<MessageType, Service> {
     /// Returns a reference to the payload of the [`Sample`]
     pub fn payload(&self) -> &MessageType {
         self.ptr.as_data_ref()
@@ -17,7 +17,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
 //! #
-//! # let service = zero_copy::Service::new(&service_name)
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
 //! #     .publish_subscribe()
 //! #     .typed::<u64>()
 //! #     .open_or_create()?;
@@ -35,7 +37,7 @@
 //! ```
 
 use crate::{
-    port::publisher::{DataSegment, PublisherSendError},
+    port::publisher::{PublisherBackend, PublisherSendError},
     raw_sample::RawSampleMut,
     service::header::publish_subscribe::Header,
 };
@@ -54,32 +56,34 @@ use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
 /// [`crate::port::publisher::Publisher`] is not thread-safe!
 ///
 /// The generic parameter `M` is either a `MessageType` or a [`core::mem::MaybeUninit<MessageType>`], depending
-/// which API is used to obtain the sample.
+/// which API is used to obtain the sample. For slice payloads it is either `[ElementType]` or
+/// `[MaybeUninit<ElementType>]`.
 #[derive(Debug)]
-pub struct SampleMut<MessageType: Debug, Service: crate::service::Service> {
-    data_segment: Arc<DataSegment<Service>>,
+pub struct SampleMut<MessageType: Debug + ?Sized, Service: crate::service::Service> {
+    publisher_backend: Arc<PublisherBackend<Service>>,
     ptr: RawSampleMut<Header, MessageType>,
     pub(crate) offset_to_chunk: PointerOffset,
 }
 
-impl<MessageType: Debug, Service: crate::service::Service> Drop
+impl<MessageType: Debug + ?Sized, Service: crate::service::Service> Drop
     for SampleMut<MessageType, Service>
 {
     fn drop(&mut self) {
-        self.data_segment.return_loaned_sample(self.offset_to_chunk);
+        self.publisher_backend
+            .return_loaned_sample(self.offset_to_chunk);
     }
 }
 
-impl<MessageType: Debug, Service: crate::service::Service>
-    SampleMut<MaybeUninit<MessageType>, Service>
+impl<MessageType: Debug + ?Sized, Service: crate::service::Service>
+    SampleMut<MessageType, Service>
 {
     pub(crate) fn new(
-        data_segment: &Arc<DataSegment<Service>>,
-        ptr: RawSampleMut<Header, MaybeUninit<MessageType>>,
+        publisher_backend: &Arc<PublisherBackend<Service>>,
+        ptr: RawSampleMut<Header, MessageType>,
         offset_to_chunk: PointerOffset,
     ) -> Self {
         Self {
-            data_segment: Arc::clone(data_segment),
+            publisher_backend: Arc::clone(publisher_backend),
             ptr,
             offset_to_chunk,
         }
@@ -98,7 +102,9 @@ impl<MessageType: Debug, Service: crate::service::Service>
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -133,7 +139,9 @@ impl<MessageType: Debug, Service: crate::service::Service>
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -155,8 +163,108 @@ impl<MessageType: Debug, Service: crate::service::Service>
     }
 }
 
+impl<ElementType: Debug, Service: crate::service::Service>
+    SampleMut<[MaybeUninit<ElementType>], Service>
+{
+    /// Initializes every element of the slice payload with the value returned by `initializer`,
+    /// which is called with the index of the element, and labels the sample as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_slice_len(16).create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(16)?;
+    /// let sample = sample.write_from_fn(|index| index as u64 * 2);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> ElementType>(
+        mut self,
+        mut initializer: F,
+    ) -> SampleMut<[ElementType], Service> {
+        for (i, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(i));
+        }
+
+        // SAFETY: this is safe since every element was initialized in the loop above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the values of the [`core::mem::MaybeUninit<ElementType>`] slice and labels the
+    /// sample as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the slice really is initialized. Calling
+    /// this when the content is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(self) -> SampleMut<[ElementType], Service> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<ElementType: Debug + Copy, Service: crate::service::Service>
+    SampleMut<[MaybeUninit<ElementType>], Service>
+{
+    /// Copies the elements of `value` into the slice payload and labels the sample as
+    /// initialized. The length of `value` must be equal to the length of the loaned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher().max_slice_len(16).create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(4)?;
+    /// let sample = sample.write_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice(mut self, value: &[ElementType]) -> SampleMut<[ElementType], Service> {
+        assert!(
+            self.payload().len() == value.len(),
+            "The length of the provided slice must be equal to the length of the loaned slice."
+        );
+
+        for (element, v) in self.payload_mut().iter_mut().zip(value.iter()) {
+            element.write(*v);
+        }
+
+        // SAFETY: this is safe since every element was initialized in the loop above
+        unsafe { self.assume_init() }
+    }
+}
+
 impl<
-        M: Debug, // `M` is either a `MessageType` or a `MaybeUninit<MessageType>`
+        M: Debug + ?Sized, // `M` is either a `MessageType`, a `[ElementType]` or their `MaybeUninit` counterpart
         Service: crate::service::Service,
     > SampleMut<M, Service>
 {
@@ -170,7 +278,9 @@ impl<
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -186,6 +296,41 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Attaches a filter key to the sample. Before the sample is delivered it is compared with
+    /// the filter of every [`crate::port::subscriber::Subscriber`], see
+    /// [`PortFactorySubscriber::filter_keys()`](crate::service::port_factory::subscriber::PortFactorySubscriber::filter_keys()),
+    /// and a [`crate::port::subscriber::Subscriber`] whose filter does not match never
+    /// receives the sample. Without a filter key the sample has the filter key `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sensor_id = 12;
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_filter_key(sensor_id);
+    /// *sample.payload_mut() = 4567;
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_filter_key(&mut self, filter_key: u64) {
+        self.ptr.as_header_mut().set_filter_key(filter_key)
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -201,7 +346,9 @@ impl<
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -232,7 +379,9 @@ impl<
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
     /// #
-    /// # let service = zero_copy::Service::new(&service_name)
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
     /// #     .publish_subscribe()
     /// #     .typed::<u64>()
     /// #     .open_or_create()?;
@@ -259,6 +408,7 @@ impl<
     /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
     /// the data is returned, otherwise a [`PublisherSendError`] describing the failure.
     pub fn send(self) -> Result<usize, PublisherSendError> {
-        self.data_segment.send_sample(self.offset_to_chunk.value())
+        self.publisher_backend
+            .send_sample(self.offset_to_chunk.value())
     }
 }
@@ -0,0 +1,86 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .attribute("owner", "perception")
+//!     .attribute("schema_version", "3")
+//!     .publish_subscribe()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! for (key, value) in service.attributes().iter() {
+//!     println!("{} = {}", key, value);
+//! }
+//!
+//! println!("schema version: {:?}", service.attributes().get("schema_version"));
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// User-defined key-value attributes of a [`crate::service::Service`], like the owner or the
+/// schema version. They are defined with [`crate::service::builder::Builder::attribute()`] when
+/// the [`crate::service::Service`] is created and stored in its
+/// [`StaticConfig`](crate::service::static_config::StaticConfig). They are accessible from the
+/// port factory of the service and from [`crate::service::Service::list()`]. Every key is unique.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AttributeSet(BTreeMap<String, String>);
+
+impl AttributeSet {
+    pub(crate) fn insert(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    /// Returns the value of the attribute with the provided key. If the attribute does not
+    /// exist it returns [`None`].
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|value| value.as_str())
+    }
+
+    /// Returns an iterator over all key-value pairs, ordered by their key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the number of attributes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if no attribute is defined, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true when every attribute of `required` is contained with the same value.
+    pub(crate) fn contains_all(&self, required: &AttributeSet) -> bool {
+        required
+            .iter()
+            .all(|(key, value)| self.get(key) == Some(value))
+    }
+}
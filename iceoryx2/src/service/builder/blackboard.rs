@@ -0,0 +1,516 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .max_readers(4)
+//!     .add::<f32>(0, 1.5)
+//!     .add::<[u8; 4]>(1, [0; 4])
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::blackboard;
+use crate::service::static_config::type_details::TypeDetails;
+use crate::service::*;
+use crate::service::{
+    self,
+    dynamic_config::blackboard::{entry_memory_size, value_type_id, DynamicConfigSettings},
+};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::hash::HashValue;
+
+use super::{OpenDynamicStorageFailure, ServiceState};
+
+/// Failures that can occur when an existing [`MessagingPattern::Blackboard`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardOpenError {
+    DoesNotExist,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    IncompatibleMessagingPattern,
+    IncompatibleKeyType,
+    InternalFailure,
+    HangsInCreation,
+    DoesNotSupportRequestedAmountOfReaders,
+    DoesNotSupportRequestedAmountOfNodes,
+    IncompatibleAttributes,
+    ExceedsMaxNumberOfNodes,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for BlackboardOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenError {}
+
+/// Failures that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardCreateError {
+    Corrupted,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    AlreadyExists,
+    PermissionDenied,
+    UnableToCreateStaticServiceInformation,
+    OldConnectionsStillActive,
+    NoEntriesProvided,
+    DuplicateKeys,
+}
+
+impl std::fmt::Display for BlackboardCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardCreateError {}
+
+enum_gen! {
+    /// Failures that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be
+    /// opened or created.
+    BlackboardOpenOrCreateError
+  mapping:
+    BlackboardOpenError,
+    BlackboardCreateError
+}
+
+impl std::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenOrCreateError {}
+
+struct BuilderEntry<KeyType> {
+    key: KeyType,
+    value_type_name: &'static str,
+    value_type: HashValue,
+    value_layout: Layout,
+    value_init: Box<dyn Fn(*mut u8)>,
+}
+
+impl<KeyType: Debug> Debug for BuilderEntry<KeyType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BuilderEntry {{ key: {:?}, value_type_name: {}, value_layout: {:?} }}",
+            self.key, self.value_type_name, self.value_layout
+        )
+    }
+}
+
+/// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<
+    KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    ServiceType: service::Service,
+> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    entries: Vec<BuilderEntry<KeyType>>,
+    verify_max_readers: bool,
+    verify_max_nodes: bool,
+}
+
+impl<
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+        ServiceType: service::Service,
+    > Builder<KeyType, ServiceType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            entries: Vec::new(),
+            verify_max_readers: false,
+            verify_max_nodes: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Blackboard(
+            static_config::blackboard::StaticConfig::new(new_self.base.global_config.as_ref()),
+        );
+        new_self.config_details().key_type_details = TypeDetails::from_type::<KeyType>();
+
+        new_self
+    }
+
+    fn config_details(&mut self) -> &mut static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::reader::Reader`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::reader::Reader`] must be at least supported.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.config_details().max_readers = value;
+        self.verify_max_readers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::node::Node`]s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`crate::node::Node`]s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// Adds a new entry with the provided `key` and the initial `value` to the blackboard. The
+    /// entries are only used when the [`Service`] is created, an existing [`Service`] always
+    /// provides the entries it was created with.
+    pub fn add<ValueType: Copy + Debug + ZeroCopySend + 'static>(
+        mut self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        self.entries.push(BuilderEntry {
+            key,
+            value_type_name: std::any::type_name::<ValueType>(),
+            value_type: value_type_id::<ServiceType::ServiceNameHasher>(&TypeDetails::from_type::<
+                ValueType,
+            >()),
+            value_layout: Layout::new::<UnrestrictedAtomic<ValueType>>(),
+            value_init: Box::new(move |ptr: *mut u8| unsafe {
+                (ptr as *mut UnrestrictedAtomic<ValueType>).write(UnrestrictedAtomic::new(value))
+            }),
+        });
+        self
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        let msg = "Unable to open or create blackboard service";
+
+        match self.base.is_service_available() {
+            Ok(Some(_)) => Ok(self.open()?),
+            Ok(None) => match self.create_impl() {
+                Ok(factory) => Ok(factory),
+                Err(BlackboardCreateError::AlreadyExists)
+                | Err(BlackboardCreateError::IsBeingCreatedByAnotherInstance) => Ok(self.open()?),
+                Err(e) => Err(e.into()),
+            },
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => Ok(self.open()?),
+            Err(ServiceState::Corrupted) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::ServiceInCorruptedState),
+                    "{} since the blackboard is in a corrupted state.", msg);
+            }
+            Err(ServiceState::IncompatibleMessagingPattern) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceState::PermissionDenied) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::PermissionDenied),
+                    "{} due to insufficient permissions.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.base.is_service_available() {
+                Ok(None) => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist,
+                        "{} since the blackboard does not exist.", msg);
+                }
+                Ok(Some((existing_config, static_storage))) => {
+                    let static_config = self.verify_service_properties(&existing_config)?;
+
+                    let (dynamic_config, dynamic_config_node_handle) = match self
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok((dynamic_config, handle)) => (Arc::new(dynamic_config), handle),
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with BlackboardOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with BlackboardOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service informations could not be opened ({:?}).", msg, e);
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(static_config);
+                    self.base.service_config.attributes = existing_config.attributes;
+
+                    return Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.base.service_config,
+                            self.base.shared_node.clone(),
+                            dynamic_config,
+                            static_storage,
+                            dynamic_config_node_handle,
+                        ),
+                    )));
+                }
+                Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with BlackboardOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceState::PermissionDenied) => {
+                    fail!(from self, with BlackboardOpenError::PermissionDenied,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(ServiceState::IncompatibleMessagingPattern) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceState::Corrupted) => {
+                    fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                        "{} since the blackboard is in a corrupted state.", msg);
+                }
+            }
+        }
+    }
+
+    fn create_impl(
+        &mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create blackboard service";
+
+        if self.entries.is_empty() {
+            fail!(from self, with BlackboardCreateError::NoEntriesProvided,
+                "{} since no entries were provided. A blackboard requires at least one entry.", msg);
+        }
+
+        for (n, entry) in self.entries.iter().enumerate() {
+            if self.entries[n + 1..].iter().any(|e| e.key == entry.key) {
+                fail!(from self, with BlackboardCreateError::DuplicateKeys,
+                    "{} since the key {:?} was added more than once.", msg, entry.key);
+            }
+        }
+
+        match self.base.is_service_available() {
+            Ok(None) => {
+                let static_config = match self.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::UnableToCreateStaticServiceInformation,
+                            "{} since the static service information could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                let blackboard_config = self.base.service_config.blackboard();
+
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_readers: blackboard_config.max_readers,
+                    number_of_entries: self.entries.len(),
+                    entries_memory_size: self
+                        .entries
+                        .iter()
+                        .map(|e| entry_memory_size(Layout::new::<KeyType>(), e.value_layout))
+                        .sum(),
+                };
+
+                let entries = &self.entries;
+                let (dynamic_config, dynamic_config_node_handle) = match self
+                    .base
+                    .create_dynamic_config_storage_with_initializer(
+                        dynamic_config::MessagingPattern::Blackboard(
+                            dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
+                        ),
+                        dynamic_config::blackboard::DynamicConfig::memory_size(
+                            &dynamic_config_setting,
+                        ),
+                        |config, allocator| {
+                            let blackboard = config.blackboard_mut();
+                            entries.iter().all(|e| unsafe {
+                                blackboard.add_entry(
+                                    allocator,
+                                    e.key,
+                                    e.value_layout,
+                                    e.value_type,
+                                    &*e.value_init,
+                                )
+                            })
+                        },
+                    ) {
+                    Ok((c, handle)) => (Arc::new(c), handle),
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::OldConnectionsStillActive,
+                            "{} since there are still active Readers or Writers.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                                            with BlackboardCreateError::Corrupted,
+                                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+
+                Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                        dynamic_config_node_handle,
+                    ),
+                )))
+            }
+            Ok(Some(_)) | Err(ServiceState::IncompatibleMessagingPattern) => {
+                fail!(from self, with BlackboardCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceState::PermissionDenied) => {
+                fail!(from self, with BlackboardCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceState::Corrupted) => {
+                fail!(from self, with BlackboardCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help,", msg);
+            }
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.create_impl()
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin, "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard";
+
+        if !self.base.has_required_attributes(existing_settings) {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attributes {:?}.",
+                msg, self.base.service_config.attributes);
+        }
+
+        let required_settings = self.base.service_config.blackboard();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
+            }
+        };
+
+        if existing_settings.key_type_details != required_settings.key_type_details {
+            fail!(from self, with BlackboardOpenError::IncompatibleKeyType,
+                "{} since the service has an incompatible key type of {:?} but {:?} was requested.",
+                msg, existing_settings.key_type_details, required_settings.key_type_details);
+        }
+
+        if self.verify_max_readers && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                "{} since the blackboard supports only {} readers but a support of {} readers was requested.",
+                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the blackboard supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
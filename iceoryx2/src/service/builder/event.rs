@@ -24,7 +24,7 @@ use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 
-use super::ServiceState;
+use super::{OpenDynamicStorageFailure, ServiceState};
 
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -38,6 +38,10 @@ pub enum EventOpenError {
     DoesNotSupportRequestedAmountOfNotifiers,
     DoesNotSupportRequestedAmountOfListeners,
     DoesNotSupportRequestedMaxEventId,
+    DoesNotSupportRequestedAmountOfNodes,
+    IncompatibleAttributes,
+    IncompatibleCountingBehavior,
+    ExceedsMaxNumberOfNodes,
     UnableToOpenDynamicServiceInformation,
 }
 
@@ -97,6 +101,8 @@ pub struct Builder<ServiceType: service::Service> {
     verify_max_notifiers: bool,
     verify_max_listeners: bool,
     verify_event_id_max_value: bool,
+    verify_max_nodes: bool,
+    verify_enable_counting: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -106,6 +112,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_max_notifiers: false,
             verify_max_listeners: false,
             verify_event_id_max_value: false,
+            verify_max_nodes: false,
+            verify_enable_counting: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -151,6 +159,26 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines how many [`crate::node::Node`]s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`crate::node::Node`]s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every notification shall be counted per
+    /// [`EventId`] so that a [`crate::port::listener::Listener`] can acquire how often an
+    /// [`EventId`] was notified, even when wake ups were coalesced or lost, see
+    /// [`crate::port::listener::Listener::try_wait_all_with_count()`]. If an existing
+    /// [`Service`] is opened it requires the service to have the defined counting behavior.
+    pub fn enable_counting(mut self, value: bool) -> Self {
+        self.config_details().enable_counting = value;
+        self.verify_enable_counting = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
@@ -196,24 +224,35 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     fail!(from self, with EventOpenError::DoesNotExist,
                         "{} since the event does not exist.", msg);
                 }
-                Ok(Some((static_config, static_storage))) => {
-                    let static_config = self.verify_service_properties(&static_config)?;
-
-                    let dynamic_config = Arc::new(
-                        fail!(from self, when self.base.open_dynamic_config_storage(),
-                            with EventOpenError::UnableToOpenDynamicServiceInformation,
-                            "{} since the dynamic service informations could not be opened.", msg),
-                    );
+                Ok(Some((existing_config, static_storage))) => {
+                    let static_config = self.verify_service_properties(&existing_config)?;
+
+                    let (dynamic_config, dynamic_config_node_handle) = match self
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok((dynamic_config, handle)) => (Arc::new(dynamic_config), handle),
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with EventOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with EventOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service informations could not be opened ({:?}).", msg, e);
+                        }
+                    };
 
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::Event(static_config);
+                    self.base.service_config.attributes = existing_config.attributes;
 
                     return Ok(event::PortFactory::new(ServiceType::from_state(
                         service::ServiceState::new(
                             self.base.service_config,
-                            self.base.global_config,
+                            self.base.shared_node.clone(),
                             dynamic_config,
                             static_storage,
+                            dynamic_config_node_handle,
                         ),
                     )));
                 }
@@ -272,15 +311,22 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_listeners: event_config.max_listeners,
                     number_of_notifiers: event_config.max_notifiers,
+                    number_of_event_counters: if event_config.enable_counting {
+                        event_config.max_listeners * (event_config.event_id_max_value + 1)
+                    } else {
+                        0
+                    },
                 };
 
-                let dynamic_config = match self.base.create_dynamic_config_storage(
-                    dynamic_config::MessagingPattern::Event(
-                        dynamic_config::event::DynamicConfig::new(&dynamic_config_setting),
-                    ),
-                    dynamic_config::event::DynamicConfig::memory_size(&dynamic_config_setting),
-                ) {
-                    Ok(c) => Arc::new(c),
+                let (dynamic_config, dynamic_config_node_handle) = match self
+                    .base
+                    .create_dynamic_config_storage(
+                        dynamic_config::MessagingPattern::Event(
+                            dynamic_config::event::DynamicConfig::new(&dynamic_config_setting),
+                        ),
+                        dynamic_config::event::DynamicConfig::memory_size(&dynamic_config_setting),
+                    ) {
+                    Ok((c, handle)) => (Arc::new(c), handle),
                     Err(DynamicStorageCreateError::AlreadyExists) => {
                         fail!(from self, with EventCreateError::OldConnectionsStillActive,
                             "{} since there are still active Listeners or Notifiers.", msg);
@@ -305,9 +351,10 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 Ok(event::PortFactory::new(ServiceType::from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
-                        self.base.global_config.clone(),
+                        self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
+                        dynamic_config_node_handle,
                     ),
                 )))
             }
@@ -348,6 +395,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             warn!(from origin, "Setting the maximum amount of listeners to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_listeners = 1;
         }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
     }
 
     fn verify_service_properties(
@@ -356,6 +409,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<static_config::event::StaticConfig, EventOpenError> {
         let msg = "Unable to open event";
 
+        if !self.base.has_required_attributes(existing_settings) {
+            fail!(from self, with EventOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attributes {:?}.",
+                msg, self.base.service_config.attributes);
+        }
+
         let required_settings = self.base.service_config.event();
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::Event(ref v) => v,
@@ -389,6 +448,20 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.event_id_max_value, required_settings.event_id_max_value);
         }
 
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the event supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        if self.verify_enable_counting
+            && existing_settings.enable_counting != required_settings.enable_counting
+        {
+            fail!(from self, with EventOpenError::IncompatibleCountingBehavior,
+                                "{} since the event has an incompatible counting behavior.",
+                                msg);
+        }
+
         Ok(*existing_settings)
     }
 }
@@ -14,18 +14,30 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
+/// Builder for [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::config;
+use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
@@ -38,9 +50,10 @@ use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
-use std::marker::PhantomData;
+use std::fmt::Debug;
 use std::sync::Arc;
 
+use super::attribute::AttributeSet;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::static_config_storage_config;
 use super::naming_scheme::dynamic_config_storage_name;
@@ -60,7 +73,8 @@ enum_gen! {
 #[doc(hidden)]
     OpenDynamicStorageFailure
   entry:
-    IsMarkedForDestruction
+    IsMarkedForDestruction,
+    ExceedsMaxNumberOfNodes
   mapping:
     DynamicStorageOpenError
 }
@@ -97,33 +111,38 @@ impl std::error::Error for ReadStaticStorageFailure {}
 #[derive(Debug)]
 pub struct Builder<S: Service> {
     name: ServiceName,
-    _phantom_s: PhantomData<S>,
+    attributes: AttributeSet,
+    shared_node: Arc<SharedNode<S>>,
 }
 
 impl<S: Service> Builder<S> {
-    pub(crate) fn new(name: &ServiceName) -> Self {
+    pub(crate) fn new(name: &ServiceName, shared_node: Arc<SharedNode<S>>) -> Self {
         Self {
             name: *name,
-            _phantom_s: PhantomData,
+            attributes: AttributeSet::default(),
+            shared_node,
         }
     }
 
-    /// Create a new builder to create a
-    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    pub fn publish_subscribe(self) -> publish_subscribe::Builder<S> {
-        self.publish_subscribe_with_custom_config(config::Config::get_global_config())
+    /// If the [`Service`] is created, it stores the attribute with the provided key and value
+    /// in the [`StaticConfig`] of the [`Service`]. If an existing [`Service`] is opened it
+    /// requires the [`Service`] to have the attribute with the same value. When the attribute
+    /// was already defined, its value is replaced.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key, value);
+        self
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    /// with a custom [`config::Config`]
-    pub fn publish_subscribe_with_custom_config(
-        self,
-        config: &config::Config,
-    ) -> publish_subscribe::Builder<S> {
+    pub fn publish_subscribe(self) -> publish_subscribe::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(&self.name, config),
-            Arc::new(config.clone()),
+            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.attributes,
+            self.shared_node,
         )
         .publish_subscribe()
     }
@@ -131,18 +150,56 @@ impl<S: Service> Builder<S> {
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
-        self.event_with_custom_config(config::Config::get_global_config())
+        BuilderWithServiceType::new(
+            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            self.attributes,
+            self.shared_node,
+        )
+        .event()
     }
 
     /// Create a new builder to create a
-    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
-    /// with a custom [`config::Config`]
-    pub fn event_with_custom_config(self, config: &config::Config) -> event::Builder<S> {
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response(self) -> request_response::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, config),
-            Arc::new(config.clone()),
+            StaticConfig::new_request_response::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.attributes,
+            self.shared_node,
         )
-        .event()
+        .request_response()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    pub fn pipeline(self) -> pipeline::Builder<S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_pipeline::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.attributes,
+            self.shared_node,
+        )
+        .pipeline()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard<KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.attributes,
+            self.shared_node,
+        )
+        .blackboard::<KeyType>()
     }
 }
 
@@ -151,15 +208,20 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
     service_config: StaticConfig,
     global_config: Arc<config::Config>,
-    _phantom_data: PhantomData<ServiceType>,
+    shared_node: Arc<SharedNode<ServiceType>>,
 }
 
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
-    fn new(service_config: StaticConfig, global_config: Arc<config::Config>) -> Self {
+    fn new(
+        mut service_config: StaticConfig,
+        attributes: AttributeSet,
+        shared_node: Arc<SharedNode<ServiceType>>,
+    ) -> Self {
+        service_config.attributes = attributes;
         Self {
             service_config,
-            global_config,
-            _phantom_data: PhantomData,
+            global_config: Arc::new(shared_node.config().clone()),
+            shared_node,
         }
     }
 
@@ -171,6 +233,20 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn request_response(self) -> request_response::Builder<ServiceType> {
+        request_response::Builder::new(self)
+    }
+
+    fn pipeline(self) -> pipeline::Builder<ServiceType> {
+        pipeline::Builder::new(self)
+    }
+
+    fn blackboard<KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, ServiceType> {
+        blackboard::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
@@ -237,6 +313,14 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    /// Returns true when the existing [`Service`] has all attributes that were defined with
+    /// [`Builder::attribute()`].
+    fn has_required_attributes(&self, existing_config: &StaticConfig) -> bool {
+        existing_config
+            .attributes
+            .contains_all(&self.service_config.attributes)
+    }
+
     fn config_init_call(config: &mut DynamicConfig, allocator: &mut BumpAllocator) -> bool {
         unsafe { config.init(allocator) };
         true
@@ -246,27 +330,57 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         &self,
         messaging_pattern: super::dynamic_config::MessagingPattern,
         additional_size: usize,
-    ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
-        match <<ServiceType::DynamicStorage as DynamicStorage<
+    ) -> Result<(ServiceType::DynamicStorage, ContainerHandle), DynamicStorageCreateError> {
+        self.create_dynamic_config_storage_with_initializer(
+            messaging_pattern,
+            additional_size,
+            |_, _| true,
+        )
+    }
+
+    /// Creates the dynamic config storage and calls `initializer` after the [`DynamicConfig`]
+    /// was initialized. It can be used to store messaging pattern specific data in the
+    /// supplementary memory of the dynamic config.
+    fn create_dynamic_config_storage_with_initializer<
+        F: FnMut(&mut DynamicConfig, &mut BumpAllocator) -> bool,
+    >(
+        &self,
+        messaging_pattern: super::dynamic_config::MessagingPattern,
+        additional_size: usize,
+        mut initializer: F,
+    ) -> Result<(ServiceType::DynamicStorage, ContainerHandle), DynamicStorageCreateError> {
+        let max_number_of_nodes = self.service_config.max_nodes();
+        let dynamic_storage = match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&dynamic_config_storage_name(&self.service_config))
             .config(&dynamic_config_storage_config::<ServiceType>(self.global_config.as_ref()))
-            .supplementary_size(additional_size)
+            .supplementary_size(additional_size + DynamicConfig::memory_size(max_number_of_nodes))
             .has_ownership(false)
-            .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(messaging_pattern) ) {
-                Ok(dynamic_storage) => Ok(dynamic_storage),
+            .initializer(|config, allocator| Self::config_init_call(config, allocator) && initializer(config, allocator))
+            .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes) ) {
+                Ok(dynamic_storage) => dynamic_storage,
                 Err(e) => {
                     fail!(from self, with e, "Failed to create dynamic storage for service.");
                 }
+            };
+
+        match dynamic_storage
+            .get()
+            .register_node_id(*self.shared_node.id())
+        {
+            Some(handle) => Ok((dynamic_storage, handle)),
+            None => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "Failed to create dynamic storage for service since the node could not be registered. This should never happen!");
             }
+        }
     }
 
     fn open_dynamic_config_storage(
         &self,
-    ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
+    ) -> Result<(ServiceType::DynamicStorage, ContainerHandle), OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
         let storage = fail!(from self, when
             <<ServiceType::DynamicStorage as DynamicStorage<
@@ -283,7 +397,16 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 with OpenDynamicStorageFailure::IsMarkedForDestruction,
                 "{} since the dynamic storage is marked for destruction.", msg);
 
-        Ok(storage)
+        match storage.get().register_node_id(*self.shared_node.id()) {
+            Some(handle) => Ok((storage, handle)),
+            None => {
+                // the service is still in use by other nodes, the counter can never drop to
+                // zero here
+                storage.get().decrement_reference_counter();
+                fail!(from self, with OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes,
+                    "{} since it would exceed the maximum number of supported nodes.", msg);
+            }
+        }
     }
 
     fn create_static_config_storage(
@@ -0,0 +1,615 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::marker::PhantomData;
+
+use crate::message::Message;
+use crate::service;
+use crate::service::dynamic_config::pipeline::DynamicConfigSettings;
+use crate::service::header::pipeline::Header;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::pipeline;
+use crate::service::static_config::pipeline::DistributionStrategy;
+use crate::service::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use super::{OpenDynamicStorageFailure, ServiceState};
+
+/// Errors that can occur when an existing [`MessagingPattern::Pipeline`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipelineOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleDistributionStrategy,
+    DoesNotSupportRequestedAmountOfProducers,
+    DoesNotSupportRequestedAmountOfConsumers,
+    DoesNotSupportRequestedMinBufferSize,
+    DoesNotSupportRequestedMinConsumerBorrowedItems,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    DoesNotSupportRequestedAmountOfNodes,
+    IncompatibleAttributes,
+    ExceedsMaxNumberOfNodes,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for PipelineOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::Pipeline`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipelineCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+    OldConnectionsStillActive,
+}
+
+impl std::fmt::Display for PipelineCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::Pipeline`] [`Service`] shall be
+    /// created or opened.
+    PipelineOpenOrCreateError
+  mapping:
+    PipelineOpenError,
+    PipelineCreateError
+}
+
+impl std::fmt::Display for PipelineOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::Pipeline`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<ServiceType: service::Service> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    verify_number_of_producers: bool,
+    verify_number_of_consumers: bool,
+    verify_consumer_max_buffer_size: bool,
+    verify_consumer_max_borrowed_items: bool,
+    verify_distribution_strategy: bool,
+    verify_max_nodes: bool,
+}
+
+impl<ServiceType: service::Service> Builder<ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_producers: false,
+            verify_number_of_consumers: false,
+            verify_consumer_max_buffer_size: false,
+            verify_consumer_max_borrowed_items: false,
+            verify_distribution_strategy: false,
+            verify_max_nodes: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Pipeline(
+            static_config::pipeline::StaticConfig::new(new_self.base.global_config.as_ref()),
+        );
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::pipeline::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Pipeline builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::pipeline::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Pipeline builder!");
+            }
+        }
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.pipeline().type_name != self.config_details().type_name {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
+                        error_msg, &config.pipeline().type_name, self.config_details().type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    fn finalize_config<PayloadType: Debug + ZeroCopySend>(&mut self) {
+        let details = self.config_details_mut();
+        details.type_name = std::any::type_name::<PayloadType>().to_string();
+        details.type_size = core::mem::size_of::<Message<Header, PayloadType>>();
+        details.type_alignment = core::mem::align_of::<Message<Header, PayloadType>>();
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::producer::Producer`]
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::producer::Producer`] must be at least supported.
+    pub fn max_producers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_producers = value;
+        self.verify_number_of_producers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::consumer::Consumer`]
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::consumer::Consumer`] must be at least supported.
+    pub fn max_consumers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_consumers = value;
+        self.verify_number_of_consumers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::node::Node`]s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`crate::node::Node`]s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details_mut().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many work items a
+    /// [`crate::port::consumer::Consumer`] can buffer per [`crate::port::producer::Producer`].
+    /// If an existing [`Service`] is opened it defines the minimum required.
+    pub fn consumer_max_buffer_size(mut self, value: usize) -> Self {
+        self.config_details_mut().consumer_max_buffer_size = value;
+        self.verify_consumer_max_buffer_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::work_item::WorkItem`]s a
+    /// [`crate::port::consumer::Consumer`] can borrow at most in parallel from a single
+    /// [`crate::port::producer::Producer`]. If an existing [`Service`] is opened it defines the
+    /// minimum required.
+    pub fn consumer_max_borrowed_items(mut self, value: usize) -> Self {
+        self.config_details_mut().consumer_max_borrowed_items = value;
+        self.verify_consumer_max_borrowed_items = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`DistributionStrategy`] the
+    /// [`crate::port::producer::Producer`]s use to select the receiving
+    /// [`crate::port::consumer::Consumer`]. If an existing [`Service`] is opened it defines the
+    /// required [`DistributionStrategy`].
+    pub fn distribution_strategy(mut self, value: DistributionStrategy) -> Self {
+        self.config_details_mut().distribution_strategy = value;
+        self.verify_distribution_strategy = true;
+        self
+    }
+
+    /// Defines the work item type of the [`Service`]. It must implement [`ZeroCopySend`].
+    pub fn typed<PayloadType: Debug + ZeroCopySend>(
+        self,
+    ) -> TypedBuilder<PayloadType, ServiceType> {
+        TypedBuilder {
+            builder: self,
+            _payload: PhantomData,
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.pipeline_mut();
+
+        if settings.max_producers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of producers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_producers = 1;
+        }
+
+        if settings.max_consumers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of consumers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_consumers = 1;
+        }
+
+        if settings.consumer_max_buffer_size == 0 {
+            warn!(from origin,
+                "Setting the consumers max buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.consumer_max_buffer_size = 1;
+        }
+
+        if settings.consumer_max_borrowed_items == 0 {
+            warn!(from origin,
+                "Setting the consumers max borrowed items to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.consumer_max_borrowed_items = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::pipeline::StaticConfig, PipelineOpenError> {
+        let msg = "Unable to open pipeline service";
+
+        if !self.base.has_required_attributes(existing_settings) {
+            fail!(from self, with PipelineOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attributes {:?}.",
+                msg, self.base.service_config.attributes);
+        }
+
+        let required_settings = self.base.service_config.pipeline();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            p => {
+                fail!(from self, with PipelineOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Pipeline is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_producers
+            && existing_settings.max_producers < required_settings.max_producers
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfProducers,
+                                "{} since the service supports only {} producers but a support of {} producers was requested.",
+                                msg, existing_settings.max_producers, required_settings.max_producers);
+        }
+
+        if self.verify_number_of_consumers
+            && existing_settings.max_consumers < required_settings.max_consumers
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfConsumers,
+                                "{} since the service supports only {} consumers but a support of {} consumers was requested.",
+                                msg, existing_settings.max_consumers, required_settings.max_consumers);
+        }
+
+        if self.verify_consumer_max_buffer_size
+            && existing_settings.consumer_max_buffer_size
+                < required_settings.consumer_max_buffer_size
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedMinBufferSize,
+                                "{} since the service supports only a consumer buffer size of {} but a buffer size of {} was requested.",
+                                msg, existing_settings.consumer_max_buffer_size, required_settings.consumer_max_buffer_size);
+        }
+
+        if self.verify_consumer_max_borrowed_items
+            && existing_settings.consumer_max_borrowed_items
+                < required_settings.consumer_max_borrowed_items
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedMinConsumerBorrowedItems,
+                                "{} since the service supports only {} borrowed consumer items but {} borrowed consumer items were requested.",
+                                msg, existing_settings.consumer_max_borrowed_items, required_settings.consumer_max_borrowed_items);
+        }
+
+        if self.verify_distribution_strategy
+            && existing_settings.distribution_strategy != required_settings.distribution_strategy
+        {
+            fail!(from self, with PipelineOpenError::IncompatibleDistributionStrategy,
+                                "{} since the service uses the distribution strategy {:?} but {:?} was requested.",
+                                msg, existing_settings.distribution_strategy, required_settings.distribution_strategy);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
+
+/// Builder to create new [`MessagingPattern::Pipeline`] based [`Service`]s with a
+/// defined work item type.
+#[derive(Debug)]
+pub struct TypedBuilder<PayloadType: Debug + ZeroCopySend, ServiceType: service::Service> {
+    builder: Builder<ServiceType>,
+    _payload: PhantomData<PayloadType>,
+}
+
+impl<PayloadType: Debug + ZeroCopySend, ServiceType: service::Service>
+    TypedBuilder<PayloadType, ServiceType>
+{
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<pipeline::PortFactory<ServiceType, PayloadType>, PipelineOpenOrCreateError> {
+        let msg = "Unable to open or create pipeline service";
+        self.builder.finalize_config::<PayloadType>();
+
+        match self.builder.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open()?),
+            Ok(None) => match self.create_impl() {
+                Ok(factory) => Ok(factory),
+                Err(PipelineCreateError::AlreadyExists)
+                | Err(PipelineCreateError::IsBeingCreatedByAnotherInstance) => Ok(self.open()?),
+                Err(e) => Err(e.into()),
+            },
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open()?),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::IncompatibleTypes),
+                    "{} since the service has an incompatible type.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<pipeline::PortFactory<ServiceType, PayloadType>, PipelineOpenError> {
+        let msg = "Unable to open pipeline service";
+        self.builder.finalize_config::<PayloadType>();
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with PipelineOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.builder.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with PipelineOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((existing_config, static_storage))) => {
+                    let static_config = self.builder.verify_service_properties(&existing_config)?;
+
+                    let (dynamic_config, dynamic_config_node_handle) = match self
+                        .builder
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok((dynamic_config, handle)) => (Arc::new(dynamic_config), handle),
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with PipelineOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with PipelineOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
+
+                    self.builder.base.service_config.messaging_pattern =
+                        MessagingPattern::Pipeline(static_config.clone());
+                    self.builder.base.service_config.attributes = existing_config.attributes;
+
+                    return Ok(pipeline::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.builder.base.service_config,
+                            self.builder.base.shared_node.clone(),
+                            dynamic_config,
+                            static_storage,
+                            dynamic_config_node_handle,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with PipelineOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout
+                        > self
+                            .builder
+                            .base
+                            .global_config
+                            .global
+                            .service
+                            .creation_timeout
+                    {
+                        fail!(from self, with PipelineOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.builder.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with PipelineOpenError::IncompatibleTypes,
+                    "{} since the service has an incompatible type.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with PipelineOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with PipelineOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with PipelineOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    fn create_impl(
+        &mut self,
+    ) -> Result<pipeline::PortFactory<ServiceType, PayloadType>, PipelineCreateError> {
+        self.builder.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create pipeline service";
+        self.builder.finalize_config::<PayloadType>();
+
+        match self.builder.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = match self.builder.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with PipelineCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with PipelineCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with PipelineCreateError::UnableToCreateStaticServiceInformation,
+                            "{} since the static service information could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                let pipeline_config = self.builder.base.service_config.pipeline();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_producers: pipeline_config.max_producers,
+                    number_of_consumers: pipeline_config.max_consumers,
+                };
+
+                let (dynamic_config, dynamic_config_node_handle) = match self
+                    .builder
+                    .base
+                    .create_dynamic_config_storage(
+                        dynamic_config::MessagingPattern::Pipeline(
+                            dynamic_config::pipeline::DynamicConfig::new(&dynamic_config_setting),
+                        ),
+                        dynamic_config::pipeline::DynamicConfig::memory_size(
+                            &dynamic_config_setting,
+                        ),
+                    ) {
+                    Ok((c, handle)) => (Arc::new(c), handle),
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with PipelineCreateError::OldConnectionsStillActive,
+                            "{} since there are still Producers, Consumers or active work items.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with PipelineCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+                let service_config = fail!(from self,
+                            when ServiceType::ConfigSerializer::serialize(&self.builder.base.service_config),
+                            with PipelineCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with PipelineCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+
+                Ok(pipeline::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.builder.base.service_config.clone(),
+                        self.builder.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                        dynamic_config_node_handle,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with PipelineCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with PipelineCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with PipelineCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with PipelineCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<pipeline::PortFactory<ServiceType, PayloadType>, PipelineCreateError> {
+        self.create_impl()
+    }
+}
@@ -16,21 +16,21 @@
 //!
 use std::marker::PhantomData;
 
-use crate::message::Message;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
-use crate::service::header::publish_subscribe::Header;
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::static_config::type_details::{Serialized, TypeDetails, Untyped};
 use crate::service::*;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
 
-use super::ServiceState;
+use super::{OpenDynamicStorageFailure, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -49,6 +49,9 @@ pub enum PublishSubscribeOpenError {
     PermissionDenied,
     ServiceInCorruptedState,
     HangsInCreation,
+    DoesNotSupportRequestedAmountOfNodes,
+    IncompatibleAttributes,
+    ExceedsMaxNumberOfNodes,
     UnableToOpenDynamicServiceInformation,
 }
 
@@ -118,6 +121,7 @@ pub struct Builder<ServiceType: service::Service> {
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_max_nodes: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -130,6 +134,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_max_nodes: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
@@ -165,10 +170,10 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available() {
             Ok(Some((config, storage))) => {
-                if config.publish_subscribe().type_name != self.config_details().type_name {
+                if config.publish_subscribe().type_details != self.config_details().type_details {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
-                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
-                        error_msg, &config.publish_subscribe().type_name , self.config_details().type_name);
+                        "{} since the service offers the type {:?} but the requested type is {:?}.",
+                        error_msg, &config.publish_subscribe().type_details, self.config_details().type_details);
                 }
 
                 Ok(Some((config, storage)))
@@ -178,11 +183,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         }
     }
 
-    fn finalize_config<MessageType: Debug>(&mut self) {
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
-        self.config_details_mut().type_size = core::mem::size_of::<Message<Header, MessageType>>();
-        self.config_details_mut().type_alignment =
-            core::mem::align_of::<Message<Header, MessageType>>();
+    fn finalize_config<MessageType: Debug + ZeroCopySend>(&mut self) {
+        self.config_details_mut().type_details = TypeDetails::from_type::<MessageType>();
+    }
+
+    fn finalize_slice_config<ElementType: Debug + ZeroCopySend>(&mut self) {
+        self.config_details_mut().type_details = TypeDetails::from_slice::<ElementType>();
     }
 
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
@@ -238,7 +244,61 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
-    pub fn typed<MessageType: Debug>(self) -> TypedBuilder<MessageType, ServiceType> {
+    /// If the [`Service`] is created it defines how many [`crate::node::Node`]s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`crate::node::Node`]s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details_mut().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// Defines the payload type of the [`Service`]. It is either a fixed size type `T` or a
+    /// dynamically sized slice `[T]`. The maximum slice length is defined per
+    /// [`crate::port::publisher::Publisher`] with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_slice_len()`].
+    /// The payload must implement [`ZeroCopySend`], custom types can derive it with
+    /// `#[derive(ZeroCopySend)]` which verifies at compile time that they can be placed into
+    /// shared memory.
+    pub fn typed<MessageType: Debug + ZeroCopySend + ?Sized>(
+        self,
+    ) -> TypedBuilder<MessageType, ServiceType> {
+        TypedBuilder {
+            builder: self,
+            _message_type: PhantomData,
+        }
+    }
+
+    /// Defines the payload type of the [`Service`] at runtime with [`TypeDetails`]. The
+    /// resulting ports do not know the concrete payload type and exchange it as raw bytes.
+    /// Intended for gateways, recorders and language bindings. An existing [`Service`] can only
+    /// be opened when its [`TypeDetails`] are equal to the provided ones.
+    pub fn untyped(mut self, type_details: &TypeDetails) -> TypedBuilder<Untyped, ServiceType> {
+        self.config_details_mut().type_details = type_details.clone();
+        TypedBuilder {
+            builder: self,
+            _message_type: PhantomData,
+        }
+    }
+
+    /// Defines a payload type `T` that is not shared memory compatible, like types containing a
+    /// [`String`] or a [`std::collections::HashMap`]. The payload is serialized with the
+    /// provided `Serializer`, for instance [`Cdr`](iceoryx2_cal::serialize::cdr::Cdr) or
+    /// [`Toml`](iceoryx2_cal::serialize::toml::Toml), into a dynamically sized chunk of bytes
+    /// and deserialized on receive. [`Cdr`](iceoryx2_cal::serialize::cdr::Cdr) does not support
+    /// maps, a value containing one fails with
+    /// [`SerializeError::UnsupportedType`](iceoryx2_cal::serialize::SerializeError::UnsupportedType).
+    /// The maximum size of a serialized value is defined per
+    /// [`crate::port::publisher::Publisher`] with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_serialized_size()`].
+    /// An existing [`Service`] can only be opened with the same payload type and `Serializer`.
+    pub fn serialized<
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    >(
+        mut self,
+    ) -> TypedBuilder<Serialized<T, Serializer>, ServiceType> {
+        self.config_details_mut().type_details = TypeDetails::from_serialized::<T, Serializer>();
         TypedBuilder {
             builder: self,
             _message_type: PhantomData,
@@ -272,6 +332,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
     }
 
     fn verify_service_properties(
@@ -280,6 +346,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<static_config::publish_subscribe::StaticConfig, PublishSubscribeOpenError> {
         let msg = "Unable to open publish subscribe service";
 
+        if !self.base.has_required_attributes(existing_settings) {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attributes {:?}.",
+                msg, self.base.service_config.attributes);
+        }
+
         let required_settings = self.base.service_config.publish_subscribe();
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -339,41 +411,48 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                                 msg);
         }
 
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
         Ok(existing_settings.clone())
     }
 }
 
+/// Builder to create or open a [`MessagingPattern::PublishSubscribe`] based [`Service`] with a
+/// defined payload type. Acquired via [`Builder::typed()`].
 #[derive(Debug)]
-pub struct TypedBuilder<MessageType: Debug, ServiceType: service::Service> {
+pub struct TypedBuilder<MessageType: Debug + ZeroCopySend + ?Sized, ServiceType: service::Service> {
     builder: Builder<ServiceType>,
     _message_type: PhantomData<MessageType>,
 }
 
-impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType, ServiceType> {
-    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
-    /// created.
-    pub fn open_or_create(
+impl<MessageType: Debug + ZeroCopySend + ?Sized, ServiceType: service::Service>
+    TypedBuilder<MessageType, ServiceType>
+{
+    fn open_or_create_impl(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<ServiceType, MessageType>,
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
-        self.builder.finalize_config::<MessageType>();
 
         match self.builder.is_service_available(msg) {
-            Ok(Some(_)) => Ok(self.open()?),
+            Ok(Some(_)) => Ok(self.open_impl()?),
             Ok(None) => match self.create_impl() {
                 Ok(factory) => Ok(factory),
                 Err(PublishSubscribeCreateError::AlreadyExists)
                 | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
-                    Ok(self.open()?)
+                    Ok(self.open_impl()?)
                 }
                 Err(e) => Err(e.into()),
             },
             Err(ServiceAvailabilityState::ServiceState(
                 ServiceState::IsBeingCreatedByAnotherInstance,
-            )) => Ok(self.open()?),
+            )) => Ok(self.open_impl()?),
             Err(ServiceAvailabilityState::IncompatibleTypes) => {
                 fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleTypes),
                     "{} since the service is not type compatible.", msg);
@@ -395,13 +474,11 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
         }
     }
 
-    /// Opens an existing [`Service`].
-    pub fn open(
+    fn open_impl(
         mut self,
     ) -> Result<publish_subscribe::PortFactory<ServiceType, MessageType>, PublishSubscribeOpenError>
     {
         let msg = "Unable to open publish subscribe service";
-        self.builder.finalize_config::<MessageType>();
 
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with PublishSubscribeOpenError::InternalFailure,
@@ -413,24 +490,36 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
                     fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
                         "{} since the service does not exist.", msg);
                 }
-                Ok(Some((static_config, static_storage))) => {
-                    let static_config = self.builder.verify_service_properties(&static_config)?;
+                Ok(Some((existing_config, static_storage))) => {
+                    let static_config = self.builder.verify_service_properties(&existing_config)?;
 
-                    let dynamic_config = Arc::new(
-                        fail!(from self, when self.builder.base.open_dynamic_config_storage(),
-                            with PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation,
-                            "{} since the dynamic service information could not be opened.", msg),
-                    );
+                    let (dynamic_config, dynamic_config_node_handle) = match self
+                        .builder
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok((dynamic_config, handle)) => (Arc::new(dynamic_config), handle),
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
 
                     self.builder.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(static_config.clone());
+                    self.builder.base.service_config.attributes = existing_config.attributes;
 
                     return Ok(publish_subscribe::PortFactory::new(
                         ServiceType::from_state(service::ServiceState::new(
                             self.builder.base.service_config,
-                            self.builder.base.global_config,
+                            self.builder.base.shared_node.clone(),
                             dynamic_config,
                             static_storage,
+                            dynamic_config_node_handle,
                         )),
                     ));
                 }
@@ -484,7 +573,6 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
         self.builder.adjust_properties_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
-        self.builder.finalize_config::<MessageType>();
 
         if !self.builder.config_details().enable_safe_overflow
             && (self.builder.config_details().subscriber_max_buffer_size
@@ -521,17 +609,20 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
                     number_of_subscribers: pubsub_config.max_subscribers,
                 };
 
-                let dynamic_config = match self.builder.base.create_dynamic_config_storage(
-                    dynamic_config::MessagingPattern::PublishSubscribe(
-                        dynamic_config::publish_subscribe::DynamicConfig::new(
+                let (dynamic_config, dynamic_config_node_handle) = match self
+                    .builder
+                    .base
+                    .create_dynamic_config_storage(
+                        dynamic_config::MessagingPattern::PublishSubscribe(
+                            dynamic_config::publish_subscribe::DynamicConfig::new(
+                                &dynamic_config_setting,
+                            ),
+                        ),
+                        dynamic_config::publish_subscribe::DynamicConfig::memory_size(
                             &dynamic_config_setting,
                         ),
-                    ),
-                    dynamic_config::publish_subscribe::DynamicConfig::memory_size(
-                        &dynamic_config_setting,
-                    ),
-                ) {
-                    Ok(c) => Arc::new(c),
+                    ) {
+                    Ok((c, handle)) => (Arc::new(c), handle),
                     Err(DynamicStorageCreateError::AlreadyExists) => {
                         fail!(from self, with PublishSubscribeCreateError::OldConnectionsStillActive,
                             "{} since there are still Publishers, Subscribers or active Samples.", msg);
@@ -556,9 +647,10 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
                 Ok(publish_subscribe::PortFactory::new(
                     ServiceType::from_state(service::ServiceState::new(
                         self.builder.base.service_config.clone(),
-                        self.builder.base.global_config.clone(),
+                        self.builder.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
+                        dynamic_config_node_handle,
                     )),
                 ))
             }
@@ -586,12 +678,155 @@ impl<MessageType: Debug, ServiceType: service::Service> TypedBuilder<MessageType
             }
         }
     }
+}
+
+impl<MessageType: Debug + ZeroCopySend, ServiceType: service::Service>
+    TypedBuilder<MessageType, ServiceType>
+{
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, MessageType>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.builder.finalize_config::<MessageType>();
+        self.open_or_create_impl()
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<publish_subscribe::PortFactory<ServiceType, MessageType>, PublishSubscribeOpenError>
+    {
+        self.builder.finalize_config::<MessageType>();
+        self.open_impl()
+    }
 
     /// Creates a new [`Service`].
     pub fn create(
         mut self,
     ) -> Result<publish_subscribe::PortFactory<ServiceType, MessageType>, PublishSubscribeCreateError>
     {
+        self.builder.finalize_config::<MessageType>();
         self.create_impl()
     }
 }
+
+impl<ElementType: Debug + ZeroCopySend, ServiceType: service::Service>
+    TypedBuilder<[ElementType], ServiceType>
+{
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [ElementType]>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.builder.finalize_slice_config::<ElementType>();
+        self.open_or_create_impl()
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<publish_subscribe::PortFactory<ServiceType, [ElementType]>, PublishSubscribeOpenError>
+    {
+        self.builder.finalize_slice_config::<ElementType>();
+        self.open_impl()
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [ElementType]>,
+        PublishSubscribeCreateError,
+    > {
+        self.builder.finalize_slice_config::<ElementType>();
+        self.create_impl()
+    }
+}
+
+impl<ServiceType: service::Service> TypedBuilder<Untyped, ServiceType> {
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Untyped>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_impl()
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<publish_subscribe::PortFactory<ServiceType, Untyped>, PublishSubscribeOpenError>
+    {
+        self.open_impl()
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<publish_subscribe::PortFactory<ServiceType, Untyped>, PublishSubscribeCreateError>
+    {
+        self.create_impl()
+    }
+}
+
+impl<
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+        ServiceType: service::Service,
+    > TypedBuilder<Serialized<T, Serializer>, ServiceType>
+{
+    fn apply_serialized_defaults(
+        mut factory: publish_subscribe::PortFactory<ServiceType, Serialized<T, Serializer>>,
+    ) -> publish_subscribe::PortFactory<ServiceType, Serialized<T, Serializer>> {
+        factory.default_max_slice_len = factory
+            .service
+            .state()
+            .global_config
+            .defaults
+            .publish_subscribe
+            .max_serialized_size;
+        factory
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Serialized<T, Serializer>>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_impl()
+            .map(Self::apply_serialized_defaults)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Serialized<T, Serializer>>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_impl().map(Self::apply_serialized_defaults)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Serialized<T, Serializer>>,
+        PublishSubscribeCreateError,
+    > {
+        self.create_impl().map(Self::apply_serialized_defaults)
+    }
+}
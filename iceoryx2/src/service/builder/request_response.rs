@@ -0,0 +1,676 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::marker::PhantomData;
+
+use crate::message::Message;
+use crate::service;
+use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::request_response;
+use crate::service::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use super::{OpenDynamicStorageFailure, ServiceState};
+
+/// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleRequestType,
+    IncompatibleResponseType,
+    IncompatibleMessagingPattern,
+    DoesNotSupportRequestedAmountOfClients,
+    DoesNotSupportRequestedAmountOfServers,
+    DoesNotSupportRequestedAmountOfActiveRequests,
+    DoesNotSupportRequestedMinServerBorrowedRequests,
+    DoesNotSupportRequestedMinClientBorrowedResponses,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    DoesNotSupportRequestedAmountOfNodes,
+    IncompatibleAttributes,
+    ExceedsMaxNumberOfNodes,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::RequestResponse`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+    OldConnectionsStillActive,
+}
+
+impl std::fmt::Display for RequestResponseCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleRequestType,
+    IncompatibleResponseType,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
+    /// created or opened.
+    RequestResponseOpenOrCreateError
+  mapping:
+    RequestResponseOpenError,
+    RequestResponseCreateError
+}
+
+impl std::fmt::Display for RequestResponseOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<ServiceType: service::Service> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    verify_number_of_clients: bool,
+    verify_number_of_servers: bool,
+    verify_max_active_requests: bool,
+    verify_server_max_borrowed_requests: bool,
+    verify_client_max_borrowed_responses: bool,
+    verify_max_nodes: bool,
+}
+
+impl<ServiceType: service::Service> Builder<ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_clients: false,
+            verify_number_of_servers: false,
+            verify_max_active_requests: false,
+            verify_server_max_borrowed_requests: false,
+            verify_client_max_borrowed_responses: false,
+            verify_max_nodes: false,
+        };
+
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::RequestResponse(static_config::request_response::StaticConfig::new(
+                new_self.base.global_config.as_ref(),
+            ));
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.request_response().request_type_name
+                    != self.config_details().request_type_name
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
+                        "{} since the service offers the request type \"{}\" but the requested request type is \"{}\".",
+                        error_msg, &config.request_response().request_type_name, self.config_details().request_type_name);
+                }
+
+                if config.request_response().response_type_name
+                    != self.config_details().response_type_name
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
+                        "{} since the service offers the response type \"{}\" but the requested response type is \"{}\".",
+                        error_msg, &config.request_response().response_type_name, self.config_details().response_type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    fn finalize_config<RequestType: Debug + ZeroCopySend, ResponseType: Debug + ZeroCopySend>(
+        &mut self,
+    ) {
+        let details = self.config_details_mut();
+        details.request_type_name = std::any::type_name::<RequestType>().to_string();
+        details.request_type_size = core::mem::size_of::<Message<RequestHeader, RequestType>>();
+        details.request_type_alignment =
+            core::mem::align_of::<Message<RequestHeader, RequestType>>();
+        details.response_type_name = std::any::type_name::<ResponseType>().to_string();
+        details.response_type_size = core::mem::size_of::<Message<ResponseHeader, ResponseType>>();
+        details.response_type_alignment =
+            core::mem::align_of::<Message<ResponseHeader, ResponseType>>();
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::client::Client`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::client::Client`] must be at least supported.
+    pub fn max_clients(mut self, value: usize) -> Self {
+        self.config_details_mut().max_clients = value;
+        self.verify_number_of_clients = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::server::Server`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::server::Server`] must be at least supported.
+    pub fn max_servers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_servers = value;
+        self.verify_number_of_servers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::node::Node`]s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`crate::node::Node`]s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details_mut().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many requests a
+    /// [`crate::port::client::Client`] can have in flight towards a single
+    /// [`crate::port::server::Server`]. If an existing [`Service`] is opened it defines the
+    /// minimum required.
+    pub fn max_active_requests(mut self, value: usize) -> Self {
+        self.config_details_mut().max_active_requests = value;
+        self.verify_max_active_requests = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::active_request::ActiveRequest`]s
+    /// a [`crate::port::server::Server`] can borrow at most in parallel from a single
+    /// [`crate::port::client::Client`]. If an existing [`Service`] is opened it defines the
+    /// minimum required.
+    pub fn server_max_borrowed_requests(mut self, value: usize) -> Self {
+        self.config_details_mut().server_max_borrowed_requests = value;
+        self.verify_server_max_borrowed_requests = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::response::Response`]s
+    /// a [`crate::port::client::Client`] can borrow at most in parallel from a single
+    /// [`crate::port::server::Server`]. If an existing [`Service`] is opened it defines the
+    /// minimum required.
+    pub fn client_max_borrowed_responses(mut self, value: usize) -> Self {
+        self.config_details_mut().client_max_borrowed_responses = value;
+        self.verify_client_max_borrowed_responses = true;
+        self
+    }
+
+    /// Defines the request and the response type of the [`Service`]. Both must implement
+    /// [`ZeroCopySend`].
+    pub fn typed<RequestType: Debug + ZeroCopySend, ResponseType: Debug + ZeroCopySend>(
+        self,
+    ) -> TypedBuilder<RequestType, ResponseType, ServiceType> {
+        TypedBuilder {
+            builder: self,
+            _request_type: PhantomData,
+            _response_type: PhantomData,
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.request_response_mut();
+
+        if settings.max_clients == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_clients = 1;
+        }
+
+        if settings.max_servers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_servers = 1;
+        }
+
+        if settings.max_active_requests == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of active requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_active_requests = 1;
+        }
+
+        if settings.server_max_borrowed_requests == 0 {
+            warn!(from origin,
+                "Setting the servers max borrowed requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.server_max_borrowed_requests = 1;
+        }
+
+        if settings.client_max_borrowed_responses == 0 {
+            warn!(from origin,
+                "Setting the clients max borrowed responses to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.client_max_borrowed_responses = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
+        let msg = "Unable to open request response service";
+
+        if !self.base.has_required_attributes(existing_settings) {
+            fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attributes {:?}.",
+                msg, self.base.service_config.attributes);
+        }
+
+        let required_settings = self.base.service_config.request_response();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            p => {
+                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_clients
+            && existing_settings.max_clients < required_settings.max_clients
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+                                "{} since the service supports only {} clients but a support of {} clients was requested.",
+                                msg, existing_settings.max_clients, required_settings.max_clients);
+        }
+
+        if self.verify_number_of_servers
+            && existing_settings.max_servers < required_settings.max_servers
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+                                "{} since the service supports only {} servers but a support of {} servers was requested.",
+                                msg, existing_settings.max_servers, required_settings.max_servers);
+        }
+
+        if self.verify_max_active_requests
+            && existing_settings.max_active_requests < required_settings.max_active_requests
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests,
+                                "{} since the service supports only {} active requests but a support of {} active requests was requested.",
+                                msg, existing_settings.max_active_requests, required_settings.max_active_requests);
+        }
+
+        if self.verify_server_max_borrowed_requests
+            && existing_settings.server_max_borrowed_requests
+                < required_settings.server_max_borrowed_requests
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinServerBorrowedRequests,
+                                "{} since the service supports only {} borrowed server requests but {} borrowed server requests were requested.",
+                                msg, existing_settings.server_max_borrowed_requests, required_settings.server_max_borrowed_requests);
+        }
+
+        if self.verify_client_max_borrowed_responses
+            && existing_settings.client_max_borrowed_responses
+                < required_settings.client_max_borrowed_responses
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinClientBorrowedResponses,
+                                "{} since the service supports only {} borrowed client responses but {} borrowed client responses were requested.",
+                                msg, existing_settings.client_max_borrowed_responses, required_settings.client_max_borrowed_responses);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s with a
+/// defined request and response type.
+#[derive(Debug)]
+pub struct TypedBuilder<
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+    ServiceType: service::Service,
+> {
+    builder: Builder<ServiceType>,
+    _request_type: PhantomData<RequestType>,
+    _response_type: PhantomData<ResponseType>,
+}
+
+impl<
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > TypedBuilder<RequestType, ResponseType, ServiceType>
+{
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenOrCreateError,
+    > {
+        let msg = "Unable to open or create request response service";
+        self.builder.finalize_config::<RequestType, ResponseType>();
+
+        match self.builder.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open()?),
+            Ok(None) => match self.create_impl() {
+                Ok(factory) => Ok(factory),
+                Err(RequestResponseCreateError::AlreadyExists)
+                | Err(RequestResponseCreateError::IsBeingCreatedByAnotherInstance) => {
+                    Ok(self.open()?)
+                }
+                Err(e) => Err(e.into()),
+            },
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open()?),
+            Err(ServiceAvailabilityState::IncompatibleRequestType) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleRequestType),
+                    "{} since the service has an incompatible request type.", msg);
+            }
+            Err(ServiceAvailabilityState::IncompatibleResponseType) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleResponseType),
+                    "{} since the service has an incompatible response type.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenError,
+    > {
+        let msg = "Unable to open request response service";
+        self.builder.finalize_config::<RequestType, ResponseType>();
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.builder.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((existing_config, static_storage))) => {
+                    let static_config = self.builder.verify_service_properties(&existing_config)?;
+
+                    let (dynamic_config, dynamic_config_node_handle) = match self
+                        .builder
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok((dynamic_config, handle)) => (Arc::new(dynamic_config), handle),
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with RequestResponseOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with RequestResponseOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
+
+                    self.builder.base.service_config.messaging_pattern =
+                        MessagingPattern::RequestResponse(static_config.clone());
+                    self.builder.base.service_config.attributes = existing_config.attributes;
+
+                    return Ok(request_response::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.builder.base.service_config,
+                            self.builder.base.shared_node.clone(),
+                            dynamic_config,
+                            static_storage,
+                            dynamic_config_node_handle,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout
+                        > self
+                            .builder
+                            .base
+                            .global_config
+                            .global
+                            .service
+                            .creation_timeout
+                    {
+                        fail!(from self, with RequestResponseOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.builder.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleRequestType) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleRequestType,
+                    "{} since the service has an incompatible request type.", msg);
+                }
+                Err(ServiceAvailabilityState::IncompatibleResponseType) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleResponseType,
+                    "{} since the service has an incompatible response type.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with RequestResponseOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    fn create_impl(
+        &mut self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestType, ResponseType>,
+        RequestResponseCreateError,
+    > {
+        self.builder.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create request response service";
+        self.builder.finalize_config::<RequestType, ResponseType>();
+
+        match self.builder.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = match self.builder.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with RequestResponseCreateError::UnableToCreateStaticServiceInformation,
+                            "{} since the static service information could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                let reqres_config = self.builder.base.service_config.request_response();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_clients: reqres_config.max_clients,
+                    number_of_servers: reqres_config.max_servers,
+                };
+
+                let (dynamic_config, dynamic_config_node_handle) = match self
+                    .builder
+                    .base
+                    .create_dynamic_config_storage(
+                        dynamic_config::MessagingPattern::RequestResponse(
+                            dynamic_config::request_response::DynamicConfig::new(
+                                &dynamic_config_setting,
+                            ),
+                        ),
+                        dynamic_config::request_response::DynamicConfig::memory_size(
+                            &dynamic_config_setting,
+                        ),
+                    ) {
+                    Ok((c, handle)) => (Arc::new(c), handle),
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with RequestResponseCreateError::OldConnectionsStillActive,
+                            "{} since there are still Clients, Servers or active Requests and Responses.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+                let service_config = fail!(from self,
+                            when ServiceType::ConfigSerializer::serialize(&self.builder.base.service_config),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+
+                Ok(request_response::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.builder.base.service_config.clone(),
+                        self.builder.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                        dynamic_config_node_handle,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleRequestType)
+            | Err(ServiceAvailabilityState::IncompatibleResponseType)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestType, ResponseType>,
+        RequestResponseCreateError,
+    > {
+        self.create_impl()
+    }
+}
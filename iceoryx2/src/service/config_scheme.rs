@@ -100,3 +100,26 @@ pub(crate) fn data_segment_config<Service: crate::service::Service>(
         &global_config.global.root_path(),
     )
 }
+
+pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
+    let origin = "node_monitoring_config";
+    let msg = "Unable to generate node monitoring directory";
+    let mut path_hint = global_config.global.root_path();
+    let service_directory: FixedSizeByteString<{ FileName::max_len() }> = fatal_panic!(from origin,
+            when FixedSizeByteString::from_bytes(global_config.global.service.directory.as_bytes()),
+            "{} since the directory entry \"{}\" is invalid.",
+            msg, global_config.global.service.directory);
+
+    fatal_panic!(from origin, when path_hint.add_path_entry(&service_directory),
+            "{} since the combination of root directory and service directory entry result in an invalid directory \"{}{}\".",
+            msg, path_hint, service_directory);
+
+    generate_default_config::<<Service::Monitoring as NamedConceptMgmt>::Configuration>(
+        origin,
+        &global_config.global.prefix,
+        &global_config.global.service.node_monitor_suffix,
+        &path_hint,
+    )
+}
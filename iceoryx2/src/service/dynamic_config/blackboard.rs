@@ -0,0 +1,251 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .create()?;
+//!
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//! println!("number of entries:            {:?}", blackboard.dynamic_config().number_of_entries());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::container::*;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_cal::hash::{Hash, HashValue};
+
+use crate::node::NodeId;
+use crate::port::port_identifiers::{UniquePortId, UniqueReaderId, UniqueWriterId};
+use crate::service::static_config::type_details::TypeDetails;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_entries: usize,
+    pub entries_memory_size: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReaderDetails {
+    pub(crate) reader_id: UniqueReaderId,
+    pub(crate) node_id: NodeId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriterDetails {
+    pub(crate) writer_id: UniqueWriterId,
+    pub(crate) node_id: NodeId,
+}
+
+/// Describes where the key and the value of a blackboard entry are stored. The offsets are
+/// relative to the start of the [`DynamicConfig`] so that every process can resolve them
+/// independent of the address the shared memory is mapped to.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EntryDetails {
+    pub(crate) key_offset: usize,
+    pub(crate) value_offset: usize,
+    pub(crate) value_type: HashValue,
+}
+
+/// Creates the identifier of a value type that is stored in the shared memory and used to verify
+/// that readers and writers access an entry with the type it was created with.
+pub(crate) fn value_type_id<Hasher: Hash>(value_type: &TypeDetails) -> HashValue {
+    Hasher::new(
+        format!(
+            "{}:{}:{}",
+            value_type.type_name(),
+            value_type.size(),
+            value_type.alignment()
+        )
+        .as_bytes(),
+    )
+    .value()
+}
+
+/// Returns the memory a single entry requires in the worst case, including the padding for its
+/// alignment.
+pub(crate) fn entry_memory_size(key_layout: Layout, value_layout: Layout) -> usize {
+    key_layout.size() + key_layout.align() - 1 + value_layout.size() + value_layout.align() - 1
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<ReaderDetails>,
+    pub(crate) writers: Container<WriterDetails>,
+    pub(crate) entries: RelocatableVec<EntryDetails>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(1) },
+            entries: unsafe { RelocatableVec::new_uninit(config.number_of_entries) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.entries.init(allocator),
+            "This should never happen! Unable to initialize entry container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + Container::<WriterDetails>::memory_size(1)
+            + RelocatableVec::<EntryDetails>::memory_size(config.number_of_entries)
+            + config.entries_memory_size
+    }
+
+    /// Stores the key and the value of a new entry in the memory provided by the `allocator`.
+    /// The value is written by `value_init` into the memory described by `value_layout`.
+    ///
+    /// # Safety
+    ///
+    ///  * must be called only during the initialization of the [`DynamicConfig`]
+    ///  * `value_init` must initialize the memory according to `value_layout`
+    pub(crate) unsafe fn add_entry<KeyType: Copy>(
+        &mut self,
+        allocator: &BumpAllocator,
+        key: KeyType,
+        value_layout: Layout,
+        value_type: HashValue,
+        value_init: &dyn Fn(*mut u8),
+    ) -> bool {
+        let key_ptr = match allocator.allocate(Layout::new::<KeyType>()) {
+            Ok(ptr) => ptr.as_ptr() as *mut u8,
+            Err(_) => return false,
+        };
+        let value_ptr = match allocator.allocate(value_layout) {
+            Ok(ptr) => ptr.as_ptr() as *mut u8,
+            Err(_) => return false,
+        };
+
+        (key_ptr as *mut KeyType).write(key);
+        value_init(value_ptr);
+
+        let base_address = self as *const Self as usize;
+        self.entries.push(EntryDetails {
+            key_offset: key_ptr as usize - base_address,
+            value_offset: value_ptr as usize - base_address,
+            value_type,
+        })
+    }
+
+    /// Returns the [`EntryDetails`] of the entry with the provided `key`.
+    ///
+    /// # Safety
+    ///
+    ///  * `KeyType` must be the key type the service was created with
+    pub(crate) unsafe fn entry<KeyType: Eq>(&self, key: &KeyType) -> Option<&EntryDetails> {
+        let base_address = self as *const Self as usize;
+        self.entries
+            .iter()
+            .find(|e| *((base_address + e.key_offset) as *const KeyType) == *key)
+    }
+
+    /// Returns the value of the provided entry.
+    ///
+    /// # Safety
+    ///
+    ///  * `ValueType` must be the value type the entry was created with
+    pub(crate) unsafe fn entry_value<ValueType: Copy>(
+        &self,
+        entry: &EntryDetails,
+    ) -> &UnrestrictedAtomic<ValueType> {
+        let base_address = self as *const Self as usize;
+        &*((base_address + entry.value_offset) as *const UnrestrictedAtomic<ValueType>)
+    }
+
+    /// Returns how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns the number of entries stored in the blackboard.
+    pub fn number_of_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn add_reader_id(&self, details: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(details) }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle) }
+    }
+
+    pub(crate) fn add_writer_id(&self, details: WriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(details) }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle) }
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<PortCleanup: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.readers
+            .get_state()
+            .for_each(|index, details: &ReaderDetails| {
+                if details.node_id == *node_id {
+                    self.readers.remove(self.readers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Reader(details.reader_id));
+                }
+            });
+
+        self.writers
+            .get_state()
+            .for_each(|index, details: &WriterDetails| {
+                if details.node_id == *node_id {
+                    self.writers.remove(self.writers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Writer(details.writer_id));
+                }
+            });
+    }
+}
@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -26,25 +28,46 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::container::*;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 
-use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId};
+use crate::node::NodeId;
+use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
+    pub number_of_event_counters: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ListenerDetails {
+    pub(crate) listener_id: UniqueListenerId,
+    pub(crate) node_id: NodeId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NotifierDetails {
+    pub(crate) notifier_id: UniqueNotifierId,
+    pub(crate) node_id: NodeId,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
 #[derive(Debug)]
 pub struct DynamicConfig {
-    pub(crate) listeners: Container<UniqueListenerId>,
-    pub(crate) notifiers: Container<UniqueNotifierId>,
+    pub(crate) listeners: Container<ListenerDetails>,
+    pub(crate) notifiers: Container<NotifierDetails>,
+    /// When counting is enabled, it contains for every [`crate::port::listener::Listener`] a
+    /// counter for every [`crate::port::event_id::EventId`]. The counters of a listener start at
+    /// the index of its [`ContainerHandle`] in `listeners` times the number of event ids.
+    pub(crate) event_counters: RelocatableVec<AtomicU64>,
 }
 
 impl DynamicConfig {
@@ -52,21 +75,35 @@ impl DynamicConfig {
         Self {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
+            event_counters: unsafe { RelocatableVec::new_uninit(config.number_of_event_counters) },
         }
     }
 
-    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.listeners.init(allocator),
             "This should never happen! Unable to initialize listener port id container.");
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+        // the event counters are only required when counting is enabled
+        if self.event_counters.capacity() == 0 {
+            return;
+        }
+
+        fatal_panic!(from "event::DynamicConfig::init",
+            when self.event_counters.init(allocator),
+            "This should never happen! Unable to initialize event counters.");
+
+        for _ in 0..self.event_counters.capacity() {
+            self.event_counters.push(AtomicU64::new(0));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
-        Container::<UniqueListenerId>::memory_size(config.number_of_listeners)
-            + Container::<UniqueNotifierId>::memory_size(config.number_of_notifiers)
+        Container::<ListenerDetails>::memory_size(config.number_of_listeners)
+            + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + RelocatableVec::<AtomicU64>::memory_size(config.number_of_event_counters)
     }
 
     /// Returns the how many [`crate::port::listener::Listener`] ports are currently connected.
@@ -79,19 +116,65 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
-    pub(crate) fn add_listener_id(&self, id: UniqueListenerId) -> Option<ContainerHandle> {
-        unsafe { self.listeners.add(id) }
+    pub(crate) fn add_listener_id(&self, details: ListenerDetails) -> Option<ContainerHandle> {
+        unsafe { self.listeners.add(details) }
     }
 
     pub(crate) fn release_listener_handle(&self, handle: ContainerHandle) {
+        self.reset_event_counters(handle.index());
         unsafe { self.listeners.remove(handle) }
     }
 
-    pub(crate) fn add_notifier_id(&self, id: UniqueNotifierId) -> Option<ContainerHandle> {
-        unsafe { self.notifiers.add(id) }
+    /// Returns the event counters of the listener that is stored at the provided index in
+    /// `listeners`. When counting is disabled, the slice is empty.
+    pub(crate) fn event_counters(&self, index: u32) -> &[AtomicU64] {
+        if self.event_counters.capacity() == 0 {
+            return &[];
+        }
+
+        let number_of_event_ids = self.event_counters.capacity() / self.listeners.capacity();
+        let start = index as usize * number_of_event_ids;
+        &self.event_counters[start..start + number_of_event_ids]
+    }
+
+    fn reset_event_counters(&self, index: u32) {
+        for counter in self.event_counters(index) {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn add_notifier_id(&self, details: NotifierDetails) -> Option<ContainerHandle> {
+        unsafe { self.notifiers.add(details) }
     }
 
     pub(crate) fn release_notifier_handle(&self, handle: ContainerHandle) {
         unsafe { self.notifiers.remove(handle) }
     }
+
+    pub(crate) unsafe fn remove_dead_node_id<PortCleanup: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.listeners
+            .get_state()
+            .for_each(|index, details: &ListenerDetails| {
+                if details.node_id == *node_id {
+                    self.reset_event_counters(index);
+                    self.listeners
+                        .remove(self.listeners.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Listener(details.listener_id));
+                }
+            });
+
+        self.notifiers
+            .get_state()
+            .for_each(|index, details: &NotifierDetails| {
+                if details.node_id == *node_id {
+                    self.notifiers
+                        .remove(self.notifiers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Notifier(details.notifier_id));
+                }
+            });
+    }
 }
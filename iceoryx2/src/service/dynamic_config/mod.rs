@@ -10,23 +10,44 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The dynamic service configuration of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
 pub mod event;
 
+/// The dynamic service configuration of a
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service.
+pub mod pipeline;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
 use std::{
     fmt::Display,
     sync::atomic::{AtomicU64, Ordering},
 };
 
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::container::*;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::process::ProcessId;
+
+use crate::node::NodeId;
+use crate::port::port_identifiers::UniquePortId;
 
 const MARKED_FOR_DESTRUCTION: u64 = u64::MAX - 1;
 
@@ -40,12 +61,16 @@ pub(crate) enum DecrementReferenceCounterResult {
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
+    Pipeline(pipeline::DynamicConfig),
 }
 
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
+    nodes: Container<NodeId>,
     reference_counter: AtomicU64,
 }
 
@@ -60,17 +85,32 @@ impl Display for DynamicConfig {
 }
 
 impl DynamicConfig {
-    pub(crate) fn new_uninit(messaging_pattern: MessagingPattern) -> Self {
+    pub(crate) fn new_uninit(
+        messaging_pattern: MessagingPattern,
+        max_number_of_nodes: usize,
+    ) -> Self {
         Self {
             messaging_pattern,
+            nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
             reference_counter: AtomicU64::new(1),
         }
     }
 
-    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
-        match &self.messaging_pattern {
-            MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
-            MessagingPattern::Event(ref v) => v.init(allocator),
+    pub(crate) fn memory_size(max_number_of_nodes: usize) -> usize {
+        Container::<NodeId>::memory_size(max_number_of_nodes)
+    }
+
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
+        fatal_panic!(from "DynamicConfig::init",
+            when self.nodes.init(allocator),
+            "This should never happen! Unable to initialize node id container.");
+
+        match &mut self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref mut v) => v.init(allocator),
+            MessagingPattern::Event(ref mut v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
+            MessagingPattern::Pipeline(ref v) => v.init(allocator),
         }
     }
 
@@ -121,6 +161,64 @@ impl DynamicConfig {
         result
     }
 
+    pub(crate) fn register_node_id(&self, node_id: NodeId) -> Option<ContainerHandle> {
+        unsafe { self.nodes.add(node_id) }
+    }
+
+    pub(crate) fn deregister_node_id(&self, handle: ContainerHandle) {
+        unsafe { self.nodes.remove(handle) }
+    }
+
+    pub(crate) fn has_node_of_process(&self, pid: ProcessId) -> bool {
+        let mut has_node = false;
+        unsafe { self.nodes.get_state() }.for_each(|_, id: &NodeId| {
+            if id.pid() == pid {
+                has_node = true;
+            }
+        });
+
+        has_node
+    }
+
+    /// Removes all ports and service handles of a dead node. For every removed port the
+    /// `port_cleanup_callback` is called so that the underlying port resources can be removed.
+    ///
+    /// # Safety
+    ///
+    ///  * the node must be dead and no one else is allowed to cleanup its resources in
+    ///    parallel
+    pub(crate) unsafe fn remove_dead_node_id<PortCleanup: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        port_cleanup_callback: PortCleanup,
+    ) -> DecrementReferenceCounterResult {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
+            MessagingPattern::RequestResponse(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Blackboard(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Pipeline(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+        }
+
+        let mut result = DecrementReferenceCounterResult::HasOwners;
+        self.nodes.get_state().for_each(|index, id: &NodeId| {
+            if id == node_id {
+                self.nodes.remove(self.nodes.handle_from_index(index));
+                result = self.decrement_reference_counter();
+            }
+        });
+
+        result
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -138,4 +236,40 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access pipeline::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::DynamicConfig {
+        match self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from "DynamicConfig::blackboard_mut()", "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually a different one.");
+            }
+        }
+    }
 }
@@ -0,0 +1,141 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pipeline = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("number of active producers:    {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:    {:?}", pipeline.dynamic_config().number_of_consumers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::container::*;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::node::NodeId;
+use crate::port::port_identifiers::{UniqueConsumerId, UniquePortId, UniqueProducerId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_producers: usize,
+    pub number_of_consumers: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProducerDetails {
+    pub(crate) producer_id: UniqueProducerId,
+    pub(crate) node_id: NodeId,
+    pub(crate) number_of_items: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConsumerDetails {
+    pub(crate) consumer_id: UniqueConsumerId,
+    pub(crate) node_id: NodeId,
+}
+
+/// The dynamic configuration of a
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) producers: Container<ProducerDetails>,
+    pub(crate) consumers: Container<ConsumerDetails>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            producers: unsafe { Container::new_uninit(config.number_of_producers) },
+            consumers: unsafe { Container::new_uninit(config.number_of_consumers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.producers.init(allocator),
+            "This should never happen! Unable to initialize producer port id container.");
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.consumers.init(allocator),
+            "This should never happen! Unable to initialize consumer port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ProducerDetails>::memory_size(config.number_of_producers)
+            + Container::<ConsumerDetails>::memory_size(config.number_of_consumers)
+    }
+
+    /// Returns how many [`crate::port::producer::Producer`] ports are currently connected.
+    pub fn number_of_producers(&self) -> usize {
+        self.producers.len()
+    }
+
+    /// Returns how many [`crate::port::consumer::Consumer`] ports are currently connected.
+    pub fn number_of_consumers(&self) -> usize {
+        self.consumers.len()
+    }
+
+    pub(crate) fn add_producer_id(&self, details: ProducerDetails) -> Option<ContainerHandle> {
+        unsafe { self.producers.add(details) }
+    }
+
+    pub(crate) fn release_producer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.producers.remove(handle) }
+    }
+
+    pub(crate) fn add_consumer_id(&self, details: ConsumerDetails) -> Option<ContainerHandle> {
+        unsafe { self.consumers.add(details) }
+    }
+
+    pub(crate) fn release_consumer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.consumers.remove(handle) }
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<PortCleanup: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.producers
+            .get_state()
+            .for_each(|index, details: &ProducerDetails| {
+                if details.node_id == *node_id {
+                    self.producers
+                        .remove(self.producers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Producer(details.producer_id));
+                }
+            });
+
+        self.consumers
+            .get_state()
+            .for_each(|index, details: &ConsumerDetails| {
+                if details.node_id == *node_id {
+                    self.consumers
+                        .remove(self.consumers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Consumer(details.consumer_id));
+                }
+            });
+    }
+}
@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let pubsub = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -27,12 +29,17 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::container::*;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 
-use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::node::NodeId;
+use crate::port::details::subscriber_filter::SubscriberFilter;
+use crate::port::port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
@@ -43,13 +50,17 @@ pub(crate) struct DynamicConfigSettings {
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct PublisherDetails {
     pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) node_id: NodeId,
     pub(crate) number_of_samples: usize,
+    pub(crate) max_slice_len: usize,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct SubscriberDetails {
     pub(crate) port_id: UniqueSubscriberId,
+    pub(crate) node_id: NodeId,
     pub(crate) buffer_size: usize,
+    pub(crate) filter: SubscriberFilter,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -58,6 +69,9 @@ pub(crate) struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    /// The number of loaned samples of every [`crate::port::publisher::Publisher`], indexed by
+    /// the index of its [`ContainerHandle`] in `publishers`.
+    pub(crate) publisher_loan_counters: RelocatableVec<AtomicUsize>,
 }
 
 impl DynamicConfig {
@@ -65,21 +79,32 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            publisher_loan_counters: unsafe {
+                RelocatableVec::new_uninit(config.number_of_publishers)
+            },
         }
     }
 
-    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.subscribers.init(allocator),
             "This should never happen! Unable to initialize subscriber port id container.");
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when self.publisher_loan_counters.init(allocator),
+            "This should never happen! Unable to initialize publisher loan counters.");
+
+        for _ in 0..self.publisher_loan_counters.capacity() {
+            self.publisher_loan_counters.push(AtomicUsize::new(0));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<AtomicUsize>::memory_size(config.number_of_publishers)
     }
 
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
@@ -105,6 +130,40 @@ impl DynamicConfig {
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
+        self.publisher_loan_counters[handle.index() as usize].store(0, Ordering::Relaxed);
         unsafe { self.publishers.remove(handle) }
     }
+
+    /// Returns the shared loan counter of the publisher that is stored at the provided index
+    /// in `publishers`.
+    pub(crate) fn publisher_loan_counter(&self, index: u32) -> &AtomicUsize {
+        &self.publisher_loan_counters[index as usize]
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<PortCleanup: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.publishers
+            .get_state()
+            .for_each(|index, details: &PublisherDetails| {
+                if details.node_id == *node_id {
+                    self.publisher_loan_counters[index as usize].store(0, Ordering::Relaxed);
+                    self.publishers
+                        .remove(self.publishers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Publisher(details.publisher_id));
+                }
+            });
+
+        self.subscribers
+            .get_state()
+            .for_each(|index, details: &SubscriberDetails| {
+                if details.node_id == *node_id {
+                    self.subscribers
+                        .remove(self.subscribers.handle_from_index(index));
+                    port_cleanup_callback(UniquePortId::Subscriber(details.port_id));
+                }
+            });
+    }
 }
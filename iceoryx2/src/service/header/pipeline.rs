@@ -0,0 +1,64 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = service.consumer().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("work item header: {:?}", work_item.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::port::port_identifiers::UniqueProducerId;
+
+/// Work item header used by
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+#[derive(Debug)]
+#[repr(C)]
+pub struct Header {
+    producer_port_id: UniqueProducerId,
+    item_id: u64,
+}
+
+impl Header {
+    pub(crate) fn new(producer_port_id: UniqueProducerId, item_id: u64) -> Self {
+        Self {
+            producer_port_id,
+            item_id,
+        }
+    }
+
+    /// Returns the [`UniqueProducerId`] of the source [`crate::port::producer::Producer`].
+    pub fn producer_id(&self) -> UniqueProducerId {
+        self.producer_port_id
+    }
+
+    /// Returns the id of the work item. It is unique for every work item of a
+    /// [`crate::port::producer::Producer`] and increases with every loan.
+    pub fn item_id(&self) -> u64 {
+        self.item_id
+    }
+}
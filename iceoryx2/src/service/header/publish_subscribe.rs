@@ -18,7 +18,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -27,11 +29,13 @@
 //!
 //! while let Some(sample) = subscriber.receive()? {
 //!     println!("header: {:?}", sample.header());
+//!     println!("latency: {:?}", sample.header().timestamp().elapsed());
 //! }
 //! # Ok(())
 //! # }
 //! ```
 use crate::port::port_identifiers::UniquePublisherId;
+use iceoryx2_bb_posix::clock::Time;
 
 /// Message header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
@@ -39,15 +43,63 @@ use crate::port::port_identifiers::UniquePublisherId;
 #[repr(C)]
 pub struct Header {
     publisher_port_id: UniquePublisherId,
+    number_of_elements: u64,
+    sequence_number: u64,
+    timestamp: Time,
+    filter_key: u64,
 }
 
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId) -> Self {
-        Self { publisher_port_id }
+    pub(crate) fn new(publisher_port_id: UniquePublisherId, number_of_elements: u64) -> Self {
+        Self {
+            publisher_port_id,
+            number_of_elements,
+            sequence_number: 0,
+            timestamp: Time::default(),
+            filter_key: 0,
+        }
+    }
+
+    pub(crate) fn set_filter_key(&mut self, filter_key: u64) {
+        self.filter_key = filter_key;
+    }
+
+    pub(crate) fn set_send_details(&mut self, sequence_number: u64, timestamp: Time) {
+        self.sequence_number = sequence_number;
+        self.timestamp = timestamp;
     }
 
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
     }
+
+    /// Returns the number of elements of the payload. For a fixed size payload it is always
+    /// `1`, for a slice payload it is the length of the slice.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
+
+    /// Returns the sequence number the [`crate::port::publisher::Publisher`] assigned when the
+    /// sample was sent. Every [`crate::port::publisher::Publisher`] counts its sent samples
+    /// starting with `0`, a gap between two received samples means that samples were lost, for
+    /// instance when the buffer of the [`crate::port::subscriber::Subscriber`] overflowed.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the point in time when the sample was sent. It uses the
+    /// [`ClockType`](iceoryx2_bb_posix::clock::ClockType) that was configured with
+    /// [`PortFactoryPublisher::clock_type()`](crate::service::port_factory::publisher::PortFactoryPublisher::clock_type())
+    /// so that [`Time::elapsed()`] returns the latency between sending and now.
+    pub fn timestamp(&self) -> Time {
+        self.timestamp
+    }
+
+    /// Returns the filter key the [`crate::port::publisher::Publisher`] attached to the sample
+    /// with [`SampleMut::set_filter_key()`](crate::sample_mut::SampleMut::set_filter_key()).
+    /// When no filter key was attached it is `0`.
+    pub fn filter_key(&self) -> u64 {
+        self.filter_key
+    }
 }
@@ -31,10 +31,37 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! See the
+//! [Wikipedia Article: Request-response](https://en.wikipedia.org/wiki/Request%E2%80%93response).
+//! It uses bi-directional communication where `n` [`Client`](crate::port::client::Client)s
+//! send requests to `m` [`Server`](crate::port::server::Server)s. Every response is
+//! delivered only to the [`Client`](crate::port::client::Client) that sent the corresponding
+//! request.
+//!
+//! ### Blackboard
+//!
+//! A shared key-value store with a fixed set of keys that is defined when the service is
+//! created. One [`Writer`](crate::port::writer::Writer) updates the values and `m`
+//! [`Reader`](crate::port::reader::Reader)s read the latest value of a key lock-free without
+//! any queues in between.
+//!
+//! ### Pipeline
+//!
+//! Distributes work items between processes. `n` [`Producer`](crate::port::producer::Producer)s
+//! send work items to `m` [`Consumer`](crate::port::consumer::Consumer)s, but unlike
+//! publish-subscribe every work item is delivered to exactly one
+//! [`Consumer`](crate::port::consumer::Consumer), selected by the
+//! [`DistributionStrategy`](crate::service::static_config::pipeline::DistributionStrategy).
 use std::fmt::Display;
 
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
+use crate::service::static_config::pipeline;
 use crate::service::static_config::publish_subscribe;
+use crate::service::static_config::request_response;
 use serde::{Deserialize, Serialize};
 
 /// Contains the static config of the corresponding messaging pattern.
@@ -52,6 +79,22 @@ pub enum MessagingPattern {
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event(event::StaticConfig),
+
+    /// Bidirectional communication pattern where the [`Client`](crate::port::client::Client)
+    /// sends requests to the [`Server`](crate::port::server::Server) which answers every
+    /// request with one or more responses that are delivered exclusively to the requesting
+    /// [`Client`](crate::port::client::Client).
+    RequestResponse(request_response::StaticConfig),
+
+    /// Key-value store where the [`Writer`](crate::port::writer::Writer) owns a fixed set of
+    /// typed entries and the [`Reader`](crate::port::reader::Reader)s read the latest value of
+    /// an entry.
+    Blackboard(blackboard::StaticConfig),
+
+    /// Unidirectional communication pattern where the
+    /// [`Producer`](crate::port::producer::Producer) hands out work items and every work item
+    /// is delivered to exactly one [`Consumer`](crate::port::consumer::Consumer).
+    Pipeline(pipeline::StaticConfig),
 }
 
 impl Display for MessagingPattern {
@@ -59,6 +102,9 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::RequestResponse(_) => write!(f, "RequestResponse"),
+            MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
+            MessagingPattern::Pipeline(_) => write!(f, "Pipeline"),
         }
     }
 }
@@ -68,6 +114,9 @@ impl From<MessagingPattern> for u32 {
         match value {
             MessagingPattern::Event(_) => 0,
             MessagingPattern::PublishSubscribe(_) => 1,
+            MessagingPattern::RequestResponse(_) => 2,
+            MessagingPattern::Blackboard(_) => 3,
+            MessagingPattern::Pipeline(_) => 4,
         }
     }
 }
@@ -81,6 +130,15 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
+            MessagingPattern::RequestResponse(_) => {
+                matches!(rhs, MessagingPattern::RequestResponse(_))
+            }
+            MessagingPattern::Blackboard(_) => {
+                matches!(rhs, MessagingPattern::Blackboard(_))
+            }
+            MessagingPattern::Pipeline(_) => {
+                matches!(rhs, MessagingPattern::Pipeline(_))
+            }
         }
     }
 
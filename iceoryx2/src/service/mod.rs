@@ -20,7 +20,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     // define the messaging pattern
 //!     .publish_subscribe()
 //!     // various QoS
@@ -46,7 +48,9 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
 //!
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     // define the messaging pattern
 //!     .event()
 //!     // various QoS
@@ -73,8 +77,10 @@
 //! // adjust the global root path under which every file/directory is stored
 //! custom_config.global.service.directory = "custom_path".to_string();
 //!
-//! let service = zero_copy::Service::new(&service_name)
-//!     .publish_subscribe_with_custom_config(&custom_config)
+//! let node = NodeBuilder::new().config(&custom_config).create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
 //!
@@ -95,14 +101,19 @@
 //! // adjust the global service path under which service related files are stored
 //! custom_config.global.service.directory = "custom_services".to_string();
 //!
-//! let service = zero_copy::Service::new(&service_name)
-//!     .event_with_custom_config(&custom_config)
+//! let node = NodeBuilder::new().config(&custom_config).create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .event()
 //!     .open_or_create()?;
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+/// User-defined key-value attributes of a [`Service`]
+pub mod attribute;
+
 /// The builder to create or open [`Service`]s
 pub mod builder;
 
@@ -138,6 +149,20 @@ pub mod process_local;
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod zero_copy;
 
+/// A configuration when communicating between different processes using posix mechanisms
+/// and unix datagram sockets to signal events so that listeners are file descriptor based.
+pub mod zero_copy_socket;
+
+/// A configuration when communicating between different processes using posix mechanisms
+/// and a futex to signal events.
+#[cfg(target_os = "linux")]
+pub mod zero_copy_futex;
+
+/// A configuration when communicating between different processes using posix mechanisms
+/// and memfd based data segments that are shared via file descriptor passing.
+#[cfg(target_os = "linux")]
+pub mod zero_copy_memfd;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -145,23 +170,27 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use crate::config;
+use crate::node::SharedNode;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::{fail, trace, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::Event;
 use iceoryx2_cal::hash::Hash;
+use iceoryx2_cal::monitoring::Monitoring;
 use iceoryx2_cal::named_concept::NamedConceptListError;
 use iceoryx2_cal::named_concept::*;
+use iceoryx2_cal::reactor::Reactor;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::shared_memory::SharedMemory;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 
-use self::builder::Builder;
 use self::dynamic_config::DecrementReferenceCounterResult;
+use self::naming_scheme::{dynamic_config_storage_name, static_config_storage_name};
 use self::service_name::ServiceName;
 
 /// Failure that can be reported by [`Service::does_exist()`] or
@@ -196,37 +225,60 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// Failure that can be reported by [`Service::remove_orphaned_services()`] or
+/// [`Service::remove_orphaned_services_with_custom_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceCleanupError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceCleanupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceCleanupError {}
+
 /// Represents the [`Service`]s state.
 #[derive(Debug)]
-pub struct ServiceState<Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> {
+pub struct ServiceState<S: Service> {
     pub(crate) static_config: StaticConfig,
     pub(crate) global_config: Arc<config::Config>,
-    pub(crate) dynamic_storage: Arc<Dynamic>,
-    pub(crate) static_storage: Static,
+    pub(crate) shared_node: Arc<SharedNode<S>>,
+    pub(crate) dynamic_storage: Arc<S::DynamicStorage>,
+    pub(crate) static_storage: S::StaticStorage,
+    dynamic_config_node_handle: ContainerHandle,
 }
 
-impl<Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> ServiceState<Static, Dynamic> {
+impl<S: Service> ServiceState<S> {
     pub(crate) fn new(
         static_config: StaticConfig,
-        global_config: Arc<config::Config>,
-        dynamic_storage: Arc<Dynamic>,
-        static_storage: Static,
+        shared_node: Arc<SharedNode<S>>,
+        dynamic_storage: Arc<S::DynamicStorage>,
+        static_storage: S::StaticStorage,
+        dynamic_config_node_handle: ContainerHandle,
     ) -> Self {
         let new_self = Self {
             static_config,
-            global_config,
+            global_config: Arc::new(shared_node.config().clone()),
+            shared_node,
             dynamic_storage,
             static_storage,
+            dynamic_config_node_handle,
         };
         trace!(from new_self, "open service");
         new_self
     }
 }
 
-impl<Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Drop
-    for ServiceState<Static, Dynamic>
-{
+impl<S: Service> Drop for ServiceState<S> {
     fn drop(&mut self) {
+        self.dynamic_storage
+            .get()
+            .deregister_node_id(self.dynamic_config_node_handle);
+
         match self.dynamic_storage.get().decrement_reference_counter() {
             DecrementReferenceCounterResult::HasOwners => {
                 trace!(from self, "close service");
@@ -240,15 +292,12 @@ impl<Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Drop
     }
 }
 
-/// Represents a service. Used to create or open new services with the [`Builder`].
+/// Represents a service. Used to create or open new services with the
+/// [`Builder`](crate::service::builder::Builder) that is acquired with
+/// [`Node::service_builder()`](crate::node::Node::service_builder()).
 /// Contains the building blocks a [`Service`] requires to create the underlying resources and
 /// establish communication.
-pub trait Service: Debug + Sized {
-    /// Creates a new [`Builder`] for a given service name
-    fn new(name: &ServiceName) -> Builder<Self> {
-        Builder::new(name)
-    }
-
+pub trait Service: Debug + Sized + 'static {
     /// Every service name will be hashed, to allow arbitrary [`ServiceName`]s with as less
     /// restrictions as possible. The hash of the [`ServiceName`] is the [`Service`]s uuid.
     type ServiceNameHasher: Hash;
@@ -272,14 +321,20 @@ pub trait Service: Debug + Sized {
     /// The mechanism used to signal events between endpoints.
     type Event: Event;
 
+    /// Monitoring mechanism to detect dead processes.
+    type Monitoring: Monitoring;
+
+    /// Event multiplexing mechanism to wait on multiple events.
+    type Reactor: Reactor;
+
     #[doc(hidden)]
-    fn from_state(state: ServiceState<Self::StaticStorage, Self::DynamicStorage>) -> Self;
+    fn from_state(state: ServiceState<Self>) -> Self;
 
     #[doc(hidden)]
-    fn state(&self) -> &ServiceState<Self::StaticStorage, Self::DynamicStorage>;
+    fn state(&self) -> &ServiceState<Self>;
 
     #[doc(hidden)]
-    fn state_mut(&mut self) -> &mut ServiceState<Self::StaticStorage, Self::DynamicStorage>;
+    fn state_mut(&mut self) -> &mut ServiceState<Self>;
 
     /// Checks if a service with the name exists.
     ///
@@ -473,4 +528,119 @@ pub trait Service: Debug + Sized {
 
         Ok(service_vec)
     }
+
+    /// Removes the static configs of all services whose dynamic config no longer exists. Those
+    /// services can never be opened again but would block the creation of a new service with
+    /// the same name. Returns the [`StaticConfig`]s of the removed services.
+    ///
+    /// The stale resources of dead [`Node`](crate::node::Node)s are not removed, use
+    /// [`DeadNodeView::remove_stale_resources()`](crate::node::DeadNodeView::remove_stale_resources())
+    /// for this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// for service in zero_copy::Service::remove_orphaned_services()? {
+    ///     println!("removed orphaned service {}", service.service_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_orphaned_services() -> Result<Vec<StaticConfig>, ServiceCleanupError> {
+        Self::remove_orphaned_services_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Removes the static configs of all services created under a given [`config::Config`]
+    /// whose dynamic config no longer exists. Services that are currently being created are
+    /// not removed. Returns the [`StaticConfig`]s of the removed services.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut custom_config = Config::default();
+    /// let removed_services = zero_copy::Service::remove_orphaned_services_with_custom_config(&custom_config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_orphaned_services_with_custom_config(
+        config: &config::Config,
+    ) -> Result<Vec<StaticConfig>, ServiceCleanupError> {
+        let msg = "Unable to remove orphaned services";
+        let origin = "Service::remove_orphaned_services_with_custom_config()";
+        let static_storage_config = config_scheme::static_config_storage_config::<Self>(config);
+        let dynamic_storage_config = config_scheme::dynamic_config_storage_config::<Self>(config);
+
+        let services = fail!(from origin,
+                when Self::list_with_custom_config(config),
+                map ServiceListError::InsufficientPermissions => ServiceCleanupError::InsufficientPermissions,
+                unmatched ServiceCleanupError::InternalError,
+                "{} due to a failure while collecting all services for config: {:?}", msg, config);
+
+        let mut removed_services = vec![];
+        for service in services {
+            let static_storage_name = static_config_storage_name(service.uuid());
+
+            // A service under creation keeps its static config locked until the dynamic
+            // config exists and a service under destruction removes the dynamic config first.
+            // Verifying right before the removal that the static config is still unlocked
+            // ensures that the static config of a service that was recreated since it was
+            // listed is not removed.
+            match <Self::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
+                &static_storage_name,
+                &static_storage_config,
+            ) {
+                Ok(true) => (),
+                Ok(false) => continue,
+                Err(NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp) => {
+                    trace!(from origin, "Skip the service {:?} since it is currently being created.",
+                        service.service_name());
+                    continue;
+                }
+                Err(e) => {
+                    warn!(from origin, "Unable to verify if the static config of the service {:?} exists ({:?}). The service is not removed.",
+                        service.service_name(), e);
+                    continue;
+                }
+            }
+
+            match <Self::DynamicStorage as NamedConceptMgmt>::does_exist_cfg(
+                &dynamic_config_storage_name(&service),
+                &dynamic_storage_config,
+            ) {
+                Ok(true) => continue,
+                Ok(false) => (),
+                Err(e) => {
+                    warn!(from origin, "Unable to verify if the dynamic config of the service {:?} exists ({:?}). The service is not removed.",
+                        service.service_name(), e);
+                    continue;
+                }
+            }
+
+            match unsafe {
+                <Self::StaticStorage as NamedConceptMgmt>::remove_cfg(
+                    &static_storage_name,
+                    &static_storage_config,
+                )
+            } {
+                Ok(true) => {
+                    trace!(from origin, "remove orphaned service {:?}", service.service_name());
+                    removed_services.push(service);
+                }
+                Ok(false) => (),
+                Err(e) => {
+                    warn!(from origin, "Unable to remove the static config of the orphaned service {:?} ({:?}).",
+                        service.service_name(), e);
+                }
+            }
+        }
+
+        Ok(removed_services)
+    }
 }
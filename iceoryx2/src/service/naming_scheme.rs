@@ -10,9 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::node::NodeId;
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueConsumerId, UniqueListenerId, UniqueProducerId, UniquePublisherId,
+    UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 
 use super::static_config::StaticConfig;
@@ -34,23 +39,79 @@ pub(crate) fn static_config_storage_name(uuid: &str) -> FileName {
     FileName::new(uuid.as_bytes()).unwrap()
 }
 
-pub(crate) fn connection_name(
-    publisher_id: UniquePublisherId,
-    subscriber_id: UniqueSubscriberId,
-) -> FileName {
-    let mut file = FileName::new(publisher_id.0.value().to_string().as_bytes()).unwrap();
+fn sender_receiver_connection_name(sender: &UniqueSystemId, receiver: &UniqueSystemId) -> FileName {
+    let mut file = FileName::new(sender.value().to_string().as_bytes()).unwrap();
     file.push(b'_').unwrap();
-    file.push_bytes(subscriber_id.0.value().to_string().as_bytes())
+    file.push_bytes(receiver.value().to_string().as_bytes())
         .unwrap();
     file
 }
 
-pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
-    let msg = "The system does not support the required file name length for the publishers data segment.";
-    let origin = "data_segment_name()";
+/// Returns the sender and receiver id values of a connection name that was generated with
+/// [`sender_receiver_connection_name()`] or [`None`] when it does not follow the scheme.
+pub(crate) fn connection_sender_and_receiver(connection_name: &FileName) -> Option<(u128, u128)> {
+    let name = core::str::from_utf8(connection_name.as_bytes()).ok()?;
+    let (sender, receiver) = name.split_once('_')?;
+    Some((sender.parse().ok()?, receiver.parse().ok()?))
+}
+
+fn port_data_segment_name(port_id: &UniqueSystemId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the ports data segment.";
+    let origin = "port_data_segment_name()";
 
-    let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
     file
 }
+
+pub(crate) fn connection_name(
+    publisher_id: UniquePublisherId,
+    subscriber_id: UniqueSubscriberId,
+) -> FileName {
+    sender_receiver_connection_name(&publisher_id.0, &subscriber_id.0)
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    sender_receiver_connection_name(&client_id.0, &server_id.0)
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    sender_receiver_connection_name(&server_id.0, &client_id.0)
+}
+
+pub(crate) fn work_item_connection_name(
+    producer_id: UniqueProducerId,
+    consumer_id: UniqueConsumerId,
+) -> FileName {
+    sender_receiver_connection_name(&producer_id.0, &consumer_id.0)
+}
+
+pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
+    port_data_segment_name(&publisher_id.0)
+}
+
+pub(crate) fn client_data_segment_name(client_id: UniqueClientId) -> FileName {
+    port_data_segment_name(&client_id.0)
+}
+
+pub(crate) fn server_data_segment_name(server_id: UniqueServerId) -> FileName {
+    port_data_segment_name(&server_id.0)
+}
+
+pub(crate) fn producer_data_segment_name(producer_id: UniqueProducerId) -> FileName {
+    port_data_segment_name(&producer_id.0)
+}
+
+pub(crate) fn node_monitor_name(node_id: &NodeId) -> FileName {
+    let msg = "The system does not support the required file name length for the nodes monitor.";
+    let origin = "node_monitor_name()";
+    fatal_panic!(from origin, when FileName::new(node_id.value().to_string().as_bytes()), "{}", msg)
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .create()?;
+//!
+//! println!("name:                         {:?}", blackboard.name());
+//! println!("uuid:                         {:?}", blackboard.uuid());
+//! println!("max readers:                  {:?}", blackboard.static_config().max_supported_readers());
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//!
+//! let writer = blackboard.writer().create()?;
+//! let reader = blackboard.reader().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::{self, static_config};
+use crate::service::{dynamic_config, ServiceName};
+
+use super::reader::PortFactoryReader;
+use super::writer::PortFactoryWriter;
+
+/// The factory for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::writer::Writer`] or [`crate::port::reader::Reader`] ports.
+#[derive(Debug)]
+pub struct PortFactory<
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+> {
+    pub(crate) service: Service,
+    _key: PhantomData<KeyType>,
+}
+
+unsafe impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Send for PortFactory<Service, KeyType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > Sync for PortFactory<Service, KeyType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + ZeroCopySend + Send + Sync + 'static,
+    > PortFactory<Service, KeyType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the [`crate::service::Service`]
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined with
+    /// [`crate::service::builder::Builder::attribute()`] when the service was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::blackboard::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::blackboard::StaticConfig {
+        self.service.state().static_config.blackboard()
+    }
+
+    /// Returns the [`dynamic_config::blackboard::DynamicConfig`] of the
+    /// [`crate::service::Service`]. Contains all dynamic settings, like the current participants
+    /// etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::blackboard::DynamicConfig {
+        self.service.state().dynamic_storage.get().blackboard()
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new [`crate::port::writer::Writer`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let blackboard = node.service_builder(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add::<f32>(0, 1.5)
+    ///     .create()?;
+    ///
+    /// let writer = blackboard.writer().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer(&self) -> PortFactoryWriter<Service, KeyType> {
+        PortFactoryWriter { factory: self }
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new [`crate::port::reader::Reader`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let blackboard = node.service_builder(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add::<f32>(0, 1.5)
+    ///     .create()?;
+    ///
+    /// let reader = blackboard.reader().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader(&self) -> PortFactoryReader<Service, KeyType> {
+        PortFactoryReader { factory: self }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let reqres = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = reqres.client()
+//!                 .max_loaned_requests(6)
+//!                 .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use super::request_response::PortFactory;
+use crate::{
+    port::client::{Client, ClientCreateError},
+    service,
+};
+
+#[derive(Debug)]
+pub(crate) struct LocalClientConfig {
+    pub(crate) max_loaned_requests: usize,
+}
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    Service: service::Service,
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+> {
+    config: LocalClientConfig,
+    pub(crate) factory: &'factory PortFactory<Service, RequestType, ResponseType>,
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > PortFactoryClient<'factory, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, RequestType, ResponseType>) -> Self {
+        Self {
+            config: LocalClientConfig {
+                max_loaned_requests: factory
+                    .service
+                    .state()
+                    .global_config
+                    .defaults
+                    .request_response
+                    .client_max_loaned_requests,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::request_mut::RequestMut`] the [`Client`] can loan with
+    /// [`Client::loan()`] or [`Client::loan_uninit()`] in parallel.
+    pub fn max_loaned_requests(mut self, value: usize) -> Self {
+        self.config.max_loaned_requests = value;
+        self
+    }
+
+    /// Creates a new [`Client`] or returns a [`ClientCreateError`] on failure.
+    pub fn create(self) -> Result<Client<Service, RequestType, ResponseType>, ClientCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Client::new(&self.factory.service, self.factory.service.state().static_config.request_response(), self.config),
+                "Failed to create new Client port."),
+        )
+    }
+}
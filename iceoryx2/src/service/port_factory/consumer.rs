@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pipeline = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = pipeline.consumer().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use super::pipeline::PortFactory;
+use crate::{
+    port::consumer::{Consumer, ConsumerCreateError},
+    service,
+};
+
+/// Factory to create a new [`Consumer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryConsumer<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ZeroCopySend,
+> {
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType>,
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend>
+    PortFactoryConsumer<'_, Service, PayloadType>
+{
+    /// Creates a new [`Consumer`] or returns a [`ConsumerCreateError`] on failure.
+    pub fn create(&self) -> Result<Consumer<Service, PayloadType>, ConsumerCreateError> {
+        Ok(
+            fail!(from self, when Consumer::new(&self.factory.service, self.factory.service.state().static_config.pipeline()),
+                "Failed to create new Consumer port."),
+        )
+    }
+}
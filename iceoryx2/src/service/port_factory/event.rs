@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
@@ -35,6 +37,7 @@
 //! ```
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+use crate::service::attribute::AttributeSet;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
 
@@ -68,6 +71,12 @@ impl<Service: service::Service> PortFactory<Service> {
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined with
+    /// [`crate::service::builder::Builder::attribute()`] when the service was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::event::StaticConfig {
@@ -89,7 +98,9 @@ impl<Service: service::Service> PortFactory<Service> {
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let event_name = ServiceName::new("MyEventName")?;
-    /// let event = zero_copy::Service::new(&event_name)
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let event = node.service_builder(&event_name)
     ///     .event()
     ///     .open_or_create()?;
     ///
@@ -110,7 +121,9 @@ impl<Service: service::Service> PortFactory<Service> {
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let event_name = ServiceName::new("MyEventName")?;
-    /// let event = zero_copy::Service::new(&event_name)
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let event = node.service_builder(&event_name)
     ///     .event()
     ///     .open_or_create()?;
     ///
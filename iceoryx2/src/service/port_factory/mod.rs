@@ -10,6 +10,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
+/// Factory to create a [`Client`](crate::port::client::Client)
+pub mod client;
+
+/// Factory to create a [`Consumer`](crate::port::consumer::Consumer)
+pub mod consumer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -21,6 +32,14 @@ pub mod listener;
 /// Factory to create a [`Notifier`](crate::port::notifier::Notifier)
 pub mod notifier;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication and to acquire static and dynamic service information
+pub mod pipeline;
+
+/// Factory to create a [`Producer`](crate::port::producer::Producer)
+pub mod producer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication and to acquire static and dynamic service information
@@ -29,5 +48,19 @@ pub mod publish_subscribe;
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`Server`](crate::port::server::Server)
+pub mod server;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
+
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
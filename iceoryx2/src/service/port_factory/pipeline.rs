@@ -0,0 +1,165 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pipeline = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", pipeline.name());
+//! println!("uuid:                             {:?}", pipeline.uuid());
+//! println!("type name:                        {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                    {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max consumers:                    {:?}", pipeline.static_config().max_supported_consumers());
+//! println!("distribution strategy:            {:?}", pipeline.static_config().distribution_strategy());
+//! println!("number of active producers:       {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:       {:?}", pipeline.dynamic_config().number_of_consumers());
+//!
+//! let producer = pipeline.producer().create()?;
+//! let consumer = pipeline.consumer().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{consumer::PortFactoryConsumer, producer::PortFactoryProducer};
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+/// The factory for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::producer::Producer`]
+/// or [`crate::port::consumer::Consumer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, PayloadType: Debug + ZeroCopySend> {
+    pub(crate) service: Service,
+    _phantom_payload_type: PhantomData<PayloadType>,
+}
+
+unsafe impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Send
+    for PortFactory<Service, PayloadType>
+{
+}
+unsafe impl<Service: service::Service, PayloadType: Debug + ZeroCopySend> Sync
+    for PortFactory<Service, PayloadType>
+{
+}
+
+impl<Service: service::Service, PayloadType: Debug + ZeroCopySend>
+    PortFactory<Service, PayloadType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_payload_type: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined with
+    /// [`crate::service::builder::Builder::attribute()`] when the service was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::pipeline::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::pipeline::StaticConfig {
+        self.service.state().static_config.pipeline()
+    }
+
+    /// Returns the [`dynamic_config::pipeline::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::pipeline::DynamicConfig {
+        self.service.state().dynamic_storage.get().pipeline()
+    }
+
+    /// Returns a [`PortFactoryProducer`] to create a new
+    /// [`crate::port::producer::Producer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let pipeline = node.service_builder(&service_name)
+    ///     .pipeline()
+    ///     .typed::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let producer = pipeline.producer()
+    ///                 .max_loaned_items(3)
+    ///                 .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn producer(&self) -> PortFactoryProducer<Service, PayloadType> {
+        PortFactoryProducer::new(self)
+    }
+
+    /// Returns a [`PortFactoryConsumer`] to create a new
+    /// [`crate::port::consumer::Consumer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let pipeline = node.service_builder(&service_name)
+    ///     .pipeline()
+    ///     .typed::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let consumer = pipeline.consumer().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn consumer(&self) -> PortFactoryConsumer<Service, PayloadType> {
+        PortFactoryConsumer { factory: self }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pipeline = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = pipeline.producer()
+//!                 .max_loaned_items(6)
+//!                 .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use super::pipeline::PortFactory;
+use crate::{
+    port::producer::{Producer, ProducerCreateError},
+    service,
+};
+
+#[derive(Debug)]
+pub(crate) struct LocalProducerConfig {
+    pub(crate) max_loaned_items: usize,
+}
+
+/// Factory to create a new [`Producer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryProducer<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ZeroCopySend,
+> {
+    config: LocalProducerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType>,
+}
+
+impl<'factory, Service: service::Service, PayloadType: Debug + ZeroCopySend>
+    PortFactoryProducer<'factory, Service, PayloadType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType>) -> Self {
+        Self {
+            config: LocalProducerConfig {
+                max_loaned_items: factory
+                    .service
+                    .state()
+                    .global_config
+                    .defaults
+                    .pipeline
+                    .producer_max_loaned_items,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::work_item_mut::WorkItemMut`] the [`Producer`] can loan with
+    /// [`Producer::loan()`] or [`Producer::loan_uninit()`] in parallel.
+    pub fn max_loaned_items(mut self, value: usize) -> Self {
+        self.config.max_loaned_items = value;
+        self
+    }
+
+    /// Creates a new [`Producer`] or returns a [`ProducerCreateError`] on failure.
+    pub fn create(self) -> Result<Producer<Service, PayloadType>, ProducerCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Producer::new(&self.factory.service, self.factory.service.state().static_config.pipeline(), self.config),
+                "Failed to create new Producer port."),
+        )
+    }
+}
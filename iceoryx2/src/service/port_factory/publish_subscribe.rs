@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let pubsub = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -45,10 +47,12 @@ use std::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+use crate::service::attribute::AttributeSet;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
@@ -56,24 +60,29 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 /// [`crate::port::publisher::Publisher`]
 /// or [`crate::port::subscriber::Subscriber`] ports.
 #[derive(Debug)]
-pub struct PortFactory<Service: service::Service, MessageType: Debug> {
+pub struct PortFactory<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> {
     pub(crate) service: Service,
+    // the max slice length of new publishers, serialized payloads are sized in bytes
+    pub(crate) default_max_slice_len: usize,
     _phantom_message_type: PhantomData<MessageType>,
 }
 
-unsafe impl<Service: service::Service, MessageType: Debug> Send
+unsafe impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> Send
     for PortFactory<Service, MessageType>
 {
 }
-unsafe impl<Service: service::Service, MessageType: Debug> Sync
+unsafe impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized> Sync
     for PortFactory<Service, MessageType>
 {
 }
 
-impl<Service: service::Service, MessageType: Debug> PortFactory<Service, MessageType> {
+impl<Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
+    PortFactory<Service, MessageType>
+{
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
+            default_max_slice_len: 1,
             _phantom_message_type: PhantomData,
         }
     }
@@ -88,6 +97,12 @@ impl<Service: service::Service, MessageType: Debug> PortFactory<Service, Message
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined with
+    /// [`crate::service::builder::Builder::attribute()`] when the service was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
@@ -114,7 +129,9 @@ impl<Service: service::Service, MessageType: Debug> PortFactory<Service, Message
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
-    /// let pubsub = zero_copy::Service::new(&service_name)
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let pubsub = node.service_builder(&service_name)
     ///     .publish_subscribe()
     ///     .typed::<u64>()
     ///     .open_or_create()?;
@@ -139,7 +156,9 @@ impl<Service: service::Service, MessageType: Debug> PortFactory<Service, Message
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
-    /// let pubsub = zero_copy::Service::new(&service_name)
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let pubsub = node.service_builder(&service_name)
     ///     .publish_subscribe()
     ///     .typed::<u64>()
     ///     .open_or_create()?;
@@ -15,10 +15,14 @@
 //! ```
 //! use iceoryx2::prelude::*;
 //! use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+//! use iceoryx2_bb_posix::clock::ClockType;
+//! use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let pubsub = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -26,6 +30,8 @@
 //! let publisher = pubsub.publisher()
 //!                     .max_loaned_samples(6)
 //!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .clock_type(ClockType::Realtime)
+//!                     .memory_placement(MemoryPlacement::new().populate(true))
 //!                     .create()?;
 //!
 //! # Ok(())
@@ -33,7 +39,10 @@
 //! ```
 use std::fmt::Debug;
 
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::ClockType;
+use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::publish_subscribe::PortFactory;
@@ -44,7 +53,10 @@ use crate::{
         publisher::PublisherCreateError,
         DegrationAction, DegrationCallback,
     },
-    service,
+    service::{
+        self,
+        static_config::type_details::{Serialized, Untyped},
+    },
 };
 
 /// Defines the strategy the [`Publisher`] shall pursue in
@@ -58,6 +70,11 @@ pub enum UnableToDeliverStrategy {
     Block,
     /// Do not deliver the [`crate::sample::Sample`].
     DiscardSample,
+    /// Keeps the [`crate::sample::Sample`] and retries the delivery on the next
+    /// [`crate::sample_mut::SampleMut::send()`] or with [`Publisher::flush_pending()`]. Per
+    /// [`crate::port::subscriber::Subscriber`] at most as many [`crate::sample::Sample`]s as its
+    /// buffer size are kept, when more are pending the oldest one is discarded.
+    RetryLater,
 }
 
 impl Serialize for UnableToDeliverStrategy {
@@ -65,7 +82,11 @@ impl Serialize for UnableToDeliverStrategy {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&std::format!("{:?}", self))
+        serializer.serialize_str(match self {
+            UnableToDeliverStrategy::Block => "block",
+            UnableToDeliverStrategy::DiscardSample => "discard_sample",
+            UnableToDeliverStrategy::RetryLater => "retry_later",
+        })
     }
 }
 
@@ -75,7 +96,7 @@ impl<'de> Visitor<'de> for UnableToDeliverStrategyVisitor {
     type Value = UnableToDeliverStrategy;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a string containing either 'block' or 'discard_sample'")
+        formatter.write_str("a string containing either 'block', 'discard_sample' or 'retry_later'")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -85,6 +106,7 @@ impl<'de> Visitor<'de> for UnableToDeliverStrategyVisitor {
         match v {
             "block" => Ok(UnableToDeliverStrategy::Block),
             "discard_sample" => Ok(UnableToDeliverStrategy::DiscardSample),
+            "retry_later" => Ok(UnableToDeliverStrategy::RetryLater),
             v => Err(E::custom(format!(
                 "Invalid UnableToDeliverStrategy provided: \"{:?}\".",
                 v
@@ -105,26 +127,36 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
+    pub(crate) max_slice_len: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) clock_type: ClockType,
+    pub(crate) memory_placement: MemoryPlacement,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactoryPublisher<'factory, Service: service::Service, MessageType: Debug> {
+pub struct PortFactoryPublisher<
+    'factory,
+    Service: service::Service,
+    MessageType: Debug + ZeroCopySend + ?Sized,
+> {
     config: LocalPublisherConfig,
     pub(crate) factory: &'factory PortFactory<Service, MessageType>,
 }
 
-impl<'factory, Service: service::Service, MessageType: Debug>
+impl<'factory, Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
     PortFactoryPublisher<'factory, Service, MessageType>
 {
     pub(crate) fn new(factory: &'factory PortFactory<Service, MessageType>) -> Self {
         Self {
             config: LocalPublisherConfig {
                 degration_callback: None,
+                max_slice_len: factory.default_max_slice_len,
+                clock_type: ClockType::default(),
+                memory_placement: MemoryPlacement::default(),
                 max_loaned_samples: factory
                     .service
                     .state()
@@ -158,6 +190,23 @@ impl<'factory, Service: service::Service, MessageType: Debug>
         self
     }
 
+    /// Sets the [`ClockType`] that is used to acquire the send timestamp that is stored in the
+    /// [`Header`](crate::service::header::publish_subscribe::Header) of every sample.
+    pub fn clock_type(mut self, value: ClockType) -> Self {
+        self.config.clock_type = value;
+        self
+    }
+
+    /// Defines the [`MemoryPlacement`] of the data segment of the [`Publisher`]. Populating the
+    /// memory and binding it to the NUMA node of the sending thread avoids page faults on the
+    /// first write of every sample, huge pages reduce the TLB misses for large samples. Huge
+    /// pages require a service with memfd based data segments, see
+    /// `iceoryx2::service::zero_copy_memfd::Service`.
+    pub fn memory_placement(mut self, value: MemoryPlacement) -> Self {
+        self.config.memory_placement = value;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -189,3 +238,42 @@ impl<'factory, Service: service::Service, MessageType: Debug>
         )
     }
 }
+
+impl<'factory, Service: service::Service, ElementType: Debug + ZeroCopySend>
+    PortFactoryPublisher<'factory, Service, [ElementType]>
+{
+    /// Defines the maximum length of a slice that the [`Publisher`] can loan with
+    /// [`Publisher::loan_slice()`] or [`Publisher::loan_slice_uninit()`]. The memory of every
+    /// sample in the data segment of the [`Publisher`] is sized for this length.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.config.max_slice_len = value;
+        self
+    }
+}
+
+impl<'factory, Service: service::Service> PortFactoryPublisher<'factory, Service, Untyped> {
+    /// Defines the maximum number of elements that the [`Publisher`] can loan with
+    /// [`Publisher::loan_bytes_uninit()`] when the payload of the service is a slice,
+    /// see
+    /// [`TypeVariant::Dynamic`](crate::service::static_config::type_details::TypeVariant::Dynamic). It is ignored for services with a fixed size payload.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.config.max_slice_len = value;
+        self
+    }
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    > PortFactoryPublisher<'factory, Service, Serialized<T, Serializer>>
+{
+    /// Defines the maximum number of bytes a serialized value can occupy so that the
+    /// [`Publisher`] can send it with [`Publisher::send()`]. By default it is set to
+    /// `defaults.publish_subscribe.max_serialized_size` of the [`crate::config::Config`].
+    pub fn max_serialized_size(mut self, value: usize) -> Self {
+        self.config.max_slice_len = value;
+        self
+    }
+}
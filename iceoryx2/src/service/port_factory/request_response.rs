@@ -0,0 +1,187 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let reqres = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", reqres.name());
+//! println!("uuid:                             {:?}", reqres.uuid());
+//! println!("request type name:                {:?}", reqres.static_config().request_type_name());
+//! println!("response type name:               {:?}", reqres.static_config().response_type_name());
+//! println!("max clients:                      {:?}", reqres.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", reqres.static_config().max_supported_servers());
+//! println!("max active requests:              {:?}", reqres.static_config().max_active_requests());
+//! println!("number of active clients:         {:?}", reqres.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", reqres.dynamic_config().number_of_servers());
+//!
+//! let client = reqres.client().create()?;
+//! let server = reqres.server().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{client::PortFactoryClient, server::PortFactoryServer};
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+/// The factory for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::client::Client`]
+/// or [`crate::port::server::Server`] ports.
+#[derive(Debug)]
+pub struct PortFactory<
+    Service: service::Service,
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+> {
+    pub(crate) service: Service,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+}
+
+unsafe impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Send for PortFactory<Service, RequestType, ResponseType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > Sync for PortFactory<Service, RequestType, ResponseType>
+{
+}
+
+impl<
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > PortFactory<Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined with
+    /// [`crate::service::builder::Builder::attribute()`] when the service was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::request_response::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::request_response::StaticConfig {
+        self.service.state().static_config.request_response()
+    }
+
+    /// Returns the [`dynamic_config::request_response::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::request_response::DynamicConfig {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new
+    /// [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let reqres = node.service_builder(&service_name)
+    ///     .request_response()
+    ///     .typed::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = reqres.client()
+    ///                 .max_loaned_requests(3)
+    ///                 .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client(&self) -> PortFactoryClient<Service, RequestType, ResponseType> {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new
+    /// [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let reqres = node.service_builder(&service_name)
+    ///     .request_response()
+    ///     .typed::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = reqres.server()
+    ///                 .max_loaned_responses(3)
+    ///                 .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server(&self) -> PortFactoryServer<Service, RequestType, ResponseType> {
+        PortFactoryServer::new(self)
+    }
+}
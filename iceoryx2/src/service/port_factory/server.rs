@@ -0,0 +1,102 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let reqres = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = reqres.server()
+//!                 .max_loaned_responses(6)
+//!                 .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use super::request_response::PortFactory;
+use crate::{
+    port::server::{Server, ServerCreateError},
+    service,
+};
+
+#[derive(Debug)]
+pub(crate) struct LocalServerConfig {
+    pub(crate) max_loaned_responses: usize,
+}
+
+/// Factory to create a new [`Server`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryServer<
+    'factory,
+    Service: service::Service,
+    RequestType: Debug + ZeroCopySend,
+    ResponseType: Debug + ZeroCopySend,
+> {
+    config: LocalServerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, RequestType, ResponseType>,
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        RequestType: Debug + ZeroCopySend,
+        ResponseType: Debug + ZeroCopySend,
+    > PortFactoryServer<'factory, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, RequestType, ResponseType>) -> Self {
+        Self {
+            config: LocalServerConfig {
+                max_loaned_responses: factory
+                    .service
+                    .state()
+                    .global_config
+                    .defaults
+                    .request_response
+                    .server_max_loaned_responses,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::response_mut::ResponseMut`] the [`Server`] can loan with
+    /// [`crate::active_request::ActiveRequest::loan()`] or
+    /// [`crate::active_request::ActiveRequest::loan_uninit()`] in parallel.
+    pub fn max_loaned_responses(mut self, value: usize) -> Self {
+        self.config.max_loaned_responses = value;
+        self
+    }
+
+    /// Creates a new [`Server`] or returns a [`ServerCreateError`] on failure.
+    pub fn create(self) -> Result<Server<Service, RequestType, ResponseType>, ServerCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Server::new(&self.factory.service, self.factory.service.state().static_config.request_response(), self.config),
+                "Failed to create new Server port."),
+        )
+    }
+}
@@ -18,7 +18,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let pubsub = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -26,12 +28,19 @@
 //! let subscriber = pubsub.subscriber()
 //!                     .create()?;
 //!
+//! // receives only the samples with the filter key 3 or 7
+//! let filtered_subscriber = pubsub.subscriber()
+//!                     .filter_keys(&[3, 7])
+//!                     .create()?;
+//!
 //! # Ok(())
 //! # }
 //! ```
 
 use std::fmt::Debug;
+use std::ops::RangeInclusive;
 
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 
 use crate::{
@@ -45,22 +54,32 @@ use crate::{
 
 use super::publish_subscribe::PortFactory;
 
+/// The maximum number of filter keys that can be set with
+/// [`PortFactorySubscriber::filter_keys()`].
+pub const MAX_NUMBER_OF_FILTER_KEYS: usize = 16;
+
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) filter_keys: Option<Vec<u64>>,
+    pub(crate) filter_key_range: Option<RangeInclusive<u64>>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactorySubscriber<'factory, Service: service::Service, MessageType: Debug> {
+pub struct PortFactorySubscriber<
+    'factory,
+    Service: service::Service,
+    MessageType: Debug + ZeroCopySend + ?Sized,
+> {
     config: SubscriberConfig,
     pub(crate) factory: &'factory PortFactory<Service, MessageType>,
 }
 
-impl<'factory, Service: service::Service, MessageType: Debug>
+impl<'factory, Service: service::Service, MessageType: Debug + ZeroCopySend + ?Sized>
     PortFactorySubscriber<'factory, Service, MessageType>
 {
     pub(crate) fn new(factory: &'factory PortFactory<Service, MessageType>) -> Self {
@@ -68,6 +87,8 @@ impl<'factory, Service: service::Service, MessageType: Debug>
             config: SubscriberConfig {
                 buffer_size: None,
                 degration_callback: None,
+                filter_keys: None,
+                filter_key_range: None,
             },
             factory,
         }
@@ -78,6 +99,29 @@ impl<'factory, Service: service::Service, MessageType: Debug>
         self
     }
 
+    /// Delivers only the samples whose filter key, set with
+    /// [`SampleMut::set_filter_key()`](crate::sample_mut::SampleMut::set_filter_key()), is one
+    /// of the provided keys. The filter is evaluated by the
+    /// [`crate::port::publisher::Publisher`] before the sample is delivered, therefore samples
+    /// that do not match never occupy a slot in the buffer of the [`Subscriber`]. At most
+    /// [`MAX_NUMBER_OF_FILTER_KEYS`] can be provided, otherwise
+    /// [`PortFactorySubscriber::create()`] fails. Replaces a previously set filter.
+    pub fn filter_keys(mut self, keys: &[u64]) -> Self {
+        self.config.filter_keys = Some(keys.to_vec());
+        self.config.filter_key_range = None;
+        self
+    }
+
+    /// Delivers only the samples whose filter key, set with
+    /// [`SampleMut::set_filter_key()`](crate::sample_mut::SampleMut::set_filter_key()), lies
+    /// in the provided range. Like [`PortFactorySubscriber::filter_keys()`] the filter is
+    /// evaluated before the sample is delivered. Replaces a previously set filter.
+    pub fn filter_key_range(mut self, range: RangeInclusive<u64>) -> Self {
+        self.config.filter_key_range = Some(range);
+        self.config.filter_keys = None;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -19,7 +19,9 @@
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
 //! // use `process_local` as communication variant
-//! let service = process_local::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<process_local::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -42,10 +44,7 @@ use super::ServiceState;
 /// Defines a process local or single address space communication setup.
 #[derive(Debug)]
 pub struct Service {
-    state: ServiceState<
-        static_storage::process_local::Storage,
-        dynamic_storage::process_local::Storage<DynamicConfig>,
-    >,
+    state: ServiceState<Self>,
 }
 
 impl crate::service::Service for Service {
@@ -56,16 +55,18 @@ impl crate::service::Service for Service {
     type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
     type Event = event::sem_bitset_process_local::Event;
+    type Monitoring = monitoring::process_local::ProcessLocalMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
 
-    fn from_state(state: ServiceState<Self::StaticStorage, Self::DynamicStorage>) -> Self {
+    fn from_state(state: ServiceState<Self>) -> Self {
         Self { state }
     }
 
-    fn state(&self) -> &ServiceState<Self::StaticStorage, Self::DynamicStorage> {
+    fn state(&self) -> &ServiceState<Self> {
         &self.state
     }
 
-    fn state_mut(&mut self) -> &mut ServiceState<Self::StaticStorage, Self::DynamicStorage> {
+    fn state_mut(&mut self) -> &mut ServiceState<Self> {
         &mut self.state
     }
 }
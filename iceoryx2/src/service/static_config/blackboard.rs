@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .create()?;
+//!
+//! println!("key type name:            {:?}", blackboard.static_config().key_type_details().type_name());
+//! println!("max readers:              {:?}", blackboard.static_config().max_supported_readers());
+//! println!("max nodes:                {:?}", blackboard.static_config().max_supported_nodes());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+use super::type_details::TypeDetails;
+
+/// The static configuration of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_readers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) key_type_details: TypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_readers: config.defaults.blackboard.max_readers,
+            max_nodes: config.defaults.blackboard.max_nodes,
+            key_type_details: TypeDetails::from_type::<()>(),
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::reader::Reader`] ports
+    pub fn max_supported_readers(&self) -> usize {
+        self.max_readers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports. A
+    /// blackboard is always owned by exactly one writer.
+    pub fn max_supported_writers(&self) -> usize {
+        1
+    }
+
+    /// Returns the maximum supported amount of [`crate::node::Node`]s that can open the
+    /// service in parallel.
+    pub fn max_supported_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the [`TypeDetails`] of the key type
+    pub fn key_type_details(&self) -> &TypeDetails {
+        &self.key_type_details
+    }
+}
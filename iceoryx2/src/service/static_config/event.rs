@@ -17,13 +17,17 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let event_name = ServiceName::new("MyEventName")?;
-//! let event = zero_copy::Service::new(&event_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let event = node.service_builder(&event_name)
 //!     .event()
 //!     .open_or_create()?;
 //!
 //! println!("max listeners:                {:?}", event.static_config().max_supported_listeners());
 //! println!("max notifiers:                {:?}", event.static_config().max_supported_notifiers());
+//! println!("max nodes:                    {:?}", event.static_config().max_supported_nodes());
 //! println!("event id max value:           {:?}", event.static_config().event_id_max_value());
+//! println!("counting enabled:             {:?}", event.static_config().has_counting_enabled());
 //!
 //! # Ok(())
 //! # }
@@ -38,7 +42,9 @@ use serde::{Deserialize, Serialize};
 pub struct StaticConfig {
     pub(crate) max_notifiers: usize,
     pub(crate) max_listeners: usize,
+    pub(crate) max_nodes: usize,
     pub(crate) event_id_max_value: usize,
+    pub(crate) enable_counting: bool,
 }
 
 impl StaticConfig {
@@ -46,7 +52,9 @@ impl StaticConfig {
         Self {
             max_notifiers: config.defaults.event.max_notifiers,
             max_listeners: config.defaults.event.max_listeners,
+            max_nodes: config.defaults.event.max_nodes,
             event_id_max_value: config.defaults.event.event_id_max_value,
+            enable_counting: config.defaults.event.enable_counting,
         }
     }
 
@@ -60,8 +68,20 @@ impl StaticConfig {
         self.max_listeners
     }
 
+    /// Returns the maximum supported amount of [`crate::node::Node`]s that can open the
+    /// service in parallel.
+    pub fn max_supported_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
     /// Returns the largest event_id that is supported by the service
     pub fn event_id_max_value(&self) -> usize {
         self.event_id_max_value
     }
+
+    /// Returns true if the service counts how often every [`crate::port::event_id::EventId`]
+    /// was notified, otherwise false.
+    pub fn has_counting_enabled(&self) -> bool {
+        self.enable_counting
+    }
 }
@@ -10,16 +10,36 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The static service configuration of a
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
 pub mod event;
 
+/// The static service configuration of a
+/// [`MessagingPattern::Pipeline`]
+/// based service.
+pub mod pipeline;
+
 /// The static service configuration of an
 /// [`MessagingPattern::PublishSubscribe`]
 /// based service.
 pub mod publish_subscribe;
 
+/// The static service configuration of a
+/// [`MessagingPattern::RequestResponse`]
+/// based service.
+pub mod request_response;
+
+/// The runtime description of the payload type of a
+/// [`MessagingPattern::PublishSubscribe`]
+/// based service.
+pub mod type_details;
+
 use crate::service::messaging_pattern::MessagingPattern;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::hash::Hash;
@@ -27,6 +47,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::config;
 
+use super::attribute::AttributeSet;
 use super::service_name::ServiceName;
 
 /// Defines a common set of static service configuration details every service shares.
@@ -35,6 +56,8 @@ pub struct StaticConfig {
     uuid: String,
     service_name: ServiceName,
     pub(crate) messaging_pattern: MessagingPattern,
+    #[serde(default)]
+    pub(crate) attributes: AttributeSet,
 }
 
 fn create_uuid<Hasher: Hash>(
@@ -59,6 +82,7 @@ impl StaticConfig {
                 .into(),
             service_name: *service_name,
             messaging_pattern,
+            attributes: AttributeSet::default(),
         }
     }
 
@@ -74,6 +98,53 @@ impl StaticConfig {
                 .into(),
             service_name: *service_name,
             messaging_pattern,
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_request_response<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
+        Self {
+            uuid: create_uuid::<Hasher>(service_name, &messaging_pattern)
+                .value()
+                .into(),
+            service_name: *service_name,
+            messaging_pattern,
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_pipeline<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Pipeline(pipeline::StaticConfig::new(config));
+        Self {
+            uuid: create_uuid::<Hasher>(service_name, &messaging_pattern)
+                .value()
+                .into(),
+            service_name: *service_name,
+            messaging_pattern,
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(config));
+        Self {
+            uuid: create_uuid::<Hasher>(service_name, &messaging_pattern)
+                .value()
+                .into(),
+            service_name: *service_name,
+            messaging_pattern,
+            attributes: AttributeSet::default(),
         }
     }
 
@@ -92,6 +163,21 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the user-defined [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
+    pub(crate) fn max_nodes(&self) -> usize {
+        match &self.messaging_pattern {
+            MessagingPattern::Event(ref v) => v.max_nodes,
+            MessagingPattern::PublishSubscribe(ref v) => v.max_nodes,
+            MessagingPattern::RequestResponse(ref v) => v.max_nodes,
+            MessagingPattern::Blackboard(ref v) => v.max_nodes,
+            MessagingPattern::Pipeline(ref v) => v.max_nodes,
+        }
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -134,4 +220,61 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn request_response_mut(&mut self) -> &mut request_response::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline_mut(&mut self) -> &mut pipeline::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
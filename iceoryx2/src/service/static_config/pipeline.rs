@@ -0,0 +1,135 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pipeline = node.service_builder(&service_name)
+//!     .pipeline()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("type name:                        {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                    {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max consumers:                    {:?}", pipeline.static_config().max_supported_consumers());
+//! println!("max nodes:                        {:?}", pipeline.static_config().max_supported_nodes());
+//! println!("consumer max buffer size:         {:?}", pipeline.static_config().consumer_max_buffer_size());
+//! println!("consumer max borrowed items:      {:?}", pipeline.static_config().consumer_max_borrowed_items());
+//! println!("distribution strategy:            {:?}", pipeline.static_config().distribution_strategy());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// Defines to which [`crate::port::consumer::Consumer`] a
+/// [`crate::port::producer::Producer`] delivers the next work item.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistributionStrategy {
+    /// The work items are handed out to the [`crate::port::consumer::Consumer`]s one after
+    /// another. A [`crate::port::consumer::Consumer`] with a full buffer is skipped.
+    RoundRobin,
+    /// Every work item is delivered to the [`crate::port::consumer::Consumer`] that holds the
+    /// least work items of the [`crate::port::producer::Producer`], counting the buffered ones
+    /// and the ones that are received but not yet released.
+    LeastLoaded,
+}
+
+/// The static configuration of a
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_producers: usize,
+    pub(crate) max_consumers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) consumer_max_buffer_size: usize,
+    pub(crate) consumer_max_borrowed_items: usize,
+    pub(crate) distribution_strategy: DistributionStrategy,
+    pub(crate) type_name: String,
+    pub(crate) type_size: usize,
+    pub(crate) type_alignment: usize,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_producers: config.defaults.pipeline.max_producers,
+            max_consumers: config.defaults.pipeline.max_consumers,
+            max_nodes: config.defaults.pipeline.max_nodes,
+            consumer_max_buffer_size: config.defaults.pipeline.consumer_max_buffer_size,
+            consumer_max_borrowed_items: config.defaults.pipeline.consumer_max_borrowed_items,
+            distribution_strategy: config.defaults.pipeline.distribution_strategy,
+            type_name: String::new(),
+            type_size: 0,
+            type_alignment: 0,
+        }
+    }
+
+    pub(crate) fn required_amount_of_items_per_data_segment(
+        &self,
+        producer_max_loaned_items: usize,
+    ) -> usize {
+        self.max_consumers * (self.consumer_max_buffer_size + self.consumer_max_borrowed_items)
+            + producer_max_loaned_items
+            + 1
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::producer::Producer`] ports
+    pub fn max_supported_producers(&self) -> usize {
+        self.max_producers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::consumer::Consumer`] ports
+    pub fn max_supported_consumers(&self) -> usize {
+        self.max_consumers
+    }
+
+    /// Returns the maximum supported amount of [`crate::node::Node`]s that can open the
+    /// service in parallel.
+    pub fn max_supported_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns how many work items a [`crate::port::consumer::Consumer`] can buffer per
+    /// connected [`crate::port::producer::Producer`].
+    pub fn consumer_max_buffer_size(&self) -> usize {
+        self.consumer_max_buffer_size
+    }
+
+    /// Returns how many [`crate::work_item::WorkItem`]s a [`crate::port::consumer::Consumer`]
+    /// can borrow in parallel from a single [`crate::port::producer::Producer`].
+    pub fn consumer_max_borrowed_items(&self) -> usize {
+        self.consumer_max_borrowed_items
+    }
+
+    /// Returns the [`DistributionStrategy`] that the [`crate::port::producer::Producer`]s use
+    /// to select the receiving [`crate::port::consumer::Consumer`].
+    pub fn distribution_strategy(&self) -> DistributionStrategy {
+        self.distribution_strategy
+    }
+
+    /// Returns the type name of the work item.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+}
@@ -17,7 +17,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
-//! let pubsub = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -25,6 +27,7 @@
 //! println!("type name:                        {:?}", pubsub.static_config().type_name());
 //! println!("max publishers:                   {:?}", pubsub.static_config().max_supported_publishers());
 //! println!("max subscribers:                  {:?}", pubsub.static_config().max_supported_subscribers());
+//! println!("max nodes:                        {:?}", pubsub.static_config().max_supported_nodes());
 //! println!("subscriber buffer size:           {:?}", pubsub.static_config().subscriber_max_buffer_size());
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
@@ -34,7 +37,11 @@
 //! # }
 //! ```
 
+use std::alloc::{Layout, LayoutError};
+
 use crate::config;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::static_config::type_details::TypeDetails;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -45,13 +52,12 @@ use serde::{Deserialize, Serialize};
 pub struct StaticConfig {
     pub(crate) max_subscribers: usize,
     pub(crate) max_publishers: usize,
+    pub(crate) max_nodes: usize,
     pub(crate) history_size: usize,
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
-    pub(crate) type_name: String,
-    pub(crate) type_size: usize,
-    pub(crate) type_alignment: usize,
+    pub(crate) type_details: TypeDetails,
 }
 
 impl StaticConfig {
@@ -59,6 +65,7 @@ impl StaticConfig {
         Self {
             max_subscribers: config.defaults.publish_subscribe.max_subscribers,
             max_publishers: config.defaults.publish_subscribe.max_publishers,
+            max_nodes: config.defaults.publish_subscribe.max_nodes,
             history_size: config.defaults.publish_subscribe.publisher_history_size,
             subscriber_max_buffer_size: config
                 .defaults
@@ -69,9 +76,7 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
-            type_name: String::new(),
-            type_size: 0,
-            type_alignment: 0,
+            type_details: TypeDetails::from_type::<()>(),
         }
     }
 
@@ -85,6 +90,12 @@ impl StaticConfig {
         self.max_subscribers
     }
 
+    /// Returns the maximum supported amount of [`crate::node::Node`]s that can open the
+    /// service in parallel.
+    pub fn max_supported_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
     /// Returns the maximum history size that can be requested on connect.
     pub fn history_size(&self) -> usize {
         self.history_size
@@ -109,16 +120,44 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the [`TypeDetails`] of the payload of the [`crate::service::Service`].
+    pub fn type_details(&self) -> &TypeDetails {
+        &self.type_details
+    }
+
     /// Returns the type name of the [`crate::service::Service`].
     pub fn type_name(&self) -> &str {
-        &self.type_name
+        self.type_details.type_name()
     }
 
+    /// Returns the size of the payload type. If the payload is a slice `[T]` it is the size
+    /// of one element `T`.
     pub fn type_size(&self) -> usize {
-        self.type_size
+        self.type_details.size()
     }
 
+    /// Returns the alignment of the payload type. If the payload is a slice `[T]` it is the
+    /// alignment of one element `T`.
     pub fn type_alignment(&self) -> usize {
-        self.type_alignment
+        self.type_details.alignment()
+    }
+
+    /// Returns the [`Layout`] of a message consisting of the [`Header`] followed by
+    /// `number_of_elements` payload elements. Fails when the resulting message size overflows.
+    pub(crate) fn message_layout(&self, number_of_elements: usize) -> Result<Layout, LayoutError> {
+        let payload_layout = Layout::from_size_align(
+            self.type_details.size().saturating_mul(number_of_elements),
+            self.type_details.alignment(),
+        )?;
+        let (message_layout, _) = Layout::new::<Header>().extend(payload_layout)?;
+
+        Ok(message_layout.pad_to_align())
+    }
+
+    /// Returns the offset of the payload relative to the start of a message.
+    pub(crate) fn payload_offset(&self) -> usize {
+        let alignment = self.type_details.alignment();
+        let header_size = core::mem::size_of::<Header>();
+        header_size.div_ceil(alignment) * alignment
     }
 }
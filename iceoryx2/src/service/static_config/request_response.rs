@@ -0,0 +1,150 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let reqres = node.service_builder(&service_name)
+//!     .request_response()
+//!     .typed::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("request type name:                {:?}", reqres.static_config().request_type_name());
+//! println!("response type name:               {:?}", reqres.static_config().response_type_name());
+//! println!("max clients:                      {:?}", reqres.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", reqres.static_config().max_supported_servers());
+//! println!("max nodes:                        {:?}", reqres.static_config().max_supported_nodes());
+//! println!("max active requests:              {:?}", reqres.static_config().max_active_requests());
+//! println!("server max borrowed requests:     {:?}", reqres.static_config().server_max_borrowed_requests());
+//! println!("client max borrowed responses:    {:?}", reqres.static_config().client_max_borrowed_responses());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) max_active_requests: usize,
+    pub(crate) server_max_borrowed_requests: usize,
+    pub(crate) client_max_borrowed_responses: usize,
+    pub(crate) request_type_name: String,
+    pub(crate) request_type_size: usize,
+    pub(crate) request_type_alignment: usize,
+    pub(crate) response_type_name: String,
+    pub(crate) response_type_size: usize,
+    pub(crate) response_type_alignment: usize,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_clients: config.defaults.request_response.max_clients,
+            max_servers: config.defaults.request_response.max_servers,
+            max_nodes: config.defaults.request_response.max_nodes,
+            max_active_requests: config.defaults.request_response.max_active_requests,
+            server_max_borrowed_requests: config
+                .defaults
+                .request_response
+                .server_max_borrowed_requests,
+            client_max_borrowed_responses: config
+                .defaults
+                .request_response
+                .client_max_borrowed_responses,
+            request_type_name: String::new(),
+            request_type_size: 0,
+            request_type_alignment: 0,
+            response_type_name: String::new(),
+            response_type_size: 0,
+            response_type_alignment: 0,
+        }
+    }
+
+    pub(crate) fn required_amount_of_requests_per_data_segment(
+        &self,
+        client_max_loaned_requests: usize,
+    ) -> usize {
+        self.max_servers * (self.max_active_requests + self.server_max_borrowed_requests)
+            + client_max_loaned_requests
+            + 1
+    }
+
+    pub(crate) fn required_amount_of_responses_per_data_segment(
+        &self,
+        server_max_loaned_responses: usize,
+    ) -> usize {
+        self.max_clients * (self.max_active_requests + self.client_max_borrowed_responses)
+            + server_max_loaned_responses
+            + 1
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_supported_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_supported_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns the maximum supported amount of [`crate::node::Node`]s that can open the
+    /// service in parallel.
+    pub fn max_supported_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns how many requests a [`crate::port::client::Client`] can have in flight towards
+    /// a single [`crate::port::server::Server`]. It is also the buffer size of the response
+    /// channel from the [`crate::port::server::Server`] to the [`crate::port::client::Client`].
+    pub fn max_active_requests(&self) -> usize {
+        self.max_active_requests
+    }
+
+    /// Returns how many [`crate::active_request::ActiveRequest`]s a
+    /// [`crate::port::server::Server`] can borrow in parallel from a single
+    /// [`crate::port::client::Client`].
+    pub fn server_max_borrowed_requests(&self) -> usize {
+        self.server_max_borrowed_requests
+    }
+
+    /// Returns how many [`crate::response::Response`]s a [`crate::port::client::Client`] can
+    /// borrow in parallel from a single [`crate::port::server::Server`].
+    pub fn client_max_borrowed_responses(&self) -> usize {
+        self.client_max_borrowed_responses
+    }
+
+    /// Returns the type name of the request.
+    pub fn request_type_name(&self) -> &str {
+        &self.request_type_name
+    }
+
+    /// Returns the type name of the response.
+    pub fn response_type_name(&self) -> &str {
+        &self.response_type_name
+    }
+}
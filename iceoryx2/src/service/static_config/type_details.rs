@@ -0,0 +1,202 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::type_details::{TypeDetails, TypeVariant};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! // the type details are usually acquired at runtime, for instance from a
+//! // foreign language binding or from the static config of an existing service
+//! let type_details = TypeDetails::new(TypeVariant::FixedSize, "u64", 8, 8)?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .publish_subscribe()
+//!     .untyped(&type_details)
+//!     .open_or_create()?;
+//!
+//! println!("type details: {:?}", service.static_config().type_details());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use serde::{Deserialize, Serialize};
+
+/// Defines if the payload of a service is a type with a fixed size or a slice with a dynamic
+/// number of elements.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum TypeVariant {
+    /// The payload consists of exactly one element of a fixed size type.
+    FixedSize,
+    /// The payload is a slice with a dynamic number of elements.
+    Dynamic,
+}
+
+/// Errors that can occur when [`TypeDetails`] are created with [`TypeDetails::new()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TypeDetailsError {
+    AlignmentIsNotAPowerOfTwo,
+    SizeIsNotAMultipleOfAlignment,
+}
+
+impl std::fmt::Display for TypeDetailsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for TypeDetailsError {}
+
+/// Describes the payload type of a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based service. Two services are only type compatible when their [`TypeDetails`] are equal.
+/// If the payload is a slice `[T]` the size and alignment describe one element `T`.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TypeDetails {
+    pub(crate) variant: TypeVariant,
+    pub(crate) type_name: String,
+    pub(crate) size: usize,
+    pub(crate) alignment: usize,
+}
+
+impl TypeDetails {
+    /// Creates new [`TypeDetails`] from runtime provided values. The `alignment` must be a
+    /// power of two and the `size` a multiple of the `alignment`.
+    pub fn new(
+        variant: TypeVariant,
+        type_name: &str,
+        size: usize,
+        alignment: usize,
+    ) -> Result<Self, TypeDetailsError> {
+        let msg = "Unable to create type details";
+        let origin = "TypeDetails::new()";
+
+        if !alignment.is_power_of_two() {
+            fail!(from origin, with TypeDetailsError::AlignmentIsNotAPowerOfTwo,
+                "{} for \"{}\" since the alignment {} is not a power of two.", msg, type_name, alignment);
+        }
+
+        if size % alignment != 0 {
+            fail!(from origin, with TypeDetailsError::SizeIsNotAMultipleOfAlignment,
+                "{} for \"{}\" since the size {} is not a multiple of the alignment {}.", msg, type_name, size, alignment);
+        }
+
+        Ok(Self {
+            variant,
+            type_name: type_name.to_string(),
+            size,
+            alignment,
+        })
+    }
+
+    /// Creates the [`TypeDetails`] of the fixed size type `T`.
+    pub fn from_type<T>() -> Self {
+        Self {
+            variant: TypeVariant::FixedSize,
+            type_name: core::any::type_name::<T>().to_string(),
+            size: core::mem::size_of::<T>(),
+            alignment: core::mem::align_of::<T>(),
+        }
+    }
+
+    /// Creates the [`TypeDetails`] of the slice `[T]`.
+    pub fn from_slice<T>() -> Self {
+        Self {
+            variant: TypeVariant::Dynamic,
+            type_name: core::any::type_name::<[T]>().to_string(),
+            size: core::mem::size_of::<T>(),
+            alignment: core::mem::align_of::<T>(),
+        }
+    }
+
+    /// Creates the [`TypeDetails`] of the [`Serialized`] payload `T` that is serialized with
+    /// `Serializer`. The payload is stored as a slice of bytes.
+    pub fn from_serialized<T, Serializer: iceoryx2_cal::serialize::Serialize>() -> Self {
+        Self {
+            variant: TypeVariant::Dynamic,
+            type_name: core::any::type_name::<Serialized<T, Serializer>>().to_string(),
+            size: core::mem::size_of::<u8>(),
+            alignment: core::mem::align_of::<u8>(),
+        }
+    }
+
+    /// Returns the [`TypeVariant`] of the payload.
+    pub fn variant(&self) -> TypeVariant {
+        self.variant
+    }
+
+    /// Returns the name of the payload type.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the size of the payload type or the size of one slice element.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of the payload type or the alignment of one slice element.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+}
+
+/// Payload marker of publish-subscribe services and ports whose payload type is only known at
+/// runtime and described by [`TypeDetails`]. Those services are created or opened with
+/// [`crate::service::builder::publish_subscribe::Builder::untyped()`] and their ports
+/// exchange the payload as raw bytes. It is unsized so that it can never be confused with a
+/// fixed size payload type.
+#[derive(Debug)]
+pub struct Untyped {
+    _data: [u8],
+}
+
+unsafe impl ZeroCopySend for Untyped {}
+
+/// Payload marker of publish-subscribe services and ports whose payload `T` is not shared memory
+/// compatible and is therefore serialized with `Serializer`, for instance
+/// [`Cdr`](iceoryx2_cal::serialize::cdr::Cdr) or [`Toml`](iceoryx2_cal::serialize::toml::Toml),
+/// into a dynamically sized chunk of bytes. Those services are created or opened with
+/// [`crate::service::builder::publish_subscribe::Builder::serialized()`].
+pub struct Serialized<T, Serializer: iceoryx2_cal::serialize::Serialize> {
+    _value: PhantomData<T>,
+    _serializer: PhantomData<Serializer>,
+    _data: [u8],
+}
+
+impl<T, Serializer: iceoryx2_cal::serialize::Serialize> Debug for Serialized<T, Serializer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Serialized<{}, {}> {{ }}",
+            core::any::type_name::<T>(),
+            core::any::type_name::<Serializer>()
+        )
+    }
+}
+
+unsafe impl<T, Serializer: iceoryx2_cal::serialize::Serialize> ZeroCopySend
+    for Serialized<T, Serializer>
+{
+}
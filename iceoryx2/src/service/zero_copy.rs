@@ -19,7 +19,9 @@
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
 //! // use `zero_copy` as communication variant
-//! let service = zero_copy::Service::new(&service_name)
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
 //!     .publish_subscribe()
 //!     .typed::<u64>()
 //!     .open_or_create()?;
@@ -42,10 +44,7 @@ use super::ServiceState;
 /// Defines a zero copy inter-process communication setup based on posix mechanisms.
 #[derive(Debug)]
 pub struct Service {
-    state: ServiceState<
-        static_storage::file::Storage,
-        dynamic_storage::posix_shared_memory::Storage<DynamicConfig>,
-    >,
+    state: ServiceState<Self>,
 }
 
 impl crate::service::Service for Service {
@@ -59,16 +58,18 @@ impl crate::service::Service for Service {
     type Event = event::sem_bitset_posix_shared_memory::Event;
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     type Event = event::unix_datagram_socket::EventImpl;
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
 
-    fn from_state(state: ServiceState<Self::StaticStorage, Self::DynamicStorage>) -> Self {
+    fn from_state(state: ServiceState<Self>) -> Self {
         Self { state }
     }
 
-    fn state(&self) -> &ServiceState<Self::StaticStorage, Self::DynamicStorage> {
+    fn state(&self) -> &ServiceState<Self> {
         &self.state
     }
 
-    fn state_mut(&mut self) -> &mut ServiceState<Self::StaticStorage, Self::DynamicStorage> {
+    fn state_mut(&mut self) -> &mut ServiceState<Self> {
         &mut self.state
     }
 }
@@ -0,0 +1,85 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Like [`zero_copy`](crate::service::zero_copy) but the events are signalled with a futex in
+//! shared memory instead of a semaphore. This lowers the notification latency. As with
+//! `zero_copy`, the [`Listener`](crate::port::listener::Listener) has no file descriptor and
+//! events cannot carry an [`EventPayload`](crate::port::event_payload::EventPayload), use
+//! [`zero_copy_socket`](crate::service::zero_copy_socket) when this is required.
+//!
+//! Processes must agree on the service variant, a [`Notifier`](crate::port::notifier::Notifier)
+//! of a `zero_copy` service cannot reach a [`Listener`](crate::port::listener::Listener) of a
+//! `zero_copy_futex` service and vice versa.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_futex;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! // use `zero_copy_futex` as communication variant
+//! let node = NodeBuilder::new().create::<zero_copy_futex::Service>()?;
+//!
+//! let event = node.service_builder(&service_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener().create()?;
+//! let notifier = event.notifier().create()?;
+//!
+//! notifier.notify()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms that
+/// signals events with a futex.
+#[derive(Debug)]
+pub struct Service {
+    state: ServiceState<Self>,
+}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::futex_bitset_posix_shared_memory::Event;
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
+
+    fn from_state(state: ServiceState<Self>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<Self> {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ServiceState<Self> {
+        &mut self.state
+    }
+}
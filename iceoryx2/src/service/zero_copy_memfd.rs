@@ -0,0 +1,89 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Like [`zero_copy`](crate::service::zero_copy) but the data segments of the
+//! [`Publisher`](crate::port::publisher::Publisher)s are anonymous memfds that have no name in
+//! `/dev/shm` and are handed over to the [`Subscriber`](crate::port::subscriber::Subscriber)s
+//! by file descriptor passing, see [`iceoryx2_cal::shared_memory::memfd`]. A data segment is
+//! handed over only to processes with a [`Node`](crate::node::Node) that opened the service
+//! and it cannot be opened by other processes of the same user. The kernel releases
+//! a data segment as soon as the last process that has mapped it exits and it supports huge
+//! pages, see
+//! [`PortFactoryPublisher::memory_placement()`](crate::service::port_factory::publisher::PortFactoryPublisher::memory_placement()).
+//!
+//! Processes must agree on the service variant, a
+//! [`Subscriber`](crate::port::subscriber::Subscriber) of a `zero_copy` service cannot
+//! connect to a [`Publisher`](crate::port::publisher::Publisher) of a `zero_copy_memfd`
+//! service and vice versa.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_memfd;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! // use `zero_copy_memfd` as communication variant
+//! let node = NodeBuilder::new().create::<zero_copy_memfd::Service>()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .publish_subscribe()
+//!     .typed::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms that
+/// shares the data segments via file descriptor passing.
+#[derive(Debug)]
+pub struct Service {
+    state: ServiceState<Self>,
+}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::memfd::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::sem_bitset_posix_shared_memory::Event;
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
+
+    fn from_state(state: ServiceState<Self>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<Self> {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ServiceState<Self> {
+        &mut self.state
+    }
+}
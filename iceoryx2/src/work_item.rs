@@ -0,0 +1,91 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #   .pipeline()
+//! #   .typed::<u64>()
+//! #   .open_or_create()?;
+//! # let consumer = service.consumer().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//!     println!("header producer id {:?}", work_item.header().producer_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::{fmt::Debug, ops::Deref};
+
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+use crate::port::consumer::ConsumerSharedState;
+use crate::port::port_identifiers::UniqueProducerId;
+use crate::raw_sample::RawSample;
+use crate::service::header::pipeline::Header;
+
+/// It stores the payload of a work item and is acquired by the
+/// [`Consumer`](crate::port::consumer::Consumer) whenever a
+/// [`Producer`](crate::port::producer::Producer) assigned a work item to it via
+/// [`Consumer::receive()`](crate::port::consumer::Consumer::receive()). When it goes out of
+/// scope the work item is returned to the [`Producer`](crate::port::producer::Producer).
+#[derive(Debug)]
+pub struct WorkItem<PayloadType: Debug, Service: crate::service::Service> {
+    pub(crate) shared_state: Arc<ConsumerSharedState<Service>>,
+    pub(crate) ptr: RawSample<Header, PayloadType>,
+    pub(crate) channel_id: usize,
+    pub(crate) offset: PointerOffset,
+    pub(crate) origin: UniqueProducerId,
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service> Deref
+    for WorkItem<PayloadType, Service>
+{
+    type Target = PayloadType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service> Drop for WorkItem<PayloadType, Service> {
+    fn drop(&mut self) {
+        self.shared_state
+            .release_item(self.channel_id, self.origin, self.offset);
+    }
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service> WorkItem<PayloadType, Service> {
+    /// Returns a reference to the payload of the [`WorkItem`]
+    pub fn payload(&self) -> &PayloadType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`WorkItem`].
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`](crate::port::producer::Producer)
+    pub fn origin(&self) -> UniqueProducerId {
+        self.origin
+    }
+}
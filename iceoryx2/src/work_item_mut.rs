@@ -0,0 +1,197 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder(&service_name)
+//! #     .pipeline()
+//! #     .typed::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let producer = service.producer().create()?;
+//!
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1234);
+//!
+//! println!("item id: {:?}", work_item.header().item_id());
+//! work_item.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::port_identifiers::UniqueConsumerId,
+    port::producer::{ProducerSendError, ProducerSharedState},
+    raw_sample::RawSampleMut,
+    service::header::pipeline::Header,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+
+/// Acquired by a [`crate::port::producer::Producer`] via
+/// [`crate::port::producer::Producer::loan()`] or
+/// [`crate::port::producer::Producer::loan_uninit()`]. It stores the payload of the work item that
+/// will be sent to exactly one of the connected [`crate::port::consumer::Consumer`]s. If the
+/// [`WorkItemMut`] is not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent work items in the
+/// [`crate::port::producer::Producer`] and the [`crate::port::producer::Producer`] is not thread-safe!
+///
+/// The generic parameter `PayloadType` is either a `PayloadType` or a
+/// [`core::mem::MaybeUninit<PayloadType>`], depending which API is used to obtain the work item.
+#[derive(Debug)]
+pub struct WorkItemMut<PayloadType: Debug, Service: crate::service::Service> {
+    shared_state: Arc<ProducerSharedState<Service>>,
+    ptr: RawSampleMut<Header, PayloadType>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service> Drop
+    for WorkItemMut<PayloadType, Service>
+{
+    fn drop(&mut self) {
+        self.shared_state.return_loaned_item(self.offset_to_chunk);
+    }
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service>
+    WorkItemMut<MaybeUninit<PayloadType>, Service>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ProducerSharedState<Service>>,
+        ptr: RawSampleMut<Header, MaybeUninit<PayloadType>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the work item and labels the work item as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .pipeline()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(1234);
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(mut self, value: PayloadType) -> WorkItemMut<PayloadType, Service> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<PayloadType>`] container and labels
+    /// the work item as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<PayloadType>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&service_name)
+    /// #     .pipeline()
+    /// #     .typed::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    ///
+    /// let mut work_item = producer.loan_uninit()?;
+    /// work_item.payload_mut().write(1234);
+    /// let work_item = unsafe { work_item.assume_init() };
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> WorkItemMut<PayloadType, Service> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        PayloadType: Debug, // `PayloadType` is either a `PayloadType` or a `MaybeUninit<PayloadType>`
+        Service: crate::service::Service,
+    > WorkItemMut<PayloadType, Service>
+{
+    /// Returns a reference to the [`Header`] of the work_item.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the work_item.
+    pub fn payload(&self) -> &PayloadType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the work_item.
+    pub fn payload_mut(&mut self) -> &mut PayloadType {
+        self.ptr.as_data_mut()
+    }
+
+    /// Send a previously loaned [`crate::port::producer::Producer::loan_uninit()`] or
+    /// [`crate::port::producer::Producer::loan()`] [`WorkItemMut`] to exactly one of the
+    /// connected [`crate::port::consumer::Consumer`]s of the service.
+    ///
+    /// The payload of the [`WorkItemMut`] must be initialized before it can be sent. Have a look
+    /// at [`WorkItemMut::write_payload()`] and [`WorkItemMut::assume_init()`]
+    /// for more details.
+    ///
+    /// On success the [`UniqueConsumerId`] of the [`crate::port::consumer::Consumer`] that
+    /// received the work item is returned or [`None`] when no
+    /// [`crate::port::consumer::Consumer`] is connected. Otherwise a [`ProducerSendError`]
+    /// describing the failure is returned.
+    pub fn send(self) -> Result<Option<UniqueConsumerId>, ProducerSendError> {
+        self.shared_state.send_item(self.offset_to_chunk)
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(all(feature = "async", unix))]
+
+#[generic_tests::define]
+mod async_ports {
+    use std::future::Future;
+    use std::time::Duration;
+
+    use futures_core::Stream;
+    use iceoryx2::port::async_listener::AsyncListener;
+    use iceoryx2::port::async_subscriber::AsyncSubscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "async_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn async_listener_wait_one_returns_notified_event<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+
+            notifier
+                .notify_with_custom_event_id(EventId::new(7))
+                .unwrap();
+
+            assert_that!(sut.wait_one().await, eq Ok(EventId::new(7)));
+            assert_that!(sut.try_wait_one().unwrap(), is_none);
+        });
+    }
+
+    #[test]
+    fn async_listener_does_not_wake_up_without_notification<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+
+            assert_that!(tokio::time::timeout(TIMEOUT, sut.wait_one()).await, is_err);
+            assert_that!(
+                tokio::time::timeout(TIMEOUT, sut.wait_all(|_| {})).await,
+                is_err
+            );
+        });
+    }
+
+    #[test]
+    fn async_listener_wait_all_collects_all_events<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        const NUMBER_OF_EVENTS: usize = 8;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+
+            for n in 0..NUMBER_OF_EVENTS {
+                notifier
+                    .notify_with_custom_event_id(EventId::new(n))
+                    .unwrap();
+            }
+
+            let mut received_events = vec![];
+            while received_events.len() < NUMBER_OF_EVENTS {
+                sut.wait_all(|id| received_events.push(id)).await.unwrap();
+            }
+
+            for n in 0..NUMBER_OF_EVENTS {
+                assert_that!(received_events, contains EventId::new(n));
+            }
+        });
+    }
+
+    #[test]
+    fn async_listener_is_woken_up_by_notification_from_other_thread<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+
+            let notifier_thread = std::thread::spawn(move || {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
+                let event = node.service_builder(&service_name).event().open().unwrap();
+                let notifier = event.notifier().create().unwrap();
+                std::thread::sleep(TIMEOUT);
+                notifier
+                    .notify_with_custom_event_id(EventId::new(21))
+                    .unwrap();
+            });
+
+            assert_that!(sut.wait_one().await, eq Ok(EventId::new(21)));
+            notifier_thread.join().unwrap();
+        });
+    }
+
+    #[test]
+    fn async_listener_stream_yields_events<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+            let mut sut = std::pin::pin!(sut);
+
+            notifier
+                .notify_with_custom_event_id(EventId::new(3))
+                .unwrap();
+
+            let next = std::future::poll_fn(|cx| sut.as_mut().poll_next(cx)).await;
+            assert_that!(next, eq Some(Ok(EventId::new(3))));
+        });
+    }
+
+    #[test]
+    fn async_listener_can_be_converted_back_into_listener<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        let listener = block_on(async {
+            let sut = AsyncListener::new(event.listener().create().unwrap()).unwrap();
+            sut.into_inner()
+        });
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(5))
+            .unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(5)));
+    }
+
+    #[test]
+    fn async_subscriber_receives_sample_after_notification<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        block_on(async {
+            let sut = AsyncSubscriber::new(
+                pubsub.subscriber().create().unwrap(),
+                event.listener().create().unwrap(),
+            )
+            .unwrap();
+            let publisher = pubsub.publisher().create().unwrap();
+            let notifier = event.notifier().create().unwrap();
+
+            assert_that!(tokio::time::timeout(TIMEOUT, sut.receive()).await, is_err);
+
+            publisher.send_copy(9182).unwrap();
+            notifier.notify().unwrap();
+
+            let sample = sut.receive().await.unwrap();
+            assert_that!(*sample, eq 9182);
+        });
+    }
+
+    #[test]
+    fn async_subscriber_receives_available_samples_without_notification<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        block_on(async {
+            let sut = AsyncSubscriber::new(
+                pubsub.subscriber().create().unwrap(),
+                event.listener().create().unwrap(),
+            )
+            .unwrap();
+            let mut sut = std::pin::pin!(sut);
+            let publisher = pubsub.publisher().create().unwrap();
+
+            publisher.send_copy(1).unwrap();
+            publisher.send_copy(2).unwrap();
+
+            assert_that!(*sut.receive().await.unwrap(), eq 1);
+            let next = std::future::poll_fn(|cx| sut.as_mut().poll_next(cx)).await;
+            assert_that!(*next.unwrap().unwrap(), eq 2);
+            assert_that!(tokio::time::timeout(TIMEOUT, sut.receive()).await, is_err);
+        });
+    }
+
+    #[test]
+    fn async_subscriber_is_woken_up_by_publisher_in_other_thread<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        const NUMBER_OF_SAMPLES: u64 = 16;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        block_on(async {
+            let sut = AsyncSubscriber::new(
+                pubsub.subscriber().create().unwrap(),
+                event.listener().create().unwrap(),
+            )
+            .unwrap();
+
+            let (done_sender, done_receiver) = std::sync::mpsc::channel();
+            let publisher_thread = std::thread::spawn(move || {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
+                let pubsub = node
+                    .service_builder(&service_name)
+                    .publish_subscribe()
+                    .typed::<u64>()
+                    .open()
+                    .unwrap();
+                let event = node.service_builder(&service_name).event().open().unwrap();
+                let publisher = pubsub.publisher().create().unwrap();
+                let notifier = event.notifier().create().unwrap();
+
+                for n in 0..NUMBER_OF_SAMPLES {
+                    std::thread::sleep(Duration::from_millis(1));
+                    publisher.send_copy(n).unwrap();
+                    notifier.notify().unwrap();
+                }
+
+                // keep the publisher alive, otherwise its undelivered samples are discarded
+                done_receiver.recv().unwrap();
+            });
+
+            for n in 0..NUMBER_OF_SAMPLES {
+                assert_that!(*sut.receive().await.unwrap(), eq n);
+            }
+            done_sender.send(()).unwrap();
+            publisher_thread.join().unwrap();
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy_socket::Service>)]
+    mod zero_copy_socket {}
+}
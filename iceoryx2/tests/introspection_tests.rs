@@ -0,0 +1,246 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod introspection {
+    use iceoryx2::introspection::*;
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "introspection_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn service_snapshot<Sut: Service>(
+        node: &Node<Sut>,
+        service_name: &ServiceName,
+    ) -> ServiceSnapshot {
+        let snapshot = Snapshot::collect::<Sut>(node.config()).unwrap();
+        snapshot
+            .services()
+            .iter()
+            .find(|s| s.service_name() == service_name)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn snapshot_contains_ports_and_connections<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(5)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber_1 = sut.subscriber().buffer_size(3).create().unwrap();
+        let subscriber_2 = sut.subscriber().buffer_size(4).create().unwrap();
+
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.number_of_ports().to_vec(), eq vec![
+            ("publishers".to_string(), 1),
+            ("subscribers".to_string(), 2)
+        ]);
+        assert_that!(snapshot.publishers(), len 1);
+        assert_that!(snapshot.publishers()[0].id(), eq publisher.id());
+        assert_that!(*snapshot.publishers()[0].node_id(), eq * node.id());
+        assert_that!(snapshot.subscribers(), len 2);
+        let subscriber_snapshot = snapshot
+            .subscribers()
+            .iter()
+            .find(|s| s.id() == subscriber_2.id())
+            .unwrap();
+        assert_that!(subscriber_snapshot.buffer_size(), eq 4);
+
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(publisher.send_copy(123), eq Ok(2));
+        assert_that!(publisher.send_copy(456), eq Ok(2));
+        assert_that!(subscriber_1.receive().unwrap(), is_some);
+
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.connections(), len 2);
+        for connection in snapshot.connections() {
+            assert_that!(connection.publisher_id(), eq publisher.id());
+            if connection.subscriber_id() == subscriber_1.id() {
+                assert_that!(connection.number_of_buffered_samples(), eq 1);
+            } else {
+                assert_that!(connection.subscriber_id(), eq subscriber_2.id());
+                assert_that!(connection.number_of_buffered_samples(), eq 2);
+            }
+        }
+    }
+
+    #[test]
+    fn snapshot_contains_number_of_loaned_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().max_loaned_samples(4).create().unwrap();
+        let _subscriber = sut.subscriber().create().unwrap();
+
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers()[0].number_of_loaned_samples(), eq 0);
+
+        let sample_1 = publisher.loan().unwrap();
+        let sample_2 = publisher.loan().unwrap();
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers()[0].number_of_loaned_samples(), eq 2);
+
+        drop(sample_1);
+        assert_that!(sample_2.send(), is_ok);
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers()[0].number_of_loaned_samples(), eq 0);
+    }
+
+    #[test]
+    fn snapshot_of_removed_publisher_is_gone<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let _sample = publisher.loan().unwrap();
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers(), len 1);
+
+        drop(_sample);
+        drop(publisher);
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers(), len 0);
+
+        let _publisher = sut.publisher().create().unwrap();
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.publishers()[0].number_of_loaned_samples(), eq 0);
+    }
+
+    #[test]
+    fn snapshot_contains_services_of_other_messaging_patterns_without_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let _notifier = sut.notifier().create().unwrap();
+
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.messaging_pattern(), eq "Event");
+        assert_that!(snapshot.publishers(), len 0);
+        assert_that!(snapshot.subscribers(), len 0);
+        assert_that!(snapshot.connections(), len 0);
+        assert_that!(snapshot.number_of_ports().to_vec(), eq vec![
+            ("notifiers".to_string(), 1),
+            ("listeners".to_string(), 0)
+        ]);
+    }
+
+    #[test]
+    fn snapshot_contains_number_of_pipeline_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let _producer = sut.producer().create().unwrap();
+
+        let snapshot = service_snapshot(&node, &service_name);
+        assert_that!(snapshot.number_of_ports().to_vec(), eq vec![
+            ("producers".to_string(), 1),
+            ("consumers".to_string(), 0)
+        ]);
+    }
+
+    #[test]
+    fn introspection_publisher_publishes_snapshot<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let introspection_service = node
+            .service_builder(&services_service_name())
+            .publish_subscribe()
+            .serialized::<Snapshot, SnapshotSerializer>()
+            .open_or_create()
+            .unwrap();
+        let subscriber = introspection_service.subscriber().create().unwrap();
+
+        let introspection = IntrospectionPublisherBuilder::new(&node).create().unwrap();
+        assert_that!(introspection.publish(), eq Ok(1));
+        assert_that!(services_service_name().starts_with(SERVICE_NAME_PREFIX), eq true);
+
+        let snapshot = subscriber.receive().unwrap().unwrap();
+        let service = snapshot
+            .services()
+            .iter()
+            .find(|s| s.service_name() == &service_name)
+            .unwrap();
+        assert_that!(service.uuid(), eq sut.uuid());
+        assert_that!(service.messaging_pattern(), eq "PublishSubscribe");
+        assert_that!(service.publishers(), len 1);
+        assert_that!(service.publishers()[0].id(), eq publisher.id());
+    }
+
+    #[test]
+    fn introspection_publisher_fails_when_snapshot_exceeds_max_size<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let introspection = IntrospectionPublisherBuilder::new(&node)
+            .max_snapshot_size(8)
+            .create()
+            .unwrap();
+        assert_that!(introspection.publish(), is_err);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
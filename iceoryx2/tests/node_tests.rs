@@ -0,0 +1,225 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node {
+    use std::collections::HashSet;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::node::{NodeId, NodeState};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn alive_node_ids<Sut: Service>(config: &Config) -> Vec<NodeId> {
+        Node::<Sut>::list_with_custom_config(config)
+            .unwrap()
+            .iter()
+            .filter_map(|state| match state {
+                NodeState::Alive(view) => Some(*view.id()),
+                NodeState::Dead(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn node_can_be_created<Sut: Service>() {
+        let sut = NodeBuilder::new().create::<Sut>();
+        assert_that!(sut, is_ok);
+
+        let sut = sut.unwrap();
+        assert_that!(sut.id().pid(), eq Process::from_self().id());
+    }
+
+    #[test]
+    fn node_ids_are_unique<Sut: Service>() {
+        const NUMBER_OF_NODES: usize = 16;
+        let nodes: Vec<_> = (0..NUMBER_OF_NODES)
+            .map(|_| NodeBuilder::new().create::<Sut>().unwrap())
+            .collect();
+
+        let ids: HashSet<_> = nodes.iter().map(|node| *node.id()).collect();
+        assert_that!(ids, len NUMBER_OF_NODES);
+    }
+
+    #[test]
+    fn list_contains_created_nodes_as_alive<Sut: Service>() {
+        let sut_1 = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut_2 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let alive_nodes = alive_node_ids::<Sut>(sut_1.config());
+        assert_that!(alive_nodes, contains * sut_1.id());
+        assert_that!(alive_nodes, contains * sut_2.id());
+
+        let sut_2_id = *sut_2.id();
+        drop(sut_2);
+
+        let alive_nodes = alive_node_ids::<Sut>(sut_1.config());
+        assert_that!(alive_nodes, contains * sut_1.id());
+        assert_that!(alive_nodes.contains(&sut_2_id), eq false);
+    }
+
+    #[test]
+    fn node_is_alive_as_long_as_one_of_its_services_exists<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = NodeBuilder::new().create::<Sut>().unwrap();
+        let config = sut.config().clone();
+        let sut_id = *sut.id();
+
+        let service = sut.service_builder(&service_name).event().create().unwrap();
+        drop(sut);
+
+        assert_that!(alive_node_ids::<Sut>(&config), contains sut_id);
+
+        drop(service);
+        assert_that!(alive_node_ids::<Sut>(&config).contains(&sut_id), eq false);
+    }
+
+    #[test]
+    fn node_with_custom_config_is_only_listed_with_that_config<Sut: Service>() {
+        let mut custom_config = Config::default();
+        custom_config.global.service.directory =
+            format!("node_tests_{}", UniqueSystemId::new().unwrap().value());
+
+        let sut = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        assert_that!(sut.config().global.service.directory, eq custom_config.global.service.directory);
+        assert_that!(alive_node_ids::<Sut>(&custom_config), contains * sut.id());
+        assert_that!(alive_node_ids::<Sut>(Config::get_global_config()).contains(sut.id()), eq false);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+mod dead_node {
+    use std::process::Command;
+
+    use iceoryx2::node::NodeState;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    type Sut = zero_copy::Service;
+
+    const SERVICE_NAME_ENV: &str = "IOX2_NODE_TESTS_DEAD_NODE_SERVICE_NAME";
+    const HELPER_TEST_NAME: &str = "dead_node::helper_process_creates_ports_and_dies";
+
+    #[test]
+    fn helper_process_creates_ports_and_dies() {
+        // only active when started as child process by the dead node tests
+        let service_name = match std::env::var(SERVICE_NAME_ENV) {
+            Ok(service_name) => ServiceName::new(&service_name).unwrap(),
+            Err(_) => return,
+        };
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher().create().unwrap();
+        let subscriber = pubsub.subscriber().create().unwrap();
+        let listener = event.listener().create().unwrap();
+        let notifier = event.notifier().create().unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        // terminate without cleaning up any resource, like a crashed process
+        std::mem::forget((
+            node, pubsub, event, publisher, subscriber, listener, notifier,
+        ));
+        std::process::exit(0);
+    }
+
+    #[test]
+    fn stale_resources_of_dead_node_are_removed() {
+        let service_name = ServiceName::new(&format!(
+            "node_tests_dead_node_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([HELPER_TEST_NAME, "--exact", "--test-threads=1"])
+            .env(SERVICE_NAME_ENV, service_name.as_str())
+            .spawn()
+            .unwrap();
+        let child_pid = child.id() as i32;
+        assert_that!(child.wait().unwrap().success(), eq true);
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(pubsub.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(pubsub.dynamic_config().number_of_subscribers(), eq 1);
+
+        let dead_node = Node::<Sut>::list()
+            .unwrap()
+            .into_iter()
+            .find_map(|state| match state {
+                NodeState::Dead(view) if view.id().pid().value() == child_pid => Some(view),
+                _ => None,
+            });
+        assert_that!(dead_node, is_some);
+
+        assert_that!(dead_node.unwrap().remove_stale_resources(), eq Ok(true));
+
+        assert_that!(pubsub.dynamic_config().number_of_publishers(), eq 0);
+        assert_that!(pubsub.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(Sut::does_exist(&service_name), eq Ok(true));
+
+        let is_listed = Node::<Sut>::list()
+            .unwrap()
+            .iter()
+            .any(|state| match state {
+                NodeState::Alive(view) => view.id().pid().value() == child_pid,
+                NodeState::Dead(view) => view.id().pid().value() == child_pid,
+            });
+        assert_that!(is_listed, eq false);
+
+        // the event service was only used by the dead node
+        assert_that!(
+            node.service_builder(&service_name).event().open().err(),
+            eq Some(iceoryx2::service::builder::event::EventOpenError::DoesNotExist)
+        );
+
+        drop(pubsub);
+        assert_that!(Sut::does_exist(&service_name), eq Ok(false));
+    }
+}
@@ -14,10 +14,12 @@
 mod publisher {
     use std::time::{Duration, Instant};
 
+    use iceoryx2::node::NodeBuilder;
     use iceoryx2::port::publisher::PublisherLoanError;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2_bb_posix::barrier::*;
+    use iceoryx2_bb_posix::memory_placement::MemoryPlacement;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -35,8 +37,10 @@ mod publisher {
 
     #[test]
     fn publisher_loan_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -52,8 +56,10 @@ mod publisher {
 
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -69,8 +75,10 @@ mod publisher {
 
     #[test]
     fn publisher_can_borrow_multiple_sample_at_once<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -100,8 +108,10 @@ mod publisher {
 
     #[test]
     fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -120,8 +130,10 @@ mod publisher {
 
     #[test]
     fn publisher_sending_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -143,8 +155,10 @@ mod publisher {
 
     #[test]
     fn publisher_dropping_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()?;
@@ -166,9 +180,11 @@ mod publisher {
 
     #[test]
     fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watchdog = Watchdog::new();
         let service_name = generate_name()?;
-        let service = Sut::new(&service_name)
+        let service = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(1)
             .enable_safe_overflow(false)
@@ -185,7 +201,8 @@ mod publisher {
 
         std::thread::scope(|s| {
             s.spawn(|| {
-                let service = Sut::new(&service_name)
+                let service = node
+                    .service_builder(&service_name)
                     .publish_subscribe()
                     .subscriber_max_buffer_size(1)
                     .typed::<u64>()
@@ -219,9 +236,400 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_loan_slice_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u64]>()
+            .create()?;
+
+        let sut = service.publisher().max_slice_len(16).create()?;
+
+        let mut sample = sut.loan_slice(16)?;
+        assert_that!(sample.payload(), len 16);
+        assert_that!(sample.payload().iter().all(|v| *v == 0), eq true);
+        sample.payload_mut()[3] = 42;
+
+        assert_that!(sample.send(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_slice_fails_when_exceeding_max_slice_len<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u8]>()
+            .create()?;
+
+        let sut = service.publisher().max_slice_len(4).create()?;
+
+        let sample = sut.loan_slice_uninit(5);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+
+        let sample = sut.loan_slice(4);
+        assert_that!(sample, is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_slice_len_defaults_to_one<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u8]>()
+            .create()?;
+
+        let sut = service.publisher().create()?;
+        assert_that!(sut.max_slice_len(), eq 1);
+        assert_that!(sut.loan_slice(1), is_ok);
+        assert_that!(sut.loan_slice(2).err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retry_later_delivers_pending_samples_on_next_send<Sut: Service>() -> TestResult<()>
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .typed::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryLater)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.send_copy(1)?, eq 1);
+        assert_that!(sut.send_copy(2)?, eq 1);
+        assert_that!(sut.send_copy(3)?, eq 0);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(subscriber.receive()?, is_none);
+
+        // the pending sample is delivered before the new one
+        assert_that!(sut.send_copy(4)?, eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 4);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retry_later_flush_pending_delivers_pending_samples<Sut: Service>() -> TestResult<()>
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .typed::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryLater)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.flush_pending()?, eq 0);
+        for n in 0..4 {
+            sut.send_copy(n)?;
+        }
+        assert_that!(sut.flush_pending()?, eq 0);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 0);
+        assert_that!(sut.flush_pending()?, eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(sut.flush_pending()?, eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(subscriber.receive()?, is_none);
+        assert_that!(sut.flush_pending()?, eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retry_later_discards_oldest_pending_sample_when_queue_is_full<Sut: Service>(
+    ) -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .typed::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryLater)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        // the discarded samples must be returned to the data segment, otherwise the loan fails
+        const NUMBER_OF_SAMPLES: u64 = 10 * BUFFER_SIZE as u64;
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut.send_copy(n), is_ok);
+        }
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 0);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(sut.flush_pending()?, eq BUFFER_SIZE);
+        assert_that!(*subscriber.receive()?.unwrap(), eq NUMBER_OF_SAMPLES - 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq NUMBER_OF_SAMPLES - 1);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retry_later_releases_pending_samples_of_removed_subscriber<Sut: Service>(
+    ) -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .typed::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryLater)
+            .create()?;
+
+        for _ in 0..4 {
+            let subscriber = service.subscriber().create()?;
+            for n in 0..2 * BUFFER_SIZE as u64 {
+                assert_that!(sut.send_copy(n), is_ok);
+            }
+            drop(subscriber);
+        }
+
+        let subscriber = service.subscriber().create()?;
+        assert_that!(sut.send_copy(1234)?, eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_populated_data_segment_delivers_samples<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher()
+            .memory_placement(MemoryPlacement::new().populate(true))
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.send_copy(8912)?, eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 8912);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2::service::zero_copy_memfd::Service>)]
+    mod zero_copy_memfd {}
+
     #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
     mod process_local {}
 }
+
+mod dead_subscriber {
+    use std::process::Command;
+
+    use iceoryx2::node::NodeState;
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    type Sut = zero_copy::Service;
+
+    const SERVICE_NAME_ENV: &str = "IOX2_PUBLISHER_TESTS_DEAD_SUBSCRIBER_SERVICE_NAME";
+    const HELPER_TEST_NAME: &str = "dead_subscriber::helper_process_creates_subscriber_and_dies";
+    const BUFFER_SIZE: usize = 2;
+
+    #[test]
+    fn helper_process_creates_subscriber_and_dies() {
+        // only active when started as child process by the dead subscriber tests
+        let service_name = match std::env::var(SERVICE_NAME_ENV) {
+            Ok(service_name) => ServiceName::new(&service_name).unwrap(),
+            Err(_) => return,
+        };
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service
+            .subscriber()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        // terminate without cleaning up any resource, like a crashed process
+        std::mem::forget((node, service, subscriber));
+        std::process::exit(0);
+    }
+
+    fn publisher_reclaims_samples_of_dead_subscriber(strategy: UnableToDeliverStrategy) {
+        let service_name = ServiceName::new(&format!(
+            "publisher_tests_dead_subscriber_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher()
+            .unable_to_deliver_strategy(strategy)
+            .create()
+            .unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([HELPER_TEST_NAME, "--exact", "--test-threads=1"])
+            .env(SERVICE_NAME_ENV, service_name.as_str())
+            .spawn()
+            .unwrap();
+        let child_pid = child.id() as i32;
+        assert_that!(child.wait().unwrap().success(), eq true);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+
+        assert_that!(publisher.update_connections(), is_ok);
+        for _ in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(123), eq Ok(1));
+        }
+
+        // the buffer of the dead subscriber is full, its samples are reclaimed
+        assert_that!(publisher.send_copy(456), eq Ok(0));
+
+        // the slot of the dead subscriber is freed with the stale resources of its node
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+        for node_state in Node::<Sut>::list().unwrap() {
+            if let NodeState::Dead(dead_node) = node_state {
+                if dead_node.id().pid().value() == child_pid {
+                    assert_that!(dead_node.remove_stale_resources(), is_ok);
+                }
+            }
+        }
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        // a restarted subscriber takes over the freed slot
+        let subscriber = service.subscriber().create().unwrap();
+        assert_that!(publisher.send_copy(789), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 789);
+    }
+
+    #[test]
+    fn blocking_publisher_reclaims_samples_of_dead_subscriber() {
+        publisher_reclaims_samples_of_dead_subscriber(UnableToDeliverStrategy::Block);
+    }
+
+    #[test]
+    fn discarding_publisher_reclaims_samples_of_dead_subscriber() {
+        publisher_reclaims_samples_of_dead_subscriber(UnableToDeliverStrategy::DiscardSample);
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod huge_pages {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::zero_copy_memfd;
+    use iceoryx2_bb_posix::memory_placement::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::system::free_huge_pages;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+
+    #[test]
+    fn publisher_with_huge_pages_delivers_samples() {
+        test_requires!(free_huge_pages(HugePageSize::Size2MB.value()) > 0);
+
+        let service_name = ServiceName::new(&format!(
+            "publisher_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let node = NodeBuilder::new()
+            .create::<zero_copy_memfd::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher()
+            .memory_placement(
+                MemoryPlacement::new()
+                    .huge_pages(HugePageSize::Size2MB)
+                    .populate(true),
+            )
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        assert_that!(sut.send_copy(1971).unwrap(), eq 1);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1971);
+    }
+}
@@ -40,8 +40,10 @@ mod sample_mut {
 
     impl<Sut: Service> TestContext<Sut> {
         fn new() -> Self {
+            let node = NodeBuilder::new().create::<Sut>().unwrap();
             let service_name = generate_name();
-            let service = Sut::new(&service_name)
+            let service = node
+                .service_builder(&service_name)
                 .publish_subscribe()
                 .max_publishers(1)
                 .typed::<u64>()
@@ -139,13 +141,15 @@ mod sample_mut {
 
     #[test]
     fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let test_context = TestContext::<Sut>::new();
         let service_name = test_context.service_name.clone();
         let _sample = test_context.publisher.loan_uninit().unwrap();
 
         drop(test_context);
 
-        let result = Sut::new(&service_name)
+        let result = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
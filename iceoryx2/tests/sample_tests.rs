@@ -17,6 +17,7 @@ mod sample {
     use iceoryx2::prelude::*;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::clock::{ClockType, Time};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
@@ -38,8 +39,10 @@ mod sample {
 
     impl<Sut: Service> TestContext<Sut> {
         fn new() -> Self {
+            let node = NodeBuilder::new().create::<Sut>().unwrap();
             let service_name = generate_name();
-            let service = Sut::new(&service_name)
+            let service = node
+                .service_builder(&service_name)
                 .publish_subscribe()
                 .max_publishers(2)
                 .max_subscribers(1)
@@ -78,6 +81,7 @@ mod sample {
 
     #[test]
     fn sample_of_dropped_service_does_not_block_new_service_creation<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let test_context = TestContext::<Sut>::new();
 
         let service_name = test_context.service_name;
@@ -89,7 +93,7 @@ mod sample {
         drop(test_context);
 
         assert_that!(
-            Sut::new(&service_name)
+            node.service_builder(&service_name)
                 .publish_subscribe()
                 .typed::<u64>()
                 .create(),
@@ -163,6 +167,79 @@ mod sample {
         assert_that!(*sample_2, eq PAYLOAD_2);
     }
 
+    #[test]
+    fn sequence_number_is_counted_per_publisher<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        for n in 0..3 {
+            assert_that!(test_context.publisher_1.send_copy(123), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq n);
+        }
+
+        assert_that!(test_context.publisher_2.send_copy(456), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.origin(), eq test_context.publisher_2.id());
+        assert_that!(sample.header().sequence_number(), eq 0);
+    }
+
+    #[test]
+    fn sequence_number_reveals_samples_lost_by_safe_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        for n in NUMBER_OF_SAMPLES - BUFFER_SIZE as u64..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(sample.header().sequence_number(), eq n);
+        }
+    }
+
+    #[test]
+    fn timestamp_is_acquired_on_send_with_configured_clock<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher()
+            .clock_type(ClockType::Realtime)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let sample = publisher.loan().unwrap();
+        let before_send = Time::now_with_clock(ClockType::Realtime).unwrap();
+        assert_that!(sample.send(), eq Ok(1));
+        let after_send = Time::now_with_clock(ClockType::Realtime).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let timestamp = sample.header().timestamp();
+        assert_that!(timestamp.clock_type(), eq ClockType::Realtime);
+        assert_that!(timestamp.as_duration(), ge before_send.as_duration());
+        assert_that!(timestamp.as_duration(), le after_send.as_duration());
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
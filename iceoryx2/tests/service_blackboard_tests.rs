@@ -0,0 +1,441 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Barrier;
+
+    use iceoryx2::port::reader::{EntryHandleError, ReaderCreateError};
+    use iceoryx2::port::writer::{EntryHandleMutError, WriterCreateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_blackboard_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+    #[repr(C)]
+    struct Position {
+        x: u64,
+        y: u64,
+        z: u64,
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+        assert_that!(sut.dynamic_config().number_of_entries(), eq 1);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .create();
+
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::NoEntriesProvided));
+    }
+
+    #[test]
+    fn creating_service_with_duplicate_keys_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(3, 0)
+            .add::<u32>(3, 0)
+            .create();
+
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::DuplicateKeys));
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut2.err(), eq Some(BlackboardCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn recreate_after_drop_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        drop(sut);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open();
+
+        assert_that!(sut.err(), eq Some(BlackboardOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn open_succeeds_when_service_does_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .add::<u8>(1, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().dynamic_config().number_of_entries(), eq 2);
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_key_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<i32>()
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::IncompatibleKeyType));
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_opener_reader_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(2)
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_does_not_find_service_with_different_messaging_pattern<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn only_one_writer_can_be_created<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create();
+        assert_that!(writer, is_ok);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        let writer2 = sut.writer().create();
+        assert_that!(writer2.err(), eq Some(WriterCreateError::ExceedsMaxSupportedWriters));
+
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+        assert_that!(sut.writer().create(), is_ok);
+    }
+
+    #[test]
+    fn number_of_readers_is_limited<Sut: Service>() {
+        const MAX_READERS: usize = 4;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(MAX_READERS)
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for i in 0..MAX_READERS {
+            readers.push(sut.reader().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_readers(), eq i + 1);
+        }
+
+        let reader = sut.reader().create();
+        assert_that!(reader.err(), eq Some(ReaderCreateError::ExceedsMaxSupportedReaders));
+
+        readers.pop();
+        assert_that!(sut.reader().create(), is_ok);
+    }
+
+    #[test]
+    fn reader_reads_initial_value<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 12)
+            .add::<Position>(1, Position { x: 1, y: 2, z: 3 })
+            .create()
+            .unwrap();
+
+        let reader = sut.reader().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 12);
+        assert_that!(reader.entry::<Position>(&1).unwrap().get(), eq Position { x: 1, y: 2, z: 3 });
+    }
+
+    #[test]
+    fn reader_reads_latest_update<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 12)
+            .add::<Position>(1, Position { x: 1, y: 2, z: 3 })
+            .create()
+            .unwrap();
+
+        let opened = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = opened.reader().create().unwrap();
+
+        let entry_mut = writer.entry::<Position>(&1).unwrap();
+        let entry = reader.entry::<Position>(&1).unwrap();
+        for i in 0..10 {
+            entry_mut.update_with_copy(Position {
+                x: i,
+                y: 2 * i,
+                z: 3 * i,
+            });
+            assert_that!(entry.get(), eq Position { x: i, y: 2 * i, z: 3 * i });
+        }
+
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 12);
+    }
+
+    #[test]
+    fn entry_handle_of_non_existing_key_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 12)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = sut.reader().create().unwrap();
+
+        assert_that!(writer.entry::<u32>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u32>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn entry_handle_with_wrong_value_type_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 12)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = sut.reader().create().unwrap();
+
+        assert_that!(writer.entry::<i32>(&0).err(), eq Some(EntryHandleMutError::WrongValueType));
+        assert_that!(reader.entry::<u64>(&0).err(), eq Some(EntryHandleError::WrongValueType));
+    }
+
+    #[test]
+    fn only_one_mutable_entry_handle_per_entry_exists<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 12)
+            .add::<u32>(1, 13)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+
+        let entry = writer.entry::<u32>(&0);
+        assert_that!(entry, is_ok);
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+        assert_that!(writer.entry::<u32>(&1), is_ok);
+
+        drop(entry);
+        assert_that!(writer.entry::<u32>(&0), is_ok);
+    }
+
+    #[test]
+    fn concurrent_readers_see_consistent_values<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        const NUMBER_OF_READERS: usize = 4;
+        const NUMBER_OF_UPDATES: u64 = 10000;
+
+        let service_name = generate_name();
+        let barrier = Barrier::new(NUMBER_OF_READERS + 1);
+        let keep_running = AtomicBool::new(true);
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(NUMBER_OF_READERS)
+            .add::<Position>(0, Position { x: 0, y: 0, z: 0 })
+            .create()
+            .unwrap();
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_READERS {
+                s.spawn(|| {
+                    let node = NodeBuilder::new().create::<Sut>().unwrap();
+                    let service = node
+                        .service_builder(&service_name)
+                        .blackboard::<u64>()
+                        .open()
+                        .unwrap();
+                    let reader = service.reader().create().unwrap();
+                    let entry = reader.entry::<Position>(&0).unwrap();
+                    barrier.wait();
+
+                    let mut last_value = 0;
+                    while keep_running.load(Ordering::Relaxed) {
+                        let value = entry.get();
+                        assert_that!(value.y, eq 2 * value.x);
+                        assert_that!(value.z, eq 3 * value.x);
+                        assert_that!(value.x, ge last_value);
+                        last_value = value.x;
+                    }
+                });
+            }
+
+            let writer = sut.writer().create().unwrap();
+            let entry = writer.entry::<Position>(&0).unwrap();
+            barrier.wait();
+
+            for i in 1..=NUMBER_OF_UPDATES {
+                entry.update_with_copy(Position {
+                    x: i,
+                    y: 2 * i,
+                    z: 3 * i,
+                });
+            }
+            keep_running.store(false, Ordering::Relaxed);
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
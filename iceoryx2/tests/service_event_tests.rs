@@ -18,13 +18,15 @@ mod service_event {
     use std::time::{Duration, Instant};
 
     use iceoryx2::config::Config;
+    use iceoryx2::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
     use iceoryx2::port::listener::Listener;
     use iceoryx2::port::notifier::NotifierNotifyError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+    use iceoryx2_cal::event::{Event, ListenerWaitError};
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -38,8 +40,9 @@ mod service_event {
 
     #[test]
     fn creating_non_existing_service_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
 
         assert_that!(sut, is_ok);
         let sut = sut.unwrap();
@@ -48,11 +51,12 @@ mod service_event {
 
     #[test]
     fn creating_same_service_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name).event().create();
+        let sut2 = node.service_builder(&service_name).event().create();
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
@@ -62,41 +66,53 @@ mod service_event {
 
     #[test]
     fn recreate_after_drop_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
         assert_that!(sut, is_ok);
 
         drop(sut);
 
-        let sut2 = Sut::new(&service_name).event().create();
+        let sut2 = node.service_builder(&service_name).event().create();
         assert_that!(sut2, is_ok);
     }
 
     #[test]
     fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().open();
+        let sut = node.service_builder(&service_name).event().open();
         assert_that!(sut, is_err);
         assert_that!(sut.err().unwrap(), eq EventOpenError::DoesNotExist);
     }
 
     #[test]
     fn open_succeeds_when_service_does_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name).event().open();
+        let sut2 = node.service_builder(&service_name).event().open();
         assert_that!(sut2, is_ok);
     }
 
     #[test]
     fn open_fails_when_service_does_not_satisfy_opener_notifier_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().max_notifiers(2).create();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(2)
+            .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name).event().max_notifiers(3).open();
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(3)
+            .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
@@ -104,17 +120,30 @@ mod service_event {
             EventOpenError::DoesNotSupportRequestedAmountOfNotifiers
         );
 
-        let sut2 = Sut::new(&service_name).event().max_notifiers(1).open();
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(1)
+            .open();
         assert_that!(sut2, is_ok);
     }
 
     #[test]
     fn open_fails_when_service_does_not_satisfy_opener_listener_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name).event().max_listeners(2).create();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(2)
+            .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name).event().max_listeners(3).open();
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(3)
+            .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
@@ -122,21 +151,28 @@ mod service_event {
             EventOpenError::DoesNotSupportRequestedAmountOfListeners
         );
 
-        let sut2 = Sut::new(&service_name).event().max_listeners(1).open();
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(1)
+            .open();
         assert_that!(sut2, is_ok);
     }
 
     #[test]
     fn open_fails_when_service_does_not_satisfy_event_id_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const EVENT_ID_MAX_VALUE: usize = 78;
 
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .event()
             .event_id_max_value(EVENT_ID_MAX_VALUE)
             .create();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .event()
             .event_id_max_value(EVENT_ID_MAX_VALUE + 1)
             .open();
@@ -144,7 +180,8 @@ mod service_event {
         assert_that!(sut2, is_err);
         assert_that!(sut2.err().unwrap(), eq EventOpenError::DoesNotSupportRequestedMaxEventId);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .event()
             .event_id_max_value(EVENT_ID_MAX_VALUE)
             .open();
@@ -152,10 +189,100 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_counting_requirement<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .enable_counting(true)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .enable_counting(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleCountingBehavior);
+
+        let sut2 = node.service_builder(&service_name).event().open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().has_counting_enabled(), eq true);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_nodes(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_nodes(3)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            EventOpenError::DoesNotSupportRequestedAmountOfNodes
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_nodes(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_number_of_nodes_exceeds_max_nodes<Sut: Service>() {
+        let service_name = generate_name();
+        let node_1 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_3 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node_1
+            .service_builder(&service_name)
+            .event()
+            .max_nodes(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node_2.service_builder(&service_name).event().open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node_3.service_builder(&service_name).event().open();
+        assert_that!(sut3, is_err);
+        assert_that!(
+            sut3.err().unwrap(), eq
+            EventOpenError::ExceedsMaxNumberOfNodes
+        );
+
+        drop(sut2);
+
+        let sut3 = node_3.service_builder(&service_name).event().open();
+        assert_that!(sut3, is_ok);
+    }
+
     #[test]
     fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_notifiers(4)
             .max_listeners(5)
@@ -164,7 +291,7 @@ mod service_event {
         assert_that!(sut.static_config().max_supported_notifiers(), eq 4);
         assert_that!(sut.static_config().max_supported_listeners(), eq 5);
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
         assert_that!(sut2.static_config().max_supported_notifiers(), eq 4);
         assert_that!(sut2.static_config().max_supported_listeners(), eq 5);
     }
@@ -176,29 +303,36 @@ mod service_event {
         custom_config.defaults.event.max_notifiers = 9;
         custom_config.defaults.event.max_listeners = 10;
 
-        let sut = Sut::new(&service_name)
-            .event_with_custom_config(&custom_config)
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
             .create()
             .unwrap();
         assert_that!(sut.static_config().max_supported_notifiers(), eq 9);
         assert_that!(sut.static_config().max_supported_listeners(), eq 10);
 
-        let sut2 = Sut::new(&service_name)
-            .event_with_custom_config(&custom_config)
-            .open()
-            .unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
         assert_that!(sut2.static_config().max_supported_notifiers(), eq 9);
         assert_that!(sut2.static_config().max_supported_listeners(), eq 10);
     }
 
     #[test]
     fn simple_communication_works_listener_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         let event_id = EventId::new(32);
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
 
         let listener = sut.listener().create().unwrap();
         let notifier = sut2.notifier().default_event_id(event_id).create().unwrap();
@@ -215,12 +349,17 @@ mod service_event {
 
     #[test]
     fn simple_communication_works_notifier_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         let event_id = EventId::new(23);
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
 
         let notifier = sut2.notifier().default_event_id(event_id).create().unwrap();
         let listener = sut.listener().create().unwrap();
@@ -237,12 +376,14 @@ mod service_event {
 
     #[test]
     fn communication_with_max_notifiers_and_listeners_single_notification<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const MAX_LISTENERS: usize = 4;
         const MAX_NOTIFIERS: usize = 6;
         const NUMBER_OF_ITERATIONS: u64 = 128;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_notifiers(MAX_NOTIFIERS)
             .max_listeners(MAX_LISTENERS)
@@ -283,12 +424,14 @@ mod service_event {
 
     #[test]
     fn communication_with_max_notifiers_and_listeners_multi_notification<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const MAX_LISTENERS: usize = 5;
         const MAX_NOTIFIERS: usize = 7;
         const NUMBER_OF_ITERATIONS: u64 = 128;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_notifiers(MAX_NOTIFIERS)
             .max_listeners(MAX_LISTENERS)
@@ -332,16 +475,18 @@ mod service_event {
 
     #[test]
     fn number_of_notifiers_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const MAX_NOTIFIERS: usize = 8;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_notifiers(MAX_NOTIFIERS)
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
 
         let mut notifiers = vec![];
 
@@ -368,16 +513,18 @@ mod service_event {
 
     #[test]
     fn number_of_listeners_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const MAX_LISTENERS: usize = 8;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_listeners(MAX_LISTENERS)
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
 
         let mut listeners = vec![];
 
@@ -404,16 +551,18 @@ mod service_event {
 
     #[test]
     fn max_event_id_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const EVENT_ID_MAX_VALUE: usize = 78;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .event_id_max_value(EVENT_ID_MAX_VALUE)
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name).event().open().unwrap();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
 
         let listener = sut.listener().create().unwrap();
         let notifier = sut2.notifier().create().unwrap();
@@ -432,6 +581,7 @@ mod service_event {
 
     #[test]
     fn concurrent_reconnecting_notifier_can_trigger_waiting_listener<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
 
         let number_of_listener_threads = 2;
@@ -443,7 +593,8 @@ mod service_event {
         let service_name = generate_name();
         let barrier = Barrier::new(number_of_notifier_threads + number_of_listener_threads);
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_listeners(number_of_listener_threads)
             .max_notifiers(number_of_notifier_threads)
@@ -489,6 +640,7 @@ mod service_event {
 
     #[test]
     fn concurrent_reconnecting_listener_can_wait_for_triggering_notifiers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
 
         let number_of_listener_threads = 2;
@@ -500,7 +652,8 @@ mod service_event {
         let service_name = generate_name();
         let barrier = Barrier::new(number_of_listener_threads + number_of_notifier_threads);
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .max_listeners(number_of_listener_threads * 2)
             .max_notifiers(number_of_notifier_threads)
@@ -547,10 +700,15 @@ mod service_event {
 
     #[test]
     fn communication_persists_when_service_is_dropped<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         let event_id = EventId::new(12);
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
         let notifier = sut.notifier().default_event_id(event_id).create().unwrap();
         let listener = sut.listener().create().unwrap();
@@ -571,10 +729,15 @@ mod service_event {
 
     #[test]
     fn persisting_connection_does_prevent_service_recreation<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         let event_id = EventId::new(43212);
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
         let notifier = sut.notifier().default_event_id(event_id).create().unwrap();
         let listener = sut.listener().create().unwrap();
@@ -583,27 +746,32 @@ mod service_event {
         drop(sut);
         assert_that!(Sut::does_exist(&service_name), eq Ok(false));
 
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
         assert_that!(sut, is_err);
         assert_that!(sut.err().unwrap(), eq EventCreateError::OldConnectionsStillActive);
 
         drop(listener);
 
-        let sut = Sut::new(&service_name).event().create();
+        let sut = node.service_builder(&service_name).event().create();
         assert_that!(sut, is_err);
         assert_that!(sut.err().unwrap(), eq EventCreateError::OldConnectionsStillActive);
 
         drop(notifier);
 
-        assert_that!(Sut::new(&service_name).event().create(), is_ok);
+        assert_that!(node.service_builder(&service_name).event().create(), is_ok);
     }
 
     #[test]
     fn try_wait_does_not_block<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
         let listener = sut.listener().create().unwrap();
 
         assert_that!(listener.try_wait_one(), is_ok);
@@ -611,10 +779,15 @@ mod service_event {
 
     #[test]
     fn timed_wait_blocks_for_at_least_timeout<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
         let listener = sut.listener().create().unwrap();
 
@@ -626,10 +799,15 @@ mod service_event {
     fn wait_blocks_until_notification<Sut: Service, F: FnMut(&Listener<Sut>) + Send>(
         mut wait_call: F,
     ) {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
         let notifier = sut.notifier().create().unwrap();
         let counter = AtomicU64::new(0);
         let barrier = Barrier::new(2);
@@ -702,10 +880,15 @@ mod service_event {
 
     #[test]
     fn try_wait_all_does_not_block<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
         let listener = sut.listener().create().unwrap();
 
@@ -716,10 +899,15 @@ mod service_event {
 
     #[test]
     fn timed_wait_all_blocks_for_at_least_timeout<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name).event().create().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
 
         let listener = sut.listener().create().unwrap();
 
@@ -773,10 +961,12 @@ mod service_event {
         number_of_notifications: usize,
         mut wait_call: F,
     ) {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .event()
             .event_id_max_value(number_of_notifications)
             .create()
@@ -819,9 +1009,341 @@ mod service_event {
         });
     }
 
+    #[test]
+    fn notify_with_payload_delivers_payload_to_all_listeners<Sut: Service>() {
+        test_requires!(<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(2)
+            .create()
+            .unwrap();
+
+        let listener_1 = sut.listener().create().unwrap();
+        let listener_2 = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        assert_that!(notifier.notify_with_payload(EventId::new(4), &0xdeadbeefu64), eq Ok(2));
+        assert_that!(notifier.notify_with_payload(EventId::new(4), &[7u8; MAX_EVENT_PAYLOAD_SIZE]), eq Ok(2));
+
+        for listener in [&listener_1, &listener_2] {
+            let mut payloads = vec![];
+            listener
+                .try_wait_all_with_payload(|event_id, payload| payloads.push((event_id, *payload)))
+                .unwrap();
+
+            assert_that!(payloads, len 2);
+            assert_that!(payloads[0].0, eq EventId::new(4));
+            assert_that!(unsafe { payloads[0].1.value::<u64>() }, eq Some(0xdeadbeef));
+            assert_that!(payloads[1].0, eq EventId::new(4));
+            assert_that!(payloads[1].1.as_bytes(), eq [7u8; MAX_EVENT_PAYLOAD_SIZE]);
+        }
+    }
+
+    #[test]
+    fn notifications_with_same_event_id_and_payload_are_not_coalesced<Sut: Service>() {
+        // only events that transport payloads keep every notification, the others coalesce
+        // the notifications of an EventId
+        test_requires!(<Sut::Event as Event>::has_payload_support());
+
+        const NUMBER_OF_NOTIFICATIONS: u64 = 8;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for counter in 0..NUMBER_OF_NOTIFICATIONS {
+            assert_that!(notifier.notify_with_payload(EventId::new(1), &counter), eq Ok(1));
+        }
+
+        let mut counters = vec![];
+        listener
+            .timed_wait_all_with_payload(
+                |_, payload| counters.push(unsafe { payload.value::<u64>() }.unwrap()),
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_that!(counters, eq(0..NUMBER_OF_NOTIFICATIONS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn notification_without_payload_has_empty_payload<Sut: Service>() {
+        test_requires!(<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(5))
+            .unwrap();
+
+        let mut payloads = vec![];
+        listener
+            .blocking_wait_all_with_payload(|event_id, payload| payloads.push((event_id, *payload)))
+            .unwrap();
+
+        assert_that!(payloads, len 1);
+        assert_that!(payloads[0].0, eq EventId::new(5));
+        assert_that!(payloads[0].1.is_empty(), eq true);
+    }
+
+    #[test]
+    fn notify_with_payload_fails_when_payload_is_too_large<Sut: Service>() {
+        test_requires!(<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        let result =
+            notifier.notify_with_payload(EventId::new(1), &[0u8; MAX_EVENT_PAYLOAD_SIZE + 1]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::PayloadTooLarge));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notify_with_payload_fails_when_event_id_is_out_of_bounds<Sut: Service>() {
+        test_requires!(<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier().create().unwrap();
+
+        let result = notifier.notify_with_payload(EventId::new(9), &1u64);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::EventIdOutOfBounds));
+    }
+
+    #[test]
+    fn notify_with_payload_fails_when_payload_is_not_supported<Sut: Service>() {
+        test_requires!(!<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        let result = notifier.notify_with_payload(EventId::new(1), &1u64);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::PayloadNotSupported));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn wait_all_with_payload_fails_when_payload_is_not_supported<Sut: Service>() {
+        test_requires!(!<Sut::Event as Event>::has_payload_support());
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)), eq Ok(1));
+
+        let result = listener.try_wait_all_with_payload(|_, _| {});
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+        let result = listener.timed_wait_all_with_payload(|_, _| {}, TIMEOUT);
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+        let result = listener.blocking_wait_all_with_payload(|_, _| {});
+        assert_that!(result.err(), eq Some(ListenerWaitError::PayloadNotSupported));
+
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(1)));
+    }
+
+    #[test]
+    fn wait_all_with_count_reports_number_of_notifications_per_event_id<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        for enable_counting in [false, true] {
+            // without counting only the received notifications are reported, an event without
+            // payload support coalesces them per EventId and reports every EventId once
+            if !enable_counting && !<Sut::Event as Event>::has_payload_support() {
+                continue;
+            }
+
+            let sut = node
+                .service_builder(&service_name)
+                .event()
+                .enable_counting(enable_counting)
+                .create()
+                .unwrap();
+
+            let listener = sut.listener().create().unwrap();
+            let notifier = sut.notifier().create().unwrap();
+
+            for event_id in [3, 1, 3, 3, 1, 7] {
+                assert_that!(notifier.notify_with_custom_event_id(EventId::new(event_id)), eq Ok(1));
+            }
+
+            let mut counts = vec![];
+            listener
+                .timed_wait_all_with_count(
+                    |event_id, count| counts.push((event_id, count)),
+                    TIMEOUT,
+                )
+                .unwrap();
+
+            assert_that!(counts, eq vec![(EventId::new(1), 2), (EventId::new(3), 3), (EventId::new(7), 1)]);
+
+            let mut counts = vec![];
+            listener
+                .try_wait_all_with_count(|event_id, count| counts.push((event_id, count)))
+                .unwrap();
+            assert_that!(counts, is_empty);
+        }
+    }
+
+    #[test]
+    fn counting_includes_notifications_that_exceed_listener_buffer<Sut: Service>() {
+        const NUMBER_OF_NOTIFICATIONS: u64 = 100;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .enable_counting(true)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for _ in 0..NUMBER_OF_NOTIFICATIONS {
+            notifier
+                .notify_with_custom_event_id(EventId::new(4))
+                .unwrap();
+        }
+
+        let mut counts = vec![];
+        listener
+            .blocking_wait_all_with_count(|event_id, count| counts.push((event_id, count)))
+            .unwrap();
+
+        assert_that!(counts, eq vec![(EventId::new(4), NUMBER_OF_NOTIFICATIONS)]);
+    }
+
+    #[test]
+    fn counting_is_independent_for_every_listener<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .enable_counting(true)
+            .max_listeners(2)
+            .create()
+            .unwrap();
+
+        let listener_1 = sut.listener().create().unwrap();
+        let listener_2 = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)), eq Ok(2));
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)), eq Ok(2));
+
+        let mut counts = vec![];
+        listener_1
+            .timed_wait_all_with_count(|event_id, count| counts.push((event_id, count)), TIMEOUT)
+            .unwrap();
+        assert_that!(counts, eq vec![(EventId::new(2), 2)]);
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)), eq Ok(2));
+
+        let mut counts = vec![];
+        listener_2
+            .timed_wait_all_with_count(|event_id, count| counts.push((event_id, count)), TIMEOUT)
+            .unwrap();
+        assert_that!(counts, eq vec![(EventId::new(2), 3)]);
+    }
+
+    #[test]
+    fn counters_are_reset_when_listener_is_dropped<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .enable_counting(true)
+            .max_listeners(1)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier().create().unwrap();
+        let listener = sut.listener().create().unwrap();
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), eq Ok(1));
+        drop(listener);
+
+        let listener = sut.listener().create().unwrap();
+        let mut counts = vec![];
+        listener
+            .try_wait_all_with_count(|event_id, count| counts.push((event_id, count)))
+            .unwrap();
+        assert_that!(counts, is_empty);
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
+    #[instantiate_tests(<iceoryx2::service::zero_copy_socket::Service>)]
+    mod zero_copy_socket {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2::service::zero_copy_futex::Service>)]
+    mod zero_copy_futex {}
+
     #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
     mod process_local {}
 }
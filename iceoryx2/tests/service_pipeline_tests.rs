@@ -0,0 +1,589 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_pipeline {
+    use iceoryx2::config::Config;
+    use iceoryx2::port::consumer::ConsumerCreateError;
+    use iceoryx2::port::producer::{ProducerCreateError, ProducerLoanError, ProducerSendError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::pipeline::{PipelineCreateError, PipelineOpenError};
+    use iceoryx2::service::static_config::pipeline::DistributionStrategy;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u32>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(2)
+            .max_consumers(3)
+            .consumer_max_buffer_size(4)
+            .distribution_strategy(DistributionStrategy::RoundRobin)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(3)
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfProducers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(4)
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfConsumers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .consumer_max_buffer_size(5)
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedMinBufferSize);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .distribution_strategy(DistributionStrategy::LeastLoaded)
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleDistributionStrategy);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(1)
+            .max_consumers(2)
+            .consumer_max_buffer_size(3)
+            .distribution_strategy(DistributionStrategy::RoundRobin)
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_messaging_pattern_is_different<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_create_creates_and_opens_service<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn settings_are_set_to_config_defaults<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let defaults = &Config::get_global_config().defaults;
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_supported_producers(), eq defaults.pipeline.max_producers);
+        assert_that!(static_config.max_supported_consumers(), eq defaults.pipeline.max_consumers);
+        assert_that!(static_config.max_supported_nodes(), eq defaults.pipeline.max_nodes);
+        assert_that!(static_config.consumer_max_buffer_size(), eq defaults.pipeline.consumer_max_buffer_size);
+        assert_that!(static_config.consumer_max_borrowed_items(), eq defaults.pipeline.consumer_max_borrowed_items);
+        assert_that!(static_config.distribution_strategy(), eq defaults.pipeline.distribution_strategy);
+    }
+
+    #[test]
+    fn settings_set_to_zero_are_adjusted_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(0)
+            .max_consumers(0)
+            .consumer_max_buffer_size(0)
+            .consumer_max_borrowed_items(0)
+            .max_nodes(0)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_supported_producers(), eq 1);
+        assert_that!(static_config.max_supported_consumers(), eq 1);
+        assert_that!(static_config.consumer_max_buffer_size(), eq 1);
+        assert_that!(static_config.consumer_max_borrowed_items(), eq 1);
+        assert_that!(static_config.max_supported_nodes(), eq 1);
+    }
+
+    #[test]
+    fn number_of_producers_and_consumers_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_PRODUCERS: usize = 4;
+        const MAX_CONSUMERS: usize = 3;
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(MAX_PRODUCERS)
+            .max_consumers(MAX_CONSUMERS)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let mut producers = vec![];
+        for i in 0..MAX_PRODUCERS {
+            producers.push(sut.producer().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_producers(), eq i + 1);
+        }
+        let producer = sut.producer().create();
+        assert_that!(producer.err().unwrap(), eq ProducerCreateError::ExceedsMaxSupportedProducers);
+
+        let mut consumers = vec![];
+        for i in 0..MAX_CONSUMERS {
+            consumers.push(sut.consumer().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_consumers(), eq i + 1);
+        }
+        let consumer = sut.consumer().create();
+        assert_that!(consumer.err().unwrap(), eq ConsumerCreateError::ExceedsMaxSupportedConsumers);
+
+        producers.clear();
+        consumers.clear();
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 0);
+    }
+
+    #[test]
+    fn simple_communication_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumer = sut.consumer().create().unwrap();
+
+        assert_that!(producer.send_copy(8127), eq Ok(Some(consumer.id())));
+
+        let work_item = consumer.receive().unwrap().unwrap();
+        assert_that!(*work_item, eq 8127);
+        assert_that!(work_item.origin(), eq producer.id());
+        assert_that!(work_item.header().producer_id(), eq producer.id());
+        assert_that!(consumer.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sending_without_consumers_delivers_to_nobody<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+
+        assert_that!(producer.send_copy(1), eq Ok(None));
+    }
+
+    #[test]
+    fn every_work_item_is_received_by_exactly_one_consumer<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        const NUMBER_OF_ITEMS: u64 = 9;
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .consumer_max_buffer_size(NUMBER_OF_ITEMS as usize)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumers: Vec<_> = (0..NUMBER_OF_CONSUMERS)
+            .map(|_| sut.consumer().create().unwrap())
+            .collect();
+
+        for n in 0..NUMBER_OF_ITEMS {
+            assert_that!(producer.send_copy(n).unwrap(), is_some);
+        }
+
+        let mut received = vec![];
+        for consumer in &consumers {
+            while let Some(work_item) = consumer.receive().unwrap() {
+                received.push(*work_item);
+            }
+        }
+
+        received.sort();
+        assert_that!(received, eq(0..NUMBER_OF_ITEMS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn round_robin_distributes_work_items_evenly<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .consumer_max_buffer_size(4)
+            .distribution_strategy(DistributionStrategy::RoundRobin)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumers: Vec<_> = (0..NUMBER_OF_CONSUMERS)
+            .map(|_| sut.consumer().create().unwrap())
+            .collect();
+
+        let mut recipients = vec![];
+        for n in 0..2 * NUMBER_OF_CONSUMERS {
+            recipients.push(producer.send_copy(n as u64).unwrap().unwrap());
+        }
+
+        for consumer in &consumers {
+            assert_that!(recipients.iter().filter(|id| **id == consumer.id()).count(), eq 2);
+        }
+
+        for n in 0..NUMBER_OF_CONSUMERS {
+            assert_that!(recipients[n], eq recipients[n + NUMBER_OF_CONSUMERS]);
+        }
+    }
+
+    #[test]
+    fn round_robin_skips_consumers_with_full_buffer<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(2)
+            .consumer_max_buffer_size(1)
+            .distribution_strategy(DistributionStrategy::RoundRobin)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumer_1 = sut.consumer().create().unwrap();
+        let consumer_2 = sut.consumer().create().unwrap();
+
+        let first_recipient = producer.send_copy(1).unwrap().unwrap();
+        let second_recipient = producer.send_copy(2).unwrap().unwrap();
+        assert_that!(first_recipient, ne second_recipient);
+
+        assert_that!(
+            producer.send_copy(3).err().unwrap(), eq
+            ProducerSendError::AllConsumerBuffersFull
+        );
+
+        let consumer = if consumer_1.id() == second_recipient {
+            &consumer_1
+        } else {
+            &consumer_2
+        };
+        assert_that!(*consumer.receive().unwrap().unwrap(), eq 2);
+
+        assert_that!(producer.send_copy(4), eq Ok(Some(second_recipient)));
+    }
+
+    #[test]
+    fn least_loaded_delivers_to_consumer_with_fewest_pending_items<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(2)
+            .consumer_max_buffer_size(4)
+            .distribution_strategy(DistributionStrategy::LeastLoaded)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let busy_consumer = sut.consumer().create().unwrap();
+        let idle_consumer = sut.consumer().create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(producer.send_copy(n), is_ok);
+        }
+
+        // every consumer has now two pending work items, the idle one processes them
+        while let Some(work_item) = idle_consumer.receive().unwrap() {
+            drop(work_item);
+        }
+
+        assert_that!(producer.send_copy(4), eq Ok(Some(idle_consumer.id())));
+        assert_that!(producer.send_copy(5), eq Ok(Some(idle_consumer.id())));
+
+        // both consumers have the same load again, the items are distributed alternately
+        let recipient_1 = producer.send_copy(6).unwrap().unwrap();
+        let recipient_2 = producer.send_copy(7).unwrap().unwrap();
+        assert_that!(recipient_1, ne recipient_2);
+
+        let mut received = 0;
+        while busy_consumer.receive().unwrap().is_some() {
+            received += 1;
+        }
+        assert_that!(received, eq 3);
+    }
+
+    #[test]
+    fn least_loaded_counts_borrowed_work_items_as_load<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_consumers(2)
+            .consumer_max_buffer_size(4)
+            .consumer_max_borrowed_items(4)
+            .distribution_strategy(DistributionStrategy::LeastLoaded)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumer_1 = sut.consumer().create().unwrap();
+        let consumer_2 = sut.consumer().create().unwrap();
+
+        assert_that!(producer.send_copy(1), is_ok);
+        assert_that!(producer.send_copy(2), is_ok);
+
+        let _borrowed_item = consumer_1.receive().unwrap().unwrap();
+        let item = consumer_2.receive().unwrap().unwrap();
+        drop(item);
+
+        assert_that!(producer.send_copy(3), eq Ok(Some(consumer_2.id())));
+    }
+
+    #[test]
+    fn producer_cannot_loan_more_than_max_loaned_items<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_LOANED_ITEMS: usize = 3;
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer()
+            .max_loaned_items(MAX_LOANED_ITEMS)
+            .create()
+            .unwrap();
+
+        let mut work_items = vec![];
+        for _ in 0..MAX_LOANED_ITEMS {
+            work_items.push(producer.loan().unwrap());
+        }
+
+        let work_item = producer.loan();
+        assert_that!(work_item.err().unwrap(), eq ProducerLoanError::ExceedsMaxLoanedItems);
+
+        work_items.pop();
+        assert_that!(producer.loan(), is_ok);
+    }
+
+    #[test]
+    fn producer_reclaims_all_work_items_after_consumer_disconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const RECONNECTIONS: usize = 20;
+        const MAX_CONSUMERS: usize = 4;
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .max_producers(1)
+            .max_consumers(MAX_CONSUMERS)
+            .consumer_max_buffer_size(3)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+
+        for n in 1..=MAX_CONSUMERS {
+            for _ in 0..RECONNECTIONS {
+                let mut consumers = vec![];
+                for _ in 0..n {
+                    consumers.push(sut.consumer().create().unwrap());
+                }
+
+                for _ in 0..3 * n {
+                    assert_that!(producer.send_copy(1234).unwrap(), is_some);
+                }
+                consumers.clear();
+                assert_that!(producer.send_copy(789), eq Ok(None));
+            }
+        }
+    }
+
+    #[test]
+    fn dropping_service_keeps_established_communication<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let consumer = sut.consumer().create().unwrap();
+        drop(sut);
+
+        assert_that!(producer.send_copy(8192), eq Ok(Some(consumer.id())));
+        assert_that!(*consumer.receive().unwrap().unwrap(), eq 8192);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -12,24 +12,75 @@
 
 #[generic_tests::define]
 mod service_publish_subscribe {
+    use std::collections::HashMap;
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::Barrier;
     use std::thread;
 
     use iceoryx2::config::Config;
-    use iceoryx2::message::Message;
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::port::publisher::{
+        PublisherCreateError, PublisherLoanError, PublisherSendError, PublisherSendSerializedError,
+    };
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::subscriber::MAX_NUMBER_OF_FILTER_KEYS;
+    use iceoryx2::service::static_config::type_details::{TypeDetails, TypeVariant};
     use iceoryx2::service::static_config::StaticConfig;
     use iceoryx2::service::Service;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::serialize::{cdr::Cdr, toml::Toml, SerializeError};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Diagnostics {
+        component: String,
+        error_count: u32,
+        details: Vec<String>,
+    }
+
+    fn diagnostics(error_count: u32) -> Diagnostics {
+        Diagnostics {
+            component: "camera/front".to_string(),
+            error_count,
+            details: (0..error_count).map(|n| format!("timeout {}", n)).collect(),
+        }
+    }
+
+    fn serialized_publisher_communicates_with_serialized_subscriber<
+        Sut: Service,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    >() {
+        const NUMBER_OF_SAMPLES: u32 = 3;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .serialized::<Diagnostics, Serializer>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().max_serialized_size(1024).create().unwrap();
+        assert_that!(publisher.max_serialized_size(), eq 1024);
+        let subscriber = sut.subscriber().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send(&diagnostics(n)), eq Ok(1));
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let value = subscriber.receive().unwrap();
+            assert_that!(value, eq Some(diagnostics(n)));
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -41,8 +92,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn creating_non_existing_service_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
@@ -54,14 +107,17 @@ mod service_publish_subscribe {
 
     #[test]
     fn creating_same_service_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
@@ -74,8 +130,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn recreate_after_drop_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
@@ -83,7 +141,8 @@ mod service_publish_subscribe {
 
         drop(sut);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
@@ -92,8 +151,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -103,14 +164,17 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_succeeds_when_service_does_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -119,14 +183,17 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_fails_when_service_has_wrong_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<i64>()
             .open();
@@ -134,10 +201,47 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_fails_when_slice_service_is_opened_with_element_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u64]>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u32]>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u64]>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(2)
             .max_subscribers(2)
@@ -150,7 +254,8 @@ mod service_publish_subscribe {
         assert_that!(sut, is_ok);
 
         // max_publishers
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(3)
             .typed::<u64>()
@@ -162,7 +267,8 @@ mod service_publish_subscribe {
             PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers
         );
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .typed::<u64>()
@@ -171,7 +277,8 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
 
         // max_subscribers
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_subscribers(3)
             .typed::<u64>()
@@ -183,7 +290,8 @@ mod service_publish_subscribe {
             PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers
         );
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_subscribers(1)
             .typed::<u64>()
@@ -192,7 +300,8 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
 
         // safe overflow
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .enable_safe_overflow(true)
             .typed::<u64>()
@@ -205,7 +314,8 @@ mod service_publish_subscribe {
         );
 
         // history size
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .history_size(3)
             .typed::<u64>()
@@ -217,7 +327,8 @@ mod service_publish_subscribe {
             PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
         );
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .history_size(1)
             .typed::<u64>()
@@ -226,7 +337,8 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
 
         // subscriber max borrow
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_borrowed_samples(3)
             .typed::<u64>()
@@ -238,7 +350,8 @@ mod service_publish_subscribe {
             PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
         );
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_borrowed_samples(1)
             .typed::<u64>()
@@ -247,7 +360,8 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
 
         // buffer size
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(3)
             .typed::<u64>()
@@ -259,7 +373,8 @@ mod service_publish_subscribe {
             PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
         );
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(1)
             .typed::<u64>()
@@ -268,16 +383,97 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_nodes(2)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_nodes(3)
+            .typed::<u64>()
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_nodes(1)
+            .typed::<u64>()
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_number_of_nodes_exceeds_max_nodes<Sut: Service>() {
+        let service_name = generate_name();
+        let node_1 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_3 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node_1
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_nodes(2)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node_2
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node_3
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open();
+        assert_that!(sut3, is_err);
+        assert_that!(
+            sut3.err().unwrap(), eq
+            PublishSubscribeOpenError::ExceedsMaxNumberOfNodes
+        );
+
+        drop(sut2);
+
+        let sut3 = node_3
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
     #[test]
     fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -285,7 +481,8 @@ mod service_publish_subscribe {
 
         drop(sut);
 
-        let sut3 = Sut::new(&service_name)
+        let sut3 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -294,14 +491,17 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_fails_when_all_previous_owners_have_been_dropped<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut, is_ok);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -310,7 +510,8 @@ mod service_publish_subscribe {
         drop(sut);
         drop(sut2);
 
-        let sut3 = Sut::new(&service_name)
+        let sut3 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open();
@@ -320,8 +521,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open_or_create();
@@ -331,14 +534,17 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open_or_create();
@@ -348,8 +554,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn max_publishers_and_subscribers_is_set_to_config_default<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
@@ -369,8 +577,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(4)
             .max_subscribers(5)
@@ -382,7 +592,8 @@ mod service_publish_subscribe {
             .create();
         assert_that!(_sut, is_ok);
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -419,8 +630,13 @@ mod service_publish_subscribe {
             .publish_subscribe
             .subscriber_max_buffer_size = 13;
 
-        let sut = Sut::new(&service_name)
-            .publish_subscribe_with_custom_config(&custom_config)
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
             .typed::<u64>()
             .create()
             .unwrap();
@@ -432,7 +648,8 @@ mod service_publish_subscribe {
         assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 12);
         assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 13);
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -448,17 +665,20 @@ mod service_publish_subscribe {
 
     #[test]
     fn number_of_publishers_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const MAX_PUBLISHERS: usize = 8;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(MAX_PUBLISHERS)
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -489,34 +709,54 @@ mod service_publish_subscribe {
 
     #[test]
     fn type_informations_are_correct<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
             .unwrap();
 
-        type MessageType = Message<iceoryx2::service::header::publish_subscribe::Header, u64>;
-
         assert_that!(sut.static_config().type_name(), eq "u64");
-        assert_that!(sut.static_config().type_size(), eq std::mem::size_of::<MessageType>());
-        assert_that!(sut.static_config().type_alignment(), eq std::mem::align_of::<MessageType>());
+        assert_that!(sut.static_config().type_size(), eq std::mem::size_of::<u64>());
+        assert_that!(sut.static_config().type_alignment(), eq std::mem::align_of::<u64>());
+    }
+
+    #[test]
+    fn type_informations_of_slice_service_are_correct<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u16]>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().type_name(), eq "[u16]");
+        assert_that!(sut.static_config().type_size(), eq std::mem::size_of::<u16>());
+        assert_that!(sut.static_config().type_alignment(), eq std::mem::align_of::<u16>());
     }
 
     #[test]
     fn number_of_subscribers_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const MAX_SUBSCRIBERS: usize = 8;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_subscribers(MAX_SUBSCRIBERS)
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -547,15 +787,18 @@ mod service_publish_subscribe {
 
     #[test]
     fn simple_communication_works_subscriber_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -583,15 +826,18 @@ mod service_publish_subscribe {
 
     #[test]
     fn simple_communication_works_publisher_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -613,13 +859,457 @@ mod service_publish_subscribe {
         assert_that!(*result.unwrap(), eq 4567);
     }
 
+    #[test]
+    fn slice_communication_preserves_slice_length<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_SLICE_LEN: usize = 32;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(MAX_SLICE_LEN + 1)
+            .typed::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.max_slice_len(), eq MAX_SLICE_LEN);
+
+        for len in 0..=MAX_SLICE_LEN {
+            let sample = publisher
+                .loan_slice_uninit(len)
+                .unwrap()
+                .write_from_fn(|i| (len * 1000 + i) as u64);
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.header().number_of_elements(), eq len as u64);
+            assert_that!(sample.send(), eq Ok(1));
+        }
+
+        for len in 0..=MAX_SLICE_LEN {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            let sample = sample.unwrap();
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.header().number_of_elements(), eq len as u64);
+            for (i, element) in sample.iter().enumerate() {
+                assert_that!(*element, eq(len * 1000 + i) as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn slice_communication_with_publishers_of_different_max_slice_len_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .typed::<[u8]>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u8]>()
+            .open()
+            .unwrap();
+
+        let small_publisher = sut.publisher().max_slice_len(3).create().unwrap();
+        let large_publisher = sut2.publisher().max_slice_len(4096).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(small_publisher.send_slice_copy(&[1, 2, 3]), eq Ok(1));
+        let large_payload: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+        assert_that!(large_publisher.send_slice_copy(&large_payload), eq Ok(1));
+
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            received.push(sample.payload().to_vec());
+        }
+
+        assert_that!(received, len 2);
+        assert_that!(received, contains vec![1, 2, 3]);
+        assert_that!(received, contains large_payload);
+    }
+
+    #[test]
+    fn slice_history_is_delivered_on_subscription<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .typed::<[i32]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().max_slice_len(8).create().unwrap();
+        assert_that!(publisher.send_slice_copy(&[1, 2, 3, 4, 5]), is_ok);
+        assert_that!(publisher.send_slice_copy(&[6]), is_ok);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [1, 2, 3, 4, 5]);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq[6]);
+    }
+
+    #[test]
+    fn untyped_service_opens_typed_service_only_with_matching_type_details<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::from_type::<u64>())
+            .open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(*sut2.static_config().type_details(), eq TypeDetails::from_type::<u64>());
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::new(TypeVariant::FixedSize, "u64", 8, 4).unwrap())
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::from_slice::<u64>())
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn typed_service_opens_untyped_service_with_matching_type_details<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::from_slice::<u32>())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u32]>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u32>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn untyped_publisher_communicates_with_typed_subscriber<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(sut.static_config().type_details())
+            .open()
+            .unwrap();
+        let publisher = sut2.publisher().max_slice_len(10).create().unwrap();
+        assert_that!(publisher.max_slice_len(), eq 1);
+
+        let sample = publisher
+            .loan_bytes_uninit(1)
+            .unwrap()
+            .write_from_slice(&0x1234_5678_9abc_def0u64.to_ne_bytes());
+        assert_that!(sample.payload(), len 8);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 0x1234_5678_9abc_def0);
+
+        let sample = publisher.loan_bytes(2);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+    }
+
+    #[test]
+    fn typed_slice_publisher_communicates_with_untyped_subscriber<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(MAX_SLICE_LEN + 1)
+            .typed::<[u32]>()
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::from_slice::<u32>())
+            .open()
+            .unwrap();
+        let subscriber = sut2.subscriber().create().unwrap();
+
+        for len in 0..=MAX_SLICE_LEN {
+            let sample = publisher
+                .loan_slice_uninit(len)
+                .unwrap()
+                .write_from_fn(|i| (len * 100 + i) as u32);
+            assert_that!(sample.send(), eq Ok(1));
+        }
+
+        for len in 0..=MAX_SLICE_LEN {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            let sample = sample.unwrap();
+            assert_that!(sample.header().number_of_elements(), eq len as u64);
+            assert_that!(sample.payload(), len len * 4);
+            for (i, element) in sample.chunks_exact(4).enumerate() {
+                let element = u32::from_ne_bytes(element.try_into().unwrap());
+                assert_that!(element, eq(len * 100 + i) as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn untyped_slice_publisher_communicates_with_typed_subscriber<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 4;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .untyped(&TypeDetails::from_slice::<u16>())
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+        assert_that!(publisher.max_slice_len(), eq MAX_SLICE_LEN);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u16]>()
+            .open()
+            .unwrap();
+        let subscriber = sut2.subscriber().create().unwrap();
+
+        let bytes: Vec<u8> = [1u16, 2, 3].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        let mut sample = publisher.loan_bytes(3).unwrap();
+        assert_that!(sample.payload(), len 6);
+        sample.payload_mut().copy_from_slice(&bytes);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        assert_that!(*sample.payload(), eq [1, 2, 3]);
+    }
+
+    #[test]
+    fn serialized_publisher_communicates_with_serialized_subscriber_with_cdr<Sut: Service>() {
+        serialized_publisher_communicates_with_serialized_subscriber::<Sut, Cdr>();
+    }
+
+    #[test]
+    fn serialized_publisher_communicates_with_serialized_subscriber_with_toml<Sut: Service>() {
+        serialized_publisher_communicates_with_serialized_subscriber::<Sut, Toml>();
+    }
+
+    #[test]
+    fn serialized_map_payload_is_supported_with_toml_but_not_with_cdr<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let mut settings = HashMap::new();
+        settings.insert("log_level".to_string(), "debug".to_string());
+        settings.insert("mode".to_string(), "replay".to_string());
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe()
+            .serialized::<HashMap<String, String>, Toml>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().max_serialized_size(256).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send(&settings), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), eq Some(settings.clone()));
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe()
+            .serialized::<HashMap<String, String>, Cdr>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().max_serialized_size(256).create().unwrap();
+
+        assert_that!(
+            publisher.send(&settings), eq
+            Err(PublisherSendSerializedError::SerializationFailure(
+                SerializeError::UnsupportedType
+            ))
+        );
+    }
+
+    #[test]
+    fn serialized_service_opens_only_with_same_type_and_serializer<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<Diagnostics, Cdr>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<Diagnostics, Cdr>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<Diagnostics, Toml>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<String, Cdr>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<[u8]>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn serialized_publisher_fails_to_send_value_exceeding_max_serialized_size<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<String, Cdr>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().max_serialized_size(16).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let result = publisher.send(&"a".repeat(64));
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq
+            PublisherSendSerializedError::SendError(PublisherSendError::LoanError(
+                PublisherLoanError::ExceedsMaxLoanSize
+            ))
+        );
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(publisher.send(&"fits".to_string()), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), eq Some("fits".to_string()));
+    }
+
+    #[test]
+    fn serialized_publisher_with_default_settings_sends_non_trivial_value<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let value = diagnostics(12);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .serialized::<Diagnostics, Cdr>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(
+            publisher.max_serialized_size(), eq
+            Config::get_global_config()
+                .defaults
+                .publish_subscribe
+                .max_serialized_size
+        );
+        assert_that!(publisher.send(&value), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), eq Some(value));
+    }
+
     #[test]
     fn publisher_reclaims_all_samples_after_disconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const RECONNECTIONS: usize = 20;
         const MAX_SUBSCRIBERS: usize = 10;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .max_subscribers(MAX_SUBSCRIBERS)
@@ -651,11 +1341,13 @@ mod service_publish_subscribe {
 
     #[test]
     fn publisher_updates_connections_after_reconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const RECONNECTIONS: usize = 20;
         const MAX_SUBSCRIBERS: usize = 10;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .max_subscribers(MAX_SUBSCRIBERS)
@@ -685,11 +1377,13 @@ mod service_publish_subscribe {
 
     #[test]
     fn subscriber_updates_connections_after_reconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const RECONNECTIONS: usize = 20;
         const MAX_PUBLISHERS: usize = 10;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(MAX_PUBLISHERS)
             .history_size(0)
@@ -721,6 +1415,7 @@ mod service_publish_subscribe {
 
     #[test]
     fn concurrent_communication_with_subscriber_reconnects_does_not_deadlock<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
 
         const NUMBER_OF_SUBSCRIBER_THREADS: usize = 2;
@@ -732,7 +1427,8 @@ mod service_publish_subscribe {
 
         thread::scope(|s| {
             s.spawn(|| {
-                let sut2 = Sut::new(&service_name)
+                let sut2 = node
+                    .service_builder(&service_name)
                     .publish_subscribe()
                     .typed::<u64>()
                     .create()
@@ -752,7 +1448,8 @@ mod service_publish_subscribe {
             let mut threads = vec![];
             for _ in 0..NUMBER_OF_SUBSCRIBER_THREADS {
                 threads.push(s.spawn(|| {
-                    let sut = Sut::new(&service_name)
+                    let sut = node
+                        .service_builder(&service_name)
                         .publish_subscribe()
                         .typed::<u64>()
                         .open()
@@ -782,6 +1479,7 @@ mod service_publish_subscribe {
 
     #[test]
     fn concurrent_communication_with_publisher_reconnects_does_not_deadlock<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let _watch_dog = Watchdog::new();
 
         const NUMBER_OF_PUBLISHER_THREADS: usize = 2;
@@ -794,7 +1492,8 @@ mod service_publish_subscribe {
 
         thread::scope(|s| {
             s.spawn(|| {
-                let sut = Sut::new(&service_name)
+                let sut = node
+                    .service_builder(&service_name)
                     .publish_subscribe()
                     .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
                     .typed::<u64>()
@@ -817,7 +1516,8 @@ mod service_publish_subscribe {
 
             for _ in 0..NUMBER_OF_PUBLISHER_THREADS {
                 s.spawn(|| {
-                    let sut2 = Sut::new(&service_name)
+                    let sut2 = node
+                        .service_builder(&service_name)
                         .publish_subscribe()
                         .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
                         .typed::<u64>()
@@ -845,12 +1545,14 @@ mod service_publish_subscribe {
 
     #[test]
     fn communication_with_max_subscribers_and_publishers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const MAX_PUB: usize = 4;
         const MAX_SUB: usize = 6;
         const NUMBER_OF_ITERATIONS: u64 = 128;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(MAX_PUB)
             .max_subscribers(MAX_SUB)
@@ -889,12 +1591,14 @@ mod service_publish_subscribe {
 
     #[test]
     fn multi_channel_communication_with_max_subscribers_and_publishers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const MAX_PUB: usize = 5;
         const MAX_SUB: usize = 7;
         const NUMBER_OF_ITERATIONS: u64 = 128;
         let service_name = generate_name();
 
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(MAX_PUB)
             .max_subscribers(MAX_SUB)
@@ -907,7 +1611,7 @@ mod service_publish_subscribe {
 
         for _ in 0..MAX_PUB + MAX_SUB {
             channels.push(
-                Sut::new(&service_name)
+                node.service_builder(&service_name)
                     .publish_subscribe()
                     .typed::<u64>()
                     .open()
@@ -946,10 +1650,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_safely_overflows_when_enabled<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const BUFFER_SIZE: usize = 2;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .enable_safe_overflow(true)
             .subscriber_max_buffer_size(BUFFER_SIZE)
@@ -976,10 +1682,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_does_not_overflow_when_deactivated<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const BUFFER_SIZE: usize = 5;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .enable_safe_overflow(false)
             .subscriber_max_buffer_size(BUFFER_SIZE)
@@ -1010,9 +1718,11 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .enable_safe_overflow(false)
             .history_size(12)
@@ -1029,10 +1739,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const BUFFER_SIZE: usize = 2;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .history_size(3)
             .subscriber_max_buffer_size(BUFFER_SIZE)
@@ -1057,10 +1769,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_history_of_zero_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const BUFFER_SIZE: usize = 2;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .history_size(0)
             .subscriber_max_buffer_size(BUFFER_SIZE)
@@ -1080,10 +1794,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         const BUFFER_SIZE: usize = 5;
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .max_subscribers(2)
@@ -1114,10 +1830,12 @@ mod service_publish_subscribe {
         max_subscribers: usize,
         max_loan: usize,
     ) {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const ITERATIONS: usize = 16;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .max_subscribers(max_subscribers)
@@ -1294,10 +2012,12 @@ mod service_publish_subscribe {
         buffer_size: usize,
         max_borrow: usize,
     ) {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const ITERATIONS: usize = 16;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(1)
             .max_subscribers(1)
@@ -1414,11 +2134,13 @@ mod service_publish_subscribe {
 
     #[test]
     fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const MAX_PUBLISHERS: usize = 4;
         const MAX_SUBSCRIBERS: usize = 8;
 
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(MAX_PUBLISHERS)
             .max_subscribers(MAX_SUBSCRIBERS)
@@ -1471,8 +2193,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_publishers(0)
             .typed::<u64>()
@@ -1484,8 +2208,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .max_subscribers(0)
             .typed::<u64>()
@@ -1497,8 +2223,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_borrowed_samples(0)
             .typed::<u64>()
@@ -1510,8 +2238,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(0)
             .typed::<u64>()
@@ -1523,10 +2253,12 @@ mod service_publish_subscribe {
 
     #[test]
     fn does_exist_works_single<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
         assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
 
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
@@ -1542,6 +2274,7 @@ mod service_publish_subscribe {
 
     #[test]
     fn does_exist_works_many<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const NUMBER_OF_SERVICES: usize = 8;
 
         let mut services = vec![];
@@ -1552,7 +2285,7 @@ mod service_publish_subscribe {
             assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
 
             services.push(
-                Sut::new(&service_name)
+                node.service_builder(&service_name)
                     .publish_subscribe()
                     .typed::<u64>()
                     .create()
@@ -1584,6 +2317,7 @@ mod service_publish_subscribe {
 
     #[test]
     fn list_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const NUMBER_OF_SERVICES: usize = 8;
 
         let mut services = vec![];
@@ -1611,7 +2345,7 @@ mod service_publish_subscribe {
             let service_name = generate_name();
 
             services.push(
-                Sut::new(&service_name)
+                node.service_builder(&service_name)
                     .publish_subscribe()
                     .typed::<u64>()
                     .create()
@@ -1637,8 +2371,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn dropping_service_keeps_established_communication<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
@@ -1658,8 +2394,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn ports_of_dropped_service_block_new_service_creation<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
@@ -1670,7 +2408,7 @@ mod service_publish_subscribe {
 
         drop(sut);
 
-        assert_that!(Sut::new(&service_name)
+        assert_that!(node.service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create().err().unwrap(),
@@ -1678,7 +2416,7 @@ mod service_publish_subscribe {
 
         drop(subscriber);
 
-        assert_that!(Sut::new(&service_name)
+        assert_that!(node.service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create().err().unwrap(),
@@ -1687,7 +2425,7 @@ mod service_publish_subscribe {
         drop(publisher);
 
         assert_that!(
-            Sut::new(&service_name)
+            node.service_builder(&service_name)
                 .publish_subscribe()
                 .typed::<u64>()
                 .create(),
@@ -1697,17 +2435,20 @@ mod service_publish_subscribe {
 
     #[test]
     fn subscriber_can_decrease_buffer_size<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const BUFFER_SIZE: usize = 16;
         let service_name = generate_name();
 
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(BUFFER_SIZE)
             .typed::<usize>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<usize>()
             .open()
@@ -1748,17 +2489,20 @@ mod service_publish_subscribe {
 
     #[test]
     fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         const BUFFER_SIZE: usize = 16;
         let service_name = generate_name();
 
-        let _sut = Sut::new(&service_name)
+        let _sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .subscriber_max_buffer_size(BUFFER_SIZE)
             .typed::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = Sut::new(&service_name)
+        let sut2 = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .open()
@@ -1771,8 +2515,10 @@ mod service_publish_subscribe {
 
     #[test]
     fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let sut = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create()
@@ -1782,6 +2528,207 @@ mod service_publish_subscribe {
         assert_that!(subscriber.buffer_size(), eq 1);
     }
 
+    fn send_with_filter_key<Sut: Service>(
+        publisher: &iceoryx2::port::publisher::Publisher<Sut, u64>,
+        filter_key: u64,
+        value: u64,
+    ) -> usize {
+        let mut sample = publisher.loan().unwrap();
+        sample.set_filter_key(filter_key);
+        *sample.payload_mut() = value;
+        sample.send().unwrap()
+    }
+
+    #[test]
+    fn subscriber_receives_only_samples_with_matching_filter_keys<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .history_size(0)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let filtered_subscriber = sut.subscriber().filter_keys(&[3, 7]).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        for filter_key in 0..10 {
+            let number_of_recipients =
+                send_with_filter_key(&publisher, filter_key, filter_key * 10);
+            let expected = if filter_key == 3 || filter_key == 7 {
+                2
+            } else {
+                1
+            };
+            assert_that!(number_of_recipients, eq expected);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().filter_key(), eq filter_key);
+        }
+
+        let sample = filtered_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().filter_key(), eq 3);
+        assert_that!(*sample, eq 30);
+        drop(sample);
+
+        let sample = filtered_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().filter_key(), eq 7);
+        assert_that!(*sample, eq 70);
+        drop(sample);
+
+        assert_that!(filtered_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_receives_only_samples_within_filter_key_range<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(8)
+            .history_size(0)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().filter_key_range(4..=6).create().unwrap();
+
+        for filter_key in 0..10 {
+            send_with_filter_key(&publisher, filter_key, filter_key);
+        }
+
+        for filter_key in 4..=6 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq filter_key);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn filtered_samples_do_not_evict_matching_samples<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const BUFFER_SIZE: usize = 2;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .history_size(0)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().filter_keys(&[1]).create().unwrap();
+
+        send_with_filter_key(&publisher, 1, 100);
+        send_with_filter_key(&publisher, 1, 101);
+        for n in 0..10 * BUFFER_SIZE as u64 {
+            assert_that!(send_with_filter_key(&publisher, 2, n), eq 0);
+        }
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 100);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 101);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn history_is_filtered_on_subscription<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .history_size(4)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        for filter_key in 0..4 {
+            send_with_filter_key(&publisher, filter_key, filter_key);
+        }
+
+        let subscriber = sut.subscriber().filter_keys(&[1, 3]).create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_without_filter_key_have_filter_key_zero<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().filter_keys(&[0]).create().unwrap();
+
+        assert_that!(publisher.send_copy(12), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().filter_key(), eq 0);
+    }
+
+    #[test]
+    fn last_set_subscriber_filter_is_used<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut
+            .subscriber()
+            .filter_keys(&[5])
+            .filter_key_range(8..=9)
+            .create()
+            .unwrap();
+
+        assert_that!(send_with_filter_key(&publisher, 5, 5), eq 0);
+        assert_that!(send_with_filter_key(&publisher, 8, 8), eq 1);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 8);
+    }
+
+    #[test]
+    fn subscriber_with_too_many_filter_keys_cannot_be_created<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let keys: Vec<u64> = (0..MAX_NUMBER_OF_FILTER_KEYS as u64 + 1).collect();
+        let subscriber = sut.subscriber().filter_keys(&keys).create();
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::ExceedsMaxSupportedFilterKeys);
+
+        let subscriber = sut
+            .subscriber()
+            .filter_keys(&keys[..MAX_NUMBER_OF_FILTER_KEYS])
+            .create();
+        assert_that!(subscriber, is_ok);
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
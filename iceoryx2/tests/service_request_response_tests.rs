@@ -0,0 +1,724 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::config::Config;
+    use iceoryx2::port::client::{ClientCreateError, ClientLoanError};
+    use iceoryx2::port::server::{ServerCreateError, ServerSendError};
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            RequestResponseCreateError::AlreadyExists
+        );
+    }
+
+    #[test]
+    fn recreate_after_drop_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        drop(sut);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_succeeds_when_service_does_exist<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_request_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<i64, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleRequestType);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_response_type<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, i64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleResponseType);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(2)
+            .max_servers(2)
+            .max_active_requests(2)
+            .server_max_borrowed_requests(2)
+            .client_max_borrowed_responses(2)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(3)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_servers(3)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_active_requests(3)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .server_max_borrowed_requests(3)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinServerBorrowedRequests);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .client_max_borrowed_responses(3)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinClientBorrowedResponses);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_servers(1)
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_nodes(2)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_nodes(3)
+            .typed::<u64, u64>()
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_nodes(1)
+            .typed::<u64, u64>()
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_number_of_nodes_exceeds_max_nodes<Sut: Service>() {
+        let service_name = generate_name();
+        let node_1 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_3 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node_1
+            .service_builder(&service_name)
+            .request_response()
+            .max_nodes(2)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node_2
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node_3
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut3, is_err);
+        assert_that!(
+            sut3.err().unwrap(), eq
+            RequestResponseOpenError::ExceedsMaxNumberOfNodes
+        );
+
+        drop(sut2);
+
+        let sut3 = node_3
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_messaging_pattern_is_different<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .typed::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_create_creates_and_opens_service<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn settings_are_set_to_config_defaults<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let defaults = &Config::get_global_config().defaults;
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_supported_clients(), eq defaults.request_response.max_clients);
+        assert_that!(static_config.max_supported_servers(), eq defaults.request_response.max_servers);
+        assert_that!(static_config.max_active_requests(), eq defaults.request_response.max_active_requests);
+        assert_that!(static_config.server_max_borrowed_requests(), eq defaults.request_response.server_max_borrowed_requests);
+        assert_that!(static_config.client_max_borrowed_responses(), eq defaults.request_response.client_max_borrowed_responses);
+    }
+
+    #[test]
+    fn settings_set_to_zero_are_adjusted_to_one<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(0)
+            .max_servers(0)
+            .max_active_requests(0)
+            .server_max_borrowed_requests(0)
+            .client_max_borrowed_responses(0)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_supported_clients(), eq 1);
+        assert_that!(static_config.max_supported_servers(), eq 1);
+        assert_that!(static_config.max_active_requests(), eq 1);
+        assert_that!(static_config.server_max_borrowed_requests(), eq 1);
+        assert_that!(static_config.client_max_borrowed_responses(), eq 1);
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_works<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_CLIENTS: usize = 4;
+        const MAX_SERVERS: usize = 3;
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(MAX_CLIENTS)
+            .max_servers(MAX_SERVERS)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let mut clients = vec![];
+        for i in 0..MAX_CLIENTS {
+            clients.push(sut.client().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_clients(), eq i + 1);
+        }
+        let client = sut.client().create();
+        assert_that!(client.err().unwrap(), eq ClientCreateError::ExceedsMaxSupportedClients);
+
+        let mut servers = vec![];
+        for i in 0..MAX_SERVERS {
+            servers.push(sut.server().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_servers(), eq i + 1);
+        }
+        let server = sut.server().create();
+        assert_that!(server.err().unwrap(), eq ServerCreateError::ExceedsMaxSupportedServers);
+
+        clients.clear();
+        servers.clear();
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 0);
+    }
+
+    #[test]
+    fn simple_communication_works_client_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+        assert_that!(client.update_connections(), is_ok);
+
+        assert_that!(client.send_copy(1234), eq Ok(1));
+
+        let request = server.receive().unwrap();
+        assert_that!(request, is_some);
+        let request = request.unwrap();
+        assert_that!(*request, eq 1234);
+        assert_that!(request.origin(), eq client.id());
+        assert_that!(request.send_copy(*request + 1), is_ok);
+
+        let response = client.receive().unwrap();
+        assert_that!(response, is_some);
+        let response = response.unwrap();
+        assert_that!(*response, eq 1235);
+        assert_that!(response.origin(), eq server.id());
+        assert_that!(response.header().request_id(), eq request.header().request_id());
+    }
+
+    #[test]
+    fn simple_communication_works_server_created_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+        assert_that!(server.update_connections(), is_ok);
+
+        let request = client.loan_uninit().unwrap();
+        let request = request.write_payload(789);
+        assert_that!(request.send(), eq Ok(1));
+
+        let request = server.receive().unwrap().unwrap();
+        assert_that!(*request.payload(), eq 789);
+
+        let mut response = request.loan().unwrap();
+        *response.payload_mut() = 987;
+        assert_that!(response.send(), is_ok);
+
+        let response = client.receive().unwrap().unwrap();
+        assert_that!(*response.payload(), eq 987);
+    }
+
+    #[test]
+    fn request_ids_are_unique_per_client<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+        let request_1 = client.loan().unwrap();
+        let request_2 = client.loan().unwrap();
+
+        assert_that!(request_1.header().client_id(), eq client.id());
+        assert_that!(request_1.header().request_id(), ne request_2.header().request_id());
+    }
+
+    #[test]
+    fn responses_are_delivered_only_to_requesting_client<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(2)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client_1 = sut.client().create().unwrap();
+        let client_2 = sut.client().create().unwrap();
+
+        assert_that!(client_1.send_copy(1), eq Ok(1));
+        assert_that!(client_2.send_copy(2), eq Ok(1));
+
+        while let Some(request) = server.receive().unwrap() {
+            assert_that!(request.send_copy(*request * 10), is_ok);
+        }
+
+        let response = client_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 10);
+        assert_that!(client_1.receive().unwrap(), is_none);
+
+        let response = client_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 20);
+        assert_that!(client_2.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn requests_are_delivered_to_all_servers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_SERVERS: usize = 3;
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_servers(MAX_SERVERS)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+        let mut servers = vec![];
+        for _ in 0..MAX_SERVERS {
+            servers.push(sut.server().create().unwrap());
+        }
+
+        assert_that!(client.send_copy(42), eq Ok(MAX_SERVERS));
+
+        for server in &servers {
+            let request = server.receive().unwrap().unwrap();
+            assert_that!(*request, eq 42);
+            assert_that!(request.send_copy(*request + 1), is_ok);
+        }
+
+        for _ in 0..MAX_SERVERS {
+            assert_that!(client.receive().unwrap(), is_some);
+        }
+        assert_that!(client.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn client_cannot_loan_more_than_max_loaned_requests<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const MAX_LOANED_REQUESTS: usize = 3;
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut
+            .client()
+            .max_loaned_requests(MAX_LOANED_REQUESTS)
+            .create()
+            .unwrap();
+
+        let mut requests = vec![];
+        for _ in 0..MAX_LOANED_REQUESTS {
+            requests.push(client.loan().unwrap());
+        }
+
+        let request = client.loan();
+        assert_that!(request.err().unwrap(), eq ClientLoanError::ExceedsMaxLoanedRequests);
+
+        requests.pop();
+        assert_that!(client.loan(), is_ok);
+    }
+
+    #[test]
+    fn sending_response_to_disconnected_client_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+        assert_that!(client.send_copy(1), eq Ok(1));
+
+        let request = server.receive().unwrap().unwrap();
+        drop(client);
+        assert_that!(server.update_connections(), is_ok);
+
+        assert_that!(
+            request.send_copy(2).err().unwrap(), eq
+            ServerSendError::ConnectionBrokenSinceClientNoLongerExists
+        );
+    }
+
+    #[test]
+    fn client_reclaims_all_requests_after_server_disconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const RECONNECTIONS: usize = 20;
+        const MAX_SERVERS: usize = 4;
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_servers(MAX_SERVERS)
+            .max_active_requests(3)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+
+        for n in 0..MAX_SERVERS {
+            for _ in 0..RECONNECTIONS {
+                let mut servers = vec![];
+                for _ in 0..n {
+                    servers.push(sut.server().create().unwrap());
+                }
+
+                assert_that!(client.send_copy(1234), eq Ok(n));
+                assert_that!(client.send_copy(4567), eq Ok(n));
+                assert_that!(client.send_copy(789), eq Ok(n));
+                servers.clear();
+                assert_that!(client.send_copy(789), eq Ok(0));
+            }
+        }
+    }
+
+    #[test]
+    fn server_reclaims_all_responses_after_client_disconnect<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        const RECONNECTIONS: usize = 20;
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_servers(1)
+            .max_active_requests(2)
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+
+        for _ in 0..RECONNECTIONS {
+            let client = sut.client().create().unwrap();
+            assert_that!(client.send_copy(1), eq Ok(1));
+            assert_that!(client.send_copy(2), eq Ok(1));
+
+            while let Some(request) = server.receive().unwrap() {
+                assert_that!(request.send_copy(*request), is_ok);
+            }
+        }
+    }
+
+    #[test]
+    fn dropping_service_keeps_established_communication<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response()
+            .typed::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+        drop(sut);
+
+        assert_that!(client.send_copy(8192), eq Ok(1));
+        let request = server.receive().unwrap().unwrap();
+        assert_that!(*request, eq 8192);
+        assert_that!(request.send_copy(2918), is_ok);
+        assert_that!(*client.receive().unwrap().unwrap(), eq 2918);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -15,12 +15,18 @@ mod service {
     use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::Barrier;
 
+    use iceoryx2::config::Config;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::AttributeSet;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::service::builder::publish_subscribe::{
         PublishSubscribeCreateError, PublishSubscribeOpenError,
     };
-    use iceoryx2::service::port_factory::{event, publish_subscribe};
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
@@ -34,32 +40,68 @@ mod service {
         .unwrap()
     }
 
-    trait SutFactory {
+    trait SutFactory<Sut: Service> {
         type Factory;
         type CreateError: std::fmt::Debug;
         type OpenError: std::fmt::Debug;
 
-        fn create(service_name: &ServiceName) -> Result<Self::Factory, Self::CreateError>;
-        fn open(service_name: &ServiceName) -> Result<Self::Factory, Self::OpenError>;
+        fn create(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::CreateError>;
+        fn open(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::OpenError>;
+
+        fn create_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::CreateError>;
+        fn open_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::OpenError>;
+        fn attributes(factory: &Self::Factory) -> &AttributeSet;
 
         fn assert_create_error(error: Self::CreateError);
         fn assert_open_error(error: Self::OpenError);
+        fn assert_incompatible_attributes(error: Self::OpenError);
+    }
+
+    fn service_builder_with_attributes<Sut: Service>(
+        node: &Node<Sut>,
+        service_name: &ServiceName,
+        attributes: &[(&str, &str)],
+    ) -> iceoryx2::service::builder::Builder<Sut> {
+        attributes.iter().fold(
+            node.service_builder(service_name),
+            |builder, (key, value)| builder.attribute(key, value),
+        )
     }
 
-    impl<Sut: Service> SutFactory for publish_subscribe::PortFactory<Sut, u64> {
+    impl<Sut: Service> SutFactory<Sut> for publish_subscribe::PortFactory<Sut, u64> {
         type Factory = publish_subscribe::PortFactory<Sut, u64>;
         type CreateError = PublishSubscribeCreateError;
         type OpenError = PublishSubscribeOpenError;
 
-        fn create(service_name: &ServiceName) -> Result<Self::Factory, Self::CreateError> {
-            Sut::new(&service_name)
+        fn create(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
                 .publish_subscribe()
                 .typed::<u64>()
                 .create()
         }
 
-        fn open(service_name: &ServiceName) -> Result<Self::Factory, Self::OpenError> {
-            Sut::new(&service_name)
+        fn open(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name)
                 .publish_subscribe()
                 .typed::<u64>()
                 .open()
@@ -85,19 +127,55 @@ mod service {
                 ])
             );
         }
+
+        fn create_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::CreateError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .publish_subscribe()
+                .typed::<u64>()
+                .create()
+        }
+
+        fn open_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::OpenError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .publish_subscribe()
+                .typed::<u64>()
+                .open()
+        }
+
+        fn attributes(factory: &Self::Factory) -> &AttributeSet {
+            factory.attributes()
+        }
+
+        fn assert_incompatible_attributes(error: Self::OpenError) {
+            assert_that!(error, eq PublishSubscribeOpenError::IncompatibleAttributes);
+        }
     }
 
-    impl<Sut: Service> SutFactory for event::PortFactory<Sut> {
+    impl<Sut: Service> SutFactory<Sut> for event::PortFactory<Sut> {
         type Factory = event::PortFactory<Sut>;
         type CreateError = EventCreateError;
         type OpenError = EventOpenError;
 
-        fn create(service_name: &ServiceName) -> Result<Self::Factory, Self::CreateError> {
-            Sut::new(&service_name).event().create()
+        fn create(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name).event().create()
         }
 
-        fn open(service_name: &ServiceName) -> Result<Self::Factory, Self::OpenError> {
-            Sut::new(&service_name).event().open()
+        fn open(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name).event().open()
         }
 
         fn assert_create_error(error: Self::CreateError) {
@@ -120,19 +198,204 @@ mod service {
                 ])
             );
         }
+
+        fn create_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::CreateError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .event()
+                .create()
+        }
+
+        fn open_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::OpenError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .event()
+                .open()
+        }
+
+        fn attributes(factory: &Self::Factory) -> &AttributeSet {
+            factory.attributes()
+        }
+
+        fn assert_incompatible_attributes(error: Self::OpenError) {
+            assert_that!(error, eq EventOpenError::IncompatibleAttributes);
+        }
+    }
+
+    impl<Sut: Service> SutFactory<Sut> for request_response::PortFactory<Sut, u64, u64> {
+        type Factory = request_response::PortFactory<Sut, u64, u64>;
+        type CreateError = RequestResponseCreateError;
+        type OpenError = RequestResponseOpenError;
+
+        fn create(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .request_response()
+                .typed::<u64, u64>()
+                .create()
+        }
+
+        fn open(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name)
+                .request_response()
+                .typed::<u64, u64>()
+                .open()
+        }
+
+        fn assert_create_error(error: Self::CreateError) {
+            assert_that!(
+                error,
+                any_of([
+                    RequestResponseCreateError::AlreadyExists,
+                    RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                ])
+            );
+        }
+        fn assert_open_error(error: Self::OpenError) {
+            assert_that!(
+                error,
+                any_of([
+                    RequestResponseOpenError::DoesNotExist,
+                    RequestResponseOpenError::PermissionDenied,
+                    RequestResponseOpenError::ServiceInCorruptedState,
+                    RequestResponseOpenError::UnableToOpenDynamicServiceInformation,
+                ])
+            );
+        }
+
+        fn create_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::CreateError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .request_response()
+                .typed::<u64, u64>()
+                .create()
+        }
+
+        fn open_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::OpenError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .request_response()
+                .typed::<u64, u64>()
+                .open()
+        }
+
+        fn attributes(factory: &Self::Factory) -> &AttributeSet {
+            factory.attributes()
+        }
+
+        fn assert_incompatible_attributes(error: Self::OpenError) {
+            assert_that!(error, eq RequestResponseOpenError::IncompatibleAttributes);
+        }
+    }
+
+    impl<Sut: Service> SutFactory<Sut> for blackboard::PortFactory<Sut, u64> {
+        type Factory = blackboard::PortFactory<Sut, u64>;
+        type CreateError = BlackboardCreateError;
+        type OpenError = BlackboardOpenError;
+
+        fn create(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .blackboard::<u64>()
+                .add::<u64>(0, 0)
+                .create()
+        }
+
+        fn open(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name)
+                .blackboard::<u64>()
+                .open()
+        }
+
+        fn assert_create_error(error: Self::CreateError) {
+            assert_that!(
+                error,
+                any_of([
+                    BlackboardCreateError::AlreadyExists,
+                    BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                ])
+            );
+        }
+        fn assert_open_error(error: Self::OpenError) {
+            assert_that!(
+                error,
+                any_of([
+                    BlackboardOpenError::DoesNotExist,
+                    BlackboardOpenError::PermissionDenied,
+                    BlackboardOpenError::ServiceInCorruptedState,
+                    BlackboardOpenError::UnableToOpenDynamicServiceInformation,
+                ])
+            );
+        }
+
+        fn create_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::CreateError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .blackboard::<u64>()
+                .add::<u64>(0, 0)
+                .create()
+        }
+
+        fn open_with_attributes(
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &[(&str, &str)],
+        ) -> Result<Self::Factory, Self::OpenError> {
+            service_builder_with_attributes(node, service_name, attributes)
+                .blackboard::<u64>()
+                .open()
+        }
+
+        fn attributes(factory: &Self::Factory) -> &AttributeSet {
+            factory.attributes()
+        }
+
+        fn assert_incompatible_attributes(error: Self::OpenError) {
+            assert_that!(error, eq BlackboardOpenError::IncompatibleAttributes);
+        }
     }
 
     #[test]
-    fn same_name_with_different_messaging_pattern_is_allowed<Sut: Service, Factory: SutFactory>() {
+    fn same_name_with_different_messaging_pattern_is_allowed<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service_name = generate_name();
-        let sut_pub_sub = Sut::new(&service_name)
+        let sut_pub_sub = node
+            .service_builder(&service_name)
             .publish_subscribe()
             .typed::<u64>()
             .create();
         assert_that!(sut_pub_sub, is_ok);
         let sut_pub_sub = sut_pub_sub.unwrap();
 
-        let sut_event = Sut::new(&service_name).event().create();
+        let sut_event = node.service_builder(&service_name).event().create();
         assert_that!(sut_event, is_ok);
         let sut_event = sut_event.unwrap();
 
@@ -156,7 +419,7 @@ mod service {
     #[test]
     fn concurent_creating_services_with_unique_names_is_successful<
         Sut: Service,
-        Factory: SutFactory,
+        Factory: SutFactory<Sut>,
     >() {
         let _watch_dog = Watchdog::new();
         let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 1024) * 2;
@@ -169,11 +432,12 @@ mod service {
             let mut threads = vec![];
             for _ in 0..number_of_threads {
                 threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().create::<Sut>().unwrap();
                     for _ in 0..NUMBER_OF_ITERATIONS {
                         let service_name = generate_name();
                         barrier_enter.wait();
 
-                        let _sut = Factory::create(&service_name).unwrap();
+                        let _sut = Factory::create(&node, &service_name).unwrap();
 
                         barrier_exit.wait();
                     }
@@ -189,7 +453,7 @@ mod service {
     #[test]
     fn concurrent_creating_services_with_same_name_fails_for_all_but_one<
         Sut: Service,
-        Factory: SutFactory,
+        Factory: SutFactory<Sut>,
     >() {
         let _watch_dog = Watchdog::new();
         let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 1024) * 2;
@@ -204,10 +468,11 @@ mod service {
             let mut threads = vec![];
             for _ in 0..number_of_threads {
                 threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().create::<Sut>().unwrap();
                     for _ in 0..NUMBER_OF_ITERATIONS {
                         barrier_enter.wait();
 
-                        let sut = Factory::create(&service_name);
+                        let sut = Factory::create(&node, &service_name);
                         match sut {
                             Ok(_) => {
                                 success_counter.fetch_add(1, Ordering::Relaxed);
@@ -236,7 +501,7 @@ mod service {
     #[test]
     fn concurrent_opening_and_closing_services_with_same_name_is_handled_gracefully<
         Sut: Service,
-        Factory: SutFactory,
+        Factory: SutFactory<Sut>,
     >() {
         let _watch_dog = Watchdog::new();
         const NUMBER_OF_CLOSE_THREADS: usize = 1;
@@ -253,8 +518,9 @@ mod service {
         std::thread::scope(|s| {
             let mut threads = vec![];
             threads.push(s.spawn(|| {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
                 for service_name in service_names {
-                    let sut = Factory::create(&service_name).unwrap();
+                    let sut = Factory::create(&node, service_name).unwrap();
                     barrier_enter.wait();
 
                     drop(sut);
@@ -265,10 +531,11 @@ mod service {
 
             for _ in 0..number_of_open_threads {
                 threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().create::<Sut>().unwrap();
                     for service_name in service_names {
                         barrier_enter.wait();
 
-                        let sut = Factory::open(&service_name);
+                        let sut = Factory::open(&node, service_name);
                         match sut {
                             Ok(_) => (),
                             Err(e) => {
@@ -287,25 +554,142 @@ mod service {
         });
     }
 
+    #[test]
+    fn service_attributes_are_stored_in_static_config<Sut: Service, Factory: SutFactory<Sut>>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let attributes = [("owner", "team-fuu"), ("schema_version", "3")];
+
+        let sut = Factory::create_with_attributes(&node, &service_name, &attributes).unwrap();
+        assert_that!(Factory::attributes(&sut).len(), eq 2);
+        assert_that!(Factory::attributes(&sut).get("owner"), eq Some("team-fuu"));
+        assert_that!(Factory::attributes(&sut).get("schema_version"), eq Some("3"));
+        assert_that!(Factory::attributes(&sut).get("units"), eq None);
+
+        let sut2 = Factory::open(&node, &service_name).unwrap();
+        assert_that!(Factory::attributes(&sut2), eq Factory::attributes(&sut));
+
+        let services = Sut::list().unwrap();
+        let service = services
+            .iter()
+            .find(|s| *s.service_name() == service_name)
+            .unwrap();
+        assert_that!(service.attributes(), eq Factory::attributes(&sut));
+        assert_that!(
+            service.attributes().iter().collect::<Vec<_>>(),
+            eq attributes.to_vec()
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_have_required_attributes<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let _sut = Factory::create_with_attributes(
+            &node,
+            &service_name,
+            &[("owner", "team-fuu"), ("schema_version", "3")],
+        )
+        .unwrap();
+
+        let sut2 = Factory::open_with_attributes(&node, &service_name, &[("schema_version", "4")]);
+        assert_that!(sut2, is_err);
+        Factory::assert_incompatible_attributes(sut2.err().unwrap());
+
+        let sut2 = Factory::open_with_attributes(&node, &service_name, &[("units", "m/s")]);
+        assert_that!(sut2, is_err);
+        Factory::assert_incompatible_attributes(sut2.err().unwrap());
+
+        let sut2 = Factory::open_with_attributes(&node, &service_name, &[("schema_version", "3")]);
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn remove_orphaned_services_keeps_active_services<Sut: Service, Factory: SutFactory<Sut>>() {
+        let mut config = Config::default();
+        config.global.service.directory =
+            format!("service_tests_{}", UniqueSystemId::new().unwrap().value());
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = Factory::create(&node, &service_name);
+        assert_that!(sut, is_ok);
+
+        let removed_services = Sut::remove_orphaned_services_with_custom_config(&config);
+        assert_that!(removed_services, is_ok);
+        assert_that!(removed_services.unwrap(), len 0);
+
+        assert_that!(Sut::does_exist_with_custom_config(&service_name, &config), eq Ok(true));
+        assert_that!(Factory::open(&node, &service_name), is_ok);
+    }
+
+    #[test]
+    fn remove_orphaned_services_removes_only_services_without_dynamic_config<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let mut config = Config::default();
+        config.global.service.directory =
+            format!("service_tests_{}", UniqueSystemId::new().unwrap().value());
+        // the dynamic configs of services created with this config are not visible to `config`
+        let mut orphan_config = config.clone();
+        orphan_config.global.service.dynamic_config_storage_suffix = ".orphaned_dynamic".into();
+
+        let orphan_node = NodeBuilder::new()
+            .config(&orphan_config)
+            .create::<Sut>()
+            .unwrap();
+        let orphaned_service_name = generate_name();
+        let _orphaned_service = Factory::create(&orphan_node, &orphaned_service_name).unwrap();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _service = Factory::create(&node, &service_name).unwrap();
+
+        let removed_services = Sut::remove_orphaned_services_with_custom_config(&config).unwrap();
+        assert_that!(removed_services, len 1);
+        assert_that!(*removed_services[0].service_name(), eq orphaned_service_name);
+
+        let remaining_services = Sut::list_with_custom_config(&config).unwrap();
+        assert_that!(remaining_services, len 1);
+        assert_that!(*remaining_services[0].service_name(), eq service_name);
+        assert_that!(Factory::open(&node, &service_name), is_ok);
+    }
+
     mod zero_copy {
+        use iceoryx2::service::port_factory::blackboard::PortFactory as BlackboardPortFactory;
         use iceoryx2::service::port_factory::event::PortFactory as EventPortFactory;
         use iceoryx2::service::port_factory::publish_subscribe::PortFactory as PubSubPortFactory;
+        use iceoryx2::service::port_factory::request_response::PortFactory as ReqResPortFactory;
         use iceoryx2::service::zero_copy::Service;
 
         #[instantiate_tests(<Service, EventPortFactory::<Service>>)]
         mod event {}
         #[instantiate_tests(<Service, PubSubPortFactory::<Service, u64>>)]
         mod publish_subscribe {}
+        #[instantiate_tests(<Service, ReqResPortFactory::<Service, u64, u64>>)]
+        mod request_response {}
+        #[instantiate_tests(<Service, BlackboardPortFactory::<Service, u64>>)]
+        mod blackboard {}
     }
 
     mod process_local {
+        use iceoryx2::service::port_factory::blackboard::PortFactory as BlackboardPortFactory;
         use iceoryx2::service::port_factory::event::PortFactory as EventPortFactory;
         use iceoryx2::service::port_factory::publish_subscribe::PortFactory as PubSubPortFactory;
+        use iceoryx2::service::port_factory::request_response::PortFactory as ReqResPortFactory;
         use iceoryx2::service::process_local::Service;
 
         #[instantiate_tests(<Service, EventPortFactory::<Service>>)]
         mod event {}
         #[instantiate_tests(<Service, PubSubPortFactory::<Service, u64>>)]
         mod publish_subscribe {}
+        #[instantiate_tests(<Service, ReqResPortFactory::<Service, u64, u64>>)]
+        mod request_response {}
+        #[instantiate_tests(<Service, BlackboardPortFactory::<Service, u64>>)]
+        mod blackboard {}
     }
 }
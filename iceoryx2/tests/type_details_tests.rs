@@ -0,0 +1,64 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::static_config::type_details::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn type_details_from_type_describes_type() {
+    let sut = TypeDetails::from_type::<u32>();
+
+    assert_that!(sut.variant(), eq TypeVariant::FixedSize);
+    assert_that!(sut.type_name(), eq core::any::type_name::<u32>());
+    assert_that!(sut.size(), eq 4);
+    assert_that!(sut.alignment(), eq 4);
+}
+
+#[test]
+fn type_details_from_slice_describes_element_type() {
+    let sut = TypeDetails::from_slice::<u64>();
+
+    assert_that!(sut.variant(), eq TypeVariant::Dynamic);
+    assert_that!(sut.type_name(), eq core::any::type_name::<[u64]>());
+    assert_that!(sut.size(), eq 8);
+    assert_that!(sut.alignment(), eq 8);
+}
+
+#[test]
+fn type_details_with_valid_layout_can_be_created() {
+    let sut = TypeDetails::new(TypeVariant::Dynamic, "MyFancyType", 24, 8).unwrap();
+
+    assert_that!(sut.variant(), eq TypeVariant::Dynamic);
+    assert_that!(sut.type_name(), eq "MyFancyType");
+    assert_that!(sut.size(), eq 24);
+    assert_that!(sut.alignment(), eq 8);
+}
+
+#[test]
+fn type_details_with_invalid_alignment_cannot_be_created() {
+    assert_that!(
+        TypeDetails::new(TypeVariant::FixedSize, "x", 8, 3).err(), eq
+        Some(TypeDetailsError::AlignmentIsNotAPowerOfTwo)
+    );
+    assert_that!(
+        TypeDetails::new(TypeVariant::FixedSize, "x", 8, 0).err(), eq
+        Some(TypeDetailsError::AlignmentIsNotAPowerOfTwo)
+    );
+}
+
+#[test]
+fn type_details_with_size_not_multiple_of_alignment_cannot_be_created() {
+    assert_that!(
+        TypeDetails::new(TypeVariant::Dynamic, "x", 6, 4).err(), eq
+        Some(TypeDetailsError::SizeIsNotAMultipleOfAlignment)
+    );
+}
@@ -0,0 +1,403 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod waitset {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::port::waitset::{WaitSetAttachmentError, WaitSetBuilder, WaitSetWaitError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::event::PortFactory;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const INFINITE_TIMEOUT: Duration = Duration::from_secs(3600);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_event<Sut: Service>(node: &Node<Sut>) -> PortFactory<Sut> {
+        node.service_builder(&generate_name())
+            .event()
+            .max_listeners(2)
+            .create()
+            .unwrap()
+    }
+
+    fn create_pair<Sut: Service>(event: &PortFactory<Sut>) -> (Listener<Sut>, Notifier<Sut>) {
+        (
+            event.listener().create().unwrap(),
+            event.notifier().create().unwrap(),
+        )
+    }
+
+    #[test]
+    fn create_works<Sut: Service>() {
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.capacity(), gt 0);
+    }
+
+    #[test]
+    fn waiting_without_attachments_fails<Sut: Service>() {
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+
+        assert_that!(sut.try_wait(|_| {}), eq Err(WaitSetWaitError::NoAttachments));
+        assert_that!(sut.timed_wait(|_| {}, TIMEOUT), eq Err(WaitSetWaitError::NoAttachments));
+        assert_that!(sut.blocking_wait(|_| {}), eq Err(WaitSetWaitError::NoAttachments));
+    }
+
+    #[test]
+    fn attach_and_detach_works<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener_1, _notifier_1) = create_pair(&event);
+        let (listener_2, _notifier_2) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        assert_that!(sut, len 1);
+        let guard_2 = sut.attach_deadline(&listener_2, TIMEOUT).unwrap();
+        assert_that!(sut, len 2);
+        let guard_3 = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(sut, len 3);
+
+        drop(guard_2);
+        assert_that!(sut, len 2);
+        drop(guard_1);
+        assert_that!(sut, len 1);
+        drop(guard_3);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn attaching_same_attachment_twice_fails<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, _notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let result = sut.attach_notification(&listener);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        let result = sut.attach_deadline(&listener, TIMEOUT);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        assert_that!(sut, len 1);
+    }
+
+    #[test]
+    fn attaching_zero_timeouts_fails<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, _notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+
+        let result = sut.attach_deadline(&listener, Duration::ZERO);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::InvalidTimeout));
+        let result = sut.attach_interval(Duration::ZERO);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::InvalidTimeout));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn try_wait_does_not_block_without_events<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, _notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let mut counter = 0;
+        assert_that!(sut.try_wait(|_| counter += 1), is_ok);
+        assert_that!(counter, eq 0);
+    }
+
+    #[test]
+    fn timed_wait_blocks_until_timeout_without_events<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, _notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let mut counter = 0;
+        let start = Instant::now();
+        assert_that!(sut.timed_wait(|_| counter += 1, TIMEOUT), is_ok);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(counter, eq 0);
+    }
+
+    #[test]
+    fn notification_wakes_up_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener_1, notifier_1) = create_pair(&event);
+        let (listener_2, _notifier_2) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+
+        // notifiers are connected to all listeners of a service, therefore the second
+        // listener must consume its events as well
+        notifier_1
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+
+        let mut event_ids = vec![];
+        let mut wakeups_1 = 0;
+        let mut wakeups_2 = 0;
+        let result = sut.timed_wait(
+            |attachment_id| {
+                if attachment_id.has_event_from(&guard_1) {
+                    wakeups_1 += 1;
+                    listener_1.try_wait_all(|id| event_ids.push(id)).unwrap();
+                } else if attachment_id.has_event_from(&guard_2) {
+                    wakeups_2 += 1;
+                    listener_2.try_wait_all(|_| {}).unwrap();
+                }
+            },
+            INFINITE_TIMEOUT,
+        );
+
+        assert_that!(result, is_ok);
+        assert_that!(wakeups_1, eq 1);
+        assert_that!(wakeups_2, eq 1);
+        assert_that!(event_ids, eq vec![EventId::new(7)]);
+
+        let mut counter = 0;
+        assert_that!(sut.try_wait(|_| counter += 1), is_ok);
+        assert_that!(counter, eq 0);
+    }
+
+    #[test]
+    fn subscriber_wakes_up_waitset_when_samples_arrived<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const NUMBER_OF_SAMPLES: u64 = 3;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .typed::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let subscriber = pubsub.subscriber().create().unwrap();
+        let (listener, notifier) = create_pair(&event);
+        let publisher = pubsub.publisher().create().unwrap();
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = sut.attach_subscriber(&subscriber, &listener).unwrap();
+        assert_that!(sut, len 1);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+            notifier.notify().unwrap();
+        }
+
+        let mut received_samples = vec![];
+        let result = sut.timed_wait(
+            |attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    while let Some(sample) = subscriber.receive().unwrap() {
+                        received_samples.push(*sample);
+                    }
+                }
+            },
+            INFINITE_TIMEOUT,
+        );
+
+        assert_that!(result, is_ok);
+        assert_that!(received_samples, eq vec![0, 1, 2]);
+
+        // the notifications of the listener were collected with the samples
+        let mut counter = 0;
+        assert_that!(sut.try_wait(|_| counter += 1), is_ok);
+        assert_that!(counter, eq 0);
+
+        drop(guard);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn only_attachments_with_events_wake_up_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event_1 = create_event(&node);
+        let event_2 = create_event(&node);
+        let (listener_1, _notifier_1) = create_pair(&event_1);
+        let (listener_2, notifier_2) = create_pair(&event_2);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+
+        notifier_2.notify().unwrap();
+
+        let mut wakeups_1 = 0;
+        let mut wakeups_2 = 0;
+        let result = sut.blocking_wait(|attachment_id| {
+            if attachment_id.has_event_from(&guard_1) {
+                wakeups_1 += 1;
+            } else if attachment_id.has_event_from(&guard_2) {
+                wakeups_2 += 1;
+            }
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(wakeups_1, eq 0);
+        assert_that!(wakeups_2, eq 1);
+    }
+
+    #[test]
+    fn interval_wakes_up_waitset<Sut: Service>() {
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        for _ in 0..2 {
+            let mut ticks = 0;
+            let start = Instant::now();
+            let result = sut.blocking_wait(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    ticks += 1;
+                }
+            });
+
+            assert_that!(result, is_ok);
+            assert_that!(start.elapsed(), ge TIMEOUT);
+            assert_that!(ticks, eq 1);
+        }
+    }
+
+    #[test]
+    fn missed_deadline_wakes_up_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, _notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+        let mut missed_deadlines = 0;
+        let mut events = 0;
+        let start = Instant::now();
+        let result = sut.blocking_wait(|attachment_id| {
+            if attachment_id.has_missed_deadline(&guard) {
+                missed_deadlines += 1;
+            } else if attachment_id.has_event_from(&guard) {
+                events += 1;
+            }
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(missed_deadlines, eq 1);
+        assert_that!(events, eq 0);
+    }
+
+    #[test]
+    fn event_within_deadline_does_not_report_missed_deadline<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener, notifier) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = sut.attach_deadline(&listener, INFINITE_TIMEOUT).unwrap();
+
+        notifier.notify().unwrap();
+
+        let mut missed_deadlines = 0;
+        let mut events = 0;
+        let result = sut.timed_wait(
+            |attachment_id| {
+                if attachment_id.has_missed_deadline(&guard) {
+                    missed_deadlines += 1;
+                } else if attachment_id.has_event_from(&guard) {
+                    events += 1;
+                    listener.try_wait_all(|_| {}).unwrap();
+                }
+            },
+            TIMEOUT,
+        );
+
+        assert_that!(result, is_ok);
+        assert_that!(missed_deadlines, eq 0);
+        assert_that!(events, eq 1);
+    }
+
+    #[test]
+    fn detached_attachment_does_not_wake_up_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node);
+        let (listener_1, notifier_1) = create_pair(&event);
+        let sut = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let _guard_2 = sut.attach_interval(INFINITE_TIMEOUT).unwrap();
+        drop(guard_1);
+
+        notifier_1.notify().unwrap();
+
+        let mut counter = 0;
+        let start = Instant::now();
+        assert_that!(sut.timed_wait(|_| counter += 1, TIMEOUT), is_ok);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(counter, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy_socket::Service>)]
+    mod zero_copy_socket {}
+}